@@ -43,6 +43,7 @@ use common_meta_types::MatchSeq;
 use common_meta_types::Operation;
 use common_meta_types::TxnRequest;
 use common_tracing::init_logging;
+use common_tracing::AuditLogConfig;
 use common_tracing::FileConfig;
 use common_tracing::QueryLogConfig;
 use common_tracing::StderrConfig;
@@ -100,6 +101,7 @@ async fn main() {
             on: false,
             dir: "./.databend/logs/query-details".to_string(),
         },
+        audit: AuditLogConfig::default(),
         tracing: TracingConfig {
             on: false,
             capture_log_level: "TRACE".to_string(),
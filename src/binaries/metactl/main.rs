@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod grpc;
+use common_tracing::AuditLogConfig;
 use common_tracing::QueryLogConfig;
 use common_tracing::TracingConfig;
 use grpc::export_meta;
@@ -58,6 +59,23 @@ pub struct Config {
     #[clap(long)]
     pub export: bool,
 
+    /// Restore a node from the backups uploaded by databend-meta.
+    #[clap(long)]
+    pub restore: bool,
+
+    /// The storage type of the backups to restore from, e.g., `fs` or `s3`.
+    #[clap(long, default_value = "")]
+    pub backup_storage_type: String,
+
+    /// The params to build the backup storage, in form of `<key>=<value>`.
+    #[clap(long)]
+    pub backup_storage_params: Vec<String>,
+
+    /// Restore to the state right after applying the raft log at this index.
+    /// If it is absent, restore to the latest backed up raft log.
+    #[clap(long)]
+    pub restore_to_index: Option<u64>,
+
     #[clap(
         long,
         env = "METASRV_GRPC_API_ADDRESS",
@@ -123,6 +141,7 @@ async fn main() -> anyhow::Result<()> {
         },
         stderr: StderrConfig::default(),
         query: QueryLogConfig::default(),
+        audit: AuditLogConfig::default(),
         tracing: TracingConfig::default(),
     };
 
@@ -189,6 +208,12 @@ async fn main() -> anyhow::Result<()> {
         return snapshot::import_data(&config).await;
     }
 
+    if config.restore {
+        eprintln!();
+        eprintln!("Restore:");
+        return snapshot::restore_data(&config).await;
+    }
+
     Err(anyhow::anyhow!("Nothing to do"))
 }
 
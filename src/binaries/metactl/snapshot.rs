@@ -19,7 +19,6 @@ use std::fs::File;
 use std::io;
 use std::io::BufRead;
 use std::io::BufReader;
-use std::io::Lines;
 use std::io::Write;
 use std::net::SocketAddr;
 use std::net::ToSocketAddrs;
@@ -55,6 +54,9 @@ use common_meta_types::Membership;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
 use common_meta_types::StoredMembership;
+use databend_meta::backup::load_backup;
+use databend_meta::backup::new_backup_operator;
+use databend_meta::configs::BackupConfig;
 use databend_meta::store::RaftStore;
 use databend_meta::store::StoreInner;
 use futures::TryStreamExt;
@@ -94,12 +96,48 @@ pub async fn import_data(config: &Config) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Restore a node from the backups in an object storage.
+///
+/// It loads the latest full backup before `restore_to_index` and the subsequent raft logs,
+/// then imports them the same way as `import_data()`.
+pub async fn restore_data(config: &Config) -> anyhow::Result<()> {
+    let raft_dir = config.raft_dir.clone().unwrap_or_default();
+    eprintln!("    Into Meta Dir: '{}'", raft_dir);
+
+    let backup_config = BackupConfig {
+        storage_type: config.backup_storage_type.clone(),
+        storage_params: config.backup_storage_params.clone(),
+        ..Default::default()
+    };
+    if !backup_config.is_enabled() {
+        return Err(anyhow!("--backup-storage-type is required to restore"));
+    }
+    let op = new_backup_operator(&backup_config)?;
+
+    let nodes = build_nodes(config.initial_cluster.clone(), config.id)?;
+
+    let lines = load_backup(&op, config.restore_to_index).await?;
+    eprintln!("    Loaded {} records from backup", lines.len());
+
+    init_sled_db(raft_dir.clone());
+
+    clear(config)?;
+    let max_log_id = import_lines(config, lines.into_iter().map(Ok)).await?;
+    upgrade(config).await?;
+
+    if config.initial_cluster.is_empty() {
+        return Ok(());
+    }
+
+    init_new_cluster(config, nodes, max_log_id, config.id).await?;
+    Ok(())
+}
+
 /// Import from lines of exported data and Return the max log id that is found.
-async fn import_lines<B: BufRead + 'static>(
+async fn import_lines(
     config: &Config,
-    lines: Lines<B>,
+    lines: impl IntoIterator<Item = Result<String, io::Error>>,
 ) -> anyhow::Result<Option<LogId>> {
-    #[allow(clippy::useless_conversion)]
     let mut it = lines.into_iter().peekable();
     let first = it
         .peek()
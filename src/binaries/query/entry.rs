@@ -30,6 +30,7 @@ use common_tracing::set_panic_hook;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::compaction::CompactionScheduler;
 use databend_query::local;
 use databend_query::metrics::MetricService;
 use databend_query::servers::FlightSQLServer;
@@ -240,6 +241,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
     println!("    file: {}", conf.log.file);
     println!("    stderr: {}", conf.log.stderr);
     println!("    query: {}", conf.log.query);
+    println!("    audit: {}", conf.log.audit);
     println!("    tracing: {}", conf.log.tracing);
     println!(
         "Meta: {}",
@@ -341,6 +343,13 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         start_time.elapsed().as_secs_f32()
     );
 
+    let compaction_scheduler = if conf.query.enable_compaction_scheduler {
+        println!("Start compaction scheduler");
+        Some(CompactionScheduler::start(conf))
+    } else {
+        None
+    };
+
     if conf.background.enable {
         println!("Start background service");
         get_background_service_handler().start().await?;
@@ -349,6 +358,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
     } else {
         shutdown_handle.wait_for_termination_request().await;
     }
+    if let Some(compaction_scheduler) = compaction_scheduler {
+        compaction_scheduler.shutdown().await;
+    }
     info!("Shutdown server.");
     Ok(())
 }
@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use parquet2::compression::CompressionOptions;
 use parquet2::error::Error as ParquetError;
 use parquet2::schema::types::ParquetType;
 use parquet2::write::Compressor;
@@ -43,16 +44,36 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     encodings: Vec<Vec<Encoding>>,
     fields: Vec<ParquetType>,
     options: WriteOptions,
+) -> RowGroupIter<'static, Error> {
+    let compressions = vec![options.compression; fields.len()];
+    row_group_iter_with_compressions(chunk, encodings, fields, options, compressions)
+}
+
+/// Same as [`row_group_iter`], but compresses the pages of each field with the
+/// compression of the same index in `compressions`, instead of `options.compression`.
+/// # Panics
+/// Iff
+/// * `encodings.len() != fields.len()` or
+/// * `encodings.len() != chunk.arrays().len()` or
+/// * `compressions.len() != fields.len()`
+pub fn row_group_iter_with_compressions<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Vec<Encoding>>,
+    fields: Vec<ParquetType>,
+    options: WriteOptions,
+    compressions: Vec<CompressionOptions>,
 ) -> RowGroupIter<'static, Error> {
     assert_eq!(encodings.len(), fields.len());
     assert_eq!(encodings.len(), chunk.arrays().len());
+    assert_eq!(compressions.len(), fields.len());
     DynIter::new(
         chunk
             .into_arrays()
             .into_iter()
             .zip(fields)
             .zip(encodings)
-            .flat_map(move |((array, type_), encoding)| {
+            .zip(compressions)
+            .flat_map(move |(((array, type_), encoding), compression)| {
                 let encoded_columns = array_to_columns(array, type_, options, &encoding).unwrap();
                 encoded_columns
                     .into_iter()
@@ -65,8 +86,8 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
                                 .map(|x| x.map_err(|e| ParquetError::OutOfSpec(e.to_string()))),
                         );
 
-                        let compressed_pages = Compressor::new(pages, options.compression, vec![])
-                            .map_err(Error::from);
+                        let compressed_pages =
+                            Compressor::new(pages, compression, vec![]).map_err(Error::from);
                         Ok(DynStreamingIterator::new(compressed_pages))
                     })
                     .collect::<Vec<_>>()
@@ -82,6 +103,7 @@ pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Re
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Vec<Encoding>>,
+    compressions: Option<Vec<CompressionOptions>>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
@@ -109,9 +131,24 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
             options,
             parquet_schema,
             encodings,
+            compressions: None,
         })
     }
 
+    /// Sets the compression of each field, which overrides the compression of the options.
+    ///
+    /// # Errors
+    /// Iff the length of the compressions is different from the number of fields in schema
+    pub fn with_compressions(mut self, compressions: Vec<CompressionOptions>) -> Result<Self> {
+        if compressions.len() != self.encodings.len() {
+            return Err(Error::InvalidArgumentError(
+                "The number of compressions must equal the number of fields".to_string(),
+            ));
+        }
+        self.compressions = Some(compressions);
+        Ok(self)
+    }
+
     /// Returns the [`SchemaDescriptor`] of the [`RowGroupIterator`].
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
@@ -135,11 +172,16 @@ impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chun
                 ));
             };
             let encodings = self.encodings.clone();
-            Ok(row_group_iter(
+            let compressions = self
+                .compressions
+                .clone()
+                .unwrap_or_else(|| vec![options.compression; encodings.len()]);
+            Ok(row_group_iter_with_compressions(
                 chunk,
                 encodings,
                 self.parquet_schema.fields().to_vec(),
                 options,
+                compressions,
             ))
         })
     }
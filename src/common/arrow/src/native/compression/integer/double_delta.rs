@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use byteorder::LittleEndian;
+use byteorder::ReadBytesExt;
+
+use super::compress_sample_ratio;
+use super::IntegerCompression;
+use super::IntegerStats;
+use super::IntegerType;
+use crate::arrow::array::PrimitiveArray;
+use crate::arrow::error::Error;
+use crate::arrow::error::Result;
+use crate::native::compression::get_bits_needed;
+use crate::native::compression::Compression;
+use crate::native::compression::SAMPLE_COUNT;
+use crate::native::compression::SAMPLE_SIZE;
+use crate::native::write::WriteOptions;
+
+// number of the delta of deltas packed with the same bit width
+const BLOCK_LEN: usize = 128;
+
+/// Encodes the differences between the consecutive deltas of the values, which are zero for
+/// values increasing by a constant step, such as the timestamps of periodic events.
+///
+/// The first value and the first delta are written as is, then the zigzag encoded delta of
+/// deltas are bitpacked by blocks of `BLOCK_LEN`, each block led by its bit width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DoubleDelta {}
+
+impl<T: IntegerType> IntegerCompression<T> for DoubleDelta {
+    fn compress(
+        &self,
+        array: &PrimitiveArray<T>,
+        _stats: &IntegerStats<T>,
+        _write_options: &WriteOptions,
+        output: &mut Vec<u8>,
+    ) -> Result<usize> {
+        let start = output.len();
+        let values = array.values();
+        if let Some(first) = values.first() {
+            output.extend_from_slice(&first.as_i64().to_le_bytes());
+        }
+        if values.len() > 1 {
+            let delta = values[1].as_i64().wrapping_sub(values[0].as_i64());
+            output.extend_from_slice(&delta.to_le_bytes());
+        }
+
+        let dods = values
+            .windows(3)
+            .map(|w| {
+                let delta = w[2].as_i64().wrapping_sub(w[1].as_i64());
+                let prev_delta = w[1].as_i64().wrapping_sub(w[0].as_i64());
+                zigzag_encode(delta.wrapping_sub(prev_delta))
+            })
+            .collect::<Vec<_>>();
+        for block in dods.chunks(BLOCK_LEN) {
+            let num_bits = block.iter().map(|v| get_bits_needed(*v)).max().unwrap_or(0);
+            output.push(num_bits as u8);
+            pack(block, num_bits, output);
+        }
+
+        Ok(output.len() - start)
+    }
+
+    fn decompress(&self, mut input: &[u8], length: usize, output: &mut Vec<T>) -> Result<()> {
+        output.reserve(length);
+        if length == 0 {
+            return Ok(());
+        }
+
+        let mut value = input.read_i64::<LittleEndian>()?;
+        output.push(T::from_i64(value));
+        if length == 1 {
+            return Ok(());
+        }
+        let mut delta = input.read_i64::<LittleEndian>()?;
+        value = value.wrapping_add(delta);
+        output.push(T::from_i64(value));
+
+        let mut remaining = length - 2;
+        while remaining > 0 {
+            let len = remaining.min(BLOCK_LEN);
+            let num_bits = input.read_u8()? as u32;
+            if num_bits > 64 {
+                return Err(Error::OutOfSpec(format!(
+                    "invalid bit width {num_bits} of double delta block"
+                )));
+            }
+            input = unpack(input, len, num_bits, |dod| {
+                delta = delta.wrapping_add(zigzag_decode(dod));
+                value = value.wrapping_add(delta);
+                output.push(T::from_i64(value));
+            })?;
+            remaining -= len;
+        }
+        Ok(())
+    }
+
+    fn to_compression(&self) -> Compression {
+        Compression::DoubleDelta
+    }
+
+    fn compress_ratio(&self, stats: &IntegerStats<T>) -> f64 {
+        // the values are encoded as i64, wider types can not be restored.
+        if std::mem::size_of::<T>() > 8 {
+            return 0.0f64;
+        }
+        compress_sample_ratio(self, stats, SAMPLE_COUNT, SAMPLE_SIZE)
+    }
+}
+
+#[inline]
+fn zigzag_encode(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+#[inline]
+fn zigzag_decode(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+fn pack(values: &[u64], num_bits: u32, output: &mut Vec<u8>) {
+    let mut buffer = 0u128;
+    let mut filled = 0u32;
+    for v in values {
+        buffer |= (*v as u128) << filled;
+        filled += num_bits;
+        while filled >= 8 {
+            output.push(buffer as u8);
+            buffer >>= 8;
+            filled -= 8;
+        }
+    }
+    if filled > 0 {
+        output.push(buffer as u8);
+    }
+}
+
+fn unpack(input: &[u8], len: usize, num_bits: u32, mut f: impl FnMut(u64)) -> Result<&[u8]> {
+    let size = (len * num_bits as usize).div_ceil(8);
+    if input.len() < size {
+        return Err(Error::OutOfSpec(
+            "double delta block is truncated".to_string(),
+        ));
+    }
+    let mask = match num_bits {
+        64 => u64::MAX,
+        n => (1u64 << n) - 1,
+    };
+
+    let mut bytes = input[..size].iter();
+    let mut buffer = 0u128;
+    let mut filled = 0u32;
+    for _ in 0..len {
+        while filled < num_bits {
+            buffer |= (*bytes.next().unwrap() as u128) << filled;
+            filled += 8;
+        }
+        f(buffer as u64 & mask);
+        buffer >>= num_bits;
+        filled -= num_bits;
+    }
+    Ok(&input[size..])
+}
@@ -15,6 +15,7 @@
 mod bp;
 mod delta_bp;
 mod dict;
+mod double_delta;
 mod freq;
 mod one_value;
 mod rle;
@@ -30,6 +31,7 @@ use self::delta_bp::DeltaBitpacking;
 pub use self::dict::Dict;
 pub use self::dict::DictEncoder;
 pub use self::dict::RawNative;
+pub use self::double_delta::DoubleDelta;
 pub use self::freq::Freq;
 pub use self::one_value::OneValue;
 pub use self::rle::Rle;
@@ -168,6 +170,7 @@ impl<T: IntegerType> IntCompressor<T> {
             Compression::Freq => Ok(Self::Extend(Box::new(Freq {}))),
             Compression::Bitpacking => Ok(Self::Extend(Box::new(Bitpacking {}))),
             Compression::DeltaBitpacking => Ok(Self::Extend(Box::new(DeltaBitpacking {}))),
+            Compression::DoubleDelta => Ok(Self::Extend(Box::new(DoubleDelta {}))),
             other => Err(Error::OutOfSpec(format!(
                 "Unknown compression codec {other:?}",
             ))),
@@ -289,6 +292,7 @@ fn choose_compressor<T: IntegerType>(
             Box::new(Rle {}) as _,
             Box::new(Bitpacking {}) as _,
             Box::new(DeltaBitpacking {}) as _,
+            Box::new(DoubleDelta {}) as _,
         ];
         for c in compressors {
             if write_options
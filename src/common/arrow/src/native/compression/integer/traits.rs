@@ -19,6 +19,9 @@ use crate::arrow::types::NativeType;
 
 pub trait IntegerType: NativeType + PartialOrd + Hash + Eq {
     fn as_i64(&self) -> i64;
+
+    /// The inverse of `as_i64` for the types no wider than i64.
+    fn from_i64(v: i64) -> Self;
 }
 
 macro_rules! integer_type {
@@ -27,6 +30,10 @@ macro_rules! integer_type {
             fn as_i64(&self) -> i64 {
                 *self as i64
             }
+
+            fn from_i64(v: i64) -> Self {
+                v as $type
+            }
         }
     };
 }
@@ -46,9 +53,17 @@ impl IntegerType for i128 {
     fn as_i64(&self) -> i64 {
         *self as i64
     }
+
+    fn from_i64(v: i64) -> Self {
+        v as i128
+    }
 }
 impl IntegerType for i256 {
     fn as_i64(&self) -> i64 {
         self.0.as_i64()
     }
+
+    fn from_i64(v: i64) -> Self {
+        i256(ethnum::I256::from(v))
+    }
 }
@@ -46,6 +46,7 @@ pub enum Compression {
     Bitpacking,
     DeltaBitpacking,
     Patas,
+    DoubleDelta,
 }
 
 impl Default for Compression {
@@ -72,6 +73,7 @@ impl Compression {
             14 => Ok(Compression::Bitpacking),
             15 => Ok(Compression::DeltaBitpacking),
             16 => Ok(Compression::Patas),
+            17 => Ok(Compression::DoubleDelta),
 
             other => Err(crate::arrow::error::Error::OutOfSpec(format!(
                 "Unknown compression codec {other}",
@@ -101,6 +103,7 @@ impl From<Compression> for u8 {
             Compression::Bitpacking => 14,
             Compression::DeltaBitpacking => 15,
             Compression::Patas => 16,
+            Compression::DoubleDelta => 17,
         }
     }
 }
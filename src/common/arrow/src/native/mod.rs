@@ -19,6 +19,7 @@ mod util;
 
 mod compression;
 pub use compression::CommonCompression;
+pub use compression::Compression;
 pub mod read;
 pub mod stat;
 pub mod write;
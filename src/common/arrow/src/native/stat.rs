@@ -43,6 +43,7 @@ pub enum PageBody {
     Patas,
     Bitpack,
     DeltaBitpack,
+    DoubleDelta,
     Common(CommonCompression),
 }
 
@@ -98,6 +99,7 @@ fn stat_body(
         Compression::Bitpacking => PageBody::Bitpack,
         Compression::DeltaBitpacking => PageBody::DeltaBitpack,
         Compression::Patas => PageBody::Patas,
+        Compression::DoubleDelta => PageBody::DoubleDelta,
         _ => PageBody::Common(CommonCompression::try_from(&codec).unwrap()),
     };
     *buffer = &buffer[compressed_size as usize..];
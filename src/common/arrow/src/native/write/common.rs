@@ -56,11 +56,17 @@ impl<W: Write> NativeWriter<W> {
             .unwrap_or(chunk.len())
             .min(chunk.len());
 
-        for (array, type_) in chunk
+        for (index, (array, type_)) in chunk
             .arrays()
             .iter()
             .zip(schema_descriptor.fields().to_vec())
+            .enumerate()
         {
+            let options = self
+                .column_options
+                .get(&index)
+                .unwrap_or(&self.options)
+                .clone();
             let array = array.as_ref();
             let nested = to_nested(array, &type_)?;
             let types: Vec<parquet2::schema::types::PrimitiveType> = to_parquet_leaves(type_);
@@ -93,7 +99,7 @@ impl<W: Write> NativeWriter<W> {
                             &sub_nested,
                             type_.clone(),
                             length,
-                            self.options.clone(),
+                            options.clone(),
                             &mut self.scratch,
                         )
                         .unwrap();
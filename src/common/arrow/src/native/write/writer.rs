@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::io::Write;
 
 use super::super::ARROW_MAGIC;
@@ -41,6 +42,8 @@ pub struct NativeWriter<W: Write> {
     pub(crate) writer: OffsetWriter<W>,
     /// pa write options
     pub(crate) options: WriteOptions,
+    /// pa write options of the columns which override `options`, by field index
+    pub(crate) column_options: HashMap<usize, WriteOptions>,
     /// A reference to the schema, used in validating record batches
     pub(crate) schema: Schema,
 
@@ -70,6 +73,7 @@ impl<W: Write> NativeWriter<W> {
                 offset: 0,
             },
             options,
+            column_options: HashMap::new(),
             schema,
             metas: Vec::with_capacity(num_cols),
             scratch: Vec::with_capacity(0),
@@ -77,6 +81,11 @@ impl<W: Write> NativeWriter<W> {
         }
     }
 
+    /// Sets the write options of the field at `index`, instead of the default options.
+    pub fn set_column_options(&mut self, index: usize, options: WriteOptions) {
+        self.column_options.insert(index, options);
+    }
+
     /// Consumes itself into the inner writer
     pub fn into_inner(self) -> W {
         self.writer.w
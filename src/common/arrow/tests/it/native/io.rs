@@ -52,6 +52,7 @@ use common_arrow::native::write::NativeWriter;
 use common_arrow::native::write::WriteOptions;
 use common_arrow::native::ColumnMeta;
 use common_arrow::native::CommonCompression;
+use common_arrow::native::Compression;
 use common_arrow::native::PageMeta;
 use rand::rngs::StdRng;
 use rand::Rng;
@@ -165,6 +166,38 @@ fn test_deleta_bitpacking() {
     test_write_read(chunk);
 }
 
+#[test]
+fn test_double_delta() {
+    let size = WRITE_PAGE * 5;
+    let chunk = Chunk::new(vec![
+        Box::new(Int64Array::from_vec(
+            (0..size as i64)
+                .map(|i| 1_700_000_000_000 + i * 1000)
+                .collect(),
+        )) as _,
+        Box::new(UInt64Array::from_vec(
+            (0..size as u64).map(|i| u64::MAX - i * i).collect(),
+        )) as _,
+        Box::new(Int16Array::from_iter(
+            (0..size).map(|i| (i % 7 != 0).then_some((i as i16).wrapping_mul(3))),
+        )) as _,
+    ]);
+    test_write_read(chunk.clone());
+    test_write_read_with_options(chunk, WriteOptions {
+        default_compression: CommonCompression::None,
+        max_page_size: Some(WRITE_PAGE),
+        default_compress_ratio: Some(0.0f64),
+        forbidden_compressions: vec![
+            Compression::Rle,
+            Compression::Dict,
+            Compression::OneValue,
+            Compression::Freq,
+            Compression::Bitpacking,
+            Compression::DeltaBitpacking,
+        ],
+    });
+}
+
 #[test]
 fn test_onevalue() {
     let size = 10000;
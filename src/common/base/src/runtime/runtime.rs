@@ -163,15 +163,24 @@ impl Runtime {
         Self::create(None, mem_stat, &mut runtime_builder)
     }
 
+    pub fn with_worker_threads(workers: usize, thread_name: Option<String>) -> Result<Self> {
+        Self::with_worker_threads_and_parent(workers, thread_name, MemStat::current())
+    }
+
+    /// Spawns a new tokio runtime whose memory is also accounted to `parent_mem_stat`.
     #[allow(unused_mut)]
-    pub fn with_worker_threads(workers: usize, mut thread_name: Option<String>) -> Result<Self> {
+    pub fn with_worker_threads_and_parent(
+        workers: usize,
+        mut thread_name: Option<String>,
+        parent_mem_stat: Option<Arc<MemStat>>,
+    ) -> Result<Self> {
         let mut mem_stat_name = String::from("UnnamedRuntime");
 
         if let Some(thread_name) = thread_name.as_ref() {
             mem_stat_name = format!("{}Runtime", thread_name);
         }
 
-        let mem_stat = MemStat::create(mem_stat_name);
+        let mem_stat = MemStat::create_child(mem_stat_name, parent_mem_stat);
         let mut runtime_builder = Self::tracker_builder(mem_stat.clone());
 
         #[cfg(debug_assertions)]
@@ -149,6 +149,9 @@ build_exceptions! {
     UnknownCatalog(1119),
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
+    UnknownRowAccessPolicy(1122),
+    // The row access policy is still attached to tables.
+    RowAccessPolicyInUse(1123),
 
     // Data Related Errors
 
@@ -170,6 +173,16 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// UnknownTableTag is used when the named snapshot tag of a table does not exist.
+    UnknownTableTag(1304),
+    /// TableTagAlreadyExists is used when creating a snapshot tag which already exists.
+    TableTagAlreadyExists(1305),
+    /// TableCannotFastForward is used when a table can not be fast-forwarded to its branch.
+    ///
+    /// For example: the table has been written since the branch was created.
+    TableCannotFastForward(1306),
+    /// UniqueConstraintViolation is used when a write breaks the PRIMARY KEY or UNIQUE constraint of a table.
+    UniqueConstraintViolation(1307),
 
     // License related errors starts here
 
@@ -225,6 +238,15 @@ build_exceptions! {
     NetworkPolicyAlreadyExists(2208),
     IllegalNetworkPolicy(2209),
     NetworkPolicyIsUsedByUser(2210),
+    UnknownWorkloadGroup(2211),
+    WorkloadGroupAlreadyExists(2212),
+    IllegalWorkloadGroup(2213),
+    WorkloadGroupQueueTimeout(2214),
+    UnknownPasswordPolicy(2215),
+    PasswordPolicyAlreadyExists(2216),
+    IllegalPasswordPolicy(2217),
+    PasswordPolicyIsUsedByUser(2218),
+    InvalidPassword(2219),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -254,6 +276,8 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2322),
 
 
     // Cluster error codes.
@@ -325,6 +349,9 @@ build_exceptions! {
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
+    // User quota error codes.
+    UserQuotaExceeded(2911),
+
 }
 
 // Storage errors [3001, 4000].
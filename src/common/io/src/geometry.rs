@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use geo::coord;
+use geo::BoundingRect;
+use geo::Geometry;
+use geo::Point;
+use geo::Rect;
+use geozero::geojson::GeoJson;
+use geozero::wkb::Wkb;
+use geozero::wkt::WktStr;
+use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToJson;
+use geozero::ToWkb;
+use geozero::ToWkt;
+
+/// Parse a geometry from any of the supported text or binary representations
+/// and return it encoded as WKB, the in-memory format of the `Geometry` type.
+///
+/// The input can be WKT (optionally prefixed with `SRID=<srid>;`), GeoJSON,
+/// hex encoded WKB or raw WKB.
+pub fn parse_to_wkb(buf: &[u8]) -> Result<Vec<u8>> {
+    let geo = parse_to_geo(buf)?;
+    geo_to_wkb(&geo)
+}
+
+pub fn parse_to_geo(buf: &[u8]) -> Result<Geometry<f64>> {
+    let geo = match std::str::from_utf8(buf) {
+        Ok(s) => {
+            let s = s.trim();
+            if s.starts_with('{') {
+                GeoJson(s).to_geo().map_err(|e| e.to_string())
+            } else if !s.is_empty() && s.len() % 2 == 0 && s.bytes().all(|c| c.is_ascii_hexdigit())
+            {
+                hex::decode(s)
+                    .map_err(|e| e.to_string())
+                    .and_then(|wkb| Wkb(wkb).to_geo().map_err(|e| e.to_string()))
+            } else {
+                let wkt = match s.split_once(';') {
+                    Some((srid, wkt)) if srid.trim().to_ascii_uppercase().starts_with("SRID") => {
+                        wkt
+                    }
+                    _ => s,
+                };
+                WktStr(wkt).to_geo().map_err(|e| e.to_string())
+            }
+        }
+        Err(_) => Wkb(buf).to_geo().map_err(|e| e.to_string()),
+    };
+
+    geo.map_err(|e| {
+        ErrorCode::BadBytes(format!(
+            "Invalid Geometry value: {:?}, {}",
+            String::from_utf8_lossy(buf),
+            e
+        ))
+    })
+}
+
+pub fn wkb_to_geo(wkb: &[u8]) -> Result<Geometry<f64>> {
+    Wkb(wkb)
+        .to_geo()
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid Geometry WKB: {}", e)))
+}
+
+pub fn geo_to_wkb(geo: &Geometry<f64>) -> Result<Vec<u8>> {
+    geo.to_wkb(CoordDimensions::xy())
+        .map_err(|e| ErrorCode::BadBytes(format!("Failed to encode Geometry as WKB: {}", e)))
+}
+
+pub fn wkb_to_wkt(wkb: &[u8]) -> Result<String> {
+    wkb_to_geo(wkb)?
+        .to_wkt()
+        .map_err(|e| ErrorCode::BadBytes(format!("Failed to encode Geometry as WKT: {}", e)))
+}
+
+pub fn wkb_to_geojson(wkb: &[u8]) -> Result<String> {
+    wkb_to_geo(wkb)?
+        .to_json()
+        .map_err(|e| ErrorCode::BadBytes(format!("Failed to encode Geometry as GeoJSON: {}", e)))
+}
+
+/// Returns the bounding box of a WKB encoded geometry,
+/// or `None` if the geometry is empty or can't be decoded.
+pub fn wkb_bounding_rect(wkb: &[u8]) -> Option<Rect<f64>> {
+    wkb_to_geo(wkb).ok()?.bounding_rect()
+}
+
+/// Returns the bounding box that covers all the given WKB encoded geometries,
+/// the empty ones are skipped.
+pub fn wkbs_bounding_rect<'a>(wkbs: impl IntoIterator<Item = &'a [u8]>) -> Option<Rect<f64>> {
+    wkbs.into_iter()
+        .filter_map(wkb_bounding_rect)
+        .reduce(|a, b| {
+            Rect::new(
+                coord! { x: a.min().x.min(b.min().x), y: a.min().y.min(b.min().y) },
+                coord! { x: a.max().x.max(b.max().x), y: a.max().y.max(b.max().y) },
+            )
+        })
+}
+
+/// Encodes the corners of a bounding box as two WKB points,
+/// which are stored as the min and max statistics of geometry columns.
+pub fn rect_to_wkb_points(rect: &Rect<f64>) -> Result<(Vec<u8>, Vec<u8>)> {
+    let min = geo_to_wkb(&Geometry::Point(Point::from(rect.min())))?;
+    let max = geo_to_wkb(&Geometry::Point(Point::from(rect.max())))?;
+    Ok((min, max))
+}
@@ -25,6 +25,7 @@
 
 pub mod constants;
 pub mod format_diagnostic;
+pub mod geometry;
 pub mod prelude;
 
 mod binary_read;
@@ -30,6 +30,7 @@ pub enum Feature {
     AggregateIndex,
     ComputedColumn,
     StorageEncryption,
+    RowAccessPolicy,
 }
 
 impl Display for Feature {
@@ -65,6 +66,9 @@ impl Display for Feature {
             Feature::StorageEncryption => {
                 write!(f, "storage_encryption")
             }
+            Feature::RowAccessPolicy => {
+                write!(f, "row_access_policy")
+            }
         }
     }
 }
@@ -21,6 +21,7 @@ pub struct Config {
     pub file: FileConfig,
     pub stderr: StderrConfig,
     pub query: QueryLogConfig,
+    pub audit: AuditLogConfig,
     pub tracing: TracingConfig,
 }
 
@@ -43,6 +44,11 @@ impl Config {
                 on: true,
                 dir: "./.databend/logs/query-details".to_string(),
             },
+            audit: AuditLogConfig {
+                on: true,
+                dir: "./.databend/logs/audit".to_string(),
+                stage: "".to_string(),
+            },
             tracing: TracingConfig {
                 on: false,
                 capture_log_level: "TRACE".to_string(),
@@ -136,6 +142,35 @@ impl Default for QueryLogConfig {
     }
 }
 
+/// Config for the audit log of logins, DDL, privilege changes and access denials.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct AuditLogConfig {
+    pub on: bool,
+    pub dir: String,
+    /// The stage the audit events are flushed to by the query node, empty means no stage.
+    pub stage: String,
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, dir={}, stage={}",
+            self.on, self.dir, self.stage
+        )
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            dir: "./.databend/logs/audit".to_string(),
+            stage: "".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
@@ -28,6 +28,9 @@ mod id;
 mod id_generator;
 pub mod kv_app_error;
 pub mod reply;
+mod row_access_policy_api;
+mod row_access_policy_api_impl;
+mod row_access_policy_api_keys;
 mod schema_api;
 mod schema_api_impl;
 mod schema_api_keys;
@@ -44,6 +47,7 @@ pub use background_api_test_suite::BackgroundApiTestSuite;
 pub use data_mask_api::DatamaskApi;
 pub use id::Id;
 pub(crate) use id_generator::IdGenerator;
+pub use row_access_policy_api::RowAccessPolicyApi;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
@@ -0,0 +1,296 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use common_meta_app::app_error::AppError;
+use common_meta_app::app_error::RowAccessPolicyAlreadyExists;
+use common_meta_app::app_error::RowAccessPolicyInUse;
+use common_meta_app::app_error::UnknownRowAccessPolicy;
+use common_meta_app::row_access_policy::CreateRowAccessPolicyReply;
+use common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use common_meta_app::row_access_policy::DropRowAccessPolicyReply;
+use common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReply;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyId;
+use common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableMeta;
+use common_meta_kvapi::kvapi;
+use common_meta_types::ConditionResult::Eq;
+use common_meta_types::MetaError;
+use common_meta_types::TxnRequest;
+use log::as_debug;
+use log::debug;
+use minitrace::func_name;
+
+use crate::fetch_id;
+use crate::get_pb_value;
+use crate::get_u64_value;
+use crate::id_generator::IdGenerator;
+use crate::kv_app_error::KVAppError;
+use crate::row_access_policy_api::RowAccessPolicyApi;
+use crate::send_txn;
+use crate::serialize_struct;
+use crate::serialize_u64;
+use crate::txn_cond_seq;
+use crate::txn_op_del;
+use crate::txn_op_put;
+use crate::util::txn_trials;
+
+/// RowAccessPolicyApi is implemented upon kvapi::KVApi.
+/// Thus every type that impl kvapi::KVApi impls RowAccessPolicyApi.
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> RowAccessPolicyApi for KV {
+    async fn create_row_access_policy(
+        &self,
+        req: CreateRowAccessPolicyReq,
+    ) -> Result<CreateRowAccessPolicyReply, KVAppError> {
+        debug!(req = as_debug!(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+        let id = loop {
+            trials.next().unwrap()?;
+            // Get row access policy by name to ensure absence
+            let (seq, id) = get_u64_value(self, name_key).await?;
+            debug!(seq = seq, id = id, name_key = as_debug!(name_key); "create_row_access_policy");
+
+            if seq > 0 {
+                return if req.if_not_exists {
+                    Ok(CreateRowAccessPolicyReply { id })
+                } else {
+                    Err(KVAppError::AppError(
+                        AppError::RowAccessPolicyAlreadyExists(RowAccessPolicyAlreadyExists::new(
+                            &name_key.name,
+                            format!("create row access policy: {}", req.name),
+                        )),
+                    ))
+                };
+            }
+
+            // Create row access policy by inserting these record:
+            // name -> id
+            // id -> policy
+            // policy name -> policy table id list
+
+            let id = fetch_id(self, IdGenerator::row_access_policy_id()).await?;
+            let id_key = RowAccessPolicyId { id };
+            let id_list_key = RowAccessPolicyTableIdListKey {
+                tenant: name_key.tenant.clone(),
+                name: name_key.name.clone(),
+            };
+
+            debug!(
+                id = as_debug!(&id_key),
+                name_key = as_debug!(name_key);
+                "new row access policy id"
+            );
+
+            {
+                let meta: RowAccessPolicyMeta = req.clone().into();
+                let id_list = RowAccessPolicyTableIdList::default();
+                let condition = vec![txn_cond_seq(name_key, Eq, 0)];
+                let if_then = vec![
+                    txn_op_put(name_key, serialize_u64(id)?), // name -> policy_id
+                    txn_op_put(&id_key, serialize_struct(&meta)?), // id -> meta
+                    txn_op_put(&id_list_key, serialize_struct(&id_list)?), // policy name -> id_list
+                ];
+
+                let txn_req = TxnRequest {
+                    condition,
+                    if_then,
+                    else_then: vec![],
+                };
+
+                let (succ, _responses) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    name = as_debug!(name_key),
+                    id = as_debug!(&id_key),
+                    succ = succ;
+                    "create_row_access_policy"
+                );
+
+                if succ {
+                    break id;
+                }
+            }
+        };
+
+        Ok(CreateRowAccessPolicyReply { id })
+    }
+
+    async fn drop_row_access_policy(
+        &self,
+        req: DropRowAccessPolicyReq,
+    ) -> Result<DropRowAccessPolicyReply, KVAppError> {
+        debug!(req = as_debug!(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+        let ctx = &func_name!();
+        let mut trials = txn_trials(None, ctx);
+
+        loop {
+            trials.next().unwrap()?;
+
+            let result = get_row_access_policy_or_err(
+                self,
+                name_key,
+                format!("drop_row_access_policy: {}", name_key),
+            )
+            .await;
+
+            let (id_seq, id, policy_seq, _) = match result {
+                Ok(v) => v,
+                Err(err) => {
+                    if let KVAppError::AppError(AppError::UnknownRowAccessPolicy(_)) = err {
+                        if req.if_exists {
+                            return Ok(DropRowAccessPolicyReply {});
+                        }
+                    }
+
+                    return Err(err);
+                }
+            };
+
+            let id_list_key = RowAccessPolicyTableIdListKey {
+                tenant: name_key.tenant.clone(),
+                name: name_key.name.clone(),
+            };
+            let (id_list_seq, id_list): (_, Option<RowAccessPolicyTableIdList>) =
+                get_pb_value(self, &id_list_key).await?;
+
+            // The id list is not cleaned up when a table is dropped, only count the tables
+            // that still use the policy.
+            let mut table_count = 0;
+            for table_id in id_list.unwrap_or_default().id_list {
+                let (_, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &TableId { table_id }).await?;
+                let attached = table_meta
+                    .and_then(|meta| meta.row_access_policy)
+                    .map_or(false, |policy| policy.name == name_key.name);
+                if attached {
+                    table_count += 1;
+                }
+            }
+            if table_count > 0 {
+                return Err(KVAppError::AppError(AppError::RowAccessPolicyInUse(
+                    RowAccessPolicyInUse::new(
+                        &name_key.name,
+                        table_count,
+                        format!("drop_row_access_policy: {}", name_key),
+                    ),
+                )));
+            }
+
+            let id_key = RowAccessPolicyId { id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    txn_cond_seq(name_key, Eq, id_seq),
+                    txn_cond_seq(&id_key, Eq, policy_seq),
+                    // no table is attached to the policy meanwhile
+                    txn_cond_seq(&id_list_key, Eq, id_list_seq),
+                ],
+                if_then: vec![
+                    txn_op_del(name_key),
+                    txn_op_del(&id_key),
+                    txn_op_del(&id_list_key),
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = as_debug!(name_key),
+                id = as_debug!(&id_key),
+                succ = succ;
+                "drop_row_access_policy"
+            );
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DropRowAccessPolicyReply {})
+    }
+
+    async fn get_row_access_policy(
+        &self,
+        req: GetRowAccessPolicyReq,
+    ) -> Result<GetRowAccessPolicyReply, KVAppError> {
+        debug!(req = as_debug!(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let (_id_seq, _id, _policy_seq, policy) = get_row_access_policy_or_err(
+            self,
+            name_key,
+            format!("get_row_access_policy: {}", name_key),
+        )
+        .await?;
+
+        Ok(GetRowAccessPolicyReply { policy })
+    }
+}
+
+/// Returns (id_seq, id, policy_seq, policy)
+async fn get_row_access_policy_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &RowAccessPolicyNameIdent,
+    msg: impl Display,
+) -> Result<(u64, u64, u64, RowAccessPolicyMeta), KVAppError> {
+    let (id_seq, id) = get_u64_value(kv_api, name_key).await?;
+    row_access_policy_has_to_exist(id_seq, name_key, &msg)?;
+
+    let id_key = RowAccessPolicyId { id };
+
+    let (policy_seq, policy) = get_pb_value(kv_api, &id_key).await?;
+    row_access_policy_has_to_exist(policy_seq, name_key, msg)?;
+
+    Ok((
+        id_seq,
+        id,
+        policy_seq,
+        // Safe unwrap(): policy_seq > 0 implies policy is not None.
+        policy.unwrap(),
+    ))
+}
+
+/// Return OK if a row access policy exists by checking the seq.
+///
+/// Otherwise returns UnknownRowAccessPolicy error
+pub fn row_access_policy_has_to_exist(
+    seq: u64,
+    name_ident: &RowAccessPolicyNameIdent,
+    msg: impl Display,
+) -> Result<(), KVAppError> {
+    if seq == 0 {
+        debug!(seq = seq, name_ident = as_debug!(name_ident); "row access policy does not exist");
+
+        Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+            UnknownRowAccessPolicy::new(&name_ident.name, format!("{}: {}", msg, name_ident)),
+        )))
+    } else {
+        Ok(())
+    }
+}
@@ -72,6 +72,8 @@ use common_meta_app::schema::SetLVTReply;
 use common_meta_app::schema::SetLVTReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyReply;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
@@ -229,6 +231,11 @@ pub trait SchemaApi: Send + Sync {
         req: SetTableColumnMaskPolicyReq,
     ) -> Result<SetTableColumnMaskPolicyReply, KVAppError>;
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError>;
+
     async fn get_drop_table_infos(
         &self,
         req: ListDroppedTableReq,
@@ -43,6 +43,7 @@ use common_meta_app::app_error::UndropTableHasNoHistory;
 use common_meta_app::app_error::UndropTableWithNoDropTime;
 use common_meta_app::app_error::UnknownCatalog;
 use common_meta_app::app_error::UnknownIndex;
+use common_meta_app::app_error::UnknownRowAccessPolicy;
 use common_meta_app::app_error::UnknownTable;
 use common_meta_app::app_error::UnknownTableId;
 use common_meta_app::app_error::VirtualColumnAlreadyExists;
@@ -50,6 +51,8 @@ use common_meta_app::app_error::WrongShare;
 use common_meta_app::app_error::WrongShareObject;
 use common_meta_app::data_mask::MaskpolicyTableIdList;
 use common_meta_app::data_mask::MaskpolicyTableIdListKey;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
 use common_meta_app::schema::CatalogId;
 use common_meta_app::schema::CatalogIdToName;
 use common_meta_app::schema::CatalogInfo;
@@ -130,6 +133,9 @@ use common_meta_app::schema::SetLVTReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyAction;
+use common_meta_app::schema::SetTableRowAccessPolicyReply;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::TableCopiedFileNameIdent;
 use common_meta_app::schema::TableId;
@@ -2949,6 +2955,92 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         )))
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            debug!(ident = as_display!(&tbid); "set_table_row_access_policy");
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "set_table_row_access_policy"),
+                )));
+            }
+            if req_seq.match_seq(tb_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        tb_meta_seq,
+                        "set_table_row_access_policy",
+                    ),
+                )));
+            }
+
+            let mut new_table_meta = table_meta.unwrap();
+            match &req.action {
+                SetTableRowAccessPolicyAction::Add(policy, _) => {
+                    new_table_meta.row_access_policy = Some(policy.clone());
+                }
+                SetTableRowAccessPolicyAction::Drop(_) => {
+                    new_table_meta.row_access_policy = None;
+                }
+            }
+
+            let mut txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&new_table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            update_row_access_policy(
+                self,
+                &req.action,
+                &mut txn_req.condition,
+                &mut txn_req.if_then,
+                req.tenant.clone(),
+                req.table_id,
+            )
+            .await?;
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id = as_debug!(&tbid),
+                succ = succ;
+                "set_table_row_access_policy"
+            );
+
+            if succ {
+                return Ok(SetTableRowAccessPolicyReply {
+                    share_table_info: get_share_table_info_map(self, &new_table_meta).await?,
+                });
+            }
+        }
+
+        Err(KVAppError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("set_table_row_access_policy", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn get_drop_table_infos(
@@ -4682,6 +4774,97 @@ async fn update_mask_policy(
     Ok(())
 }
 
+async fn update_row_access_policy_table_id_list(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+    tenant: String,
+    name: String,
+    table_id: u64,
+    add: bool,
+) -> Result<(), KVAppError> {
+    let id_list_key = RowAccessPolicyTableIdListKey { tenant, name };
+
+    let (id_list_seq, id_list_opt): (_, Option<RowAccessPolicyTableIdList>) =
+        get_pb_value(kv_api, &id_list_key).await?;
+    match id_list_opt {
+        Some(mut id_list) => {
+            if add {
+                id_list.id_list.insert(table_id);
+            } else {
+                id_list.id_list.remove(&table_id);
+            }
+
+            // the policy is not dropped meanwhile
+            condition.push(txn_cond_seq(&id_list_key, Eq, id_list_seq));
+            if_then.push(txn_op_put(&id_list_key, serialize_struct(&id_list)?));
+        }
+        None if add => {
+            return Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+                UnknownRowAccessPolicy::new(
+                    &id_list_key.name,
+                    format!("set_table_row_access_policy: {}", id_list_key),
+                ),
+            )));
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+async fn update_row_access_policy(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    action: &SetTableRowAccessPolicyAction,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+    tenant: String,
+    table_id: u64,
+) -> Result<(), KVAppError> {
+    match action {
+        SetTableRowAccessPolicyAction::Add(new_policy, old_policy_name_opt) => {
+            update_row_access_policy_table_id_list(
+                kv_api,
+                condition,
+                if_then,
+                tenant.clone(),
+                new_policy.name.clone(),
+                table_id,
+                true,
+            )
+            .await?;
+            if let Some(old_policy_name) = old_policy_name_opt {
+                if old_policy_name != &new_policy.name {
+                    update_row_access_policy_table_id_list(
+                        kv_api,
+                        condition,
+                        if_then,
+                        tenant.clone(),
+                        old_policy_name.clone(),
+                        table_id,
+                        false,
+                    )
+                    .await?;
+                }
+            }
+        }
+        SetTableRowAccessPolicyAction::Drop(policy_name) => {
+            update_row_access_policy_table_id_list(
+                kv_api,
+                condition,
+                if_then,
+                tenant.clone(),
+                policy_name.clone(),
+                table_id,
+                false,
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Return OK if a table lock exists by checking the seq.
 ///
 /// Otherwise returns TableLockExpired error
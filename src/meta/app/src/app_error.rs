@@ -74,6 +74,22 @@ impl DatamaskAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("RowAccessPolicyAlreadyExists: `{name}` while `{context}`")]
+pub struct RowAccessPolicyAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl RowAccessPolicyAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("BackgroundJobAlreadyExists: `{name}` while `{context}`")]
 pub struct BackgroundJobAlreadyExists {
@@ -316,6 +332,40 @@ impl UnknownDatamask {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("UnknownRowAccessPolicy: `{name}` while `{context}`")]
+pub struct UnknownRowAccessPolicy {
+    name: String,
+    context: String,
+}
+
+impl UnknownRowAccessPolicy {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("RowAccessPolicyInUse: `{name}` is attached to {table_count} tables while `{context}`")]
+pub struct RowAccessPolicyInUse {
+    name: String,
+    table_count: usize,
+    context: String,
+}
+
+impl RowAccessPolicyInUse {
+    pub fn new(name: impl Into<String>, table_count: usize, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            table_count,
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownBackgroundJob: `{name}` while `{context}`")]
 pub struct UnknownBackgroundJob {
@@ -935,6 +985,15 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDatamask(#[from] UnknownDatamask),
 
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] RowAccessPolicyAlreadyExists),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownRowAccessPolicy),
+
+    #[error(transparent)]
+    RowAccessPolicyInUse(#[from] RowAccessPolicyInUse),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] BackgroundJobAlreadyExists),
 
@@ -1229,6 +1288,27 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for RowAccessPolicyAlreadyExists {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownRowAccessPolicy {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' does not exists", self.name)
+    }
+}
+
+impl AppErrorMessage for RowAccessPolicyInUse {
+    fn message(&self) -> String {
+        format!(
+            "Row access policy '{}' is attached to {} tables, drop it from the tables first",
+            self.name, self.table_count
+        )
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1335,6 +1415,13 @@ impl From<AppError> for ErrorCode {
             AppError::GetIndexWithDropTIme(err) => ErrorCode::GetIndexWithDropTime(err.message()),
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDatamask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
+            AppError::RowAccessPolicyInUse(err) => ErrorCode::RowAccessPolicyInUse(err.message()),
 
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
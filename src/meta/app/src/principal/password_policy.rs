@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::DateTime;
+use chrono::Utc;
+
+pub const DEFAULT_PASSWORD_MIN_LENGTH: u64 = 8;
+pub const DEFAULT_PASSWORD_MAX_LENGTH: u64 = 256;
+pub const DEFAULT_PASSWORD_MIN_CHARS: u64 = 1;
+pub const DEFAULT_PASSWORD_MIN_SPECIAL_CHARS: u64 = 0;
+pub const DEFAULT_PASSWORD_MIN_AGE_DAYS: u64 = 0;
+pub const DEFAULT_PASSWORD_MAX_AGE_DAYS: u64 = 90;
+pub const DEFAULT_PASSWORD_MAX_RETRIES: u64 = 5;
+pub const DEFAULT_PASSWORD_LOCKOUT_TIME_MINS: u64 = 15;
+pub const DEFAULT_PASSWORD_HISTORY: u64 = 0;
+
+/// The most passwords kept in the history of a user, the `PASSWORD_HISTORY` of a policy can not
+/// be larger than it.
+pub const MAX_PASSWORD_HISTORY: u64 = 24;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct PasswordPolicy {
+    pub name: String,
+    pub min_length: u64,
+    pub max_length: u64,
+    pub min_upper_case_chars: u64,
+    pub min_lower_case_chars: u64,
+    pub min_numeric_chars: u64,
+    pub min_special_chars: u64,
+    /// The days before a password can be changed again, 0 means no limit.
+    pub min_age_days: u64,
+    /// The days before a password expires, 0 means never expire.
+    pub max_age_days: u64,
+    /// The failed logins in a row before the user is locked, 0 means never lock.
+    pub max_retries: u64,
+    pub lockout_time_mins: u64,
+    /// The number of the latest passwords which can not be reused.
+    pub history: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
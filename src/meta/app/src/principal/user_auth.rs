@@ -23,6 +23,7 @@ const NO_PASSWORD_STR: &str = "no_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
+const LDAP_AUTH_STR: &str = "ldap";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
@@ -30,6 +31,7 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    Ldap,
 }
 
 impl std::str::FromStr for AuthType {
@@ -40,6 +42,7 @@ impl std::str::FromStr for AuthType {
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
+            LDAP_AUTH_STR => Ok(AuthType::Ldap),
             _ => Err(ErrorCode::InvalidAuthInfo(AuthType::bad_auth_types(s))),
         }
     }
@@ -52,6 +55,7 @@ impl AuthType {
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
+            AuthType::Ldap => LDAP_AUTH_STR,
         }
     }
 
@@ -61,6 +65,7 @@ impl AuthType {
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
             JWT_AUTH_STR,
+            LDAP_AUTH_STR,
         ];
         let all = all
             .iter()
@@ -90,6 +95,8 @@ pub enum AuthInfo {
         hash_method: PasswordHashMethod,
     },
     JWT,
+    /// The password is checked by the LDAP server, see `LdapAuthenticator`.
+    Ldap,
 }
 
 fn calc_sha1(v: &[u8]) -> [u8; 20] {
@@ -107,6 +114,7 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
+            AuthType::Ldap => Ok(AuthInfo::Ldap),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => match auth_string {
                 Some(p) => {
                     let method = auth_type.get_password_type().unwrap();
@@ -165,6 +173,7 @@ impl AuthInfo {
         match self {
             AuthInfo::None => AuthType::NoPassword,
             AuthInfo::JWT => AuthType::JWT,
+            AuthInfo::Ldap => AuthType::Ldap,
             AuthInfo::Password {
                 hash_value: _,
                 hash_method: t,
@@ -181,7 +190,7 @@ impl AuthInfo {
                 hash_value: p,
                 hash_method: t,
             } => t.to_string(p),
-            AuthInfo::None | AuthInfo::JWT => "".to_string(),
+            AuthInfo::None | AuthInfo::JWT | AuthInfo::Ldap => "".to_string(),
         }
     }
 
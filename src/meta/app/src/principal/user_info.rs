@@ -15,6 +15,8 @@
 use core::fmt;
 use std::convert::TryFrom;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use enumflags2::bitflags;
@@ -26,6 +28,7 @@ use crate::principal::AuthInfo;
 use crate::principal::UserGrantSet;
 use crate::principal::UserIdentity;
 use crate::principal::UserQuota;
+use crate::principal::MAX_PASSWORD_HISTORY;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
@@ -41,6 +44,19 @@ pub struct UserInfo {
     pub quota: UserQuota,
 
     pub option: UserOption,
+
+    /// The previous passwords of the user, the latest goes first, see `PASSWORD_HISTORY`.
+    pub history_auth_infos: Vec<AuthInfo>,
+
+    /// The failed logins in a row since the last successful login.
+    pub password_fails: Vec<DateTime<Utc>>,
+
+    /// When the password is set, None for the users created before password policies until a
+    /// password policy is attached to them.
+    pub password_update_on: Option<DateTime<Utc>>,
+
+    /// The user is locked out until this time after too many failed logins.
+    pub lockout_time: Option<DateTime<Utc>>,
 }
 
 impl UserInfo {
@@ -57,6 +73,10 @@ impl UserInfo {
             grants,
             quota,
             option,
+            history_auth_infos: vec![],
+            password_fails: vec![],
+            password_update_on: None,
+            lockout_time: None,
         }
     }
 
@@ -77,12 +97,56 @@ impl UserInfo {
 
     pub fn update_auth_option(&mut self, auth: Option<AuthInfo>, option: Option<UserOption>) {
         if let Some(auth_info) = auth {
-            self.auth_info = auth_info;
+            self.update_auth_history(auth_info);
         };
         if let Some(user_option) = option {
+            // the users created before password policies have no `password_update_on`, the
+            // max age of the password is counted from when a password policy is attached.
+            if user_option.password_policy().is_some()
+                && self.password_update_on.is_none()
+                && matches!(self.auth_info, AuthInfo::Password { .. })
+            {
+                self.password_update_on = Some(Utc::now());
+            }
             self.option = user_option;
         };
     }
+
+    /// Changes the auth info, and keeps the previous password in the history.
+    pub fn update_auth_history(&mut self, auth_info: AuthInfo) {
+        if auth_info == self.auth_info {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.auth_info, auth_info);
+        if let AuthInfo::Password { .. } = previous {
+            self.history_auth_infos.insert(0, previous);
+            self.history_auth_infos
+                .truncate(MAX_PASSWORD_HISTORY as usize);
+        }
+        self.password_update_on = match self.auth_info {
+            AuthInfo::Password { .. } => Some(Utc::now()),
+            _ => None,
+        };
+        self.password_fails.clear();
+        self.lockout_time = None;
+    }
+
+    /// Records a failed login, and locks the user out for `lockout_time_mins` after
+    /// `max_retries` failed logins in a row.
+    pub fn update_login_fail(&mut self, max_retries: u64, lockout_time_mins: u64) {
+        let now = Utc::now();
+        self.password_fails.push(now);
+        if max_retries > 0 && self.password_fails.len() as u64 >= max_retries {
+            self.lockout_time = Some(now + chrono::Duration::minutes(lockout_time_mins as i64));
+            self.password_fails.clear();
+        }
+    }
+
+    /// Clears the failed logins and the lockout, after a successful login or `UNLOCK`.
+    pub fn clear_login_fail(&mut self) {
+        self.password_fails.clear();
+        self.lockout_time = None;
+    }
 }
 
 impl TryFrom<Vec<u8>> for UserInfo {
@@ -107,6 +171,8 @@ pub struct UserOption {
     default_role: Option<String>,
 
     network_policy: Option<String>,
+
+    password_policy: Option<String>,
 }
 
 impl UserOption {
@@ -115,6 +181,7 @@ impl UserOption {
             flags,
             default_role: None,
             network_policy: None,
+            password_policy: None,
         }
     }
 
@@ -137,6 +204,11 @@ impl UserOption {
         self
     }
 
+    pub fn with_password_policy(mut self, password_policy: Option<String>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -154,6 +226,10 @@ impl UserOption {
         self.network_policy.as_ref()
     }
 
+    pub fn password_policy(&self) -> Option<&String> {
+        self.password_policy.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -162,6 +238,10 @@ impl UserOption {
         self.network_policy = network_policy;
     }
 
+    pub fn set_password_policy(&mut self, password_policy: Option<String>) {
+        self.password_policy = password_policy;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
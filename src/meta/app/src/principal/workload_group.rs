@@ -0,0 +1,37 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A group of queries sharing the admission limits of a query node, assigned to users and
+/// roles. A limit of 0 means no limit.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// The max number of running queries of the group on each query node, the others wait in
+    /// the queue of the node.
+    pub max_concurrency: u64,
+    /// The share of `max_server_memory_usage` the queries of the group can use, in percent.
+    pub memory_percentage: u64,
+    /// The relative weight of the group when the threads of a query are decided.
+    pub cpu_weight: u64,
+    /// How long a query can wait in the queue before it is rejected, in seconds.
+    pub queue_timeout_secs: u64,
+    pub users: Vec<String>,
+    pub roles: Vec<String>,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
@@ -83,6 +83,9 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::SetTableRowAccessPolicyAction;
+pub use table::SetTableRowAccessPolicyReply;
+pub use table::SetTableRowAccessPolicyReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileLockKey;
 pub use table::TableCopiedFileNameIdent;
@@ -31,6 +31,7 @@ use common_meta_types::MatchSeq;
 use common_meta_types::MetaId;
 use maplit::hashmap;
 
+use crate::row_access_policy::TableRowAccessPolicy;
 use crate::schema::database::DatabaseNameIdent;
 use crate::schema::Ownership;
 use crate::share::ShareNameIdent;
@@ -239,6 +240,7 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
     pub owner: Option<Ownership>,
 }
 
@@ -354,6 +356,7 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            row_access_policy: None,
             owner: None,
         }
     }
@@ -653,6 +656,27 @@ pub struct SetTableColumnMaskPolicyReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum SetTableRowAccessPolicyAction {
+    // new policy, prev policy name(if any)
+    Add(TableRowAccessPolicy, Option<String>),
+    // prev policy name
+    Drop(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReq {
+    pub tenant: String,
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub action: SetTableRowAccessPolicyAction,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
@@ -16,6 +16,7 @@ use common_exception::exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::PasswordHashMethod;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserOption;
 
 #[test]
 fn test_user_info() -> Result<()> {
@@ -49,3 +50,26 @@ fn test_user_info() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_user_info_attach_password_policy() -> Result<()> {
+    let mut user_info = UserInfo::new("old-name", "old-host", AuthInfo::Password {
+        hash_value: Vec::from("pwd"),
+        hash_method: PasswordHashMethod::Sha256,
+    });
+    assert!(user_info.password_update_on.is_none());
+
+    // the max age of the password of an old user is counted from when a policy is attached.
+    user_info.update_auth_option(None, Some(UserOption::empty()));
+    assert!(user_info.password_update_on.is_none());
+    let option = UserOption::empty().with_password_policy(Some("p1".to_string()));
+    user_info.update_auth_option(None, Some(option.clone()));
+    let update_on = user_info.password_update_on;
+    assert!(update_on.is_some());
+
+    // attaching the policy again does not reset it.
+    user_info.update_auth_option(None, Some(option));
+    assert_eq!(user_info.password_update_on, update_on);
+
+    Ok(())
+}
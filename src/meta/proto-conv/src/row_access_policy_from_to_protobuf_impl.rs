@@ -0,0 +1,126 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_meta_app::row_access_policy as mt;
+use common_protos::pb;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::RowAccessPolicyMeta {
+    type PB = pb::RowAccessPolicyMeta;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::RowAccessPolicyMeta) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        if p.arg_names.len() != p.arg_types.len() {
+            return Err(Incompatible {
+                reason: format!(
+                    "RowAccessPolicyMeta has {} arg names but {} arg types",
+                    p.arg_names.len(),
+                    p.arg_types.len()
+                ),
+            });
+        }
+
+        let v = Self {
+            args: p.arg_names.into_iter().zip(p.arg_types).collect(),
+            body: p.body,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::RowAccessPolicyMeta, Incompatible> {
+        let p = pb::RowAccessPolicyMeta {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            arg_names: self.args.iter().map(|(name, _)| name.clone()).collect(),
+            arg_types: self.args.iter().map(|(_, ty)| ty.clone()).collect(),
+            body: self.body.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            name: p.name,
+            columns: p.columns,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            columns: self.columns.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::RowAccessPolicyTableIdList {
+    type PB = pb::DbIdList;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::DbIdList) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            id_list: p.ids.iter().copied().collect(),
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::DbIdList, Incompatible> {
+        let p = pb::DbIdList {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            ids: self.id_list.iter().copied().collect(),
+        };
+        Ok(p)
+    }
+}
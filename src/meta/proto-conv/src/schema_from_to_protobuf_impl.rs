@@ -241,6 +241,7 @@ impl FromToProto for ex::TableDataType {
                         Box::into_inner(x),
                     )?)),
                     Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                     Dt24::TupleT(t) => {
                         reader_check_msg(t.ver, t.min_reader_ver)?;
 
@@ -300,6 +301,7 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::MapT(Box::new(x)))
             }
             TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Tuple {
                 fields_name,
                 fields_type,
@@ -21,6 +21,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use common_expression as ex;
+use common_meta_app::row_access_policy::TableRowAccessPolicy;
 use common_meta_app::schema as mt;
 use common_meta_app::storage::StorageParams;
 use common_protos::pb;
@@ -218,6 +219,10 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            row_access_policy: match p.row_access_policy {
+                Some(policy) => Some(TableRowAccessPolicy::from_pb(policy)?),
+                None => None,
+            },
             owner: match p.owner {
                 Some(owner) => Some(mt::Ownership::from_pb(owner)?),
                 None => None,
@@ -258,6 +263,10 @@ impl FromToProto for mt::TableMeta {
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
+            row_access_policy: match self.row_access_policy.as_ref() {
+                Some(policy) => Some(policy.to_pb()?),
+                None => None,
+            },
             owner: match self.owner.as_ref() {
                 Some(o) => Some(o.to_pb()?),
                 None => None,
@@ -95,6 +95,11 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (63, "2023-10-30: Add: connection.proto"),
     (64, "2023-11-16: Add: user.proto/NDJsonFileFormatParams add field `missing_field_as` and `null_field_as`", ),
     (65, "2023-11-16: Retype: use Datetime<Utc> instead of u64 to in lvt.time", ),
+    (66, "2023-11-20: Add: datatype.proto/DataType add geometry_t", ),
+    (67, "2023-11-27: Add: user.proto/PasswordPolicy and UserOption::password_policy, UserInfo add password history, fails and lockout", ),
+    (68, "2023-11-29: Add: user.proto/AuthInfo add LDAP", ),
+    (69, "2023-12-04: Add: row_access_policy.proto, table.proto/TableMeta add row_access_policy", ),
+    (70, "2023-12-11: Add: workload_group.proto", ),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -69,3 +69,8 @@ mod v062_table_lock_meta;
 mod v063_connection;
 mod v064_ndjson_format_params;
 mod v065_least_visible_time;
+mod v066_geometry_schema;
+mod v067_password_policy;
+mod v068_ldap_auth;
+mod v069_row_access_policy;
+mod v070_workload_group;
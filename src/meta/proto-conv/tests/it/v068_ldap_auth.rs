@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v68_auth_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![34, 0, 160, 6, 68, 168, 6, 24];
+
+    let want = || common_meta_app::principal::AuthInfo::Ldap;
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 68, want())
+}
+
+#[test]
+fn test_decode_v68_user_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 9, 116, 101, 115, 116, 95, 117, 115, 101, 114, 18, 1, 37, 26, 8, 34, 0, 160, 6, 68,
+        168, 6, 24, 34, 19, 18, 11, 10, 7, 97, 110, 97, 108, 121, 115, 116, 16, 1, 160, 6, 68, 168,
+        6, 24, 42, 6, 160, 6, 68, 168, 6, 24, 50, 15, 18, 7, 97, 110, 97, 108, 121, 115, 116, 160,
+        6, 68, 168, 6, 24, 160, 6, 68, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::UserInfo {
+        name: "test_user".to_string(),
+        hostname: "%".to_string(),
+        auth_info: common_meta_app::principal::AuthInfo::Ldap,
+        grants: common_meta_app::principal::UserGrantSet::new(
+            vec![],
+            HashSet::from(["analyst".to_string()]),
+        ),
+        quota: common_meta_app::principal::UserQuota {
+            max_cpu: 0,
+            max_memory_in_bytes: 0,
+            max_storage_in_bytes: 0,
+        },
+        option: common_meta_app::principal::UserOption::default()
+            .with_default_role(Some("analyst".to_string())),
+        history_auth_infos: vec![],
+        password_fails: vec![],
+        password_update_on: None,
+        lockout_time: None,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 68, want())
+}
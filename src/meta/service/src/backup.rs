@@ -0,0 +1,473 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Online backup of meta-service data to an object storage.
+//!
+//! Layout of the backup storage:
+//!
+//! - `full/<timestamp>.data`: lines exported by [`StoreInner::export`](crate::store::StoreInner::export),
+//!   i.e., the header, raft state, raft logs and the latest snapshot of the state machine.
+//! - `full/<timestamp>.manifest`: a [`FullBackupManifest`] in json that describes the data file.
+//!   It is written after the data file is completed,
+//!   thus a data file without manifest is an incomplete backup and is ignored.
+//! - `log/<first_index>-<last_index>.data`: the raft logs applied after a full backup,
+//!   in the same line format as the data file.
+//!
+//! A node can be restored to any log index covered by a full backup and the following log segments,
+//! with `databend-metactl --restore`.
+
+use std::cmp::max;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::io;
+use std::io::ErrorKind;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyerror::AnyError;
+use common_base::base::tokio;
+use common_base::base::tokio::time::Instant;
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use common_meta_raft_store::state::RaftStateKey;
+use common_meta_raft_store::state::RaftStateValue;
+use common_meta_raft_store::state_machine::StateMachineMetaKey;
+use common_meta_raft_store::state_machine::StateMachineMetaValue;
+use common_meta_types::LogId;
+use common_meta_types::MetaStartupError;
+use futures::TryStreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use opendal::Operator;
+use opendal::Scheme;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::configs::BackupConfig;
+use crate::meta_service::MetaNode;
+
+pub const FULL_BACKUP_DIR: &str = "full/";
+pub const LOG_SEGMENT_DIR: &str = "log/";
+
+const DATA_SUFFIX: &str = ".data";
+const MANIFEST_SUFFIX: &str = ".manifest";
+
+/// Describes a full backup.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FullBackupManifest {
+    /// Path of the data file in the backup storage.
+    pub data_path: String,
+
+    /// The last log applied to the state machine snapshot in the data file.
+    pub snapshot_last_applied: Option<LogId>,
+
+    /// The greatest index of the logs in the data file that are known to be committed.
+    ///
+    /// Logs after it may be uncommitted and will be ignored when restoring.
+    pub last_committed: u64,
+}
+
+impl FullBackupManifest {
+    pub fn snapshot_index(&self) -> u64 {
+        self.snapshot_last_applied
+            .map(|x| x.index)
+            .unwrap_or_default()
+    }
+}
+
+/// A segment of raft logs in range `[first, last]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LogSegment {
+    pub first: u64,
+    pub last: u64,
+}
+
+impl LogSegment {
+    pub fn path(&self) -> String {
+        format!(
+            "{}{:020}-{:020}{}",
+            LOG_SEGMENT_DIR, self.first, self.last, DATA_SUFFIX
+        )
+    }
+
+    /// Parse a log segment from its path, returns `None` if it is not a log segment.
+    pub fn parse(path: &str) -> Option<Self> {
+        let name = path.strip_prefix(LOG_SEGMENT_DIR)?;
+        let range = name.strip_suffix(DATA_SUFFIX)?;
+        let (first, last) = range.split_once('-')?;
+
+        Some(Self {
+            first: first.parse().ok()?,
+            last: last.parse().ok()?,
+        })
+    }
+}
+
+/// Build an operator to access the backup storage.
+pub fn new_backup_operator(config: &BackupConfig) -> Result<Operator, MetaStartupError> {
+    let scheme = Scheme::from_str(&config.storage_type).map_err(|e| {
+        MetaStartupError::InvalidConfig(format!(
+            "invalid backup storage type: '{}': {}",
+            config.storage_type, e
+        ))
+    })?;
+
+    let params: HashMap<String, String> = config.params()?.into_iter().collect();
+
+    let op = Operator::via_map(scheme, params).map_err(|e| {
+        MetaStartupError::InvalidConfig(format!("failed to build backup storage: {}", e))
+    })?;
+    Ok(op)
+}
+
+/// List all completed full backups, ordered by the snapshot index.
+pub async fn list_full_backups(op: &Operator) -> Result<Vec<FullBackupManifest>, io::Error> {
+    let mut manifests = vec![];
+
+    for entry in op.list(FULL_BACKUP_DIR).await? {
+        if !entry.path().ends_with(MANIFEST_SUFFIX) {
+            continue;
+        }
+
+        let buf = op.read(entry.path()).await?;
+        let manifest: FullBackupManifest = serde_json::from_slice(&buf)?;
+        manifests.push(manifest);
+    }
+
+    manifests.sort_by_key(|m| (m.snapshot_index(), m.last_committed));
+    Ok(manifests)
+}
+
+/// List all log segments, ordered by the log index range.
+pub async fn list_log_segments(op: &Operator) -> Result<Vec<LogSegment>, io::Error> {
+    let mut segments = op
+        .list(LOG_SEGMENT_DIR)
+        .await?
+        .iter()
+        .filter_map(|entry| LogSegment::parse(entry.path()))
+        .collect::<Vec<_>>();
+
+    segments.sort();
+    Ok(segments)
+}
+
+async fn read_entries(
+    op: &Operator,
+    path: &str,
+) -> Result<Vec<(String, RaftStoreEntry)>, io::Error> {
+    let buf = op.read(path).await?;
+
+    let mut entries = vec![];
+    for line in buf.split(|b| *b == b'\n') {
+        if line.is_empty() {
+            continue;
+        }
+        let ent: (String, RaftStoreEntry) = serde_json::from_slice(line)?;
+        entries.push(ent);
+    }
+    Ok(entries)
+}
+
+/// Load the lines for restoring a node to the state right after applying log `to_index`,
+/// or to the latest backed up log if `to_index` is `None`.
+///
+/// The returned lines are in the format of [`StoreInner::export`](crate::store::StoreInner::export),
+/// thus they can be imported the same way as exported data.
+pub async fn load_backup(op: &Operator, to_index: Option<u64>) -> Result<Vec<String>, io::Error> {
+    let to_index = to_index.unwrap_or(u64::MAX);
+
+    let full = list_full_backups(op)
+        .await?
+        .into_iter()
+        .rev()
+        .find(|m| m.snapshot_index() <= to_index)
+        .ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::NotFound,
+                format!("no full backup found before log index {}", to_index),
+            )
+        })?;
+
+    info!("restore from full backup: {:?}", full);
+
+    let mut entries = vec![];
+    let mut raft_state_tree = "raft_state".to_string();
+    let mut last_log_id = full.snapshot_last_applied;
+    let mut last = min(full.last_committed, to_index);
+
+    for (tree_name, ent) in read_entries(op, &full.data_path).await? {
+        match &ent {
+            RaftStoreEntry::Logs { key, value } => {
+                if *key > last {
+                    continue;
+                }
+                last_log_id = max(last_log_id, Some(value.log_id));
+            }
+            RaftStoreEntry::RaftStateKV {
+                key: RaftStateKey::Committed,
+                ..
+            } => {
+                // Committed is rebuilt with the last restored log.
+                raft_state_tree = tree_name;
+                continue;
+            }
+            _ => {}
+        }
+        entries.push((tree_name, ent));
+    }
+
+    for segment in list_log_segments(op).await? {
+        if last >= to_index {
+            break;
+        }
+        if segment.last <= last {
+            continue;
+        }
+        if segment.first > last + 1 {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                format!("missing raft logs in backup since index {}", last + 1),
+            ));
+        }
+
+        for (tree_name, ent) in read_entries(op, &segment.path()).await? {
+            if let RaftStoreEntry::Logs { key, value } = &ent {
+                if *key <= last || *key > to_index {
+                    continue;
+                }
+                last = *key;
+                last_log_id = max(last_log_id, Some(value.log_id));
+                entries.push((tree_name, ent));
+            }
+        }
+    }
+
+    if to_index != u64::MAX && last < to_index {
+        return Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "backup only covers raft logs up to index {}, can not restore to {}",
+                last, to_index
+            ),
+        ));
+    }
+
+    entries.push((raft_state_tree, RaftStoreEntry::RaftStateKV {
+        key: RaftStateKey::Committed,
+        value: RaftStateValue::Committed(last_log_id),
+    }));
+
+    let mut lines = Vec::with_capacity(entries.len());
+    for ent in entries {
+        lines.push(serde_json::to_string(&ent)?);
+    }
+    Ok(lines)
+}
+
+/// Upload full backups and the subsequently applied raft logs periodically.
+///
+/// Only the leader uploads backups.
+/// When a node becomes the leader, it starts with a full backup.
+pub struct MetaBackup {
+    meta_node: Arc<MetaNode>,
+    operator: Operator,
+    config: BackupConfig,
+
+    /// The index of the last uploaded log, `None` if no full backup is uploaded by this node.
+    last_uploaded: Option<u64>,
+    last_full_backup: Option<Instant>,
+}
+
+impl MetaBackup {
+    /// Spawn a task to upload backups, which quits when the meta node is shut down.
+    pub async fn spawn(
+        meta_node: Arc<MetaNode>,
+        config: &BackupConfig,
+    ) -> Result<(), MetaStartupError> {
+        let operator = new_backup_operator(config)?;
+
+        info!("start meta backup to {:?}", operator.info().scheme());
+
+        let mut backup = Self {
+            meta_node: meta_node.clone(),
+            operator,
+            config: config.clone(),
+            last_uploaded: None,
+            last_full_backup: None,
+        };
+
+        let mut running_rx = meta_node.running_rx.clone();
+        let interval = Duration::from_secs(config.interval);
+
+        let h = tokio::spawn(async move {
+            loop {
+                let shutdown = tokio::time::timeout(interval, running_rx.changed()).await;
+                if shutdown.is_ok() {
+                    info!("meta backup quit");
+                    break;
+                }
+
+                if let Err(e) = backup.run_once().await {
+                    error!("meta backup failed: {}", e);
+                }
+            }
+
+            Ok::<(), AnyError>(())
+        });
+
+        let mut jh = meta_node.join_handles.lock().await;
+        jh.push(h);
+        Ok(())
+    }
+
+    async fn run_once(&mut self) -> Result<(), io::Error> {
+        let is_leader = {
+            let metrics = self.meta_node.raft.metrics();
+            let current_leader = metrics.borrow().current_leader;
+            current_leader == Some(self.meta_node.sto.id)
+        };
+
+        if !is_leader {
+            self.last_uploaded = None;
+            return Ok(());
+        }
+
+        let full_backup_due = match (self.last_uploaded, self.last_full_backup) {
+            (Some(_), Some(t)) => t.elapsed() >= Duration::from_secs(self.config.full_interval),
+            _ => true,
+        };
+
+        if full_backup_due {
+            self.full_backup().await
+        } else {
+            self.upload_logs().await
+        }
+    }
+
+    async fn last_applied_index(&self) -> u64 {
+        let sm = self.meta_node.sto.state_machine.read().await;
+        sm.sys_data_ref()
+            .last_applied_ref()
+            .map(|x| x.index)
+            .unwrap_or_default()
+    }
+
+    async fn full_backup(&mut self) -> Result<(), io::Error> {
+        let ts = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time went backwards")
+            .as_millis();
+        let data_path = format!("{}{:020}{}", FULL_BACKUP_DIR, ts, DATA_SUFFIX);
+        let manifest_path = format!("{}{:020}{}", FULL_BACKUP_DIR, ts, MANIFEST_SUFFIX);
+
+        let mut writer = self.operator.writer(&data_path).await?;
+        let mut lines = self.meta_node.sto.inner().export();
+
+        let mut snapshot_last_applied = None;
+        let mut last_log_index = None;
+        let mut n = 0;
+
+        while let Some(line) = lines.try_next().await? {
+            let (_, ent): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+            match ent {
+                RaftStoreEntry::Logs { key, .. } => {
+                    last_log_index = max(last_log_index, Some(key));
+                }
+                RaftStoreEntry::StateMachineMeta {
+                    key: StateMachineMetaKey::LastApplied,
+                    value: StateMachineMetaValue::LogId(log_id),
+                } => {
+                    snapshot_last_applied = Some(log_id);
+                }
+                _ => {}
+            }
+
+            writer.write(format!("{}\n", line)).await?;
+            n += 1;
+        }
+        writer.close().await?;
+
+        // Exported logs that are not yet applied may be uncommitted.
+        let applied = self.last_applied_index().await;
+        let snapshot_index = snapshot_last_applied.map(|x| x.index).unwrap_or_default();
+        let last_committed = max(
+            snapshot_index,
+            min(last_log_index.unwrap_or_default(), applied),
+        );
+
+        let manifest = FullBackupManifest {
+            data_path,
+            snapshot_last_applied,
+            last_committed,
+        };
+        self.operator
+            .write(&manifest_path, serde_json::to_vec(&manifest)?)
+            .await?;
+
+        info!("uploaded full backup with {} records: {:?}", n, manifest);
+
+        self.last_uploaded = Some(last_committed);
+        self.last_full_backup = Some(Instant::now());
+        Ok(())
+    }
+
+    async fn upload_logs(&mut self) -> Result<(), io::Error> {
+        // Safe unwrap: full backup is always done before uploading logs.
+        let first = self.last_uploaded.unwrap() + 1;
+        let last = self.last_applied_index().await;
+        if last < first {
+            return Ok(());
+        }
+
+        let (tree_name, logs) = {
+            let log = self.meta_node.sto.log.read().await;
+            let logs = log
+                .range_values(first..=last)
+                .map_err(|e| io::Error::new(ErrorKind::Other, e))?;
+            (log.inner.name.clone(), logs)
+        };
+
+        let first_index = logs.first().map(|x| x.log_id.index);
+        let last_index = logs.last().map(|x| x.log_id.index);
+        if first_index != Some(first) || last_index != Some(last) {
+            warn!(
+                "raft logs [{}, {}] are partially purged, start a new full backup",
+                first, last
+            );
+            return self.full_backup().await;
+        }
+
+        let mut buf = vec![];
+        for entry in logs {
+            let ent = RaftStoreEntry::Logs {
+                key: entry.log_id.index,
+                value: entry,
+            };
+            serde_json::to_writer(&mut buf, &(&tree_name, ent))?;
+            buf.push(b'\n');
+        }
+
+        let segment = LogSegment { first, last };
+        self.operator.write(&segment.path(), buf).await?;
+
+        info!("uploaded raft log segment: {:?}", segment);
+
+        self.last_uploaded = Some(last);
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 
 use common_meta_raft_store::config::RaftConfig;
@@ -41,6 +42,7 @@ pub struct Config {
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
     pub raft_config: RaftConfig,
+    pub backup: BackupConfig,
 }
 
 impl Default for Config {
@@ -63,6 +65,7 @@ impl Default for Config {
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
             raft_config: Default::default(),
+            backup: Default::default(),
         }
     }
 }
@@ -84,6 +87,7 @@ impl Config {
                 e, self.grpc_api_address
             ))
         })?;
+        self.backup.validate()?;
         Ok(())
     }
 
@@ -133,3 +137,62 @@ impl Config {
         !self.grpc_tls_server_key.is_empty() && !self.grpc_tls_server_cert.is_empty()
     }
 }
+
+/// Config for backing up meta data to an object storage.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct BackupConfig {
+    /// The opendal scheme of the backup storage, such as `fs` or `s3`.
+    /// Backup is disabled if it is empty.
+    pub storage_type: String,
+    /// Storage params in form of `<key>=<value>`, e.g., `root=/backup` or `bucket=meta`.
+    pub storage_params: Vec<String>,
+    /// The interval in seconds to upload the newly applied raft logs.
+    pub interval: u64,
+    /// The interval in seconds to upload a full backup.
+    pub full_interval: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            storage_type: "".to_string(),
+            storage_params: vec![],
+            interval: 60,
+            full_interval: 86400,
+        }
+    }
+}
+
+impl BackupConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.storage_type.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), MetaStartupError> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        if self.interval == 0 || self.full_interval == 0 {
+            return Err(MetaStartupError::InvalidConfig(
+                "backup interval and full_interval must be greater than 0".to_string(),
+            ));
+        }
+        self.params()?;
+        Ok(())
+    }
+
+    /// Parse `storage_params` into a map.
+    pub fn params(&self) -> Result<BTreeMap<String, String>, MetaStartupError> {
+        let mut params = BTreeMap::new();
+        for p in self.storage_params.iter() {
+            let (k, v) = p.split_once('=').ok_or_else(|| {
+                MetaStartupError::InvalidConfig(format!(
+                    "invalid backup storage param: '{}', expect '<key>=<value>'",
+                    p
+                ))
+            })?;
+            params.insert(k.trim().to_string(), v.trim().to_string());
+        }
+        Ok(params)
+    }
+}
@@ -20,6 +20,7 @@ use clap::Parser;
 use common_meta_raft_store::config::get_default_raft_advertise_host;
 use common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use common_meta_types::MetaStartupError;
+use common_tracing::AuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig;
@@ -32,6 +33,7 @@ use serfig::collectors::from_file;
 use serfig::collectors::from_self;
 use serfig::parsers::Toml;
 
+use super::inner::BackupConfig as InnerBackupConfig;
 use super::inner::Config as InnerConfig;
 use crate::version::METASRV_COMMIT_VERSION;
 
@@ -127,6 +129,9 @@ pub struct Config {
 
     #[clap(flatten)]
     pub raft_config: RaftConfig,
+
+    #[clap(flatten)]
+    pub backup: BackupConfig,
 }
 
 impl Default for Config {
@@ -163,6 +168,7 @@ impl From<Config> for InnerConfig {
             grpc_tls_server_cert: outer.grpc_tls_server_cert,
             grpc_tls_server_key: outer.grpc_tls_server_key,
             raft_config: outer.raft_config.into(),
+            backup: outer.backup.into(),
         }
     }
 }
@@ -189,6 +195,7 @@ impl From<InnerConfig> for Config {
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
             grpc_tls_server_key: inner.grpc_tls_server_key,
             raft_config: inner.raft_config.into(),
+            backup: inner.backup.into(),
         }
     }
 }
@@ -292,6 +299,11 @@ pub struct ConfigViaEnv {
     pub kvsrv_id: u64,
     pub sled_tree_prefix: String,
     pub cluster_name: String,
+
+    pub metasrv_backup_storage_type: String,
+    pub metasrv_backup_storage_params: Vec<String>,
+    pub metasrv_backup_interval: u64,
+    pub metasrv_backup_full_interval: u64,
 }
 
 impl Default for ConfigViaEnv {
@@ -337,6 +349,10 @@ impl From<Config> for ConfigViaEnv {
             kvsrv_id: cfg.raft_config.id,
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             cluster_name: cfg.raft_config.cluster_name,
+            metasrv_backup_storage_type: cfg.backup.backup_storage_type,
+            metasrv_backup_storage_params: cfg.backup.backup_storage_params,
+            metasrv_backup_interval: cfg.backup.backup_interval,
+            metasrv_backup_full_interval: cfg.backup.backup_full_interval,
         }
     }
 }
@@ -368,6 +384,12 @@ impl Into<Config> for ConfigViaEnv {
             sled_tree_prefix: self.sled_tree_prefix,
             cluster_name: self.cluster_name,
         };
+        let backup = BackupConfig {
+            backup_storage_type: self.metasrv_backup_storage_type,
+            backup_storage_params: self.metasrv_backup_storage_params,
+            backup_interval: self.metasrv_backup_interval,
+            backup_full_interval: self.metasrv_backup_full_interval,
+        };
         let log_config = LogConfig {
             file: FileLogConfig {
                 file_on: self.metasrv_log_file_on,
@@ -403,6 +425,7 @@ impl Into<Config> for ConfigViaEnv {
             grpc_tls_server_cert: self.grpc_tls_server_cert,
             grpc_tls_server_key: self.grpc_tls_server_key,
             raft_config,
+            backup,
         }
     }
 }
@@ -593,6 +616,7 @@ impl Into<InnerLogConfig> for LogConfig {
                 on: false,
                 dir: "".to_string(),
             },
+            audit: AuditLogConfig::default(),
             tracing: TracingConfig::default(),
         }
     }
@@ -704,3 +728,57 @@ impl From<InnerStderrLogConfig> for StderrLogConfig {
         }
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// The storage type to upload backups to, e.g., `fs`, `s3`.
+    /// Online backup is disabled if it is empty.
+    #[clap(long = "backup-storage-type", default_value = "")]
+    #[serde(rename = "storage_type")]
+    pub backup_storage_type: String,
+
+    /// The params to build the backup storage, in form of `<key>=<value>`,
+    /// e.g., `--backup-storage-params root=/backup --backup-storage-params bucket=meta`
+    #[clap(long = "backup-storage-params")]
+    #[serde(rename = "storage_params")]
+    pub backup_storage_params: Vec<String>,
+
+    /// The interval in seconds to upload the newly applied raft logs.
+    #[clap(long = "backup-interval", default_value = "60")]
+    #[serde(rename = "interval")]
+    pub backup_interval: u64,
+
+    /// The interval in seconds to upload a full backup.
+    #[clap(long = "backup-full-interval", default_value = "86400")]
+    #[serde(rename = "full_interval")]
+    pub backup_full_interval: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        InnerBackupConfig::default().into()
+    }
+}
+
+impl From<BackupConfig> for InnerBackupConfig {
+    fn from(x: BackupConfig) -> InnerBackupConfig {
+        InnerBackupConfig {
+            storage_type: x.backup_storage_type,
+            storage_params: x.backup_storage_params,
+            interval: x.backup_interval,
+            full_interval: x.backup_full_interval,
+        }
+    }
+}
+
+impl From<InnerBackupConfig> for BackupConfig {
+    fn from(inner: InnerBackupConfig) -> Self {
+        Self {
+            backup_storage_type: inner.storage_type,
+            backup_storage_params: inner.storage_params,
+            backup_interval: inner.interval,
+            backup_full_interval: inner.full_interval,
+        }
+    }
+}
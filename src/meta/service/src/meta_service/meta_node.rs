@@ -80,6 +80,7 @@ use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
 
+use crate::backup::MetaBackup;
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
@@ -545,6 +546,11 @@ impl MetaNode {
     pub async fn start(config: &MetaConfig) -> Result<Arc<MetaNode>, MetaStartupError> {
         info!(config = as_debug!(config); "start()");
         let mn = Self::do_start(config).await?;
+
+        if config.backup.is_enabled() {
+            MetaBackup::spawn(mn.clone(), &config.backup).await?;
+        }
+
         info!("Done starting MetaNode: {:?}", config);
         Ok(mn)
     }
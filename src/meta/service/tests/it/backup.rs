@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::ErrorKind;
+
+use common_meta_raft_store::key_spaces::RaftStoreEntry;
+use common_meta_raft_store::state::RaftStateKey;
+use common_meta_raft_store::state::RaftStateValue;
+use common_meta_types::new_log_id;
+use common_meta_types::Entry;
+use common_meta_types::EntryPayload;
+use common_meta_types::LogId;
+use databend_meta::backup::list_full_backups;
+use databend_meta::backup::list_log_segments;
+use databend_meta::backup::load_backup;
+use databend_meta::backup::new_backup_operator;
+use databend_meta::backup::FullBackupManifest;
+use databend_meta::backup::LogSegment;
+use databend_meta::configs::BackupConfig;
+use opendal::Operator;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::testing::meta_service_test_harness_sync;
+
+fn log_line(index: u64) -> String {
+    let ent = RaftStoreEntry::Logs {
+        key: index,
+        value: Entry {
+            log_id: new_log_id(1, 0, index),
+            payload: EntryPayload::Blank,
+        },
+    };
+    serde_json::to_string(&("raft_log".to_string(), ent)).unwrap()
+}
+
+fn committed_line(log_id: Option<LogId>) -> String {
+    let ent = RaftStoreEntry::RaftStateKV {
+        key: RaftStateKey::Committed,
+        value: RaftStateValue::Committed(log_id),
+    };
+    serde_json::to_string(&("raft_state".to_string(), ent)).unwrap()
+}
+
+/// Returns the indexes of the restored logs and the restored committed log id.
+fn parse_restored(lines: &[String]) -> (Vec<u64>, Option<Option<LogId>>) {
+    let mut logs = vec![];
+    let mut committed = None;
+    for line in lines {
+        let (_, ent): (String, RaftStoreEntry) = serde_json::from_str(line).unwrap();
+        match ent {
+            RaftStoreEntry::Logs { key, .. } => logs.push(key),
+            RaftStoreEntry::RaftStateKV {
+                key: RaftStateKey::Committed,
+                value: RaftStateValue::Committed(log_id),
+            } => {
+                assert!(committed.is_none(), "committed is restored only once");
+                committed = Some(log_id);
+            }
+            _ => {}
+        }
+    }
+    (logs, committed)
+}
+
+async fn write_lines(op: &Operator, path: &str, lines: Vec<String>) -> anyhow::Result<()> {
+    let buf = lines.into_iter().map(|l| l + "\n").collect::<String>();
+    op.write(path, buf).await?;
+    Ok(())
+}
+
+/// Writes a full backup with logs `[1, 5]`, of which `[1, 3]` are committed,
+/// followed by the log segments `[4, 6]` and `[7, 8]`.
+async fn write_backup(op: &Operator) -> anyhow::Result<()> {
+    let data_path = "full/00000000000000000001.data";
+    let mut lines = (1..=5).map(log_line).collect::<Vec<_>>();
+    lines.push(committed_line(Some(new_log_id(1, 0, 5))));
+    write_lines(op, data_path, lines).await?;
+
+    let manifest = FullBackupManifest {
+        data_path: data_path.to_string(),
+        snapshot_last_applied: None,
+        last_committed: 3,
+    };
+    op.write(
+        "full/00000000000000000001.manifest",
+        serde_json::to_vec(&manifest)?,
+    )
+    .await?;
+
+    // An incomplete full backup without manifest is ignored.
+    write_lines(op, "full/00000000000000000002.data", vec![log_line(1)]).await?;
+
+    for (first, last) in [(4, 6), (7, 8)] {
+        let segment = LogSegment { first, last };
+        write_lines(op, &segment.path(), (first..=last).map(log_line).collect()).await?;
+    }
+    Ok(())
+}
+
+fn fs_operator(root: &str) -> anyhow::Result<Operator> {
+    let config = BackupConfig {
+        storage_type: "fs".to_string(),
+        storage_params: vec![format!("root={}", root)],
+        ..Default::default()
+    };
+    Ok(new_backup_operator(&config)?)
+}
+
+#[test(harness = meta_service_test_harness_sync)]
+#[minitrace::trace]
+fn test_log_segment_path() -> anyhow::Result<()> {
+    let segment = LogSegment { first: 3, last: 15 };
+    assert_eq!(
+        "log/00000000000000000003-00000000000000000015.data",
+        segment.path()
+    );
+    assert_eq!(Some(segment), LogSegment::parse(&segment.path()));
+
+    assert_eq!(None, LogSegment::parse("full/00000000000000000003.data"));
+    assert_eq!(None, LogSegment::parse("log/3-15.manifest"));
+    assert_eq!(None, LogSegment::parse("log/3.data"));
+    assert_eq!(None, LogSegment::parse("log/a-15.data"));
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_backup_list() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let op = fs_operator(dir.path().to_str().unwrap())?;
+    write_backup(&op).await?;
+
+    let full = list_full_backups(&op).await?;
+    assert_eq!(1, full.len());
+    assert_eq!("full/00000000000000000001.data", full[0].data_path);
+
+    let segments = list_log_segments(&op).await?;
+    assert_eq!(
+        vec![LogSegment { first: 4, last: 6 }, LogSegment {
+            first: 7,
+            last: 8
+        }],
+        segments
+    );
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_backup_restore() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let op = fs_operator(dir.path().to_str().unwrap())?;
+    write_backup(&op).await?;
+
+    // Restore to the latest backed up log.
+    let (logs, committed) = parse_restored(&load_backup(&op, None).await?);
+    assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8], logs);
+    assert_eq!(Some(Some(new_log_id(1, 0, 8))), committed);
+
+    // Restore to a log in the full backup, the uncommitted logs are dropped.
+    let (logs, committed) = parse_restored(&load_backup(&op, Some(2)).await?);
+    assert_eq!(vec![1, 2], logs);
+    assert_eq!(Some(Some(new_log_id(1, 0, 2))), committed);
+
+    // Restore to a log in a log segment.
+    let (logs, committed) = parse_restored(&load_backup(&op, Some(5)).await?);
+    assert_eq!(vec![1, 2, 3, 4, 5], logs);
+    assert_eq!(Some(Some(new_log_id(1, 0, 5))), committed);
+
+    // Logs after the last segment are not backed up.
+    let err = load_backup(&op, Some(9)).await.unwrap_err();
+    assert!(err.to_string().contains("up to index 8"), "{}", err);
+
+    // A missing segment breaks the restore after it.
+    op.delete(&LogSegment { first: 4, last: 6 }.path()).await?;
+    let err = load_backup(&op, None).await.unwrap_err();
+    assert!(err.to_string().contains("since index 4"), "{}", err);
+    let (logs, _) = parse_restored(&load_backup(&op, Some(3)).await?);
+    assert_eq!(vec![1, 2, 3], logs);
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_backup_restore_without_full_backup() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let op = fs_operator(dir.path().to_str().unwrap())?;
+
+    let err = load_backup(&op, None).await.unwrap_err();
+    assert_eq!(ErrorKind::NotFound, err.kind());
+    Ok(())
+}
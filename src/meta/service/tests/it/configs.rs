@@ -65,6 +65,12 @@ join = ["j1", "j2"]
 id = 20
 sled_tree_prefix = "sled_foo"
 cluster_name = "foo_cluster"
+
+[backup]
+storage_type = "fs"
+storage_params = ["root=/tmp/meta_backup"]
+interval = 30
+full_interval = 3600
              "#
     )?;
 
@@ -92,6 +98,11 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.raft_config.id, 20);
         assert_eq!(cfg.raft_config.sled_tree_prefix, "sled_foo");
         assert_eq!(cfg.raft_config.cluster_name, "foo_cluster");
+        assert!(cfg.backup.is_enabled());
+        assert_eq!(cfg.backup.storage_type, "fs");
+        assert_eq!(cfg.backup.storage_params, vec!["root=/tmp/meta_backup"]);
+        assert_eq!(cfg.backup.interval, 30);
+        assert_eq!(cfg.backup.full_interval, 3600);
     });
 
     temp_env::with_vars(
@@ -123,5 +134,21 @@ cluster_name = "foo_cluster"
         },
     );
 
+    // Test backup config.
+    temp_env::with_vars(
+        vec![
+            (
+                "METASRV_CONFIG_FILE",
+                Some(file_path.to_str().expect("must be valid str")),
+            ),
+            ("METASRV_BACKUP_INTERVAL", Some("10")),
+        ],
+        || {
+            let cfg = Config::load_for_test().expect("load must success");
+            assert_eq!(cfg.backup.interval, 10);
+            assert_eq!(cfg.backup.storage_type, "fs");
+        },
+    );
+
     Ok(())
 }
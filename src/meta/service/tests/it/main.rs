@@ -19,6 +19,7 @@
 #![recursion_limit = "1024"]
 #![feature(extend_one)]
 mod api;
+mod backup;
 mod configs;
 mod grpc;
 mod meta_node;
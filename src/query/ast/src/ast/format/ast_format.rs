@@ -1336,6 +1336,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(cluster_by_format_ctx, cluster_by_children);
             children.push(cluster_by_node);
         }
+        if !stmt.partition_by.is_empty() {
+            let mut partition_by_children = Vec::with_capacity(stmt.partition_by.len());
+            for partition_by in stmt.partition_by.iter() {
+                self.visit_expr(partition_by);
+                partition_by_children.push(self.children.pop().unwrap());
+            }
+            let partition_by_name = "PartitionByList".to_string();
+            let partition_by_format_ctx =
+                AstFormatContext::with_children(partition_by_name, partition_by_children.len());
+            let partition_by_node =
+                FormatTreeNode::with_children(partition_by_format_ctx, partition_by_children);
+            children.push(partition_by_node);
+        }
         if !stmt.table_options.is_empty() {
             let mut table_options_children = Vec::with_capacity(stmt.table_options.len());
             for (k, v) in stmt.table_options.iter() {
@@ -1365,12 +1378,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in constraints.iter() {
+                    let name = format!("Constraint {}", constraint);
+                    let format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1388,6 +1406,23 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let mut children = vec![self.children.pop().unwrap()];
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1476,6 +1511,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         format!("Action ModifyColumn column {}", column),
                         "Action ConvertStoredComputedColumn".to_string(),
                     ),
+                    ModifyColumnAction::SetCodec(column, codecs) => (
+                        format!("Action ModifyColumn column {}", column),
+                        format!(
+                            "Action SetCodec {}",
+                            codecs
+                                .iter()
+                                .map(|codec| codec.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    ),
                 };
                 let child_format_ctx = AstFormatContext::new(child_name);
                 let child = FormatTreeNode::new(child_format_ctx);
@@ -1537,6 +1583,99 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                let action_name = format!("Action CreateTag {}", tag);
+                let mut children = Vec::new();
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::CreateBranch {
+                branch,
+                travel_point,
+            } => {
+                let action_name = format!("Action CreateBranch {}", branch);
+                let mut children = Vec::new();
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::DropTag { tag } => {
+                let action_name = format!("Action DropTag {}", tag);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::MergeBranch { branch } => {
+                let action_name = format!("Action MergeBranch {}", branch);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropPartition { values, .. } => {
+                let mut children = Vec::with_capacity(values.len());
+                for value in values.iter() {
+                    self.visit_expr(value);
+                    children.push(self.children.pop().unwrap());
+                }
+                let action_name = "Action DropPartition".to_string();
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::TruncatePartition { values } => {
+                let mut children = Vec::with_capacity(values.len());
+                for value in values.iter() {
+                    self.visit_expr(value);
+                    children.push(self.children.pop().unwrap());
+                }
+                let action_name = "Action TruncatePartition".to_string();
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::ReplacePartition {
+                values,
+                catalog,
+                database,
+                table,
+            } => {
+                let mut children = Vec::with_capacity(values.len() + 1);
+                for value in values.iter() {
+                    self.visit_expr(value);
+                    children.push(self.children.pop().unwrap());
+                }
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                let action_name = "Action ReplacePartition".to_string();
+                let action_format_ctx =
+                    AstFormatContext::with_children(action_name, children.len());
+                FormatTreeNode::with_children(action_format_ctx, children)
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action AddRowAccessPolicy {} on {}",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|column| column.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action DropRowAccessPolicy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -2465,6 +2604,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
         let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -2511,6 +2680,88 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_password_policy(&mut self, stmt: &'ast CreatePasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreatePasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_password_policy(&mut self, stmt: &'ast AlterPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_password_policy(&mut self, stmt: &'ast DropPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_password_policy(&mut self, stmt: &'ast DescPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_password_policies(&mut self) {
+        let ctx = AstFormatContext::new("ShowPasswordPolicies".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_workload_group(&mut self, stmt: &'ast AlterWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_workload_groups(&mut self) {
+        let ctx = AstFormatContext::new("ShowWorkloadGroups".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -2930,6 +3181,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Tag(tag) => {
+                let name = format!("Tag {}", tag);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
         }
     }
 
@@ -78,6 +78,15 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if !stmt.partition_by.is_empty() {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(parenthesized(
+                    interweave_comma(stmt.partition_by.into_iter().map(pretty_expr)).group(),
+                ))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.table_options.is_empty() {
             RcDoc::line()
                 .append(interweave_comma(stmt.table_options.iter().map(|(k, v)| {
@@ -106,11 +115,16 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
+        CreateTableSource::Columns(columns, constraints) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    ),
             )
             .group(),
         )),
@@ -132,6 +146,35 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(match travel_point {
+                Some(TimeTravelPoint::Snapshot(sid)) => {
+                    RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
+                }
+                Some(TimeTravelPoint::Timestamp(ts)) => {
+                    RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+                }
+                Some(TimeTravelPoint::Tag(tag)) => RcDoc::text(format!(" AT (TAG => {tag})")),
+                None => RcDoc::nil(),
+            }),
     }
 }
 
@@ -216,6 +259,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RevertTo { point } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
+            TimeTravelPoint::Tag(tag) => RcDoc::text(format!(" AT (TAG => {tag})")),
         },
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
@@ -225,6 +269,68 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::CreateTag { tag, travel_point } => RcDoc::line()
+            .append(RcDoc::text(format!("CREATE TAG {tag}")))
+            .append(match travel_point {
+                Some(travel_point) => RcDoc::text(format!(" AT{travel_point}")),
+                None => RcDoc::nil(),
+            }),
+        AlterTableAction::DropTag { tag } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP TAG {tag}")))
+        }
+        AlterTableAction::CreateBranch {
+            branch,
+            travel_point,
+        } => RcDoc::line()
+            .append(RcDoc::text(format!("CREATE BRANCH {branch}")))
+            .append(match travel_point {
+                Some(travel_point) => RcDoc::text(format!(" AT{travel_point}")),
+                None => RcDoc::nil(),
+            }),
+        AlterTableAction::MergeBranch { branch } => {
+            RcDoc::line().append(RcDoc::text(format!("MERGE BRANCH {branch}")))
+        }
+        AlterTableAction::DropPartition { if_exists, values } => RcDoc::line()
+            .append(RcDoc::text("DROP PARTITION "))
+            .append(if if_exists {
+                RcDoc::text("IF EXISTS ")
+            } else {
+                RcDoc::nil()
+            })
+            .append(parenthesized(
+                interweave_comma(values.into_iter().map(pretty_expr)).group(),
+            )),
+        AlterTableAction::TruncatePartition { values } => RcDoc::line()
+            .append(RcDoc::text("TRUNCATE PARTITION "))
+            .append(parenthesized(
+                interweave_comma(values.into_iter().map(pretty_expr)).group(),
+            )),
+        AlterTableAction::ReplacePartition {
+            values,
+            catalog,
+            database,
+            table,
+        } => RcDoc::line()
+            .append(RcDoc::text("REPLACE PARTITION "))
+            .append(parenthesized(
+                interweave_comma(values.into_iter().map(pretty_expr)).group(),
+            ))
+            .append(RcDoc::text(" FROM "))
+            .append(
+                RcDoc::text(catalog.map(|c| format!("{c}.")).unwrap_or_default())
+                    .append(RcDoc::text(
+                        database.map(|d| format!("{d}.")).unwrap_or_default(),
+                    ))
+                    .append(RcDoc::text(table.to_string())),
+            ),
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text(format!("ADD ROW ACCESS POLICY {policy} ON ")))
+            .append(parenthesized(
+                interweave_comma(columns.into_iter().map(|c| RcDoc::text(c.to_string()))).group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy { policy } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP ROW ACCESS POLICY {policy}")))
+        }
     }
 }
 
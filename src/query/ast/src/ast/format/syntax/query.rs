@@ -339,6 +339,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
         } else if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
             RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+        } else if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+            RcDoc::text(format!(" AT (TAG => {tag})"))
         } else {
             RcDoc::nil()
         })
@@ -215,6 +215,7 @@ pub enum Indirection {
 pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
+    Tag(Identifier),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -442,6 +443,10 @@ impl Display for TableReference {
                     write!(f, " AT (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+                    write!(f, " AT (TAG => {tag})")?;
+                }
+
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -771,6 +776,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, " (TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Tag(tag) => {
+                write!(f, " (TAG => {tag})")?;
+            }
         }
 
         Ok(())
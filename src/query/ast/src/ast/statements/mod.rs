@@ -27,9 +27,11 @@ mod insert;
 mod kill;
 mod merge_into;
 mod network_policy;
+mod password_policy;
 mod pipe;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod share;
 mod show;
 mod stage;
@@ -42,6 +44,7 @@ mod update;
 mod user;
 mod view;
 mod virtual_column;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -58,9 +61,11 @@ pub use insert::*;
 pub use kill::*;
 pub use merge_into::*;
 pub use network_policy::*;
+pub use password_policy::*;
 pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
@@ -73,3 +78,4 @@ pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_column::*;
+pub use workload_group::*;
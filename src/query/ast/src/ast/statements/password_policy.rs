@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordPolicyOption {
+    MinLength(u64),
+    MaxLength(u64),
+    MinUpperCaseChars(u64),
+    MinLowerCaseChars(u64),
+    MinNumericChars(u64),
+    MinSpecialChars(u64),
+    MinAgeDays(u64),
+    MaxAgeDays(u64),
+    MaxRetries(u64),
+    LockoutTimeMins(u64),
+    History(u64),
+    Comment(String),
+}
+
+impl Display for PasswordPolicyOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PasswordPolicyOption::MinLength(v) => write!(f, "PASSWORD_MIN_LENGTH = {v}"),
+            PasswordPolicyOption::MaxLength(v) => write!(f, "PASSWORD_MAX_LENGTH = {v}"),
+            PasswordPolicyOption::MinUpperCaseChars(v) => {
+                write!(f, "PASSWORD_MIN_UPPER_CASE_CHARS = {v}")
+            }
+            PasswordPolicyOption::MinLowerCaseChars(v) => {
+                write!(f, "PASSWORD_MIN_LOWER_CASE_CHARS = {v}")
+            }
+            PasswordPolicyOption::MinNumericChars(v) => {
+                write!(f, "PASSWORD_MIN_NUMERIC_CHARS = {v}")
+            }
+            PasswordPolicyOption::MinSpecialChars(v) => {
+                write!(f, "PASSWORD_MIN_SPECIAL_CHARS = {v}")
+            }
+            PasswordPolicyOption::MinAgeDays(v) => write!(f, "PASSWORD_MIN_AGE_DAYS = {v}"),
+            PasswordPolicyOption::MaxAgeDays(v) => write!(f, "PASSWORD_MAX_AGE_DAYS = {v}"),
+            PasswordPolicyOption::MaxRetries(v) => write!(f, "PASSWORD_MAX_RETRIES = {v}"),
+            PasswordPolicyOption::LockoutTimeMins(v) => {
+                write!(f, "PASSWORD_LOCKOUT_TIME_MINS = {v}")
+            }
+            PasswordPolicyOption::History(v) => write!(f, "PASSWORD_HISTORY = {v}"),
+            PasswordPolicyOption::Comment(v) => write!(f, "COMMENT = '{v}'"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatePasswordPolicyStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub options: Vec<PasswordPolicyOption>,
+}
+
+impl Display for CreatePasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE PASSWORD POLICY ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        for option in &self.options {
+            write!(f, " {option}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterPasswordPolicyStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub options: Vec<PasswordPolicyOption>,
+}
+
+impl Display for AlterPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER PASSWORD POLICY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET", self.name)?;
+        for option in &self.options {
+            write!(f, " {option}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropPasswordPolicyStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP PASSWORD POLICY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescPasswordPolicyStmt {
+    pub name: String,
+}
+
+impl Display for DescPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE PASSWORD POLICY {}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -133,6 +133,7 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Vec<Expr>,
+    pub partition_by: Vec<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
@@ -174,6 +175,12 @@ impl Display for CreateTableStmt {
             write!(f, ")")?
         }
 
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, ")")?
+        }
+
         // Format table options
         write_comma_separated_map(f, &self.table_options)?;
         if let Some(as_query) = &self.as_query {
@@ -216,20 +223,30 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<TableConstraint>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -240,6 +257,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -365,6 +395,40 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    CreateTag {
+        tag: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropTag {
+        tag: Identifier,
+    },
+    CreateBranch {
+        branch: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    MergeBranch {
+        branch: Identifier,
+    },
+    DropPartition {
+        if_exists: bool,
+        values: Vec<Expr>,
+    },
+    TruncatePartition {
+        values: Vec<Expr>,
+    },
+    ReplacePartition {
+        values: Vec<Expr>,
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+    },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -419,6 +483,60 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                write!(f, "CREATE TAG {tag}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+            }
+            AlterTableAction::DropTag { tag } => {
+                write!(f, "DROP TAG {tag}")?;
+            }
+            AlterTableAction::CreateBranch {
+                branch,
+                travel_point,
+            } => {
+                write!(f, "CREATE BRANCH {branch}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT{travel_point}")?;
+                }
+            }
+            AlterTableAction::MergeBranch { branch } => {
+                write!(f, "MERGE BRANCH {branch}")?;
+            }
+            AlterTableAction::DropPartition { if_exists, values } => {
+                write!(f, "DROP PARTITION ")?;
+                if *if_exists {
+                    write!(f, "IF EXISTS ")?;
+                }
+                write!(f, "(")?;
+                write_comma_separated_list(f, values)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::TruncatePartition { values } => {
+                write!(f, "TRUNCATE PARTITION (")?;
+                write_comma_separated_list(f, values)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::ReplacePartition {
+                values,
+                catalog,
+                database,
+                table,
+            } => {
+                write!(f, "REPLACE PARTITION (")?;
+                write_comma_separated_list(f, values)?;
+                write!(f, ") FROM ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")?;
+            }
         };
         Ok(())
     }
@@ -744,6 +862,61 @@ impl Display for ColumnExpr {
     }
 }
 
+/// A `PRIMARY KEY` or `UNIQUE` constraint in the column list of `CREATE TABLE`.
+///
+/// The column level constraints, e.g. `a INT PRIMARY KEY`, are parsed as table constraints too.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableConstraint {
+    PrimaryKey { columns: Vec<Identifier> },
+    Unique { columns: Vec<Identifier> },
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableConstraint::PrimaryKey { columns } => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraint::Unique { columns } => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// A column level `CODEC(...)` item, either an encoding or a compression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnCodec {
+    Delta,
+    DoubleDelta,
+    Gorilla,
+    Dictionary,
+    None,
+    Lz4,
+    Snappy,
+    Zstd(Option<u64>),
+}
+
+impl Display for ColumnCodec {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ColumnCodec::Delta => write!(f, "DELTA"),
+            ColumnCodec::DoubleDelta => write!(f, "DOUBLEDELTA"),
+            ColumnCodec::Gorilla => write!(f, "GORILLA"),
+            ColumnCodec::Dictionary => write!(f, "DICTIONARY"),
+            ColumnCodec::None => write!(f, "NONE"),
+            ColumnCodec::Lz4 => write!(f, "LZ4"),
+            ColumnCodec::Snappy => write!(f, "SNAPPY"),
+            ColumnCodec::Zstd(None) => write!(f, "ZSTD"),
+            ColumnCodec::Zstd(Some(level)) => write!(f, "ZSTD({level})"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum NullableConstraint {
     Null,
@@ -757,6 +930,7 @@ pub struct ColumnDefinition {
     pub expr: Option<ColumnExpr>,
     pub comment: Option<String>,
     pub nullable_constraint: Option<NullableConstraint>,
+    pub codecs: Vec<ColumnCodec>,
 }
 
 impl Display for ColumnDefinition {
@@ -773,6 +947,11 @@ impl Display for ColumnDefinition {
         if let Some(expr) = &self.expr {
             write!(f, "{expr}")?;
         }
+        if !self.codecs.is_empty() {
+            write!(f, " CODEC(")?;
+            write_comma_separated_list(f, &self.codecs)?;
+            write!(f, ")")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, " COMMENT '{comment}'")?;
         }
@@ -790,6 +969,8 @@ pub enum ModifyColumnAction {
     SetDataType(Vec<ColumnDefinition>),
     // column name id
     ConvertStoredComputedColumn(Identifier),
+    // (column name id, codecs)
+    SetCodec(Identifier, Vec<ColumnCodec>),
 }
 
 impl Display for ModifyColumnAction {
@@ -834,6 +1015,11 @@ impl Display for ModifyColumnAction {
             ModifyColumnAction::ConvertStoredComputedColumn(column) => {
                 write!(f, "{} DROP STORED", column)?
             }
+            ModifyColumnAction::SetCodec(column, codecs) => {
+                write!(f, "{} CODEC(", column)?;
+                write_comma_separated_list(f, codecs)?;
+                write!(f, ")")?
+            }
         }
 
         Ok(())
@@ -21,6 +21,7 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserOptionFlag;
 use common_meta_app::principal::UserPrivilegeType;
+use common_meta_app::principal::UserQuota;
 
 use crate::ast::write_comma_separated_list;
 
@@ -168,6 +169,12 @@ pub enum UserOptionItem {
     DefaultRole(String),
     SetNetworkPolicy(String),
     UnsetNetworkPolicy,
+    SetPasswordPolicy(String),
+    UnsetPasswordPolicy,
+    Unlock,
+    MaxCpu(u64),
+    MaxMemory(u64),
+    MaxStorage(u64),
 }
 
 impl UserOptionItem {
@@ -179,6 +186,19 @@ impl UserOptionItem {
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
             Self::SetNetworkPolicy(v) => option.set_network_policy(Some(v.clone())),
             Self::UnsetNetworkPolicy => option.set_network_policy(None),
+            Self::SetPasswordPolicy(v) => option.set_password_policy(Some(v.clone())),
+            Self::UnsetPasswordPolicy => option.set_password_policy(None),
+            Self::Unlock | Self::MaxCpu(_) | Self::MaxMemory(_) | Self::MaxStorage(_) => {}
+        }
+    }
+
+    /// Applies the quota options, 0 means no limit.
+    pub fn apply_quota(&self, quota: &mut UserQuota) {
+        match self {
+            Self::MaxCpu(v) => quota.max_cpu = *v,
+            Self::MaxMemory(v) => quota.max_memory_in_bytes = *v,
+            Self::MaxStorage(v) => quota.max_storage_in_bytes = *v,
+            _ => {}
         }
     }
 }
@@ -247,6 +267,12 @@ impl Display for UserOptionItem {
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
             UserOptionItem::SetNetworkPolicy(v) => write!(f, "SET NETWORK POLICY = '{}'", v),
             UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
+            UserOptionItem::SetPasswordPolicy(v) => write!(f, "SET PASSWORD POLICY = '{}'", v),
+            UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
+            UserOptionItem::Unlock => write!(f, "UNLOCK"),
+            UserOptionItem::MaxCpu(v) => write!(f, "MAX_CPU = {}", v),
+            UserOptionItem::MaxMemory(v) => write!(f, "MAX_MEMORY = {}", v),
+            UserOptionItem::MaxStorage(v) => write!(f, "MAX_STORAGE = {}", v),
         }
     }
 }
@@ -0,0 +1,111 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_quoted_list;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkloadGroupOption {
+    MaxConcurrency(u64),
+    MemoryPercentage(u64),
+    CpuWeight(u64),
+    QueueTimeout(u64),
+    Users(Vec<String>),
+    Roles(Vec<String>),
+}
+
+impl Display for WorkloadGroupOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            WorkloadGroupOption::MaxConcurrency(v) => write!(f, "MAX_CONCURRENCY = {v}"),
+            WorkloadGroupOption::MemoryPercentage(v) => write!(f, "MEMORY_PERCENTAGE = {v}"),
+            WorkloadGroupOption::CpuWeight(v) => write!(f, "CPU_WEIGHT = {v}"),
+            WorkloadGroupOption::QueueTimeout(v) => write!(f, "QUEUE_TIMEOUT = {v}"),
+            WorkloadGroupOption::Users(users) => {
+                write!(f, "USERS = (")?;
+                write_comma_separated_quoted_list(f, users)?;
+                write!(f, ")")
+            }
+            WorkloadGroupOption::Roles(roles) => {
+                write!(f, "ROLES = (")?;
+                write_comma_separated_quoted_list(f, roles)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateWorkloadGroupStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub options: Vec<WorkloadGroupOption>,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE WORKLOAD GROUP ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        for option in &self.options {
+            write!(f, " {option}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub options: Vec<WorkloadGroupOption>,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET", self.name)?;
+        for option in &self.options {
+            write!(f, " {option}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -1395,6 +1395,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     map(
         alt((
             rule! {
@@ -1422,6 +1423,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_datetime
             | #ty_string
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ NULL? : "type name" },
         )),
@@ -25,6 +25,7 @@ pub mod statement;
 pub mod token;
 pub mod unescape;
 
+pub use parser::parse_bloom_index_columns;
 pub use parser::parse_comma_separated_exprs;
 pub use parser::parse_comma_separated_idents;
 pub use parser::parse_expr;
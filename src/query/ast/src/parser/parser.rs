@@ -22,17 +22,21 @@ use crate::error::display_parser_error;
 use crate::input::Dialect;
 use crate::input::Input;
 use crate::parser::expr;
+use crate::parser::expr::literal_u64;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::values_with_placeholder;
 use crate::parser::statement::statement;
 use crate::parser::token::Token;
 use crate::parser::token::TokenKind;
 use crate::parser::token::Tokenizer;
+use crate::rule;
 use crate::util::comma_separated_list0;
 use crate::util::comma_separated_list1;
 use crate::util::ident;
+use crate::util::map_res;
 use crate::util::transform_span;
 use crate::Backtrace;
+use crate::ErrorKind;
 
 pub fn tokenize_sql(sql: &str) -> Result<Vec<Token>> {
     Tokenizer::new(sql).collect::<Result<Vec<_>>>()
@@ -116,6 +120,40 @@ pub fn parse_comma_separated_idents<'a>(
     }
 }
 
+/// Parse the columns of the bloom index, e.g. `a, b NGRAM(3)`,
+/// returns the columns with the optional n-gram sizes.
+pub fn parse_bloom_index_columns<'a>(
+    sql_tokens: &'a [Token<'a>],
+    dialect: Dialect,
+) -> Result<Vec<(Identifier, Option<u64>)>> {
+    let backtrace = Backtrace::new();
+    let ngram = map_res(
+        rule! { #ident ~ "(" ~ #literal_u64 ~ ")" },
+        |(name, _, gram_size, _)| {
+            if name.name.eq_ignore_ascii_case("ngram") {
+                Ok(gram_size)
+            } else {
+                Err(ErrorKind::Other("expected NGRAM"))
+            }
+        },
+    );
+    let mut columns_parser = comma_separated_list1(rule! { #ident ~ #ngram? });
+    match columns_parser(Input(sql_tokens, dialect, &backtrace)) {
+        Ok((rest, columns)) if rest[0].kind == TokenKind::EOI => Ok(columns),
+        Ok((rest, _)) => Err(ErrorCode::SyntaxException(
+            "unable to parse rest of the bloom index columns".to_string(),
+        )
+        .set_span(transform_span(&rest[..1]))),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            let source = sql_tokens[0].source;
+            Err(ErrorCode::SyntaxException(display_parser_error(
+                err, source,
+            )))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!(),
+    }
+}
+
 pub fn parser_values_with_placeholder<'a>(
     sql_tokens: &'a [Token<'a>],
     dialect: Dialect,
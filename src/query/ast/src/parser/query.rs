@@ -429,9 +429,13 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ TIMESTAMP ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Timestamp(Box::new(e)),
     );
+    let at_tag = map(
+        rule! { "(" ~ TAG ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, tag, _)| TimeTravelPoint::Tag(tag),
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp
+        #at_snapshot | #at_timestamp | #at_tag
     )(i)
 }
 
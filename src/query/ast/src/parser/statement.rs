@@ -672,6 +672,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ ( #engine )?
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -685,6 +686,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_partition_by,
             opt_table_options,
             opt_as_query,
         )| {
@@ -699,6 +701,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 cluster_by: opt_cluster_by
                     .map(|(_, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
+                partition_by: opt_partition_by
+                    .map(|(_, _, _, exprs, _)| exprs)
+                    .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
@@ -1020,17 +1025,22 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ALTER ~ USER ~ ( #map(rule! { USER ~ "(" ~ ")" }, |_| None) | #map(user_identity, Some) )
             ~ ( IDENTIFIED ~ ( WITH ~ ^#auth_type )? ~ ( BY ~ ^#literal_string )? )?
             ~ ( WITH ~ ^#comma_separated_list1(user_option) )?
-        },
-        |(_, _, user, opt_auth_option, opt_user_option)| {
+            ~ UNLOCK?
+        },
+        |(_, _, user, opt_auth_option, opt_user_option, opt_unlock)| {
+            let mut user_options = opt_user_option
+                .map(|(_, user_options)| user_options)
+                .unwrap_or_default();
+            if opt_unlock.is_some() {
+                user_options.push(UserOptionItem::Unlock);
+            }
             Statement::AlterUser(AlterUserStmt {
                 user,
                 auth_option: opt_auth_option.map(|(_, opt_auth_type, opt_password)| AuthOption {
                     auth_type: opt_auth_type.map(|(_, auth_type)| auth_type),
                     password: opt_password.map(|(_, password)| password),
                 }),
-                user_options: opt_user_option
-                    .map(|(_, user_options)| user_options)
-                    .unwrap_or_default(),
+                user_options,
             })
         },
     );
@@ -1479,6 +1489,63 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    // row access policy
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ AS ~ ^"(" ~ ^#comma_separated_list1(row_access_policy_arg) ~ ^")"
+            ~ RETURNS ~ ^#type_name ~ "->" ~ ^#expr
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            body,
+            opt_comment,
+        )| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                args,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_network_policy = map(
         rule! {
             CREATE ~ NETWORK ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
@@ -1580,6 +1647,100 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! { SHOW ~ NETWORK ~ POLICIES },
     );
 
+    let create_password_policy = map(
+        rule! {
+            CREATE ~ PASSWORD ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+             ~ #password_policy_option*
+        },
+        |(_, _, _, opt_if_not_exists, name, options)| {
+            Statement::CreatePasswordPolicy(CreatePasswordPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let alter_password_policy = map(
+        rule! {
+            ALTER ~ PASSWORD ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident ~ SET
+             ~ #password_policy_option+
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| {
+            Statement::AlterPasswordPolicy(AlterPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let drop_password_policy = map(
+        rule! {
+            DROP ~ PASSWORD ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropPasswordPolicy(DropPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let describe_password_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ PASSWORD ~ POLICY ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescPasswordPolicy(DescPasswordPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_password_policies = value(
+        Statement::ShowPasswordPolicies,
+        rule! { SHOW ~ PASSWORD ~ POLICIES },
+    );
+
+    let create_workload_group = map(
+        rule! {
+            CREATE ~ WORKLOAD ~ GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+             ~ #workload_group_option*
+        },
+        |(_, _, _, opt_if_not_exists, name, options)| {
+            Statement::CreateWorkloadGroup(CreateWorkloadGroupStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ GROUP ~ ( IF ~ ^EXISTS )? ~ #ident ~ SET
+             ~ #workload_group_option+
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| {
+            Statement::AlterWorkloadGroup(AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ GROUP ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropWorkloadGroup(DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_workload_groups = value(
+        Statement::ShowWorkloadGroups,
+        rule! { SHOW ~ WORKLOAD ~ GROUPS },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1673,6 +1834,19 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_network_policy: "`DROP NETWORK POLICY [IF EXISTS] name`"
             | #describe_network_policy: "`DESC NETWORK POLICY name`"
             | #show_network_policies: "`SHOW NETWORK POLICIES`"
+            | #create_password_policy: "`CREATE PASSWORD POLICY [IF NOT EXISTS] name [PASSWORD_MIN_LENGTH = <u64>] ... [COMMENT = '<string_literal>']`"
+            | #alter_password_policy: "`ALTER PASSWORD POLICY [IF EXISTS] name SET [PASSWORD_MIN_LENGTH = <u64>] ... [COMMENT = '<string_literal>']`"
+            | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
+            | #describe_password_policy: "`DESC PASSWORD POLICY name`"
+            | #show_password_policies: "`SHOW PASSWORD POLICIES`"
+            | #create_workload_group: "`CREATE WORKLOAD GROUP [IF NOT EXISTS] name [MAX_CONCURRENCY = <u64>] [MEMORY_PERCENTAGE = <u64>] [CPU_WEIGHT = <u64>] [QUEUE_TIMEOUT = <u64>] [USERS = ('user1' [, 'user2'])] [ROLES = ('role1' [, 'role2'])]`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name SET [MAX_CONCURRENCY = <u64>] [MEMORY_PERCENTAGE = <u64>] [CPU_WEIGHT = <u64>] [QUEUE_TIMEOUT = <u64>] [USERS = ('user1' [, 'user2'])] [ROLES = ('role1' [, 'role2'])]`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS`"
+            // before `describe_table`, `ROW` is not a reserved keyword
+            | #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <policy_name> AS (<arg_name> <arg_type>, ...) RETURNS BOOLEAN -> <expr> [COMMENT = '<string_literal>']`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <policy_name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <policy_name>`"
         ),
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
@@ -1724,7 +1898,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule!(
             #show_users : "`SHOW USERS`"
             | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
-            | #alter_user : "`ALTER USER ('<username>'@'hostname' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...]`"
+            | #alter_user : "`ALTER USER ('<username>'@'hostname' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...] [UNLOCK]`"
             | #drop_user : "`DROP USER [IF EXISTS] '<username>'@'hostname'`"
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
@@ -1977,6 +2151,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        Codec(Vec<ColumnCodec>),
     }
 
     let nullable = alt((
@@ -2003,6 +2178,12 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             |(_, _, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
     ));
+    let codec = map(
+        rule! {
+            CODEC ~ ^"(" ~ ^#comma_separated_list1(column_codec) ~ ^")"
+        },
+        |(_, _, codecs, _)| ColumnConstraint::Codec(codecs),
+    );
 
     let comment = map(
         rule! {
@@ -2015,9 +2196,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         rule! {
             #ident
             ~ #type_name
-            ~ ( #nullable | #expr )*
+            ~ ( #nullable | #expr | #codec )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [CODEC(<codec>, ...)] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let def = ColumnDefinition {
@@ -2026,6 +2207,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                 expr: None,
                 comment,
                 nullable_constraint: None,
+                codecs: vec![],
             };
             (def, constraints)
         },
@@ -2055,12 +2237,29 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ColumnConstraint::StoredExpr(stored_expr) => {
                 def.expr = Some(ColumnExpr::Stored(stored_expr))
             }
+            ColumnConstraint::Codec(codecs) => def.codecs = codecs,
         }
     }
 
     Ok((i, def))
 }
 
+pub fn column_codec(i: Input) -> IResult<ColumnCodec> {
+    alt((
+        value(ColumnCodec::Delta, rule! { DELTA }),
+        value(ColumnCodec::DoubleDelta, rule! { DOUBLEDELTA }),
+        value(ColumnCodec::Gorilla, rule! { GORILLA }),
+        value(ColumnCodec::Dictionary, rule! { DICTIONARY }),
+        value(ColumnCodec::None, rule! { NONE }),
+        value(ColumnCodec::Lz4, rule! { LZ4 }),
+        value(ColumnCodec::Snappy, rule! { SNAPPY }),
+        map(
+            rule! { ZSTD ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
+            |(_, level)| ColumnCodec::Zstd(level.map(|(_, level, _)| level)),
+        ),
+    ))(i)
+}
+
 pub fn role_name(i: Input) -> IResult<String> {
     let role_ident = map(
         rule! {
@@ -2322,11 +2521,62 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
 }
 
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    enum TableElement {
+        Column(ColumnDefinition, Option<bool>),
+        Constraint(TableConstraint),
+    }
+
+    fn key_constraint(i: Input) -> IResult<bool> {
+        alt((
+            value(true, rule! { PRIMARY ~ KEY }),
+            value(false, rule! { UNIQUE }),
+        ))(i)
+    }
+
+    let column = map(
+        rule! {
+            #column_def ~ #key_constraint?
+        },
+        |(column, is_primary_key)| TableElement::Column(column, is_primary_key),
+    );
+    let constraint = map(
+        rule! {
+            #key_constraint ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(is_primary_key, _, columns, _)| {
+            TableElement::Constraint(if is_primary_key {
+                TableConstraint::PrimaryKey { columns }
+            } else {
+                TableConstraint::Unique { columns }
+            })
+        },
+    );
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(alt((constraint, column))) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut constraints = vec![];
+            for element in elements {
+                match element {
+                    TableElement::Column(column, is_primary_key) => {
+                        match is_primary_key {
+                            Some(true) => constraints.push(TableConstraint::PrimaryKey {
+                                columns: vec![column.name.clone()],
+                            }),
+                            Some(false) => constraints.push(TableConstraint::Unique {
+                                columns: vec![column.name.clone()],
+                            }),
+                            None => {}
+                        }
+                        columns.push(column);
+                    }
+                    TableElement::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -2338,10 +2588,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, p)| p),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -2398,6 +2660,7 @@ pub fn modify_column_type(i: Input) -> IResult<ColumnDefinition> {
                 expr: None,
                 comment,
                 nullable_constraint: None,
+                codecs: vec![],
             };
             for constraint in constraints {
                 match constraint {
@@ -2443,6 +2706,13 @@ pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
         |(column, _, _)| ModifyColumnAction::ConvertStoredComputedColumn(column),
     );
 
+    let set_codec = map(
+        rule! {
+            #ident ~ CODEC ~ ^"(" ~ ^#comma_separated_list1(column_codec) ~ ^")"
+        },
+        |(column, _, _, codecs, _)| ModifyColumnAction::SetCodec(column, codecs),
+    );
+
     let modify_column_type = map(
         rule! {
             #modify_column_type ~ ("," ~ COLUMN ~ #modify_column_type)*
@@ -2460,6 +2730,7 @@ pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
         #set_mask_policy
         | #unset_mask_policy
         | #convert_stored_computed_column
+        | #set_codec
         | #modify_column_type
     )(i)
 }
@@ -2542,9 +2813,92 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ #ident ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, _, tag, opt_point)| AlterTableAction::CreateTag {
+            tag,
+            travel_point: opt_point.map(|(_, point)| point),
+        },
+    );
+
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ #ident
+        },
+        |(_, _, tag)| AlterTableAction::DropTag { tag },
+    );
+
+    let create_branch = map(
+        rule! {
+            CREATE ~ BRANCH ~ #ident ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, _, branch, opt_point)| AlterTableAction::CreateBranch {
+            branch,
+            travel_point: opt_point.map(|(_, point)| point),
+        },
+    );
+
+    let merge_branch = map(
+        rule! {
+            MERGE ~ BRANCH ~ #ident
+        },
+        |(_, _, branch)| AlterTableAction::MergeBranch { branch },
+    );
+
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ( IF ~ ^EXISTS )? ~ "(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, opt_if_exists, _, values, _)| AlterTableAction::DropPartition {
+            if_exists: opt_if_exists.is_some(),
+            values,
+        },
+    );
+
+    let truncate_partition = map(
+        rule! {
+            TRUNCATE ~ PARTITION ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, values, _)| AlterTableAction::TruncatePartition { values },
+    );
+
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
+    let replace_partition = map(
+        rule! {
+            REPLACE ~ PARTITION ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+            ~ ^FROM ~ ^#dot_separated_idents_1_to_3
+        },
+        |(_, _, _, values, _, _, (catalog, database, table))| AlterTableAction::ReplacePartition {
+            values,
+            catalog,
+            database,
+            table,
+        },
+    );
+
     rule!(
         #rename_table
         | #rename_column
+        | #add_row_access_policy
+        | #drop_row_access_policy
         | #add_column
         | #drop_column
         | #modify_column
@@ -2553,6 +2907,13 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #create_tag
+        | #drop_tag
+        | #create_branch
+        | #merge_branch
+        | #drop_partition
+        | #truncate_partition
+        | #replace_partition
     )(i)
 }
 
@@ -2885,33 +3246,55 @@ pub fn show_options(i: Input) -> IResult<ShowOptions> {
 pub fn table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     map(
         rule! {
-           ( #ident ~ "=" ~ #parameter_to_string )*
-        },
-        |opts| {
-            BTreeMap::from_iter(
-                opts.iter()
-                    .map(|(k, _, v)| (k.name.to_lowercase(), v.clone())),
-            )
+           ( #table_option_item )*
         },
+        BTreeMap::from_iter,
     )(i)
 }
 
 pub fn set_table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     map(
         rule! {
-           ( #ident ~ "=" ~ #parameter_to_string ) ~ ("," ~ #ident ~ "=" ~ #parameter_to_string )*
+           #table_option_item ~ ("," ~ #table_option_item )*
         },
-        |(key, _, value, opts)| {
-            let mut options = BTreeMap::from_iter(
-                opts.iter()
-                    .map(|(_, k, _, v)| (k.name.to_lowercase(), v.clone())),
-            );
-            options.insert(key.name.to_lowercase(), value);
+        |(option, opts)| {
+            let mut options = BTreeMap::from_iter(opts.into_iter().map(|(_, opt)| opt));
+            options.insert(option.0, option.1);
             options
         },
     )(i)
 }
 
+fn table_option_item(i: Input) -> IResult<(String, String)> {
+    // `TTL = <expr>`, the expression is kept as its SQL text.
+    let ttl = map(
+        rule! {
+            TTL ~ "=" ~ #expr
+        },
+        |(_, _, expr)| {
+            let value = match expr {
+                Expr::Literal {
+                    lit: Literal::String(s),
+                    ..
+                } => s,
+                expr => expr.to_string(),
+            };
+            ("ttl".to_string(), value)
+        },
+    );
+    let option = map(
+        rule! {
+            #ident ~ "=" ~ #parameter_to_string
+        },
+        |(k, _, v)| (k.name.to_lowercase(), v),
+    );
+
+    rule!(
+        #ttl
+        | #option
+    )(i)
+}
+
 pub fn engine(i: Input) -> IResult<Engine> {
     let engine = alt((
         value(Engine::Null, rule! { NULL }),
@@ -2997,6 +3380,36 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, _)| UserOptionItem::UnsetNetworkPolicy,
     );
+    let set_password_policy = map(
+        rule! {
+            SET ~ PASSWORD ~ POLICY ~ "=" ~ #literal_string
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetPasswordPolicy(policy),
+    );
+    let unset_password_policy = map(
+        rule! {
+            UNSET ~ PASSWORD ~ POLICY
+        },
+        |(_, _, _)| UserOptionItem::UnsetPasswordPolicy,
+    );
+    let max_cpu = map(
+        rule! {
+            MAX_CPU ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| UserOptionItem::MaxCpu(v),
+    );
+    let max_memory = map(
+        rule! {
+            MAX_MEMORY ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| UserOptionItem::MaxMemory(v),
+    );
+    let max_storage = map(
+        rule! {
+            MAX_STORAGE ~ "=" ~ #literal_u64
+        },
+        |(_, _, v)| UserOptionItem::MaxStorage(v),
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -3006,9 +3419,104 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         default_role_option,
         set_network_policy,
         unset_network_policy,
+        set_password_policy,
+        unset_password_policy,
+        value(UserOptionItem::Unlock, rule! { UNLOCK }),
+        max_cpu,
+        max_memory,
+        max_storage,
     ))(i)
 }
 
+pub fn password_policy_option(i: Input) -> IResult<PasswordPolicyOption> {
+    alt((
+        map(
+            rule! { PASSWORD_MIN_LENGTH ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MinLength(v),
+        ),
+        map(
+            rule! { PASSWORD_MAX_LENGTH ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MaxLength(v),
+        ),
+        map(
+            rule! { PASSWORD_MIN_UPPER_CASE_CHARS ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MinUpperCaseChars(v),
+        ),
+        map(
+            rule! { PASSWORD_MIN_LOWER_CASE_CHARS ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MinLowerCaseChars(v),
+        ),
+        map(
+            rule! { PASSWORD_MIN_NUMERIC_CHARS ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MinNumericChars(v),
+        ),
+        map(
+            rule! { PASSWORD_MIN_SPECIAL_CHARS ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MinSpecialChars(v),
+        ),
+        map(
+            rule! { PASSWORD_MIN_AGE_DAYS ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MinAgeDays(v),
+        ),
+        map(
+            rule! { PASSWORD_MAX_AGE_DAYS ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MaxAgeDays(v),
+        ),
+        map(
+            rule! { PASSWORD_MAX_RETRIES ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::MaxRetries(v),
+        ),
+        map(
+            rule! { PASSWORD_LOCKOUT_TIME_MINS ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::LockoutTimeMins(v),
+        ),
+        map(
+            rule! { PASSWORD_HISTORY ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| PasswordPolicyOption::History(v),
+        ),
+        map(rule! { COMMENT ~ ^"=" ~ ^#literal_string }, |(_, _, v)| {
+            PasswordPolicyOption::Comment(v)
+        }),
+    ))(i)
+}
+
+pub fn workload_group_option(i: Input) -> IResult<WorkloadGroupOption> {
+    alt((
+        map(
+            rule! { MAX_CONCURRENCY ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| WorkloadGroupOption::MaxConcurrency(v),
+        ),
+        map(
+            rule! { MEMORY_PERCENTAGE ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| WorkloadGroupOption::MemoryPercentage(v),
+        ),
+        map(rule! { CPU_WEIGHT ~ ^"=" ~ ^#literal_u64 }, |(_, _, v)| {
+            WorkloadGroupOption::CpuWeight(v)
+        }),
+        map(
+            rule! { QUEUE_TIMEOUT ~ ^"=" ~ ^#literal_u64 },
+            |(_, _, v)| WorkloadGroupOption::QueueTimeout(v),
+        ),
+        map(
+            rule! { USERS ~ ^"=" ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" },
+            |(_, _, _, users, _)| WorkloadGroupOption::Users(users),
+        ),
+        map(
+            rule! { ROLES ~ ^"=" ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" },
+            |(_, _, _, roles, _)| WorkloadGroupOption::Roles(roles),
+        ),
+    ))(i)
+}
+
+pub fn row_access_policy_arg(i: Input) -> IResult<RowAccessPolicyArg> {
+    map(rule! { #ident ~ #type_name }, |(arg_name, arg_type)| {
+        RowAccessPolicyArg {
+            arg_name: arg_name.name,
+            arg_type,
+        }
+    })(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
@@ -3027,6 +3535,7 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::Ldap, rule! { LDAP }),
     ))(i)
 }
 
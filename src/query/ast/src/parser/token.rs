@@ -310,6 +310,8 @@ pub enum TokenKind {
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
     ALLOWED_IP_LIST,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ADD", ignore(ascii_case))]
     ADD,
     #[token("AFTER", ignore(ascii_case))]
@@ -364,6 +366,8 @@ pub enum TokenKind {
     BOTH,
     #[token("BY", ignore(ascii_case))]
     BY,
+    #[token("BRANCH", ignore(ascii_case))]
+    BRANCH,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
@@ -380,8 +384,12 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
+    #[token("CODEC", ignore(ascii_case))]
+    CODEC,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
@@ -456,10 +464,14 @@ pub enum TokenKind {
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
+    #[token("DELTA", ignore(ascii_case))]
+    DELTA,
     #[token("DESC", ignore(ascii_case))]
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
     DESCRIBE,
+    #[token("DICTIONARY", ignore(ascii_case))]
+    DICTIONARY,
     #[token("DISABLE_VARIANT_CHECK", ignore(ascii_case))]
     DISABLE_VARIANT_CHECK,
     #[token("DISTINCT", ignore(ascii_case))]
@@ -470,6 +482,8 @@ pub enum TokenKind {
     DOUBLE_SHA1_PASSWORD,
     #[token("DOUBLE", ignore(ascii_case))]
     DOUBLE,
+    #[token("DOUBLEDELTA", ignore(ascii_case))]
+    DOUBLEDELTA,
     #[token("DOW", ignore(ascii_case))]
     DOW,
     #[token("WEEK", ignore(ascii_case))]
@@ -568,12 +582,18 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
+    #[token("GORILLA", ignore(ascii_case))]
+    GORILLA,
     #[token("GRAPH", ignore(ascii_case))]
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
@@ -647,6 +667,8 @@ pub enum TokenKind {
     /// L2DISTANCE op, from https://github.com/pgvector/pgvector
     #[token("<->")]
     L2DISTANCE,
+    #[token("LDAP", ignore(ascii_case))]
+    LDAP,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -657,6 +679,8 @@ pub enum TokenKind {
     LIMIT,
     #[token("LIST", ignore(ascii_case))]
     LIST,
+    #[token("LZ4", ignore(ascii_case))]
+    LZ4,
     #[token("LZO", ignore(ascii_case))]
     LZO,
     #[token("MASKING", ignore(ascii_case))]
@@ -665,6 +689,20 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_CPU", ignore(ascii_case))]
+    MAX_CPU,
+    #[token("MAX_MEMORY", ignore(ascii_case))]
+    MAX_MEMORY,
+    #[token("MAX_STORAGE", ignore(ascii_case))]
+    MAX_STORAGE,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
+    #[token("MEMORY_PERCENTAGE", ignore(ascii_case))]
+    MEMORY_PERCENTAGE,
+    #[token("CPU_WEIGHT", ignore(ascii_case))]
+    CPU_WEIGHT,
+    #[token("QUEUE_TIMEOUT", ignore(ascii_case))]
+    QUEUE_TIMEOUT,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEMO", ignore(ascii_case))]
@@ -735,6 +773,30 @@ pub enum TokenKind {
     PARTITION,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
+    #[token("PASSWORD", ignore(ascii_case))]
+    PASSWORD,
+    #[token("PASSWORD_HISTORY", ignore(ascii_case))]
+    PASSWORD_HISTORY,
+    #[token("PASSWORD_LOCKOUT_TIME_MINS", ignore(ascii_case))]
+    PASSWORD_LOCKOUT_TIME_MINS,
+    #[token("PASSWORD_MAX_AGE_DAYS", ignore(ascii_case))]
+    PASSWORD_MAX_AGE_DAYS,
+    #[token("PASSWORD_MAX_LENGTH", ignore(ascii_case))]
+    PASSWORD_MAX_LENGTH,
+    #[token("PASSWORD_MAX_RETRIES", ignore(ascii_case))]
+    PASSWORD_MAX_RETRIES,
+    #[token("PASSWORD_MIN_AGE_DAYS", ignore(ascii_case))]
+    PASSWORD_MIN_AGE_DAYS,
+    #[token("PASSWORD_MIN_LENGTH", ignore(ascii_case))]
+    PASSWORD_MIN_LENGTH,
+    #[token("PASSWORD_MIN_LOWER_CASE_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_LOWER_CASE_CHARS,
+    #[token("PASSWORD_MIN_NUMERIC_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_NUMERIC_CHARS,
+    #[token("PASSWORD_MIN_SPECIAL_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_SPECIAL_CHARS,
+    #[token("PASSWORD_MIN_UPPER_CASE_CHARS", ignore(ascii_case))]
+    PASSWORD_MIN_UPPER_CASE_CHARS,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
@@ -805,6 +867,8 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
@@ -849,6 +913,8 @@ pub enum TokenKind {
     SET,
     #[token("UNSET", ignore(ascii_case))]
     UNSET,
+    #[token("UNLOCK", ignore(ascii_case))]
+    UNLOCK,
     #[token("SETTINGS", ignore(ascii_case))]
     SETTINGS,
     #[token("STAGES", ignore(ascii_case))]
@@ -953,6 +1019,10 @@ pub enum TokenKind {
     TRY_CAST,
     #[token("TSV", ignore(ascii_case))]
     TSV,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TUPLE", ignore(ascii_case))]
     TUPLE,
     #[token("TYPE", ignore(ascii_case))]
@@ -961,6 +1031,8 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
     #[token("UINT32", ignore(ascii_case))]
@@ -1065,6 +1137,8 @@ pub enum TokenKind {
     TASKS,
     #[token("WAREHOUSE", ignore(ascii_case))]
     WAREHOUSE,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
     #[token("SCHEDULE", ignore(ascii_case))]
     SCHEDULE,
     #[token("SUSPEND_TASK_AFTER_NUM_FAILURES", ignore(ascii_case))]
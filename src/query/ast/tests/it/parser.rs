@@ -134,8 +134,12 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET PASSWORD POLICY = 'pp1';"#,
+        r#"ALTER USER u1 UNLOCK;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
+        r#"CREATE USER u1 IDENTIFIED BY '123456' WITH MAX_CPU = 4, MAX_MEMORY = 1073741824, max_storage = 0"#,
+        r#"CREATE USER u1 IDENTIFIED WITH ldap"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
         r#"select * from t4;"#,
@@ -201,6 +205,8 @@ fn test_statement() {
         r#"ALTER TABLE t DROP COLUMN b;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
         r#"ALTER TABLE t MODIFY COLUMN b UNSET MASKING POLICY;"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY rap ON (region);"#,
+        r#"ALTER TABLE t DROP ROW ACCESS POLICY rap;"#,
         r#"ALTER TABLE t MODIFY COLUMN a int DEFAULT 1, COLUMN b float;"#,
         r#"ALTER TABLE t MODIFY COLUMN a int NULL DEFAULT 1, COLUMN b float NOT NULL COMMENT 'column b';"#,
         r#"ALTER TABLE t MODIFY COLUMN a int;"#,
@@ -479,12 +485,19 @@ fn test_statement() {
         r#"CREATE MASKING POLICY email_mask AS (val STRING) RETURNS STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"DESC MASKING POLICY email_mask"#,
         r#"DROP MASKING POLICY IF EXISTS email_mask"#,
+        r#"CREATE ROW ACCESS POLICY IF NOT EXISTS rap AS (region STRING) RETURNS BOOLEAN -> region = current_role() COMMENT = 'test'"#,
+        r#"DESC ROW ACCESS POLICY rap"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS rap"#,
         r#"CREATE VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"ALTER VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMN FOR t"#,
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE WORKLOAD GROUP IF NOT EXISTS etl MAX_CONCURRENCY = 2 MEMORY_PERCENTAGE = 30 CPU_WEIGHT = 10 QUEUE_TIMEOUT = 60 USERS = ('u1', 'u2') ROLES = ('r1')"#,
+        r#"ALTER WORKLOAD GROUP etl SET MAX_CONCURRENCY = 4 ROLES = ()"#,
+        r#"CREATE PASSWORD POLICY IF NOT EXISTS pp1 PASSWORD_MIN_LENGTH = 10 PASSWORD_MIN_SPECIAL_CHARS = 1 PASSWORD_MAX_RETRIES = 3 PASSWORD_HISTORY = 5 COMMENT = 'test'"#,
+        r#"ALTER PASSWORD POLICY pp1 SET PASSWORD_MAX_AGE_DAYS = 30 PASSWORD_LOCKOUT_TIME_MINS = 10"#,
         // tasks
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 SCHEDULE = USING CRON '0 6 * * *' 'America/Los_Angeles' COMMENT = 'serverless + cron' AS insert into t (c1, c2) values (1, 2), (3, 4)"#,
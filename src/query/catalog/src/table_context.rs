@@ -74,11 +74,13 @@ pub struct ProcessInfo {
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
+    pub workload_group: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ProcessInfoState {
     Query,
+    Queued,
     Aborting,
     Idle,
 }
@@ -87,6 +89,7 @@ impl Display for ProcessInfoState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ProcessInfoState::Query => write!(f, "Query"),
+            ProcessInfoState::Queued => write!(f, "Queued"),
             ProcessInfoState::Aborting => write!(f, "Aborting"),
             ProcessInfoState::Idle => write!(f, "Idle"),
         }
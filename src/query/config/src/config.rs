@@ -42,12 +42,15 @@ use common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig as InnerStorageConfig;
+use common_tracing::AuditLogConfig as InnerAuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig as InnerQueryLogConfig;
 use common_tracing::StderrConfig as InnerStderrLogConfig;
 use common_tracing::TracingConfig as InnerTracingConfig;
 use common_users::idm_config::IDMConfig as InnerIDMConfig;
+use common_users::JwtConfig as InnerJwtConfig;
+use common_users::LdapConfig as InnerLdapConfig;
 use serde::Deserialize;
 use serde::Serialize;
 use serfig::collectors::from_env;
@@ -1465,6 +1468,83 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub jwt_key_files: Vec<String>,
 
+    /// The LDAP server to authenticate the users of `IDENTIFIED WITH ldap`, such as
+    /// `ldaps://ldap.example.org`. The users not created yet are created on their first login.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub ldap_url: String,
+
+    /// Upgrades the connection of an `ldap://` url by StartTLS.
+    #[clap(long, value_name = "VALUE")]
+    pub ldap_start_tls: bool,
+
+    /// Allows an `ldap://` url without StartTLS, the passwords of the users are sent in
+    /// cleartext. Only for testing.
+    #[clap(long, value_name = "VALUE")]
+    pub ldap_allow_insecure: bool,
+
+    /// The account to search the LDAP users with, the search is anonymous if empty.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub ldap_bind_dn: String,
+
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub ldap_bind_password: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub ldap_base_dn: String,
+
+    /// The filter to find the entry of a user, `{username}` is replaced with the login name.
+    /// For Active Directory, it is usually `(sAMAccountName={username})`.
+    #[clap(long, value_name = "VALUE", default_value = "(uid={username})")]
+    pub ldap_user_filter: String,
+
+    /// The attribute of a user entry which lists the groups of the user.
+    #[clap(long, value_name = "VALUE", default_value = "memberOf")]
+    pub ldap_group_attribute: String,
+
+    /// The filter to find the groups of a user, `{dn}` is replaced with the dn of the user,
+    /// such as `(&(objectClass=groupOfNames)(member={dn}))`.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub ldap_group_filter: String,
+
+    /// The roles granted to the members of the LDAP groups in the form of `group:role`, they are
+    /// granted and revoked on each login of the user.
+    #[clap(long, value_name = "VALUE")]
+    pub ldap_group_role_mapping: Vec<String>,
+
+    /// The audiences allowed for the jwt of an issuer in the form of `issuer=audience`. Once
+    /// any issuer rule is set, the tokens of the issuers not listed are rejected.
+    #[clap(long, value_name = "VALUE")]
+    pub jwt_issuer_audiences: Vec<String>,
+
+    /// The claims the jwt of an issuer must have in the form of `issuer=claim`.
+    #[clap(long, value_name = "VALUE")]
+    pub jwt_issuer_required_claims: Vec<String>,
+
+    /// The claim of the jwt which lists the groups of the user, such as `groups`.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub jwt_group_claim: String,
+
+    /// The roles granted to the members of the groups in the group claim in the form of
+    /// `group:role`, they are granted and revoked on each login of the user.
+    #[clap(long, value_name = "VALUE")]
+    pub jwt_group_role_mapping: Vec<String>,
+
+    /// The OAuth2 token introspection endpoint (RFC 7662) to validate the opaque access tokens.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub oauth2_introspection_url: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub oauth2_client_id: String,
+
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub oauth2_client_secret: String,
+
+    /// How long the result of an active token introspection is cached.
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub oauth2_introspection_cache_ttl_secs: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "auto")]
     pub default_storage_format: String,
 
@@ -1585,6 +1665,39 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE")]
     pub udf_server_allow_list: Vec<String>,
 
+    /// Enable the compaction scheduler, which compacts the fragmented fuse tables in the background.
+    #[clap(long, value_name = "VALUE", default_value = "false")]
+    pub enable_compaction_scheduler: bool,
+
+    /// The interval between two rounds of the compaction scheduler, in seconds.
+    #[clap(long, value_name = "VALUE", default_value = "300")]
+    pub compaction_scheduler_interval_secs: u64,
+
+    /// The max number of tables compacted in a round of the compaction scheduler.
+    #[clap(long, value_name = "VALUE", default_value = "4")]
+    pub compaction_scheduler_tables_per_round: u64,
+
+    /// The max threads used to compact a table, i.e. the CPU budget of the compaction scheduler.
+    #[clap(long, value_name = "VALUE", default_value = "2")]
+    pub compaction_scheduler_max_threads: u64,
+
+    /// The max concurrent storage IO requests used to compact a table, i.e. the IO budget of the
+    /// compaction scheduler.
+    #[clap(long, value_name = "VALUE", default_value = "16")]
+    pub compaction_scheduler_max_io_requests: u64,
+
+    /// The max number of segments compacted at a time, large tables are compacted incrementally.
+    #[clap(long, value_name = "VALUE", default_value = "256")]
+    pub compaction_scheduler_segment_limit: u64,
+
+    /// A table is compacted if at least this number of segments can be saved by merging its segments.
+    #[clap(long, value_name = "VALUE", default_value = "8")]
+    pub compaction_scheduler_segment_threshold: u64,
+
+    /// A table is compacted if at least this number of its blocks are undersized.
+    #[clap(long, value_name = "VALUE", default_value = "64")]
+    pub compaction_scheduler_block_threshold: u64,
+
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
 }
@@ -1644,6 +1757,28 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
+            ldap: InnerLdapConfig {
+                url: self.ldap_url,
+                start_tls: self.ldap_start_tls,
+                allow_insecure: self.ldap_allow_insecure,
+                bind_dn: self.ldap_bind_dn,
+                bind_password: self.ldap_bind_password,
+                base_dn: self.ldap_base_dn,
+                user_filter: self.ldap_user_filter,
+                group_attribute: self.ldap_group_attribute,
+                group_filter: self.ldap_group_filter,
+                group_role_mapping: self.ldap_group_role_mapping,
+            },
+            jwt: InnerJwtConfig {
+                issuer_audiences: self.jwt_issuer_audiences,
+                issuer_required_claims: self.jwt_issuer_required_claims,
+                group_claim: self.jwt_group_claim,
+                group_role_mapping: self.jwt_group_role_mapping,
+                introspection_url: self.oauth2_introspection_url,
+                client_id: self.oauth2_client_id,
+                client_secret: self.oauth2_client_secret,
+                introspection_cache_ttl_secs: self.oauth2_introspection_cache_ttl_secs,
+            },
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
             idm: InnerIDMConfig {
@@ -1663,6 +1798,14 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_version: self.openai_api_version,
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
+            enable_compaction_scheduler: self.enable_compaction_scheduler,
+            compaction_scheduler_interval_secs: self.compaction_scheduler_interval_secs,
+            compaction_scheduler_tables_per_round: self.compaction_scheduler_tables_per_round,
+            compaction_scheduler_max_threads: self.compaction_scheduler_max_threads,
+            compaction_scheduler_max_io_requests: self.compaction_scheduler_max_io_requests,
+            compaction_scheduler_segment_limit: self.compaction_scheduler_segment_limit,
+            compaction_scheduler_segment_threshold: self.compaction_scheduler_segment_threshold,
+            compaction_scheduler_block_threshold: self.compaction_scheduler_block_threshold,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
         })
     }
@@ -1721,6 +1864,24 @@ impl From<InnerQueryConfig> for QueryConfig {
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
+            ldap_url: inner.ldap.url,
+            ldap_start_tls: inner.ldap.start_tls,
+            ldap_allow_insecure: inner.ldap.allow_insecure,
+            ldap_bind_dn: inner.ldap.bind_dn,
+            ldap_bind_password: inner.ldap.bind_password,
+            ldap_base_dn: inner.ldap.base_dn,
+            ldap_user_filter: inner.ldap.user_filter,
+            ldap_group_attribute: inner.ldap.group_attribute,
+            ldap_group_filter: inner.ldap.group_filter,
+            ldap_group_role_mapping: inner.ldap.group_role_mapping,
+            jwt_issuer_audiences: inner.jwt.issuer_audiences,
+            jwt_issuer_required_claims: inner.jwt.issuer_required_claims,
+            jwt_group_claim: inner.jwt.group_claim,
+            jwt_group_role_mapping: inner.jwt.group_role_mapping,
+            oauth2_introspection_url: inner.jwt.introspection_url,
+            oauth2_client_id: inner.jwt.client_id,
+            oauth2_client_secret: inner.jwt.client_secret,
+            oauth2_introspection_cache_ttl_secs: inner.jwt.introspection_cache_ttl_secs,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
             users: users_from_inner(inner.idm.users),
@@ -1750,6 +1911,14 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_embedding_model: inner.openai_api_embedding_model,
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
+            enable_compaction_scheduler: inner.enable_compaction_scheduler,
+            compaction_scheduler_interval_secs: inner.compaction_scheduler_interval_secs,
+            compaction_scheduler_tables_per_round: inner.compaction_scheduler_tables_per_round,
+            compaction_scheduler_max_threads: inner.compaction_scheduler_max_threads,
+            compaction_scheduler_max_io_requests: inner.compaction_scheduler_max_io_requests,
+            compaction_scheduler_segment_limit: inner.compaction_scheduler_segment_limit,
+            compaction_scheduler_segment_threshold: inner.compaction_scheduler_segment_threshold,
+            compaction_scheduler_block_threshold: inner.compaction_scheduler_block_threshold,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
         }
     }
@@ -1795,6 +1964,9 @@ pub struct LogConfig {
     #[clap(flatten)]
     pub query: QueryLogConfig,
 
+    #[clap(flatten)]
+    pub audit: AuditLogConfig,
+
     #[clap(flatten)]
     pub tracing: TracingConfig,
 }
@@ -1848,12 +2020,23 @@ impl TryInto<InnerLogConfig> for LogConfig {
             query.dir = format!("{}/query-details", &file.dir);
         }
 
+        let mut audit: InnerAuditLogConfig = self.audit.try_into()?;
+        if audit.dir.is_empty() {
+            if file.dir.is_empty() {
+                return Err(ErrorCode::InvalidConfig(
+                    "`dir` or `file.dir` must be set when `audit.dir` is empty".to_string(),
+                ));
+            }
+            audit.dir = format!("{}/audit", &file.dir);
+        }
+
         let tracing: InnerTracingConfig = self.tracing.try_into()?;
 
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
             query,
+            audit,
             tracing,
         })
     }
@@ -1867,6 +2050,7 @@ impl From<InnerLogConfig> for LogConfig {
             file: inner.file.into(),
             stderr: inner.stderr.into(),
             query: inner.query.into(),
+            audit: inner.audit.into(),
             tracing: inner.tracing.into(),
 
             // Deprecated fields
@@ -2032,6 +2216,57 @@ impl From<InnerQueryLogConfig> for QueryLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct AuditLogConfig {
+    #[clap(long = "log-audit-on", value_name = "VALUE", default_value = "false", action = ArgAction::Set, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    #[serde(rename = "on")]
+    pub log_audit_on: bool,
+
+    /// Audit Log file dir
+    #[clap(
+        long = "log-audit-dir",
+        value_name = "VALUE",
+        default_value = "",
+        help = "Default to <log-file-dir>/audit"
+    )]
+    #[serde(rename = "dir")]
+    pub log_audit_dir: String,
+
+    /// The stage the audit events are also written to, such as a stage in object storage
+    #[clap(long = "log-audit-stage", value_name = "VALUE", default_value = "")]
+    #[serde(rename = "stage")]
+    pub log_audit_stage: String,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        InnerAuditLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerAuditLogConfig> for AuditLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerAuditLogConfig> {
+        Ok(InnerAuditLogConfig {
+            on: self.log_audit_on,
+            dir: self.log_audit_dir,
+            stage: self.log_audit_stage,
+        })
+    }
+}
+
+impl From<InnerAuditLogConfig> for AuditLogConfig {
+    fn from(inner: InnerAuditLogConfig) -> Self {
+        Self {
+            log_audit_on: inner.on,
+            log_audit_dir: inner.dir,
+            log_audit_stage: inner.stage,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct TracingConfig {
@@ -2306,6 +2541,7 @@ impl TryInto<AuthInfo> for UserAuthConfig {
         match auth_type {
             AuthType::NoPassword => check_no_auth_string(self.auth_string, AuthInfo::None),
             AuthType::JWT => check_no_auth_string(self.auth_string, AuthInfo::JWT),
+            AuthType::Ldap => check_no_auth_string(self.auth_string, AuthInfo::Ldap),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match self.auth_string {
@@ -30,6 +30,8 @@ use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig;
 use common_tracing::Config as LogConfig;
 use common_users::idm_config::IDMConfig;
+use common_users::JwtConfig;
+use common_users::LdapConfig;
 
 use super::config::Commands;
 use super::config::Config;
@@ -200,6 +202,8 @@ pub struct QueryConfig {
 
     pub jwt_key_file: String,
     pub jwt_key_files: Vec<String>,
+    pub ldap: LdapConfig,
+    pub jwt: JwtConfig,
     pub default_storage_format: String,
     pub default_compression: String,
     pub idm: IDMConfig,
@@ -222,6 +226,16 @@ pub struct QueryConfig {
     pub enable_udf_server: bool,
     pub udf_server_allow_list: Vec<String>,
 
+    /// The compaction scheduler, see `enable_compaction_scheduler`.
+    pub enable_compaction_scheduler: bool,
+    pub compaction_scheduler_interval_secs: u64,
+    pub compaction_scheduler_tables_per_round: u64,
+    pub compaction_scheduler_max_threads: u64,
+    pub compaction_scheduler_max_io_requests: u64,
+    pub compaction_scheduler_segment_limit: u64,
+    pub compaction_scheduler_segment_threshold: u64,
+    pub compaction_scheduler_block_threshold: u64,
+
     pub cloud_control_grpc_server_address: Option<String>,
 }
 
@@ -271,6 +285,8 @@ impl Default for QueryConfig {
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
+            ldap: LdapConfig::default(),
+            jwt: JwtConfig::default(),
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
             idm: IDMConfig::default(),
@@ -289,6 +305,14 @@ impl Default for QueryConfig {
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
+            enable_compaction_scheduler: false,
+            compaction_scheduler_interval_secs: 300,
+            compaction_scheduler_tables_per_round: 4,
+            compaction_scheduler_max_threads: 2,
+            compaction_scheduler_max_io_requests: 16,
+            compaction_scheduler_segment_limit: 256,
+            compaction_scheduler_segment_threshold: 8,
+            compaction_scheduler_block_threshold: 64,
             cloud_control_grpc_server_address: None,
         }
     }
@@ -309,6 +333,8 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.ldap.bind_password = mask_string(&self.ldap.bind_password, 3);
+        sanitized.jwt.client_secret = mask_string(&self.jwt.client_secret, 3);
         sanitized
     }
 }
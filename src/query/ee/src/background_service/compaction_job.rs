@@ -25,6 +25,8 @@ use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::base::tokio::sync::Mutex;
 use common_base::base::tokio::time::Instant;
 use common_base::base::uuid::Uuid;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_api::BackgroundApi;
@@ -44,6 +46,7 @@ use common_meta_app::background::UpdateBackgroundTaskReq;
 use common_meta_app::schema::TableStatistics;
 use common_meta_store::MetaStore;
 use common_users::UserApiProvider;
+use databend_query::interpreters::OptimizeTableInterpreter;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::Session;
 use databend_query::table_functions::SuggestedBackgroundTasksSource;
@@ -51,6 +54,7 @@ use log::as_debug;
 use log::debug;
 use log::error;
 use log::info;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::background_service::job::Job;
 use crate::background_service::session::create_session;
@@ -278,6 +282,17 @@ impl CompactionJob {
         tb_id: u64,
         manual: Option<ManualTriggerParams>,
     ) -> Result<()> {
+        // a failure of dropping the expired blocks should not stop the compaction
+        if let Err(e) = self
+            .do_drop_expired_blocks(session.clone(), database.clone(), table.clone())
+            .await
+        {
+            error!(
+                "drop expired blocks failed, db: {}, table: {}, err: {}",
+                database, table, e
+            );
+        }
+
         let (seg, blk, stats) = Self::do_check_table(
             session.clone(),
             database.clone(),
@@ -626,6 +641,31 @@ impl CompactionJob {
         Ok((need_segment_compact, need_block_compact, table_statistics))
     }
 
+    // drop the blocks whose rows are all expired, if the table has a ttl. The history of
+    // the table is kept, the dropped blocks are only removed by purging the table.
+    async fn do_drop_expired_blocks(
+        &self,
+        session: Arc<Session>,
+        database: String,
+        table: String,
+    ) -> Result<()> {
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let tbl = catalog
+            .get_table(ctx.get_tenant().as_str(), &database, &table)
+            .await?;
+        if tbl
+            .options()
+            .get(OPT_KEY_TTL)
+            .map_or(true, |ttl| ttl.is_empty())
+        {
+            return Ok(());
+        }
+
+        debug!(job = "compaction", background = true, database = database.clone(), table = table.clone(); "expired_blocks_drop");
+        OptimizeTableInterpreter::drop_expired_blocks(ctx, catalog, tbl).await
+    }
+
     async fn do_segment_compaction(
         &self,
         session: Arc<Session>,
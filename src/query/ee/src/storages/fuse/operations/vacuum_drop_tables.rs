@@ -22,6 +22,7 @@ use futures_util::TryStreamExt;
 use log::info;
 use opendal::EntryMode;
 use opendal::Metakey;
+use storages_common_table_meta::table::OPT_KEY_CLONE_MARKER_LOCATION;
 
 #[async_backtrace::framed]
 async fn do_vacuum_drop_table(
@@ -48,11 +49,29 @@ async fn do_vacuum_drop_table(
     let operator = fuse_table.get_operator_ref();
 
     let dir = format!("{}/", FuseTable::parse_storage_prefix(table_info)?);
+
+    // the data files are shared with the tables cloned from this table, keep them
+    let clone_markers = fuse_table.active_clone_markers().await?;
+    if !clone_markers.is_empty() {
+        info!(
+            "ignore table {} whose data are shared with {} cloned tables",
+            table.get_table_info().name,
+            clone_markers.len()
+        );
+        return Ok(None);
+    }
+
     info!("vacuum drop table {:?} dir {:?}", table.name(), dir);
     let start = Instant::now();
 
     let ret = match dry_run_limit {
         None => {
+            // the files of the source tables are no longer shared with this table
+            if let Some(markers) = table_info.meta.options.get(OPT_KEY_CLONE_MARKER_LOCATION) {
+                for marker in markers.split(',') {
+                    let _ = operator.delete(marker).await;
+                }
+            }
             let _ = operator.remove_all(&dir).await;
 
             Ok(None)
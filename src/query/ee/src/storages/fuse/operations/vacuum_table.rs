@@ -29,6 +29,9 @@ use common_storages_fuse::io::SnapshotLiteExtended;
 use common_storages_fuse::io::SnapshotsIO;
 use common_storages_fuse::io::TableMetaLocationGenerator;
 use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_TBL_BLOCK_PREFIX;
+use common_storages_fuse::FUSE_TBL_SEGMENT_PREFIX;
+use common_storages_fuse::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 
@@ -137,31 +140,30 @@ pub async fn get_snapshot_referenced_files(
 }
 
 // return orphan files to be purged
+//
+// only the files under `{table_prefix}/{dir}/` are listed: a cloned table may reference the files
+// of its source table, which must never be purged by the cloned table. the files shared with the
+// tables cloned from this table are kept as well.
 #[async_backtrace::framed]
 async fn get_orphan_files_to_be_purged(
     fuse_table: &FuseTable,
+    dir: &str,
     referenced_files: HashSet<String>,
+    shared_files: &HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
-        Some(location) => {
-            let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
-            if let Some(prefix) = prefix {
-                fuse_table
-                    .list_files(prefix, |location, modified| {
-                        modified <= retention_time && !referenced_files.contains(&location)
-                    })
-                    .await?
-            } else {
-                vec![]
-            }
-        }
-        None => {
-            vec![]
-        }
-    };
+    if referenced_files.is_empty() {
+        return Ok(vec![]);
+    }
 
-    Ok(files_to_be_purged)
+    let prefix = format!("{}/{}/", fuse_table.meta_location_generator().prefix(), dir);
+    fuse_table
+        .list_files(prefix, |location, modified| {
+            modified <= retention_time
+                && !referenced_files.contains(&location)
+                && !shared_files.contains(&location)
+        })
+        .await
 }
 
 #[async_backtrace::framed]
@@ -176,6 +178,7 @@ pub async fn do_gc_orphan_files(
         Some(referenced_files) => referenced_files,
         None => return Ok(()),
     };
+    let shared_files = fuse_table.get_protected_locations(ctx).await?;
     let status = format!(
         "gc orphan: read referenced files:{},{},{}, cost:{} sec",
         referenced_files.segments.len(),
@@ -187,9 +190,14 @@ pub async fn do_gc_orphan_files(
 
     // 2. Purge orphan segment files.
     // 2.1 Get orphan segment files to be purged
-    let segment_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
-            .await?;
+    let segment_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_SEGMENT_PREFIX,
+        referenced_files.segments,
+        &shared_files,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read segment_locations_to_be_purged:{}, cost:{} sec, retention_time: {}",
         segment_locations_to_be_purged.len(),
@@ -215,8 +223,14 @@ pub async fn do_gc_orphan_files(
 
     // 3. Purge orphan block files.
     // 3.1 Get orphan block files to be purged
-    let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_BLOCK_PREFIX,
+        referenced_files.blocks,
+        &shared_files,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read block_locations_to_be_purged:{}, cost:{} sec",
         block_locations_to_be_purged.len(),
@@ -241,9 +255,14 @@ pub async fn do_gc_orphan_files(
 
     // 4. Purge orphan block index files.
     // 4.1 Get orphan block index files to be purged
-    let index_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
-            .await?;
+    let index_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+        referenced_files.blocks_index,
+        &shared_files,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "gc orphan: read index_locations_to_be_purged:{}, cost:{} sec",
         index_locations_to_be_purged.len(),
@@ -283,6 +302,7 @@ pub async fn do_dry_run_orphan_files(
         Some(referenced_files) => referenced_files,
         None => return Ok(()),
     };
+    let shared_files = fuse_table.get_protected_locations(ctx).await?;
     let status = format!(
         "dry_run orphan: read referenced files:{},{},{}, cost:{} sec",
         referenced_files.segments.len(),
@@ -293,9 +313,14 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     // 2. Get purge orphan segment files.
-    let segment_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.segments, retention_time)
-            .await?;
+    let segment_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_SEGMENT_PREFIX,
+        referenced_files.segments,
+        &shared_files,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read segment_locations_to_be_purged:{}, cost:{} sec",
         segment_locations_to_be_purged.len(),
@@ -309,8 +334,14 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 3. Get purge orphan block files.
-    let block_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks, retention_time).await?;
+    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_BLOCK_PREFIX,
+        referenced_files.blocks,
+        &shared_files,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read block_locations_to_be_purged:{}, cost:{} sec",
         block_locations_to_be_purged.len(),
@@ -323,9 +354,14 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 4. Get purge orphan block index files.
-    let index_locations_to_be_purged =
-        get_orphan_files_to_be_purged(fuse_table, referenced_files.blocks_index, retention_time)
-            .await?;
+    let index_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        FUSE_TBL_XOR_BLOOM_INDEX_PREFIX,
+        referenced_files.blocks_index,
+        &shared_files,
+        retention_time,
+    )
+    .await?;
     let status = format!(
         "dry_run orphan: read index_locations_to_be_purged:{}, cost:{} sec",
         index_locations_to_be_purged.len(),
@@ -121,6 +121,7 @@ impl Payload {
             DataType::String => Column::String(self.flush_string_column(col_offset, state)),
             DataType::Bitmap => Column::Bitmap(self.flush_string_column(col_offset, state)),
             DataType::Variant => Column::Variant(self.flush_string_column(col_offset, state)),
+            DataType::Geometry => Column::Geometry(self.flush_string_column(col_offset, state)),
             DataType::Nullable(_) => unreachable!(),
             DataType::Array(_) => todo!(),
             DataType::Map(_) => todo!(),
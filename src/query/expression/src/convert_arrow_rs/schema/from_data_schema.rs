@@ -26,6 +26,7 @@ use crate::DataSchema;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -47,6 +48,7 @@ impl From<&DataField> for ArrowField {
             DataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             DataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             DataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            DataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
             _ => None,
         };
 
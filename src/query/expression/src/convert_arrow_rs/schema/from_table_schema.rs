@@ -29,6 +29,7 @@ use crate::TableField;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -40,6 +41,7 @@ impl From<&TableField> for ArrowField {
             TableDataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             TableDataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             TableDataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            TableDataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
             _ => None,
         };
 
@@ -116,6 +118,7 @@ impl From<&TableDataType> for ArrowDataType {
 
             TableDataType::Bitmap => ArrowDataType::LargeBinary,
             TableDataType::Variant => ArrowDataType::LargeBinary,
+            TableDataType::Geometry => ArrowDataType::LargeBinary,
         }
     }
 }
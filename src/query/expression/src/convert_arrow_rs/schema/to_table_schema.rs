@@ -26,6 +26,7 @@ use crate::TableSchema;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -66,6 +67,7 @@ impl TryFrom<&ArrowField> for TableDataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(TableDataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(TableDataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(TableDataType::Bitmap),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(TableDataType::Geometry),
             _ => None,
         };
 
@@ -50,6 +50,8 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
             let values = x.iter().map(scalar_to_datavalue).collect();
             DataValue::Struct(values)
         }
-        Scalar::EmptyMap | Scalar::Map(_) | Scalar::Bitmap(_) => unimplemented!(),
+        Scalar::EmptyMap | Scalar::Map(_) | Scalar::Bitmap(_) | Scalar::Geometry(_) => {
+            unimplemented!()
+        }
     }
 }
@@ -35,6 +35,7 @@ use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -327,6 +328,10 @@ impl Column {
                 columns.map(|col| col.into_variant().unwrap()),
                 capacity,
             )),
+            Column::Geometry(_) => GeometryType::upcast_column(Self::concat_string_types(
+                columns.map(|col| col.into_geometry().unwrap()),
+                capacity,
+            )),
         }
     }
 
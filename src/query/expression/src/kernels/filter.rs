@@ -177,6 +177,10 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::Variant(column)
             }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
         }
     }
 
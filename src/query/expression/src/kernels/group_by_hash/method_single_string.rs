@@ -47,7 +47,8 @@ impl HashMethod for HashMethodSingleString {
         match keys_state {
             KeysState::Column(Column::String(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col)) => Ok(col.iter()),
             _ => unreachable!(),
         }
     }
@@ -60,7 +61,8 @@ impl HashMethod for HashMethodSingleString {
         match keys_state {
             KeysState::Column(Column::String(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => {
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col)) => {
                 hashes.extend(col.iter().map(hash_join_fast_string_hash));
                 let (data, offsets) = col.into_buffer();
                 Ok(Box::new(StringKeyAccessor::new(data, offsets)))
@@ -32,6 +32,7 @@ use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -184,6 +185,11 @@ impl Column {
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(Self::take_string_types(
+                column,
+                indices,
+                string_items_buf.as_mut(),
+            )),
         }
     }
 
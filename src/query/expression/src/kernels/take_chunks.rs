@@ -38,6 +38,7 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberColumnVec;
 use crate::types::NumberType;
@@ -370,6 +371,10 @@ impl Column {
                 let builder = VariantType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<VariantType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
         }
     }
 
@@ -571,6 +576,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Variant(columns)
             }
+            Column::Geometry(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| GeometryType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Geometry(columns)
+            }
         }
     }
 
@@ -705,6 +717,9 @@ impl Column {
             ColumnVec::Variant(columns) => VariantType::upcast_column(
                 Self::take_block_vec_string_types(columns, indices, string_items_buf.as_mut()),
             ),
+            ColumnVec::Geometry(columns) => GeometryType::upcast_column(
+                Self::take_block_vec_string_types(columns, indices, string_items_buf.as_mut()),
+            ),
         }
     }
 
@@ -30,6 +30,7 @@ use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -179,6 +180,9 @@ impl Column {
             Column::Variant(column) => VariantType::upcast_column(Self::take_compact_string_types(
                 column, indices, num_rows,
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(
+                Self::take_compact_string_types(column, indices, num_rows),
+            ),
         }
     }
 
@@ -19,6 +19,7 @@ use crate::types::decimal::Decimal128Type;
 use crate::types::decimal::Decimal256Type;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::GeometryDomain;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
@@ -105,6 +106,7 @@ pub enum Domain {
     /// `Map(None)` means that the map is empty, thus there is no inner domain information.
     Map(Option<(Box<Domain>, Box<Domain>)>),
     Tuple(Vec<Domain>),
+    Geometry(GeometryDomain),
     /// For certain types, like `Variant`, the domain is useless therefore is not defined.
     Undefined,
 }
@@ -202,6 +204,7 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
+            DataType::Geometry => Domain::Geometry(GeometryDomain::full()),
             DataType::Bitmap | DataType::Variant => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
@@ -329,6 +332,9 @@ impl Domain {
                     .map(|(self_tup, other_tup)| self_tup.merge(other_tup))
                     .collect(),
             ),
+            (Domain::Geometry(this), Domain::Geometry(other)) => {
+                Domain::Geometry(this.merge(other))
+            }
             (Domain::Undefined, Domain::Undefined) => Domain::Undefined,
             (this, other) => unreachable!("unable to merge {this:?} with {other:?}"),
         }
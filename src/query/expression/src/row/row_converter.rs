@@ -62,6 +62,7 @@ impl RowConverter {
             | DataType::EmptyMap
             | DataType::Map(_)
             | DataType::Bitmap
+            | DataType::Geometry
             | DataType::Tuple(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(inner) => Self::support_data_type(inner.as_ref()),
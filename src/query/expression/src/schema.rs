@@ -38,6 +38,7 @@ use crate::Scalar;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -136,6 +137,7 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Geometry,
 }
 
 impl DataSchema {
@@ -1114,6 +1116,7 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Geometry => DataType::Geometry,
         }
     }
 }
@@ -1384,6 +1387,7 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
             },
             // this is safe, because we define the datatype firstly
@@ -1517,6 +1521,11 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             _ => unreachable!(),
         }
     }
@@ -1600,6 +1609,11 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1633,6 +1647,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         }
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -698,6 +698,7 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_boolean",
     "to_decimal",
     "to_bitmap",
+    "to_geometry",
 ];
 
 pub fn is_simple_cast_function(name: &str) -> bool {
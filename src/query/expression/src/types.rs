@@ -21,6 +21,7 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -48,6 +49,7 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -81,6 +83,7 @@ pub enum DataType {
     Bitmap,
     Tuple(Vec<DataType>),
     Variant,
+    Geometry,
 
     // Used internally for generic types
     Generic(usize),
@@ -190,7 +193,7 @@ impl DataType {
     #[inline]
     pub fn is_string_column(&self) -> bool {
         match self {
-            DataType::String | DataType::Bitmap | DataType::Variant => true,
+            DataType::String | DataType::Bitmap | DataType::Variant | DataType::Geometry => true,
             DataType::Nullable(ty) => ty.is_string_column(),
             _ => false,
         }
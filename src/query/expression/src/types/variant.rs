@@ -14,6 +14,7 @@
 
 use std::ops::Range;
 
+use common_io::geometry::wkb_to_geojson;
 use roaring::RoaringTreemap;
 
 use super::date::date_to_string;
@@ -251,6 +252,17 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(bytes);
             return;
         }
+        ScalarRef::Geometry(bytes) => {
+            // Geometries are converted to their GeoJSON representation.
+            match wkb_to_geojson(bytes)
+                .ok()
+                .and_then(|json| jsonb::parse_value(json.as_bytes()).ok())
+            {
+                Some(value) => value.write_to_vec(buf),
+                None => jsonb::Value::Null.write_to_vec(buf),
+            }
+            return;
+        }
     };
     value.write_to_vec(buf);
 }
@@ -70,6 +70,7 @@ impl_from_data! { DateType }
 impl_from_data! { TimestampType }
 impl_from_data! { VariantType }
 impl_from_data! { BitmapType }
+impl_from_data! { GeometryType }
 
 impl<'a> FromData<&'a str> for StringType {
     fn from_data(d: Vec<&'a str>) -> Column {
@@ -21,6 +21,7 @@ use comfy_table::Cell;
 use comfy_table::Table;
 use common_io::display_decimal_128;
 use common_io::display_decimal_256;
+use common_io::geometry::wkb_to_wkt;
 use itertools::Itertools;
 use num_traits::FromPrimitive;
 use roaring::RoaringTreemap;
@@ -40,6 +41,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::GeometryDomain;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -158,6 +160,7 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Geometry(s) => write!(f, "0x{}", &hex::encode(s)),
         }
     }
 }
@@ -180,6 +183,7 @@ impl Debug for Column {
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -243,6 +247,10 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Geometry(s) => match wkb_to_wkt(s) {
+                Ok(wkt) => write!(f, "{wkt}"),
+                Err(_) => write!(f, "0x{}", &hex::encode(s)),
+            },
         }
     }
 }
@@ -476,6 +484,7 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Geometry => write!(f, "Geometry"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -522,6 +531,7 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Geometry => write!(f, "Geometry"),
         }
     }
 }
@@ -914,6 +924,19 @@ impl Display for DecimalDomain {
     }
 }
 
+impl Display for GeometryDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{({}, {})..=({}, {})}}",
+            display_f64(self.min_x),
+            display_f64(self.min_y),
+            display_f64(self.max_x),
+            display_f64(self.max_y)
+        )
+    }
+}
+
 impl<T: Display> Display for SimpleDomain<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{{{}..={}}}", self.min, self.max)
@@ -946,6 +969,7 @@ impl Display for Domain {
             Domain::Map(Some((key_domain, val_domain))) => {
                 write!(f, "{{[{key_domain}], [{val_domain}]}}")
             }
+            Domain::Geometry(domain) => write!(f, "{domain}"),
             Domain::Undefined => write!(f, "Undefined"),
         }
     }
@@ -38,6 +38,7 @@ pub fn contains_variant(data_type: &DataType) -> bool {
         | DataType::Timestamp
         | DataType::Date
         | DataType::Bitmap
+        | DataType::Geometry
         | DataType::Generic(_) => false,
         DataType::Nullable(ty) => contains_variant(ty.as_ref()),
         DataType::Array(ty) => contains_variant(ty.as_ref()),
@@ -76,7 +77,8 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::Date(_)
         | ScalarRef::Boolean(_)
         | ScalarRef::String(_)
-        | ScalarRef::Bitmap(_) => scalar.to_owned(),
+        | ScalarRef::Bitmap(_)
+        | ScalarRef::Geometry(_) => scalar.to_owned(),
         ScalarRef::Array(col) => Scalar::Array(transform_column(&col, decode)?),
         ScalarRef::Map(col) => Scalar::Map(transform_column(&col, decode)?),
         ScalarRef::Tuple(scalars) => {
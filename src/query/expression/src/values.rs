@@ -55,6 +55,8 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::GeometryDomain;
+use crate::types::geometry::GEOMETRY_EMPTY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -114,6 +116,7 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Geometry(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -133,6 +136,7 @@ pub enum ScalarRef<'a> {
     Bitmap(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Geometry(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -152,6 +156,7 @@ pub enum Column {
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
+    Geometry(StringColumn),
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -171,6 +176,7 @@ pub enum ColumnVec {
     Nullable(Box<NullableColumnVec>),
     Tuple(Vec<ColumnVec>),
     Variant(Vec<StringColumn>),
+    Geometry(Vec<StringColumn>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -190,6 +196,7 @@ pub enum ColumnBuilder {
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
+    Geometry(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -334,6 +341,7 @@ impl Scalar {
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
+            Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
         }
     }
 
@@ -378,6 +386,7 @@ impl Scalar {
             }
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
+            DataType::Geometry => Scalar::Geometry(GEOMETRY_EMPTY.to_vec()),
 
             _ => unimplemented!(),
         }
@@ -413,6 +422,7 @@ impl<'a> ScalarRef<'a> {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
+            ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
         }
     }
 
@@ -482,6 +492,7 @@ impl<'a> ScalarRef<'a> {
                 )
             }
             ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Geometry(g) => Domain::Geometry(GeometryDomain::from_wkb(g)),
         }
     }
 
@@ -509,6 +520,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Bitmap(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
+            ScalarRef::Geometry(buf) => buf.len(),
         }
     }
 
@@ -540,6 +552,7 @@ impl<'a> ScalarRef<'a> {
                 DataType::Tuple(inner)
             }
             ScalarRef::Variant(_) => DataType::Variant,
+            ScalarRef::Geometry(_) => DataType::Geometry,
         }
     }
 }
@@ -563,6 +576,7 @@ impl PartialOrd for Scalar {
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
             }
+            (Scalar::Geometry(g1), Scalar::Geometry(g2)) => g1.partial_cmp(g2),
             _ => None,
         }
     }
@@ -597,6 +611,7 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => b1.partial_cmp(b2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
+            (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => g1.partial_cmp(g2),
             _ => None,
         }
     }
@@ -645,6 +660,7 @@ impl Hash for ScalarRef<'_> {
                 v.hash(state);
             }
             ScalarRef::Variant(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
         }
     }
 }
@@ -683,6 +699,9 @@ impl PartialOrd for Column {
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
                 .partial_cmp_by(col2.iter(), |v1, v2| jsonb::compare(v1, v2).ok()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             _ => None,
         }
     }
@@ -699,6 +718,7 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -718,6 +738,7 @@ impl Column {
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
+            Column::Geometry(col) => col.len(),
         }
     }
 
@@ -743,6 +764,7 @@ impl Column {
                     .collect::<Option<Vec<_>>>()?,
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
         }
     }
 
@@ -771,6 +793,7 @@ impl Column {
                     .collect::<Vec<_>>(),
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
         }
     }
 
@@ -820,6 +843,7 @@ impl Column {
                     .collect(),
             ),
             Column::Variant(col) => Column::Variant(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
         }
     }
 
@@ -903,6 +927,9 @@ impl Column {
                 Domain::Tuple(domains)
             }
             Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            // The bounding box is only known from the block statistics, decoding every
+            // geometry here is too expensive.
+            Column::Geometry(_) => Domain::Geometry(GeometryDomain::full()),
         }
     }
 
@@ -940,6 +967,7 @@ impl Column {
                 DataType::Tuple(inner)
             }
             Column::Variant(_) => DataType::Variant,
+            Column::Geometry(_) => DataType::Geometry,
         }
     }
 
@@ -1166,7 +1194,7 @@ impl Column {
                 )
                 .unwrap(),
             ),
-            Column::Variant(col) => {
+            Column::Variant(col) | Column::Geometry(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets().iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1226,6 +1254,7 @@ impl Column {
             Column::String(x) => x.check_valid(),
             Column::Variant(x) => x.check_valid(),
             Column::Bitmap(x) => x.check_valid(),
+            Column::Geometry(x) => x.check_valid(),
             Column::Map(x) => {
                 for y in x.iter() {
                     y.check_valid()?;
@@ -1389,6 +1418,9 @@ impl Column {
                     DataType::Bitmap => {
                         Column::Bitmap(StringColumn::new(arrow_col.values().clone(), offsets))
                     }
+                    DataType::Geometry => {
+                        Column::Geometry(StringColumn::new(arrow_col.values().clone(), offsets))
+                    }
                     _ => Column::String(StringColumn::new(arrow_col.values().clone(), offsets)),
                 }
             }
@@ -1414,6 +1446,10 @@ impl Column {
                         arrow_col.values().clone(),
                         offsets.into(),
                     )),
+                    DataType::Geometry => Column::Geometry(StringColumn::new(
+                        arrow_col.values().clone(),
+                        offsets.into(),
+                    )),
                     _ => Column::String(StringColumn::new(
                         arrow_col.values().clone(),
                         offsets.into(),
@@ -1680,6 +1716,40 @@ impl Column {
                     ),
                 }
             }
+            ArrowDataType::Extension(name, box ty, None) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                match ty {
+                    ArrowDataType::LargeBinary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                        let offsets = arrow_col.offsets().clone().into_inner();
+
+                        let offsets =
+                            unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                        Column::Geometry(StringColumn::new(arrow_col.values().clone(), offsets))
+                    }
+                    ArrowDataType::Binary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i32>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i32>`");
+                        let offsets = arrow_col
+                            .offsets()
+                            .buffer()
+                            .iter()
+                            .map(|x| *x as u64)
+                            .collect::<Vec<_>>();
+                        Column::Geometry(StringColumn::new(
+                            arrow_col.values().clone(),
+                            offsets.into(),
+                        ))
+                    }
+                    _ => unreachable!(
+                        "fail to read from arrow: array should be `BinaryArray<i32>` or `BinaryArray<i64>`"
+                    ),
+                }
+            }
             ty => unimplemented!("unsupported arrow type {ty:?}"),
         };
 
@@ -1818,6 +1888,20 @@ impl Column {
                 }
                 VariantType::from_data(data)
             }
+            DataType::Geometry => GeometryType::from_data(
+                (0..len)
+                    .map(|_| {
+                        // WKB encoded point in little endian
+                        let (x, y): (f64, f64) = SmallRng::from_entropy().gen();
+                        let mut buf = Vec::with_capacity(21);
+                        buf.push(1);
+                        buf.extend_from_slice(&1u32.to_le_bytes());
+                        buf.extend_from_slice(&x.to_le_bytes());
+                        buf.extend_from_slice(&y.to_le_bytes());
+                        buf
+                    })
+                    .collect_vec(),
+            ),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1879,6 +1963,7 @@ impl Column {
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.memory_size(),
+            Column::Geometry(col) => col.memory_size(),
         }
     }
 
@@ -1898,7 +1983,10 @@ impl Column {
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Boolean(c) => c.len(),
-            Column::String(col) | Column::Bitmap(col) | Column::Variant(col) => col.memory_size(),
+            Column::String(col)
+            | Column::Bitmap(col)
+            | Column::Variant(col)
+            | Column::Geometry(col) => col.memory_size(),
             Column::Array(col) | Column::Map(col) => col.values.serialize_size() + col.len() * 8,
             Column::Nullable(c) => c.column.serialize_size() + c.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.serialize_size()).sum(),
@@ -1988,6 +2076,7 @@ impl ColumnBuilder {
                     .collect(),
             ),
             Column::Variant(col) => ColumnBuilder::Variant(StringColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
         }
     }
 
@@ -2049,6 +2138,7 @@ impl ColumnBuilder {
                 )
             }
             ScalarRef::Variant(s) => ColumnBuilder::Variant(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Geometry(s) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(s, n)),
         }
     }
 
@@ -2069,6 +2159,7 @@ impl ColumnBuilder {
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
         }
     }
 
@@ -2103,6 +2194,7 @@ impl ColumnBuilder {
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
         }
     }
 
@@ -2136,6 +2228,7 @@ impl ColumnBuilder {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
             }
             ColumnBuilder::Variant(_) => DataType::Variant,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
         }
     }
 
@@ -2206,6 +2299,10 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -2254,6 +2351,10 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         }
     }
@@ -2282,6 +2383,10 @@ impl ColumnBuilder {
                 builder.put_slice(JSONB_NULL);
                 builder.commit_row();
             }
+            ColumnBuilder::Geometry(builder) => {
+                builder.put_slice(GEOMETRY_EMPTY);
+                builder.commit_row();
+            }
         }
     }
 
@@ -2308,7 +2413,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 let offset = reader.read_scalar::<u64>()? as usize;
                 builder.data.resize(offset + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2394,7 +2500,8 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2506,6 +2613,7 @@ impl ColumnBuilder {
                 }
             }
             ColumnBuilder::Variant(builder) => builder.pop().map(Scalar::Variant),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
         }
     }
 
@@ -2535,6 +2643,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Timestamp(builder), Column::Timestamp(other)) => {
                 builder.extend_from_slice(other);
             }
@@ -2587,6 +2698,7 @@ impl ColumnBuilder {
                 Column::Tuple(fields.into_iter().map(|field| field.build()).collect())
             }
             ColumnBuilder::Variant(builder) => Column::Variant(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
         }
     }
 
@@ -2613,6 +2725,7 @@ impl ColumnBuilder {
                     .collect(),
             ),
             ColumnBuilder::Variant(builder) => Scalar::Variant(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
         }
     }
 }
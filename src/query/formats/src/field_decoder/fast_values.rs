@@ -52,6 +52,7 @@ use common_io::cursor_ext::DateTimeResType;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::geometry::parse_to_wkb;
 use common_io::parse_bitmap;
 use common_io::prelude::FormatSettings;
 use jsonb::parse_value;
@@ -146,6 +147,7 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader, positions),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, positions),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, positions),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
             _ => unimplemented!(),
         }
     }
@@ -428,6 +430,20 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let wkb = parse_to_wkb(&buf)?;
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
@@ -38,6 +38,7 @@ use common_expression::ColumnBuilder;
 use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::DateTimeResType;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::geometry::parse_to_wkb;
 use common_io::parse_bitmap;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
@@ -93,6 +94,7 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
             _ => unimplemented!(),
         }
     }
@@ -269,6 +271,18 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_geometry(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        let wkb = match value {
+            Value::String(v) => parse_to_wkb(v.as_bytes())?,
+            // GeoJSON objects are embedded in the JSON document directly.
+            Value::Object(_) => parse_to_wkb(value.to_string().as_bytes())?,
+            _ => return Err(ErrorCode::BadBytes("Incorrect Geometry value")),
+        };
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
         let v = jsonb::Value::from(value);
         v.write_to_vec(&mut column.data);
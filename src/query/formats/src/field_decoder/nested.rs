@@ -48,6 +48,7 @@ use common_io::cursor_ext::DateTimeResType;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_io::cursor_ext::ReadNumberExt;
+use common_io::geometry::parse_to_wkb;
 use common_io::parse_bitmap;
 use jsonb::parse_value;
 use lexical_core::FromLexical;
@@ -131,6 +132,7 @@ impl NestedValues {
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader),
             _ => unimplemented!(),
         }
     }
@@ -273,6 +275,19 @@ impl NestedValues {
         Ok(())
     }
 
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        let wkb = parse_to_wkb(&buf)?;
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
@@ -48,6 +48,7 @@ use common_io::cursor_ext::read_num_text_exact;
 use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::DateTimeResType;
 use common_io::cursor_ext::ReadBytesExt;
+use common_io::geometry::parse_to_wkb;
 use common_io::parse_bitmap;
 use common_meta_app::principal::CsvFileFormatParams;
 use common_meta_app::principal::TsvFileFormatParams;
@@ -156,6 +157,7 @@ impl SeparatedTextDecoder {
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, data),
             ColumnBuilder::Variant(c) => self.read_variant(c, data),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, data),
             _ => unimplemented!(),
         }
     }
@@ -276,6 +278,13 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_geometry(&self, column: &mut StringColumnBuilder, data: &[u8]) -> Result<()> {
+        let wkb = parse_to_wkb(data)?;
+        column.put_slice(&wkb);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant(&self, column: &mut StringColumnBuilder, data: &[u8]) -> Result<()> {
         match parse_value(data) {
             Ok(value) => {
@@ -24,6 +24,7 @@ use common_expression::types::timestamp::timestamp_to_string;
 use common_expression::types::NumberColumn;
 use common_expression::types::ValueType;
 use common_expression::Column;
+use common_expression::ScalarRef;
 use common_io::constants::FALSE_BYTES_NUM;
 use common_io::constants::INF_BYTES_LONG;
 use common_io::constants::INF_BYTES_LOWER;
@@ -126,6 +127,7 @@ impl FieldEncoderValues {
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, in_nested),
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, in_nested),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, in_nested),
+            Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
 
             Column::Array(box c) => self.write_array(c, row_index, out_buf),
             Column::Map(box c) => self.write_map(c, row_index, out_buf),
@@ -275,6 +277,19 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_geometry(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        // Written as WKT, or as hex encoded WKB if the value can't be decoded.
+        let s = ScalarRef::Geometry(v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
     fn write_array<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
@@ -17,6 +17,7 @@ use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
+use common_io::geometry::wkb_to_geojson;
 use common_io::prelude::FormatSettings;
 use roaring::RoaringTreemap;
 use serde_json::Map as JsonMap;
@@ -140,6 +141,11 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Geometry(x) => match wkb_to_geojson(x) {
+            Ok(json) => serde_json::from_str(&json)
+                .unwrap_or_else(|_| JsonValue::String(ScalarRef::Geometry(x).to_string())),
+            Err(_) => JsonValue::String(ScalarRef::Geometry(x).to_string()),
+        },
     }
 }
 
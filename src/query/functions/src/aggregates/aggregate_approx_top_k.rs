@@ -0,0 +1,416 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::date_helper::TzLUT;
+use common_expression::type_check::check_number;
+use common_expression::types::variant::cast_scalar_to_variant;
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::types::ValueType;
+use common_expression::types::VariantType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use jsonb::build_array;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::aggregate_function_factory::AggregateFunctionFeatures;
+use super::deserialize_state;
+use super::serialize_state;
+use super::StateAddr;
+use crate::aggregates::assert_variadic_arguments;
+use crate::BUILTIN_FUNCTIONS;
+
+const MAX_TOP_K: u64 = 4096;
+// The sketch keeps more counters than requested, which makes the counts
+// of the reported items much more accurate for skewed data.
+const CAPACITY_MULTIPLIER: usize = 3;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+struct Counter {
+    count: u64,
+    // The maximum overestimation of `count`.
+    error: u64,
+}
+
+/// The Space-Saving sketch, see "Efficient Computation of Frequent and Top-k Elements
+/// in Data Streams" by Metwally et al. Two sketches are merged following
+/// "Mergeable Summaries" by Agarwal et al., so that the state can be used
+/// in the partial/final aggregation and stored by the `_state` combinator.
+///
+/// The counters are kept in a binary min-heap indexed by the items, so that both counting
+/// a tracked item and replacing the minimum counter take O(log capacity).
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SpaceSavingState {
+    capacity: usize,
+    heap: Vec<(Scalar, Counter)>,
+    // The position of each item in `heap`, rebuilt after the state is deserialized.
+    #[serde(skip)]
+    positions: HashMap<Scalar, usize>,
+}
+
+impl SpaceSavingState {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            heap: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.heap.len() >= self.capacity
+    }
+
+    // The count an item absent from a full sketch may have.
+    fn min_count(&self) -> u64 {
+        if !self.is_full() {
+            return 0;
+        }
+        self.heap.first().map(|(_, c)| c.count).unwrap_or(0)
+    }
+
+    fn add(&mut self, value: ScalarRef) {
+        // Look up with the borrowed value, the value is only copied when it is inserted.
+        let hash = self.positions.hasher().hash_one(&value);
+        if let Some((_, &pos)) = self
+            .positions
+            .raw_entry()
+            .from_hash(hash, |v| v.as_ref() == value)
+        {
+            self.heap[pos].1.count += 1;
+            self.sift_down(pos);
+            return;
+        }
+
+        let value = value.to_owned();
+        if !self.is_full() {
+            let pos = self.heap.len();
+            self.positions.insert(value.clone(), pos);
+            self.heap.push((value, Counter { count: 1, error: 0 }));
+            self.sift_up(pos);
+            return;
+        }
+
+        // Replace the item with the minimum count, it inherits the count as its error.
+        let (min_value, min_counter) = &self.heap[0];
+        let counter = Counter {
+            count: min_counter.count + 1,
+            error: min_counter.count,
+        };
+        self.positions.remove(min_value);
+        self.positions.insert(value.clone(), 0);
+        self.heap[0] = (value, counter);
+        self.sift_down(0);
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.heap[parent].1.count <= self.heap[pos].1.count {
+                break;
+            }
+            self.swap(parent, pos);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let left = pos * 2 + 1;
+            if left >= self.heap.len() {
+                break;
+            }
+            let right = left + 1;
+            let child =
+                if right < self.heap.len() && self.heap[right].1.count < self.heap[left].1.count {
+                    right
+                } else {
+                    left
+                };
+            if self.heap[pos].1.count <= self.heap[child].1.count {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        *self.positions.get_mut(&self.heap[a].0).unwrap() = a;
+        *self.positions.get_mut(&self.heap[b].0).unwrap() = b;
+    }
+
+    fn rebuild_positions(&mut self) {
+        self.positions = self
+            .heap
+            .iter()
+            .enumerate()
+            .map(|(pos, (value, _))| (value.clone(), pos))
+            .collect();
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        let lhs_min = self.min_count();
+        let rhs_min = rhs.min_count();
+
+        // The items absent from one side may have the minimum count of that side.
+        let mut counters: HashMap<Scalar, Counter> = std::mem::take(&mut self.heap)
+            .into_iter()
+            .map(|(value, counter)| {
+                (value, Counter {
+                    count: counter.count + rhs_min,
+                    error: counter.error + rhs_min,
+                })
+            })
+            .collect();
+        for (value, rhs_counter) in rhs.heap.iter() {
+            match counters.get_mut(value) {
+                Some(counter) => {
+                    counter.count = counter.count - rhs_min + rhs_counter.count;
+                    counter.error = counter.error - rhs_min + rhs_counter.error;
+                }
+                None => {
+                    counters.insert(value.clone(), Counter {
+                        count: rhs_counter.count + lhs_min,
+                        error: rhs_counter.error + lhs_min,
+                    });
+                }
+            }
+        }
+
+        let mut counters = counters.into_iter().collect::<Vec<_>>();
+        counters.sort_by(Self::compare);
+        counters.truncate(self.capacity);
+        // Sorted by the count ascending is a valid min-heap.
+        counters.reverse();
+        self.heap = counters;
+        self.rebuild_positions();
+    }
+
+    // Order by the count descending, ties are broken by the value to keep the result stable.
+    fn compare(lhs: &(Scalar, Counter), rhs: &(Scalar, Counter)) -> Ordering {
+        rhs.1
+            .count
+            .cmp(&lhs.1.count)
+            .then_with(|| lhs.0.partial_cmp(&rhs.0).unwrap_or(Ordering::Equal))
+    }
+
+    fn top_k(&self, k: usize) -> Vec<(Scalar, Counter)> {
+        let mut counters = self.heap.clone();
+        counters.sort_by(Self::compare);
+        counters.truncate(k);
+        counters
+    }
+}
+
+/// `approx_top_k(col, k)` returns the approximately most frequent `k` values as
+/// a variant array of `[value, count]` pairs, ordered by the count descending.
+#[derive(Clone)]
+pub struct AggregateApproxTopKFunction {
+    display_name: String,
+    k: usize,
+}
+
+impl AggregateApproxTopKFunction {
+    fn try_create(
+        display_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 2))?;
+        if params.len() != 1 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect a constant k as the second argument",
+                display_name
+            )));
+        }
+        let k = check_number::<_, u64>(
+            None,
+            &FunctionContext::default(),
+            &Expr::<usize>::Constant {
+                span: None,
+                scalar: params[0].clone(),
+                data_type: params[0].as_ref().infer_data_type(),
+            },
+            &BUILTIN_FUNCTIONS,
+        )?;
+        if k == 0 || k > MAX_TOP_K {
+            return Err(ErrorCode::BadArguments(format!(
+                "The k of {} must be in the range [1, {}], but got {}",
+                display_name, MAX_TOP_K, k
+            )));
+        }
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            k: k as usize,
+        }))
+    }
+}
+
+impl AggregateFunction for AggregateApproxTopKFunction {
+    fn name(&self) -> &str {
+        "AggregateApproxTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Variant)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| SpaceSavingState::new(self.k * CAPACITY_MULTIPLIER));
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<SpaceSavingState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        match validity {
+            Some(validity) => {
+                for (value, valid) in columns[0].iter().zip(validity.iter()) {
+                    if valid {
+                        state.add(value);
+                    }
+                }
+            }
+            None => {
+                for value in columns[0].iter() {
+                    state.add(value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (value, place) in columns[0].iter().zip(places.iter()) {
+            let state = place.next(offset).get::<SpaceSavingState>();
+            state.add(value);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        state.add(columns[0].index(row).unwrap());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let rhs: SpaceSavingState = deserialize_state(reader)?;
+        state.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let other = rhs.get::<SpaceSavingState>();
+        state.merge(other);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<SpaceSavingState>();
+        let builder = VariantType::try_downcast_builder(builder).unwrap();
+
+        // Timestamps are rendered in UTC, the aggregate functions have no access to the session.
+        let tz = TzLUT::default();
+        let mut items = Vec::with_capacity(self.k);
+        for (value, counter) in state.top_k(self.k) {
+            let mut value_buf = Vec::new();
+            cast_scalar_to_variant(value.as_ref(), tz, &mut value_buf);
+            let mut count_buf = Vec::new();
+            cast_scalar_to_variant(
+                ScalarRef::Number(NumberScalar::UInt64(counter.count)),
+                tz,
+                &mut count_buf,
+            );
+            let mut item = Vec::new();
+            build_array([&value_buf[..], &count_buf[..]], &mut item)
+                .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+            items.push(item);
+        }
+
+        build_array(items.iter().map(|b| &b[..]), &mut builder.data)
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<SpaceSavingState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateApproxTopKFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        has_self_describing_state: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(AggregateApproxTopKFunction::try_create),
+        features,
+    )
+}
@@ -0,0 +1,164 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionCreator;
+use crate::aggregates::aggregate_function_factory::CombinatorDescription;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// `xxx_merge(state)` merges the serialized states produced by `xxx_state` and
+/// returns the same result as `xxx`.
+///
+/// Only the functions whose state does not depend on the argument types can be
+/// merged this way, see `AggregateFunctionFeatures::has_self_describing_state`.
+#[derive(Clone)]
+pub struct AggregateMergeCombinator {
+    name: String,
+    nested: AggregateFunctionRef,
+}
+
+impl AggregateMergeCombinator {
+    pub fn try_create(
+        nested_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+        nested_creator: &AggregateFunctionCreator,
+    ) -> Result<AggregateFunctionRef> {
+        if arguments.is_empty() || arguments[0].remove_nullable() != DataType::String {
+            return Err(ErrorCode::BadArguments(format!(
+                "The first argument of {}_merge must be the string state of {}_state",
+                nested_name, nested_name
+            )));
+        }
+
+        let name = format!("MergeCombinator({nested_name})");
+        let nested = nested_creator(nested_name, params, arguments)?;
+
+        Ok(Arc::new(AggregateMergeCombinator { name, nested }))
+    }
+
+    pub fn combinator_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(Self::try_create))
+    }
+}
+
+impl AggregateFunction for AggregateMergeCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.nested.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        self.nested.init_state(place);
+    }
+
+    fn state_layout(&self) -> Layout {
+        self.nested.state_layout()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        match validity {
+            Some(validity) => {
+                for (mut data, valid) in column.iter().zip(validity.iter()) {
+                    if valid {
+                        self.nested.merge(place, &mut data)?;
+                    }
+                }
+            }
+            None => {
+                for mut data in column.iter() {
+                    self.nested.merge(place, &mut data)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        for (mut data, place) in column.iter().zip(places.iter()) {
+            self.nested.merge(place.next(offset), &mut data)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
+        if let Some(mut data) = StringType::index_column(&column, row) {
+            self.nested.merge(place, &mut data)?;
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.nested.serialize(place, writer)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        self.nested.merge(place, reader)
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.nested.merge_states(place, rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.nested.merge_result(place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        self.nested.need_manual_drop_state()
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        self.nested.drop_state(place);
+    }
+}
+
+impl fmt::Display for AggregateMergeCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
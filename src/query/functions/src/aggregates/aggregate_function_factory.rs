@@ -27,6 +27,7 @@ use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
 const STATE_SUFFIX: &str = "_state";
+const MERGE_SUFFIX: &str = "_merge";
 
 pub type AggregateFunctionCreator =
     Box<dyn Fn(&str, Vec<Scalar>, Vec<DataType>) -> Result<AggregateFunctionRef> + Sync + Send>;
@@ -73,6 +74,10 @@ pub struct AggregateFunctionFeatures {
     ///   AVG(C) = SUM(C) / COUNT(C)
     pub(crate) is_decomposable: bool,
 
+    /// The serialized state does not depend on the argument types, so the states
+    /// produced by `xxx_state` can be merged again by `xxx_merge`.
+    pub(crate) has_self_describing_state: bool,
+
     // Function Category
     pub category: &'static str,
     // Introduce the function in brief.
@@ -245,6 +250,14 @@ impl AggregateFunctionFactory {
                         if suffix.eq_ignore_ascii_case(STATE_SUFFIX) {
                             features.returns_default_when_only_null = true;
                         }
+                        if suffix.eq_ignore_ascii_case(MERGE_SUFFIX)
+                            && !features.has_self_describing_state
+                        {
+                            return Err(ErrorCode::UnknownAggregateFunction(format!(
+                                "Aggregate function {} does not support the {} combinator",
+                                nested_name, MERGE_SUFFIX
+                            )));
+                        }
                         return (desc.creator)(
                             nested_name,
                             params,
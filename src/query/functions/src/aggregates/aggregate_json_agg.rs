@@ -0,0 +1,350 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::date_helper::TzLUT;
+use common_expression::types::variant::cast_scalar_to_variant;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::types::VariantType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use jsonb::build_array;
+use jsonb::build_object;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function::AggregateFunction;
+use super::aggregate_function::AggregateFunctionRef;
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::deserialize_state;
+use super::serialize_state;
+use super::StateAddr;
+use crate::aggregates::assert_binary_arguments;
+use crate::aggregates::assert_unary_arguments;
+
+// The values are encoded as jsonb when they are accumulated, timestamps are
+// rendered in UTC since the aggregate functions have no access to the session.
+fn encode_variant(value: ScalarRef) -> Vec<u8> {
+    let mut buf = Vec::new();
+    cast_scalar_to_variant(value, TzLUT::default(), &mut buf);
+    buf
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct JsonArrayAggState {
+    values: Vec<Vec<u8>>,
+}
+
+/// `json_array_agg(v)` collects the non-null values into a variant array.
+#[derive(Clone)]
+pub struct AggregateJsonArrayAggFunction {
+    display_name: String,
+}
+
+impl AggregateFunction for AggregateJsonArrayAggFunction {
+    fn name(&self) -> &str {
+        "AggregateJsonArrayAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Variant)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(JsonArrayAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<JsonArrayAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        match validity {
+            Some(validity) => {
+                for (value, valid) in columns[0].iter().zip(validity.iter()) {
+                    if valid {
+                        state.values.push(encode_variant(value));
+                    }
+                }
+            }
+            None => {
+                for value in columns[0].iter() {
+                    state.values.push(encode_variant(value));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (value, place) in columns[0].iter().zip(places.iter()) {
+            let state = place.next(offset).get::<JsonArrayAggState>();
+            state.values.push(encode_variant(value));
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        state
+            .values
+            .push(encode_variant(columns[0].index(row).unwrap()));
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        let rhs: JsonArrayAggState = deserialize_state(reader)?;
+        state.values.extend(rhs.values);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        let other = rhs.get::<JsonArrayAggState>();
+        state.values.extend(other.values.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        let builder = VariantType::try_downcast_builder(builder).unwrap();
+        build_array(state.values.iter().map(|v| &v[..]), &mut builder.data)
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<JsonArrayAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateJsonArrayAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_json_array_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, argument_types.len())?;
+    Ok(Arc::new(AggregateJsonArrayAggFunction {
+        display_name: display_name.to_string(),
+    }))
+}
+
+pub fn aggregate_json_array_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_json_array_agg_function))
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct JsonObjectAggState {
+    kvs: Vec<(String, Vec<u8>)>,
+}
+
+impl JsonObjectAggState {
+    fn add(&mut self, key: &[u8], value: ScalarRef) {
+        let key = String::from_utf8_lossy(key).into_owned();
+        self.kvs.push((key, encode_variant(value)));
+    }
+}
+
+/// `json_object_agg(k, v)` collects the key-value pairs into a variant object,
+/// the pairs with a null key or a null value are ignored.
+#[derive(Clone)]
+pub struct AggregateJsonObjectAggFunction {
+    display_name: String,
+}
+
+impl AggregateFunction for AggregateJsonObjectAggFunction {
+    fn name(&self) -> &str {
+        "AggregateJsonObjectAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Variant)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(JsonObjectAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<JsonObjectAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let keys = StringType::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<JsonObjectAggState>();
+        match validity {
+            Some(validity) => {
+                for ((key, value), valid) in keys.iter().zip(columns[1].iter()).zip(validity.iter())
+                {
+                    if valid {
+                        state.add(key, value);
+                    }
+                }
+            }
+            None => {
+                for (key, value) in keys.iter().zip(columns[1].iter()) {
+                    state.add(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let keys = StringType::try_downcast_column(&columns[0]).unwrap();
+        for ((key, value), place) in keys.iter().zip(columns[1].iter()).zip(places.iter()) {
+            let state = place.next(offset).get::<JsonObjectAggState>();
+            state.add(key, value);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let keys = StringType::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<JsonObjectAggState>();
+        state.add(
+            StringType::index_column(&keys, row).unwrap(),
+            columns[1].index(row).unwrap(),
+        );
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        let rhs: JsonObjectAggState = deserialize_state(reader)?;
+        state.kvs.extend(rhs.kvs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        let other = rhs.get::<JsonObjectAggState>();
+        state.kvs.extend(other.kvs.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        let builder = VariantType::try_downcast_builder(builder).unwrap();
+
+        let mut kvs = BTreeMap::new();
+        for (key, value) in state.kvs.iter() {
+            if kvs.insert(key, &value[..]).is_some() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{}: Keys have to be unique, but got duplicate key '{}'",
+                    self.display_name, key
+                )));
+            }
+        }
+        build_object(kvs.into_iter(), &mut builder.data)
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<JsonObjectAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateJsonObjectAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_json_object_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_binary_arguments(display_name, argument_types.len())?;
+    if argument_types[0].remove_nullable() != DataType::String {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "The key of aggregate function {} must be string",
+            display_name
+        )));
+    }
+    Ok(Arc::new(AggregateJsonObjectAggFunction {
+        display_name: display_name.to_string(),
+    }))
+}
+
+pub fn aggregate_json_object_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_json_object_agg_function))
+}
@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::decimal::*;
+use common_expression::types::number::*;
+use common_expression::types::*;
+use common_expression::with_number_mapped_type;
+use common_expression::Scalar;
+use ethnum::i256;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::deserialize_state;
+use super::serialize_state;
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::with_simple_no_number_mapped_type;
+
+#[derive(Serialize, Deserialize)]
+pub struct ModeState<T>
+where
+    T: ValueType,
+    T::Scalar: Serialize + DeserializeOwned + Hash + Eq,
+{
+    #[serde(bound(deserialize = "T::Scalar: DeserializeOwned + Hash + Eq"))]
+    pub frequency_map: HashMap<T::Scalar, u64>,
+}
+
+impl<T> Default for ModeState<T>
+where
+    T: ValueType,
+    T::Scalar: Serialize + DeserializeOwned + Hash + Eq,
+{
+    fn default() -> Self {
+        ModeState {
+            frequency_map: HashMap::new(),
+        }
+    }
+}
+
+impl<T> UnaryState<T, T> for ModeState<T>
+where
+    T: ValueType + Send + Sync,
+    T::Scalar: Serialize + DeserializeOwned + Hash + Eq + PartialOrd + Send + Sync,
+{
+    fn add(&mut self, other: T::ScalarRef<'_>) -> Result<()> {
+        let other = T::to_owned_scalar(other);
+        *self.frequency_map.entry(other).or_insert(0) += 1;
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        for (key, value) in rhs.frequency_map.iter() {
+            match self.frequency_map.get_mut(key) {
+                Some(entry) => *entry += value,
+                None => {
+                    self.frequency_map.insert(key.clone(), *value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut T::ColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        // Ties are broken by the smallest value, so that the result does not depend
+        // on how the rows are distributed among the partial aggregations.
+        let mode = self.frequency_map.iter().max_by(|(lk, lv), (rk, rv)| {
+            lv.cmp(rv)
+                .then_with(|| rk.partial_cmp(lk).unwrap_or(Ordering::Equal))
+        });
+        match mode {
+            Some((key, _)) => T::push_item(builder, T::to_scalar_ref(key)),
+            None => T::push_default(builder),
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        serialize_state(writer, self)
+    }
+
+    fn deserialize(reader: &mut &[u8]) -> Result<Self>
+    where Self: Sized {
+        deserialize_state(reader)
+    }
+}
+
+pub fn try_create_aggregate_mode_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, argument_types.len())?;
+    let mut data_type = argument_types[0].clone();
+
+    // null use dummy func, it's already covered in `AggregateNullResultFunction`
+    if data_type.is_null() {
+        data_type = DataType::String;
+    }
+
+    with_simple_no_number_mapped_type!(|T| match data_type {
+        DataType::T => {
+            let return_type = data_type.clone();
+            let func = AggregateUnaryFunction::<ModeState<T>, T, T>::try_create(
+                display_name,
+                return_type,
+                params,
+                data_type,
+            )
+            .with_need_drop(true);
+
+            Ok(Arc::new(func))
+        }
+        DataType::Number(num_type) => {
+            with_number_mapped_type!(|NUM| match num_type {
+                NumberDataType::NUM => {
+                    let return_type = data_type.clone();
+                    let func = AggregateUnaryFunction::<
+                        ModeState<NumberType<NUM>>,
+                        NumberType<NUM>,
+                        NumberType<NUM>,
+                    >::try_create(
+                        display_name, return_type, params, data_type
+                    )
+                    .with_need_drop(true);
+
+                    Ok(Arc::new(func))
+                }
+            })
+        }
+        DataType::Decimal(DecimalDataType::Decimal128(_)) => {
+            let return_type = data_type.clone();
+            let func = AggregateUnaryFunction::<
+                ModeState<DecimalType<i128>>,
+                DecimalType<i128>,
+                DecimalType<i128>,
+            >::try_create(display_name, return_type, params, data_type)
+            .with_need_drop(true);
+
+            Ok(Arc::new(func))
+        }
+        DataType::Decimal(DecimalDataType::Decimal256(_)) => {
+            let return_type = data_type.clone();
+            let func = AggregateUnaryFunction::<
+                ModeState<DecimalType<i256>>,
+                DecimalType<i256>,
+                DecimalType<i256>,
+            >::try_create(display_name, return_type, params, data_type)
+            .with_need_drop(true);
+
+            Ok(Arc::new(func))
+        }
+        _ => {
+            let return_type = data_type.clone();
+            let func = AggregateUnaryFunction::<ModeState<AnyType>, AnyType, AnyType>::try_create(
+                display_name,
+                return_type,
+                params,
+                data_type,
+            )
+            .with_need_drop(true);
+
+            Ok(Arc::new(func))
+        }
+    })
+}
+
+pub fn aggregate_mode_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_mode_function))
+}
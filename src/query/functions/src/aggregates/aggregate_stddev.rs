@@ -42,8 +42,12 @@ use crate::aggregates::aggregator_common::assert_unary_arguments;
 use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 
-const POP: u8 = 0;
-const SAMP: u8 = 1;
+// The lowest bit of `TYPE` tells population or sample,
+// the second bit tells whether to return the variance instead of the standard deviation.
+const STD_POP: u8 = 0;
+const STD_SAMP: u8 = 1;
+const VAR_POP: u8 = 2;
+const VAR_SAMP: u8 = 3;
 
 #[derive(Serialize, Deserialize)]
 struct AggregateStddevState {
@@ -96,7 +100,12 @@ impl<T, const TYPE: u8> AggregateFunction for AggregateStddevFunction<T, TYPE>
 where T: Number + AsPrimitive<f64>
 {
     fn name(&self) -> &str {
-        "AggregateStddevPopFunction"
+        match TYPE {
+            STD_POP => "AggregateStddevPopFunction",
+            STD_SAMP => "AggregateStddevSampFunction",
+            VAR_POP => "AggregateVarPopFunction",
+            _ => "AggregateVarSampFunction",
+        }
     }
 
     fn return_type(&self) -> Result<DataType> {
@@ -192,8 +201,12 @@ where T: Number + AsPrimitive<f64>
     fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
         let state = place.get::<AggregateStddevState>();
         let builder = NumberType::<F64>::try_downcast_builder(builder).unwrap();
-        let variance = state.variance / (state.count - TYPE as u64) as f64;
-        builder.push(variance.sqrt().into());
+        let variance = state.variance / (state.count - (TYPE & STD_SAMP) as u64) as f64;
+        if TYPE & VAR_POP != 0 {
+            builder.push(variance.into());
+        } else {
+            builder.push(variance.sqrt().into());
+        }
         Ok(())
     }
 }
@@ -230,18 +243,32 @@ pub fn try_create_aggregate_stddev_pop_function<const TYPE: u8>(
             AggregateStddevFunction::<NUM_TYPE, TYPE>::try_create(display_name, arguments)
         }
         _ => Err(ErrorCode::BadDataValueType(format!(
-            "AggregateStddevPopFunction does not support type '{:?}'",
-            arguments[0]
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
         ))),
     })
 }
 
 pub fn aggregate_stddev_pop_function_desc() -> AggregateFunctionDescription {
-    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_stddev_pop_function::<POP>))
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_stddev_pop_function::<STD_POP>,
+    ))
 }
 
 pub fn aggregate_stddev_samp_function_desc() -> AggregateFunctionDescription {
     AggregateFunctionDescription::creator(Box::new(
-        try_create_aggregate_stddev_pop_function::<SAMP>,
+        try_create_aggregate_stddev_pop_function::<STD_SAMP>,
+    ))
+}
+
+pub fn aggregate_var_pop_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_stddev_pop_function::<VAR_POP>,
+    ))
+}
+
+pub fn aggregate_var_samp_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_stddev_pop_function::<VAR_SAMP>,
     ))
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_function_desc;
+use super::aggregate_approx_top_k::aggregate_approx_top_k_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
@@ -25,6 +26,7 @@ use super::aggregate_bitmap::aggregate_bitmap_union_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_xor_count_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
+use super::aggregate_combinator_merge::AggregateMergeCombinator;
 use super::aggregate_combinator_state::AggregateStateCombinator;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
@@ -33,6 +35,8 @@ use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
 use super::aggregate_stddev::aggregate_stddev_pop_function_desc;
 use super::aggregate_stddev::aggregate_stddev_samp_function_desc;
+use super::aggregate_stddev::aggregate_var_pop_function_desc;
+use super::aggregate_stddev::aggregate_var_samp_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
@@ -40,14 +44,27 @@ use super::AggregateIfCombinator;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
+use crate::aggregates::aggregate_corr_function_desc;
+use crate::aggregates::aggregate_json_array_agg_function_desc;
+use crate::aggregates::aggregate_json_object_agg_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
 use crate::aggregates::aggregate_median_tdigest_weighted_function_desc;
+use crate::aggregates::aggregate_mode_function_desc;
 use crate::aggregates::aggregate_quantile_cont_function_desc;
 use crate::aggregates::aggregate_quantile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_weighted_function_desc;
+use crate::aggregates::aggregate_regr_avgx_function_desc;
+use crate::aggregates::aggregate_regr_avgy_function_desc;
+use crate::aggregates::aggregate_regr_count_function_desc;
+use crate::aggregates::aggregate_regr_intercept_function_desc;
+use crate::aggregates::aggregate_regr_r2_function_desc;
+use crate::aggregates::aggregate_regr_slope_function_desc;
+use crate::aggregates::aggregate_regr_sxx_function_desc;
+use crate::aggregates::aggregate_regr_sxy_function_desc;
+use crate::aggregates::aggregate_regr_syy_function_desc;
 use crate::aggregates::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
@@ -75,6 +92,21 @@ impl Aggregators {
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("stddev", aggregate_stddev_pop_function_desc());
         factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register("var_pop", aggregate_var_pop_function_desc());
+        factory.register("variance_pop", aggregate_var_pop_function_desc());
+        factory.register("var_samp", aggregate_var_samp_function_desc());
+        factory.register("variance_samp", aggregate_var_samp_function_desc());
+        factory.register("corr", aggregate_corr_function_desc());
+        factory.register("regr_slope", aggregate_regr_slope_function_desc());
+        factory.register("regr_intercept", aggregate_regr_intercept_function_desc());
+        factory.register("regr_r2", aggregate_regr_r2_function_desc());
+        factory.register("regr_count", aggregate_regr_count_function_desc());
+        factory.register("regr_avgx", aggregate_regr_avgx_function_desc());
+        factory.register("regr_avgy", aggregate_regr_avgy_function_desc());
+        factory.register("regr_sxx", aggregate_regr_sxx_function_desc());
+        factory.register("regr_syy", aggregate_regr_syy_function_desc());
+        factory.register("regr_sxy", aggregate_regr_sxy_function_desc());
+        factory.register("mode", aggregate_mode_function_desc());
         factory.register("quantile", aggregate_quantile_disc_function_desc());
         factory.register("quantile_disc", aggregate_quantile_disc_function_desc());
         factory.register("quantile_cont", aggregate_quantile_cont_function_desc());
@@ -97,9 +129,12 @@ impl Aggregators {
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("approx_top_k", aggregate_approx_top_k_function_desc());
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
+        factory.register("json_array_agg", aggregate_json_array_agg_function_desc());
+        factory.register("json_object_agg", aggregate_json_object_agg_function_desc());
         factory.register(
             "group_array_moving_avg",
             aggregate_array_moving_avg_function_desc(),
@@ -140,5 +175,6 @@ impl Aggregators {
         factory.register_combinator("_if", AggregateIfCombinator::combinator_desc());
         factory.register_combinator("_distinct", aggregate_combinator_distinct_desc());
         factory.register_combinator("_state", AggregateStateCombinator::combinator_desc());
+        factory.register_combinator("_merge", AggregateMergeCombinator::combinator_desc());
     }
 }
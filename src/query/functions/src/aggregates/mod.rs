@@ -18,6 +18,7 @@ mod aggregate_function_factory;
 
 mod adaptors;
 mod aggregate_approx_count_distinct;
+mod aggregate_approx_top_k;
 mod aggregate_arg_min_max;
 mod aggregate_array_agg;
 mod aggregate_array_moving;
@@ -25,16 +26,20 @@ mod aggregate_avg;
 mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
+mod aggregate_combinator_merge;
 mod aggregate_combinator_state;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
+mod aggregate_json_agg;
 mod aggregate_kurtosis;
 mod aggregate_min_max_any;
+mod aggregate_mode;
 mod aggregate_null_result;
 mod aggregate_quantile_cont;
 mod aggregate_quantile_disc;
 mod aggregate_quantile_tdigest;
 mod aggregate_quantile_tdigest_weighted;
+mod aggregate_regression;
 mod aggregate_retention;
 mod aggregate_scalar_state;
 mod aggregate_skewness;
@@ -47,22 +52,27 @@ mod aggregator;
 mod aggregator_common;
 
 pub use adaptors::*;
+pub use aggregate_approx_top_k::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
+pub use aggregate_combinator_merge::AggregateMergeCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
 pub use aggregate_function::*;
 pub use aggregate_function_factory::AggregateFunctionFactory;
+pub use aggregate_json_agg::*;
 pub use aggregate_kurtosis::*;
 pub use aggregate_min_max_any::*;
+pub use aggregate_mode::*;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_quantile_cont::*;
 pub use aggregate_quantile_disc::*;
 pub use aggregate_quantile_tdigest::*;
 pub use aggregate_quantile_tdigest_weighted::*;
+pub use aggregate_regression::*;
 pub use aggregate_retention::*;
 pub use aggregate_skewness::*;
 pub use aggregate_string_agg::*;
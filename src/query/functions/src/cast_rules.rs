@@ -210,6 +210,7 @@ pub const CAST_FROM_STRING_RULES: AutoCastRules = &[
     (DataType::String, DataType::Number(NumberDataType::UInt64)),
     (DataType::String, DataType::Number(NumberDataType::Float64)),
     (DataType::String, DataType::Number(NumberDataType::Float32)),
+    (DataType::String, DataType::Geometry),
 ];
 
 #[allow(non_snake_case)]
@@ -19,6 +19,7 @@
 #![feature(type_ascription)]
 #![feature(try_blocks)]
 #![feature(downcast_unchecked)]
+#![feature(hash_raw_entry)]
 
 use aggregates::AggregateFunctionFactory;
 use common_expression::FunctionRegistry;
@@ -0,0 +1,378 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::boolean::BooleanDomain;
+use common_expression::types::geometry::GeometryType;
+use common_expression::types::geometry::GEOMETRY_EMPTY;
+use common_expression::types::number::Float64Type;
+use common_expression::types::number::F64;
+use common_expression::types::BooleanType;
+use common_expression::types::NullableType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_io::geometry::geo_to_wkb;
+use common_io::geometry::parse_to_wkb;
+use common_io::geometry::wkb_to_geo;
+use common_io::geometry::wkb_to_geojson;
+use common_io::geometry::wkb_to_wkt;
+use geo::Area;
+use geo::Centroid;
+use geo::Contains;
+use geo::EuclideanDistance;
+use geo::EuclideanLength;
+use geo::Geometry;
+use geo::Intersects;
+use geo::LineString;
+use geo::Point;
+use geo::Polygon;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("st_geometryfromwkt", &[
+        "st_geomfromwkt",
+        "st_geometryfromtext",
+        "st_geomfromtext",
+    ]);
+    registry.register_aliases("st_geometryfromwkb", &["st_geomfromwkb"]);
+    registry.register_aliases("st_geometryfromgeojson", &["st_geomfromgeojson"]);
+    registry.register_aliases("st_aswkt", &["st_astext"]);
+    registry.register_aliases("st_aswkb", &["st_asbinary"]);
+
+    // All the parsing functions accept WKT, WKB (raw or hex encoded) and GeoJSON,
+    // the different names only exist for compatibility with other databases.
+    for name in [
+        "to_geometry",
+        "st_geometryfromwkt",
+        "st_geometryfromwkb",
+        "st_geometryfromgeojson",
+    ] {
+        registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+                match parse_to_wkb(s) {
+                    Ok(wkb) => builder.put_slice(&wkb),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            }),
+        );
+    }
+
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, NullableType<GeometryType>>(|s, output, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(output.len()) {
+                    output.push_null();
+                    return;
+                }
+            }
+            match parse_to_wkb(s) {
+                Ok(wkb) => output.push(&wkb),
+                Err(_) => output.push_null(),
+            }
+        }),
+    );
+
+    // Geometries are cast to string as WKT.
+    for name in ["to_string", "st_aswkt"] {
+        registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<GeometryType, StringType>(|wkb, builder, ctx| {
+                match wkb_to_wkt(wkb) {
+                    Ok(wkt) => builder.put_str(&wkt),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            }),
+        );
+    }
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|wkb, builder, ctx| {
+            match wkb_to_geojson(wkb) {
+                Ok(json) => builder.put_str(&json),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|wkb, builder, _| {
+            builder.put_slice(wkb);
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, GeometryType, _, _>(
+        "st_makepoint",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Float64Type, Float64Type, GeometryType>(
+            |x, y, builder, ctx| {
+                let point = Geometry::Point(Point::new(x.0, y.0));
+                match geo_to_wkb(&point) {
+                    Ok(wkb) => builder.put_slice(&wkb),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry.register_aliases("st_makepoint", &["st_point"]);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_x",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|wkb, builder, ctx| {
+            match wkb_to_geo(wkb) {
+                Ok(Geometry::Point(p)) => builder.push(F64::from(p.x())),
+                Ok(_) => {
+                    ctx.set_error(builder.len(), "st_x only supports POINT geometries");
+                    builder.push(F64::from(0.0));
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_y",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|wkb, builder, ctx| {
+            match wkb_to_geo(wkb) {
+                Ok(Geometry::Point(p)) => builder.push(F64::from(p.y())),
+                Ok(_) => {
+                    ctx.set_error(builder.len(), "st_y only supports POINT geometries");
+                    builder.push(F64::from(0.0));
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|wkb, builder, ctx| {
+            match wkb_to_geo(wkb) {
+                Ok(geo) => builder.push(F64::from(geo.unsigned_area())),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Float64Type, _, _>(
+        "st_length",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Float64Type>(|wkb, builder, ctx| {
+            match wkb_to_geo(wkb) {
+                Ok(geo) => {
+                    // Only the linear parts contribute to the length, the same as PostGIS.
+                    let length = flatten(geo)
+                        .iter()
+                        .map(|part| match part {
+                            Part::LineString(l) => l.euclidean_length(),
+                            _ => 0.0,
+                        })
+                        .sum::<f64>();
+                    builder.push(F64::from(length));
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_centroid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(|wkb, builder, ctx| {
+            let centroid = wkb_to_geo(wkb).and_then(|geo| match geo.centroid() {
+                Some(point) => geo_to_wkb(&Geometry::Point(point)),
+                None => Ok(GEOMETRY_EMPTY.to_vec()),
+            });
+            match centroid {
+                Ok(wkb) => builder.put_slice(&wkb),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, Float64Type, _, _>(
+        "st_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, Float64Type>(
+            |l, r, builder, ctx| match (wkb_to_geo(l), wkb_to_geo(r)) {
+                (Ok(l), Ok(r)) => builder.push(F64::from(euclidean_distance(l, r))),
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, l, r| {
+            if l.intersects(r) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(BooleanDomain {
+                    has_false: true,
+                    has_true: false,
+                })
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l, r, builder, ctx| match (wkb_to_geo(l), wkb_to_geo(r)) {
+                (Ok(l), Ok(r)) => builder.push(l.intersects(&r)),
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        // The rows may be contained even if the bounding boxes of the whole columns are not,
+        // but never without their boxes intersecting.
+        |_, l, r| {
+            if l.intersects(r) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(BooleanDomain {
+                    has_false: true,
+                    has_true: false,
+                })
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l, r, builder, ctx| match (wkb_to_geo(l), wkb_to_geo(r)) {
+                (Ok(l), Ok(r)) => builder.push(l.contains(&r)),
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_within",
+        // The rows may be contained even if the bounding boxes of the whole columns are not,
+        // but never without their boxes intersecting.
+        |_, l, r| {
+            if l.intersects(r) {
+                FunctionDomain::MayThrow
+            } else {
+                FunctionDomain::Domain(BooleanDomain {
+                    has_false: true,
+                    has_true: false,
+                })
+            }
+        },
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l, r, builder, ctx| match (wkb_to_geo(l), wkb_to_geo(r)) {
+                (Ok(l), Ok(r)) => builder.push(r.contains(&l)),
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+}
+
+/// The primitive parts of a geometry that `geo` knows how to measure against each other.
+enum Part {
+    Point(Point<f64>),
+    LineString(LineString<f64>),
+    Polygon(Polygon<f64>),
+}
+
+fn flatten(geo: Geometry<f64>) -> Vec<Part> {
+    let mut parts = Vec::new();
+    flatten_into(geo, &mut parts);
+    parts
+}
+
+fn flatten_into(geo: Geometry<f64>, parts: &mut Vec<Part>) {
+    match geo {
+        Geometry::Point(p) => parts.push(Part::Point(p)),
+        Geometry::Line(l) => parts.push(Part::LineString(LineString::from(vec![l.start, l.end]))),
+        Geometry::LineString(l) => parts.push(Part::LineString(l)),
+        Geometry::Polygon(p) => parts.push(Part::Polygon(p)),
+        Geometry::MultiPoint(mp) => parts.extend(mp.0.into_iter().map(Part::Point)),
+        Geometry::MultiLineString(ml) => parts.extend(ml.0.into_iter().map(Part::LineString)),
+        Geometry::MultiPolygon(mp) => parts.extend(mp.0.into_iter().map(Part::Polygon)),
+        Geometry::GeometryCollection(gc) => {
+            for geo in gc.0 {
+                flatten_into(geo, parts);
+            }
+        }
+        Geometry::Rect(r) => parts.push(Part::Polygon(r.to_polygon())),
+        Geometry::Triangle(t) => parts.push(Part::Polygon(t.to_polygon())),
+    }
+}
+
+/// The minimum cartesian distance between two geometries,
+/// or `0` if either of them is empty.
+fn euclidean_distance(l: Geometry<f64>, r: Geometry<f64>) -> f64 {
+    let l = flatten(l);
+    let r = flatten(r);
+    let mut distance: Option<f64> = None;
+    for a in l.iter() {
+        for b in r.iter() {
+            let d = match (a, b) {
+                (Part::Point(a), Part::Point(b)) => a.euclidean_distance(b),
+                (Part::Point(a), Part::LineString(b)) => a.euclidean_distance(b),
+                (Part::Point(a), Part::Polygon(b)) => a.euclidean_distance(b),
+                (Part::LineString(a), Part::Point(b)) => a.euclidean_distance(b),
+                (Part::LineString(a), Part::LineString(b)) => a.euclidean_distance(b),
+                (Part::LineString(a), Part::Polygon(b)) => a.euclidean_distance(b),
+                (Part::Polygon(a), Part::Point(b)) => a.euclidean_distance(b),
+                (Part::Polygon(a), Part::LineString(b)) => a.euclidean_distance(b),
+                (Part::Polygon(a), Part::Polygon(b)) => a.euclidean_distance(b),
+            };
+            distance = Some(distance.map_or(d, |min| min.min(d)));
+        }
+    }
+    distance.unwrap_or(0.0)
+}
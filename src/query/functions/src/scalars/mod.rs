@@ -25,6 +25,7 @@ mod datetime;
 mod decimal;
 mod geo;
 mod geo_h3;
+mod geometry;
 mod hash;
 mod map;
 mod math;
@@ -60,4 +61,5 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    geometry::register(registry);
 }
@@ -22,6 +22,7 @@ use chrono::Datelike;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use common_expression::date_helper::TzLUT;
 use common_expression::types::date::string_to_date;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::nullable::NullableDomain;
@@ -69,6 +70,7 @@ use jsonb::build_object;
 use jsonb::contains;
 use jsonb::exists_all_keys;
 use jsonb::exists_any_keys;
+use jsonb::from_slice;
 use jsonb::get_by_index;
 use jsonb::get_by_keypath;
 use jsonb::get_by_name;
@@ -79,6 +81,8 @@ use jsonb::is_array;
 use jsonb::is_object;
 use jsonb::jsonpath::parse_json_path;
 use jsonb::keypath::parse_key_paths;
+use jsonb::keypath::KeyPath;
+use jsonb::keypath::KeyPaths;
 use jsonb::object_keys;
 use jsonb::parse_value;
 use jsonb::path_exists;
@@ -91,6 +95,8 @@ use jsonb::to_str;
 use jsonb::to_string;
 use jsonb::to_u64;
 use jsonb::type_of;
+use jsonb::Object as JsonbObject;
+use jsonb::Value as JsonbValue;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("json_object_keys", &["object_keys"]);
@@ -1175,6 +1181,103 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
+    registry.register_function_factory("json_set", |_, args_type| {
+        if args_type.len() != 3
+            || !is_variant_or_null(&args_type[0])
+            || !is_string_or_null(&args_type[1])
+        {
+            return None;
+        }
+        Some(Arc::new(json_modify_function(
+            "json_set",
+            args_type,
+            |json, args, tz, buf| json_set_impl(json, args, tz, buf, true),
+        )))
+    });
+
+    registry.register_function_factory("json_insert", |_, args_type| {
+        if args_type.len() != 3
+            || !is_variant_or_null(&args_type[0])
+            || !is_string_or_null(&args_type[1])
+        {
+            return None;
+        }
+        Some(Arc::new(json_modify_function(
+            "json_insert",
+            args_type,
+            |json, args, tz, buf| json_set_impl(json, args, tz, buf, false),
+        )))
+    });
+
+    registry.register_function_factory("json_remove", |_, args_type| {
+        if args_type.len() < 2
+            || !is_variant_or_null(&args_type[0])
+            || !args_type[1..].iter().all(is_string_or_null)
+        {
+            return None;
+        }
+        Some(Arc::new(json_modify_function(
+            "json_remove",
+            args_type,
+            json_remove_impl,
+        )))
+    });
+
+    registry.register_function_factory("object_insert", |_, args_type| {
+        if !(args_type.len() == 3 || args_type.len() == 4)
+            || !is_variant_or_null(&args_type[0])
+            || !is_string_or_null(&args_type[1])
+            || !args_type.get(3).map_or(true, |ty| {
+                matches!(ty.remove_nullable(), DataType::Boolean | DataType::Null)
+            })
+        {
+            return None;
+        }
+        Some(Arc::new(json_modify_function(
+            "object_insert",
+            args_type,
+            object_insert_impl,
+        )))
+    });
+
+    registry.register_function_factory("object_delete", |_, args_type| {
+        if args_type.len() < 2
+            || !is_variant_or_null(&args_type[0])
+            || !args_type[1..].iter().all(is_string_or_null)
+        {
+            return None;
+        }
+        Some(Arc::new(json_modify_function(
+            "object_delete",
+            args_type,
+            object_delete_impl,
+        )))
+    });
+
+    registry.register_passthrough_nullable_2_arg(
+        "json_merge_patch",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<VariantType, VariantType, VariantType>(
+            |target, patch, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.commit_row();
+                        return;
+                    }
+                }
+                match (from_slice(target), from_slice(patch)) {
+                    (Ok(target), Ok(patch)) => {
+                        merge_patch(target, patch).write_to_vec(&mut output.data);
+                    }
+                    (Err(err), _) | (_, Err(err)) => {
+                        ctx.set_error(output.len(), err.to_string());
+                    }
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
     registry.register_passthrough_nullable_2_arg(
         "json_contains_in_left",
         |_, _, _| FunctionDomain::Full,
@@ -1357,6 +1460,296 @@ fn json_object_impl_fn(
     }
 }
 
+fn is_variant_or_null(ty: &DataType) -> bool {
+    matches!(ty.remove_nullable(), DataType::Variant | DataType::Null)
+}
+
+fn is_string_or_null(ty: &DataType) -> bool {
+    matches!(ty.remove_nullable(), DataType::String | DataType::Null)
+}
+
+/// Modifies the json value in the first argument with the rest arguments, writes the
+/// result into the buffer and returns `Ok(true)`, or returns `Ok(false)` for NULL.
+type JsonModifyFn =
+    fn(&[u8], &[ScalarRef], TzLUT, &mut Vec<u8>) -> std::result::Result<bool, String>;
+
+fn json_modify_function(name: &str, args_type: &[DataType], modify: JsonModifyFn) -> Function {
+    Function {
+        signature: FunctionSignature {
+            name: name.to_string(),
+            args_type: args_type.to_vec(),
+            return_type: DataType::Nullable(Box::new(DataType::Variant)),
+        },
+        eval: FunctionEval::Scalar {
+            calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+            eval: Box::new(move |args, ctx| json_modify_fn(args, ctx, modify)),
+        },
+    }
+}
+
+fn json_modify_fn(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    modify: JsonModifyFn,
+) -> Value<AnyType> {
+    let (columns, len) = prepare_args_columns(args, ctx);
+    let cap = len.unwrap_or(1);
+    let mut builder = StringColumnBuilder::with_capacity(cap, cap * 50);
+    let mut validity = MutableBitmap::with_capacity(cap);
+    let mut row = Vec::with_capacity(columns.len());
+
+    for idx in 0..cap {
+        row.clear();
+        for column in &columns {
+            row.push(unsafe { column.index_unchecked(idx) });
+        }
+        let is_valid = match row[0] {
+            ScalarRef::Variant(json) => {
+                match modify(json, &row[1..], ctx.func_ctx.tz, &mut builder.data) {
+                    Ok(is_valid) => is_valid,
+                    Err(err) => {
+                        ctx.set_error(builder.len(), err);
+                        false
+                    }
+                }
+            }
+            _ => false,
+        };
+        validity.push(is_valid);
+        builder.commit_row();
+    }
+
+    let validity: Bitmap = validity.into();
+    match len {
+        Some(_) => Value::Column(Column::Variant(builder.build())).wrap_nullable(Some(validity)),
+        None => {
+            if !validity.get_bit(0) {
+                Value::Scalar(Scalar::Null)
+            } else {
+                Value::Scalar(Scalar::Variant(builder.build_scalar()))
+            }
+        }
+    }
+}
+
+fn parse_key_paths_arg<'a>(
+    arg: &ScalarRef<'a>,
+) -> std::result::Result<Option<KeyPaths<'a>>, String> {
+    match arg {
+        ScalarRef::String(path) => parse_key_paths(path).map(Some).map_err(|e| e.to_string()),
+        ScalarRef::Null => Ok(None),
+        _ => Err("The path must be a string value".to_string()),
+    }
+}
+
+fn normalize_array_index(index: i32, len: usize) -> Option<usize> {
+    if index < 0 {
+        let index = len as i64 + index as i64;
+        (index >= 0).then_some(index as usize)
+    } else {
+        Some(index as usize)
+    }
+}
+
+// Returns the value pointed to by the key paths, or `None` if it does not exist.
+fn get_by_keypath_mut<'a, 'b>(
+    value: &'b mut JsonbValue<'a>,
+    paths: &[KeyPath],
+) -> Option<&'b mut JsonbValue<'a>> {
+    let mut current = value;
+    for path in paths {
+        current = match (current, path) {
+            (JsonbValue::Object(obj), KeyPath::Name(name) | KeyPath::QuotedName(name)) => {
+                obj.get_mut(name.as_ref())?
+            }
+            (JsonbValue::Array(arr), KeyPath::Index(index)) => {
+                let len = arr.len();
+                arr.get_mut(normalize_array_index(*index, len)?)?
+            }
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+// `json_set(json, path, value)` replaces the existing value or adds a new one,
+// `json_insert(json, path, value)` only adds a new value. In both cases the parent
+// of the path must exist, a new array element is always appended to the end.
+fn json_set_impl(
+    json: &[u8],
+    args: &[ScalarRef],
+    tz: TzLUT,
+    buf: &mut Vec<u8>,
+    replace: bool,
+) -> std::result::Result<bool, String> {
+    let Some(key_paths) = parse_key_paths_arg(&args[0])? else {
+        return Ok(false);
+    };
+    let mut new_buf = Vec::new();
+    cast_scalar_to_variant(args[1].clone(), tz, &mut new_buf);
+    let new_value = from_slice(&new_buf).map_err(|e| e.to_string())?;
+    let mut value = from_slice(json).map_err(|e| e.to_string())?;
+
+    match key_paths.paths.split_last() {
+        None => {
+            if replace {
+                value = new_value;
+            }
+        }
+        Some((last, parent_paths)) => {
+            if let Some(parent) = get_by_keypath_mut(&mut value, parent_paths) {
+                match (parent, last) {
+                    (JsonbValue::Object(obj), KeyPath::Name(name) | KeyPath::QuotedName(name)) => {
+                        if replace || !obj.contains_key(name.as_ref()) {
+                            obj.insert(name.to_string(), new_value);
+                        }
+                    }
+                    (JsonbValue::Array(arr), KeyPath::Index(index)) => {
+                        match normalize_array_index(*index, arr.len()) {
+                            Some(index) if index < arr.len() => {
+                                if replace {
+                                    arr[index] = new_value;
+                                }
+                            }
+                            Some(_) => arr.push(new_value),
+                            None => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    value.write_to_vec(buf);
+    Ok(true)
+}
+
+// `json_remove(json, path, ...)` removes the values pointed to by the paths if they exist.
+fn json_remove_impl(
+    json: &[u8],
+    args: &[ScalarRef],
+    _tz: TzLUT,
+    buf: &mut Vec<u8>,
+) -> std::result::Result<bool, String> {
+    let mut value = from_slice(json).map_err(|e| e.to_string())?;
+    for arg in args {
+        let Some(key_paths) = parse_key_paths_arg(arg)? else {
+            return Ok(false);
+        };
+        let Some((last, parent_paths)) = key_paths.paths.split_last() else {
+            return Err("The path of json_remove can not be empty".to_string());
+        };
+        if let Some(parent) = get_by_keypath_mut(&mut value, parent_paths) {
+            match (parent, last) {
+                (JsonbValue::Object(obj), KeyPath::Name(name) | KeyPath::QuotedName(name)) => {
+                    obj.remove(name.as_ref());
+                }
+                (JsonbValue::Array(arr), KeyPath::Index(index)) => {
+                    if let Some(index) = normalize_array_index(*index, arr.len()) {
+                        if index < arr.len() {
+                            arr.remove(index);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    value.write_to_vec(buf);
+    Ok(true)
+}
+
+// `object_insert(object, key, value [, update_flag])` inserts a new key into the object.
+// A NULL value is not inserted, and an existing key is only updated if `update_flag` is true.
+fn object_insert_impl(
+    json: &[u8],
+    args: &[ScalarRef],
+    tz: TzLUT,
+    buf: &mut Vec<u8>,
+) -> std::result::Result<bool, String> {
+    let key = match &args[0] {
+        ScalarRef::String(key) => String::from_utf8_lossy(key).into_owned(),
+        ScalarRef::Null => return Ok(false),
+        _ => return Err("The key of object_insert must be a string value".to_string()),
+    };
+    let update = match args.get(2) {
+        Some(ScalarRef::Boolean(update)) => *update,
+        Some(ScalarRef::Null) | None => false,
+        Some(_) => {
+            return Err("The update_flag of object_insert must be a boolean value".to_string());
+        }
+    };
+    let mut new_buf = Vec::new();
+    cast_scalar_to_variant(args[1].clone(), tz, &mut new_buf);
+    let new_value = from_slice(&new_buf).map_err(|e| e.to_string())?;
+    let mut value = from_slice(json).map_err(|e| e.to_string())?;
+
+    match &mut value {
+        // A NULL value leaves the object unchanged, even if the key exists.
+        JsonbValue::Object(_) if args[1] == ScalarRef::Null => {}
+        JsonbValue::Object(obj) => {
+            if obj.contains_key(&key) && !update {
+                return Err(format!(
+                    "Duplicate key '{}' in object_insert, set the update_flag to true to update it",
+                    key
+                ));
+            }
+            obj.insert(key, new_value);
+        }
+        _ => return Err("The first argument of object_insert must be an object".to_string()),
+    }
+    value.write_to_vec(buf);
+    Ok(true)
+}
+
+// `object_delete(object, key, ...)` removes the keys from the object.
+fn object_delete_impl(
+    json: &[u8],
+    args: &[ScalarRef],
+    _tz: TzLUT,
+    buf: &mut Vec<u8>,
+) -> std::result::Result<bool, String> {
+    let mut value = from_slice(json).map_err(|e| e.to_string())?;
+    match &mut value {
+        JsonbValue::Object(obj) => {
+            for arg in args {
+                match arg {
+                    ScalarRef::String(key) => {
+                        obj.remove(String::from_utf8_lossy(key).as_ref());
+                    }
+                    ScalarRef::Null => {}
+                    _ => return Err("The key of object_delete must be a string value".to_string()),
+                }
+            }
+        }
+        _ => return Err("The first argument of object_delete must be an object".to_string()),
+    }
+    value.write_to_vec(buf);
+    Ok(true)
+}
+
+// Applies the patch to the target as described in RFC 7396.
+fn merge_patch<'a>(target: JsonbValue<'a>, patch: JsonbValue<'a>) -> JsonbValue<'a> {
+    match patch {
+        JsonbValue::Object(patch) => {
+            let mut target = match target {
+                JsonbValue::Object(target) => target,
+                _ => JsonbObject::new(),
+            };
+            for (key, value) in patch {
+                if let JsonbValue::Null = value {
+                    target.remove(&key);
+                } else {
+                    let current = target.remove(&key).unwrap_or(JsonbValue::Null);
+                    target.insert(key, merge_patch(current, value));
+                }
+            }
+            JsonbValue::Object(target)
+        }
+        patch => patch,
+    }
+}
+
 fn prepare_args_columns(
     args: &[ValueRef<AnyType>],
     ctx: &EvalContext,
@@ -18,6 +18,7 @@ mod cluster;
 mod connection;
 mod file_format;
 mod network_policy;
+mod password_policy;
 mod quota;
 mod role;
 mod serde;
@@ -25,6 +26,7 @@ mod setting;
 mod stage;
 mod udf;
 mod user;
+mod workload_group;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
@@ -34,6 +36,8 @@ pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyApi;
 pub use network_policy::NetworkPolicyMgr;
+pub use password_policy::PasswordPolicyApi;
+pub use password_policy::PasswordPolicyMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
@@ -48,3 +52,5 @@ pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
+pub use workload_group::WorkloadGroupApi;
+pub use workload_group::WorkloadGroupMgr;
@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::workload_group::workload_group_api::WorkloadGroupApi;
+
+static WORKLOAD_GROUP_API_KEY_PREFIX: &str = "__fd_workload_groups";
+
+pub struct WorkloadGroupMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    workload_group_prefix: String,
+}
+
+impl WorkloadGroupMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create workload group)",
+            ));
+        }
+
+        Ok(WorkloadGroupMgr {
+            kv_api,
+            workload_group_prefix: format!("{}/{}", WORKLOAD_GROUP_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_workload_group_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.workload_group_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl WorkloadGroupApi for WorkloadGroupMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_workload_group(&self, workload_group: WorkloadGroup) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_workload_group_key(workload_group.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &workload_group,
+            ErrorCode::IllegalWorkloadGroup,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res_seq = upsert_kv.await?.added_seq_or_else(|v| {
+            ErrorCode::WorkloadGroupAlreadyExists(format!(
+                "WorkloadGroup already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res_seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_workload_group(
+        &self,
+        workload_group: WorkloadGroup,
+        match_seq: MatchSeq,
+    ) -> Result<u64> {
+        let key = self.make_workload_group_key(workload_group.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &workload_group,
+            ErrorCode::IllegalWorkloadGroup,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown WorkloadGroup, or seq not match {}",
+                workload_group.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_workload_group(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_workload_group_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown WorkloadGroup {}",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_workload_group(&self, name: &str, seq: MatchSeq) -> Result<SeqV<WorkloadGroup>> {
+        let key = self.make_workload_group_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownWorkloadGroup(format!("Unknown WorkloadGroup {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalWorkloadGroup, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownWorkloadGroup(format!(
+                "Unknown WorkloadGroup {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_workload_groups(&self) -> Result<Vec<WorkloadGroup>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.workload_group_prefix)
+            .await?;
+
+        let mut workload_groups = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let workload_group =
+                deserialize_struct(&value.data, ErrorCode::IllegalWorkloadGroup, || "")?;
+            workload_groups.push(workload_group);
+        }
+        Ok(workload_groups)
+    }
+}
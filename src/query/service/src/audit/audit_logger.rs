@@ -0,0 +1,410 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use chrono::Utc;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::init_stage_operator;
+use common_storages_system::AuditLogElement;
+use common_storages_system::AuditLogQueue;
+use common_users::UserApiProvider;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+
+/// How often the buffered audit events are written to the audit stage.
+const STAGE_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The most audit events kept in memory while the audit stage can not be written.
+const MAX_STAGE_BUFFERED_EVENTS: usize = 100_000;
+
+/// The file in the audit stage keeping the hash of the last event written by a node, the
+/// chain of the node goes on from it after a restart.
+const STAGE_LAST_HASH_FILE: &str = "last_hash";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditEventType {
+    Login,
+    Ddl,
+    Privilege,
+    AccessDenied,
+    /// The events dropped while the audit stage could not be written, see [StageEvent::Gap].
+    Gap,
+}
+
+impl Display for AuditEventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditEventType::Login => write!(f, "login"),
+            AuditEventType::Ddl => write!(f, "ddl"),
+            AuditEventType::Privilege => write!(f, "privilege"),
+            AuditEventType::AccessDenied => write!(f, "access_denied"),
+            AuditEventType::Gap => write!(f, "gap"),
+        }
+    }
+}
+
+/// The audited statement of a query, such as a DDL or a GRANT.
+#[derive(Clone, Debug)]
+pub struct AuditAction {
+    pub event_type: AuditEventType,
+    // the variant of the plan, such as `CreateTable`
+    pub action: String,
+    pub object: String,
+}
+
+/// Writes the audit events of logins, DDL, privilege changes and access denials to
+/// `system.audit_log`, the audit log file and the audit stage, see `log.audit`.
+///
+/// The events of a node are chained by the hash of the previous event, so a removed or
+/// modified event can be detected by verifying the chain.
+pub struct AuditLogger {
+    on: bool,
+    tenant: String,
+    cluster_id: String,
+    node_id: String,
+    stage: String,
+    // the hash of the last event, the events are appended in the order of the chain.
+    last_hash: Mutex<String>,
+    // the events not written to the audit stage yet.
+    stage_buffer: Mutex<VecDeque<StageEvent>>,
+}
+
+/// An event buffered for the audit stage.
+enum StageEvent {
+    Event {
+        hash: String,
+        prev_hash: String,
+        event_str: String,
+    },
+    /// The events dropped from the buffer while the audit stage could not be written.
+    ///
+    /// It takes the place of the dropped events in the chain: `prev_hash` is the one of the
+    /// first dropped event and `hash` the one of the last, so the chain of the stage can
+    /// still be verified on both sides of the gap.
+    Gap {
+        hash: String,
+        prev_hash: String,
+        first_hash: String,
+        count: usize,
+    },
+}
+
+impl StageEvent {
+    fn hash(&self) -> &str {
+        match self {
+            StageEvent::Event { hash, .. } => hash,
+            StageEvent::Gap { hash, .. } => hash,
+        }
+    }
+}
+
+impl AuditLogger {
+    /// Must be called after `UserApiProvider::init`, the chain goes on from the last hash
+    /// written to the audit stage.
+    #[async_backtrace::framed]
+    pub async fn init(conf: &InnerConfig) -> Result<()> {
+        let logger = Arc::new(AuditLogger {
+            on: conf.log.audit.on,
+            tenant: conf.query.tenant_id.clone(),
+            cluster_id: conf.query.cluster_id.clone(),
+            node_id: conf.query.node_id.clone(),
+            stage: conf.log.audit.stage.clone(),
+            last_hash: Mutex::new(String::new()),
+            stage_buffer: Mutex::new(VecDeque::new()),
+        });
+
+        if logger.on && !logger.stage.is_empty() {
+            match logger.read_stage_last_hash().await {
+                Ok(last_hash) => *logger.last_hash.lock() = last_hash,
+                Err(e) => warn!(
+                    "failed to read the last audit hash from stage {}, the chain restarts: {}",
+                    logger.stage, e
+                ),
+            }
+        }
+        GlobalInstance::set(logger.clone());
+
+        if logger.on && !logger.stage.is_empty() {
+            GlobalIORuntime::instance().spawn("audit-log-flusher", async move {
+                loop {
+                    sleep(STAGE_FLUSH_INTERVAL).await;
+                    if let Err(e) = logger.flush_to_stage().await {
+                        warn!("failed to write audit log to stage {}: {}", logger.stage, e);
+                    }
+                }
+            });
+        }
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<AuditLogger> {
+        GlobalInstance::get()
+    }
+
+    /// Logs a login of `user` by the auth method `action`, `error` is the reason it failed.
+    pub fn log_login(
+        &self,
+        tenant: &str,
+        user: &str,
+        client_address: Option<&str>,
+        action: &str,
+        error: Option<&ErrorCode>,
+    ) {
+        self.log(AuditLogElement {
+            event_type: AuditEventType::Login.to_string(),
+            tenant_id: tenant.to_string(),
+            user: user.to_string(),
+            client_address: client_address.unwrap_or_default().to_string(),
+            action: action.to_string(),
+            result: if error.is_some() { "denied" } else { "allowed" }.to_string(),
+            ..self.new_element(error)
+        });
+    }
+
+    /// Logs the audited statement of a query, which is denied if it failed the access check
+    /// and failed if `error` is set otherwise.
+    pub fn log_query(&self, ctx: &QueryContext, action: AuditAction, error: Option<&ErrorCode>) {
+        if !self.on {
+            return;
+        }
+        let result = if action.event_type == AuditEventType::AccessDenied {
+            "denied"
+        } else if error.is_some() {
+            "failed"
+        } else {
+            "allowed"
+        };
+        let user = ctx
+            .get_current_user()
+            .map(|user| user.identity().to_string())
+            .unwrap_or_default();
+        self.log(AuditLogElement {
+            event_type: action.event_type.to_string(),
+            tenant_id: ctx.get_tenant(),
+            user,
+            client_address: ctx
+                .get_client_address()
+                .map(|addr| addr.to_string())
+                .unwrap_or_default(),
+            query_id: ctx.get_id(),
+            action: action.action,
+            object: action.object,
+            query_text: ctx.get_query_str(),
+            result: result.to_string(),
+            ..self.new_element(error)
+        });
+    }
+
+    fn new_element(&self, error: Option<&ErrorCode>) -> AuditLogElement {
+        AuditLogElement {
+            event_time: convert_query_log_timestamp(SystemTime::now()),
+            event_type: String::new(),
+            tenant_id: self.tenant.clone(),
+            cluster_id: self.cluster_id.clone(),
+            node_id: self.node_id.clone(),
+            user: String::new(),
+            client_address: String::new(),
+            query_id: String::new(),
+            action: String::new(),
+            object: String::new(),
+            query_text: String::new(),
+            result: String::new(),
+            error_code: error.map_or(0, |e| e.code().into()),
+            error_message: error.map(|e| e.message()).unwrap_or_default(),
+            prev_hash: String::new(),
+            hash: String::new(),
+        }
+    }
+
+    fn log(&self, mut event: AuditLogElement) {
+        if !self.on {
+            return;
+        }
+
+        // the hash covers the event with the previous hash and an empty `hash`.
+        let mut last_hash = self.last_hash.lock();
+        event.prev_hash = last_hash.clone();
+        let event_str = match serde_json::to_string(&event) {
+            Ok(event_str) => event_str,
+            Err(e) => {
+                warn!("failed to serialize audit event: {}", e);
+                return;
+            }
+        };
+        event.hash = format!("{:x}", Sha256::digest(event_str.as_bytes()));
+        *last_hash = event.hash.clone();
+
+        let event_str = serde_json::to_string(&event).unwrap_or(event_str);
+        info!(target: "audit", "{}", event_str);
+        if !self.stage.is_empty() {
+            let mut stage_buffer = self.stage_buffer.lock();
+            if stage_buffer.len() >= MAX_STAGE_BUFFERED_EVENTS {
+                drop_oldest_events(&mut stage_buffer, 1);
+            }
+            stage_buffer.push_back(StageEvent::Event {
+                hash: event.hash.clone(),
+                prev_hash: event.prev_hash.clone(),
+                event_str,
+            });
+        }
+        if let Ok(queue) = AuditLogQueue::instance() {
+            let _ = queue.append_data(event);
+        }
+    }
+
+    async fn read_stage_last_hash(&self) -> Result<String> {
+        let stage = UserApiProvider::instance()
+            .get_stage(&self.tenant, &self.stage)
+            .await?;
+        let operator = init_stage_operator(&stage)?;
+        let path = format!("audit/{}/{}", self.node_id, STAGE_LAST_HASH_FILE);
+        match operator.read(&path).await {
+            Ok(data) => Ok(String::from_utf8_lossy(&data).trim().to_string()),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok(String::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn flush_to_stage(&self) -> Result<()> {
+        let events = std::mem::take(&mut *self.stage_buffer.lock());
+        let Some(last_hash) = events.back().map(|event| event.hash().to_string()) else {
+            return Ok(());
+        };
+
+        let path = format!(
+            "audit/{}/{}.ndjson",
+            self.node_id,
+            Utc::now().format("%Y%m%d%H%M%S%6f")
+        );
+        let mut data = String::new();
+        for event in &events {
+            match event {
+                StageEvent::Event { event_str, .. } => data.push_str(event_str),
+                StageEvent::Gap {
+                    hash,
+                    prev_hash,
+                    first_hash,
+                    count,
+                } => data.push_str(&self.gap_event_str(hash, prev_hash, first_hash, *count)),
+            }
+            data.push('\n');
+        }
+        let res = async {
+            let stage = UserApiProvider::instance()
+                .get_stage(&self.tenant, &self.stage)
+                .await?;
+            let operator = init_stage_operator(&stage)?;
+            operator.write(&path, data).await?;
+            let last_hash_path = format!("audit/{}/{}", self.node_id, STAGE_LAST_HASH_FILE);
+            operator.write(&last_hash_path, last_hash).await?;
+            Ok::<_, ErrorCode>(())
+        }
+        .await;
+
+        if res.is_err() {
+            // keep the events for the next flush, before the events logged since then.
+            let mut stage_buffer = self.stage_buffer.lock();
+            let newer = std::mem::replace(&mut *stage_buffer, events);
+            stage_buffer.extend(newer);
+            let excess = stage_buffer.len().saturating_sub(MAX_STAGE_BUFFERED_EVENTS);
+            drop_oldest_events(&mut stage_buffer, excess);
+        }
+        res
+    }
+
+    /// Serializes the gap event written to the audit stage in place of the dropped events,
+    /// its `hash` is the one of the last dropped event instead of the hash of itself.
+    fn gap_event_str(&self, hash: &str, prev_hash: &str, first_hash: &str, count: usize) -> String {
+        let event = AuditLogElement {
+            event_type: AuditEventType::Gap.to_string(),
+            action: "drop".to_string(),
+            object: first_hash.to_string(),
+            result: "dropped".to_string(),
+            error_message: format!(
+                "{} audit events from {} were dropped, the audit stage could not be written",
+                count, first_hash
+            ),
+            prev_hash: prev_hash.to_string(),
+            hash: hash.to_string(),
+            ..self.new_element(None)
+        };
+        serde_json::to_string(&event).unwrap_or_default()
+    }
+}
+
+/// Drops the oldest `count` events of `stage_buffer`, they are replaced by a gap event,
+/// which also takes the gaps before and between them.
+fn drop_oldest_events(stage_buffer: &mut VecDeque<StageEvent>, count: usize) {
+    let mut gap: Option<(String, String, String, usize)> = None;
+    let mut dropped = 0;
+    while dropped < count {
+        let (hash, prev_hash, first_hash, n) = match stage_buffer.pop_front() {
+            None => break,
+            Some(StageEvent::Event {
+                hash, prev_hash, ..
+            }) => {
+                dropped += 1;
+                (hash.clone(), prev_hash, hash, 1)
+            }
+            Some(StageEvent::Gap {
+                hash,
+                prev_hash,
+                first_hash,
+                count,
+            }) => (hash, prev_hash, first_hash, count),
+        };
+        gap = Some(match gap {
+            None => (hash, prev_hash, first_hash, n),
+            Some((_, gap_prev_hash, gap_first_hash, gap_count)) => {
+                (hash, gap_prev_hash, gap_first_hash, gap_count + n)
+            }
+        });
+    }
+
+    let Some((hash, prev_hash, first_hash, count)) = gap else {
+        return;
+    };
+    // warn once the gap starts or grows by a full buffer, not for each dropped event.
+    if count == dropped || count % MAX_STAGE_BUFFERED_EVENTS < dropped {
+        warn!(
+            "the audit stage can not be written, {} audit events from {} are dropped",
+            count, first_hash
+        );
+    }
+    stage_buffer.push_front(StageEvent::Gap {
+        hash,
+        prev_hash,
+        first_hash,
+        count,
+    });
+}
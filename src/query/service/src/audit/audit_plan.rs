@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use common_catalog::query_kind::QueryKind;
+use common_sql::plans::Plan;
+
+use crate::audit::AuditEventType;
+
+/// Returns the audit event type and the object of a plan, None if the plan is not audited.
+///
+/// Queries, DML and the statements which only read or change the session are not audited,
+/// the other statements change the schema or the account and are audited as DDL.
+pub fn audit_plan(plan: &Plan) -> Option<(AuditEventType, String)> {
+    let event_type = match plan {
+        Plan::GrantPriv(_)
+        | Plan::RevokePriv(_)
+        | Plan::GrantRole(_)
+        | Plan::RevokeRole(_)
+        | Plan::GrantShareObject(_)
+        | Plan::RevokeShareObject(_) => AuditEventType::Privilege,
+        Plan::UseDatabase(_)
+        | Plan::ExistsTable(_)
+        | Plan::SetRole(_)
+        | Plan::SetSecondaryRoles(_)
+        | Plan::Presign(_)
+        | Plan::SetVariable(_)
+        | Plan::UnSetVariable(_)
+        | Plan::Kill(_)
+        | Plan::ExecuteTask(_)
+        | Plan::CopyIntoLocation(_)
+        | Plan::RemoveStage(_)
+        | Plan::AnalyzeTable(_)
+        | Plan::ReclusterTable(_)
+        | Plan::RefreshIndex(_)
+        | Plan::RefreshVirtualColumn(_) => return None,
+        plan if plan.has_result_set() || plan.kind() != QueryKind::Other => return None,
+        _ => AuditEventType::Ddl,
+    };
+    Some((event_type, audit_object(plan)))
+}
+
+/// The name of the statement of a plan in the audit log, such as `CreateTable`.
+pub fn audit_action(plan: &Plan) -> String {
+    // the name of the variant is the debug format of the plan up to its fields.
+    struct VariantName(String);
+    impl Write for VariantName {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            match s.find(|c: char| !c.is_ascii_alphanumeric()) {
+                Some(end) => {
+                    self.0.push_str(&s[..end]);
+                    Err(std::fmt::Error)
+                }
+                None => {
+                    self.0.push_str(s);
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    let mut name = VariantName(String::new());
+    let _ = write!(name, "{:?}", plan);
+    name.0
+}
+
+fn audit_object(plan: &Plan) -> String {
+    match plan {
+        Plan::CreateDatabase(p) => format!("{}.{}", p.catalog, p.database),
+        Plan::DropDatabase(p) => format!("{}.{}", p.catalog, p.database),
+        Plan::UndropDatabase(p) => format!("{}.{}", p.catalog, p.database),
+        Plan::CreateTable(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::DropTable(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::UndropTable(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::RenameTable(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::TruncateTable(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::AddTableColumn(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::DropTableColumn(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::ModifyTableColumn(p) => format!("{}.{}.{}", p.catalog, p.database, p.table),
+        Plan::CreateView(p) => format!("{}.{}.{}", p.catalog, p.database, p.view_name),
+        Plan::AlterView(p) => format!("{}.{}.{}", p.catalog, p.database, p.view_name),
+        Plan::DropView(p) => format!("{}.{}.{}", p.catalog, p.database, p.view_name),
+        Plan::CreateUser(p) => p.user.to_string(),
+        Plan::AlterUser(p) => p.user.to_string(),
+        Plan::DropUser(p) => p.user.to_string(),
+        Plan::CreateRole(p) => p.role_name.clone(),
+        Plan::DropRole(p) => p.role_name.clone(),
+        // the principals are displayed with a leading space.
+        Plan::GrantPriv(p) => format!("{} TO{}", p.on, p.principal),
+        Plan::RevokePriv(p) => format!("{} FROM{}", p.on, p.principal),
+        Plan::GrantRole(p) => format!("ROLE {} TO{}", p.role, p.principal),
+        Plan::RevokeRole(p) => format!("ROLE {} FROM{}", p.role, p.principal),
+        Plan::CreateStage(p) => p.stage_info.stage_name.clone(),
+        Plan::DropStage(p) => p.name.clone(),
+        Plan::CreateNetworkPolicy(p) => p.name.clone(),
+        Plan::AlterNetworkPolicy(p) => p.name.clone(),
+        Plan::DropNetworkPolicy(p) => p.name.clone(),
+        Plan::CreatePasswordPolicy(p) => p.name.clone(),
+        Plan::AlterPasswordPolicy(p) => p.name.clone(),
+        Plan::DropPasswordPolicy(p) => p.name.clone(),
+        Plan::CreateWorkloadGroup(p) => p.name.clone(),
+        Plan::AlterWorkloadGroup(p) => p.name.clone(),
+        Plan::DropWorkloadGroup(p) => p.name.clone(),
+        _ => String::new(),
+    }
+}
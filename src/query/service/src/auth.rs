@@ -21,13 +21,17 @@ use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
+use common_users::EnsureUser;
 use common_users::JwtAuthenticator;
+use common_users::LdapAuthenticator;
 use common_users::UserApiProvider;
 
+use crate::audit::AuditLogger;
 use crate::sessions::Session;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    ldap_auth: Option<LdapAuthenticator>,
 }
 
 pub enum Credential {
@@ -44,7 +48,7 @@ pub enum Credential {
 
 impl AuthMgr {
     pub fn init(cfg: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(AuthMgr::create(cfg));
+        GlobalInstance::set(AuthMgr::create(cfg)?);
         Ok(())
     }
 
@@ -52,17 +56,49 @@ impl AuthMgr {
         GlobalInstance::get()
     }
 
-    fn create(cfg: &InnerConfig) -> Arc<AuthMgr> {
-        Arc::new(AuthMgr {
+    fn create(cfg: &InnerConfig) -> Result<Arc<AuthMgr>> {
+        Ok(Arc::new(AuthMgr {
             jwt_auth: JwtAuthenticator::create(
                 cfg.query.jwt_key_file.clone(),
                 cfg.query.jwt_key_files.clone(),
-            ),
-        })
+                &cfg.query.jwt,
+            )?,
+            ldap_auth: LdapAuthenticator::create(&cfg.query.ldap)?,
+        }))
     }
 
     #[async_backtrace::framed]
     pub async fn auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
+        let res = self.do_auth(session.clone(), credential).await;
+
+        // the user of a failed jwt login is unknown, as the token may not be parsed.
+        let (user, client_ip, method) = match credential {
+            Credential::Jwt { client_ip, .. } => {
+                let user = session
+                    .get_current_user()
+                    .map(|user| user.identity().to_string());
+                (user.unwrap_or_default(), client_ip, "jwt")
+            }
+            Credential::Password {
+                name, client_ip, ..
+            } => (
+                UserIdentity::new(name, "%").to_string(),
+                client_ip,
+                "password",
+            ),
+        };
+        AuditLogger::instance().log_login(
+            &session.get_current_tenant(),
+            &user,
+            client_ip.as_deref(),
+            method,
+            res.as_ref().err(),
+        );
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn do_auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
         let user_api = UserApiProvider::instance();
         match credential {
             Credential::Jwt {
@@ -74,6 +110,7 @@ impl AuthMgr {
                     .as_ref()
                     .ok_or_else(|| ErrorCode::AuthenticateFailure("jwt auth not configured."))?;
                 let jwt = jwt_auth.parse_jwt_claims(t.as_str()).await?;
+                let roles = jwt_auth.map_roles(&jwt);
                 let user_name = jwt.subject.ok_or_else(|| {
                     ErrorCode::AuthenticateFailure(
                         "jwt auth not configured correctly, user name is missing.",
@@ -93,8 +130,17 @@ impl AuthMgr {
                     .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
                     .await
                 {
-                    Ok(user_info) => match user_info.auth_info {
-                        AuthInfo::JWT => user_info,
+                    Ok(mut user_info) => match user_info.auth_info {
+                        AuthInfo::JWT => {
+                            self.sync_mapped_roles(
+                                &tenant,
+                                &mut user_info,
+                                &roles,
+                                jwt_auth.mapped_roles(),
+                            )
+                            .await?;
+                            user_info
+                        }
                         _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                     },
                     Err(e) => {
@@ -105,10 +151,12 @@ impl AuthMgr {
                             }
                             _ => return Err(ErrorCode::AuthenticateFailure(e.message())),
                         }
-                        let ensure_user = jwt
-                            .custom
-                            .ensure_user
-                            .ok_or(ErrorCode::AuthenticateFailure(e.message()))?;
+                        // the users of the mapped groups are created as if `ensure_user` is set.
+                        let ensure_user = match jwt.custom.ensure_user {
+                            Some(ensure_user) => ensure_user,
+                            None if !roles.is_empty() => EnsureUser::default(),
+                            None => return Err(ErrorCode::AuthenticateFailure(e.message())),
+                        };
                         // create a new user if not exists
                         let mut user_info = UserInfo::new(&user_name, "%", AuthInfo::JWT);
                         if let Some(ref roles) = ensure_user.roles {
@@ -116,12 +164,22 @@ impl AuthMgr {
                                 user_info.grants.grant_role(role);
                             }
                         }
+                        for role in &roles {
+                            user_info.grants.grant_role(role.clone());
+                        }
+                        if !roles.is_empty() {
+                            user_info.option.set_default_role(roles.first().cloned());
+                        }
                         user_api.add_user(&tenant, user_info.clone(), true).await?;
                         user_info
                     }
                 };
 
                 session.set_authed_user(user, jwt.custom.role).await?;
+                // the roles of the groups are active along with the current role.
+                if !roles.is_empty() {
+                    session.set_secondary_roles_checked(Some(roles)).await?;
+                }
             }
             Credential::Password {
                 name: n,
@@ -130,9 +188,21 @@ impl AuthMgr {
             } => {
                 let tenant = session.get_current_tenant();
                 let identity = UserIdentity::new(n, "%");
-                let user = user_api
+                let user = match user_api
                     .get_user_with_client_ip(&tenant, identity, client_ip.as_deref())
-                    .await?;
+                    .await
+                {
+                    Ok(user) => user,
+                    // the LDAP users are created on their first login.
+                    Err(e) if e.code() == ErrorCode::UNKNOWN_USER && self.ldap_auth.is_some() => {
+                        let user = self.auth_ldap_user(&tenant, n, p, None).await?;
+                        session.set_authed_user(user, None).await?;
+                        return Ok(());
+                    }
+                    Err(e) => return Err(e),
+                };
+                // check the user is not locked out and the password is not expired.
+                user_api.check_login_password(&tenant, &user).await?;
                 let user = match &user.auth_info {
                     AuthInfo::None => user,
                     AuthInfo::Password {
@@ -142,12 +212,19 @@ impl AuthMgr {
                         None => return Err(ErrorCode::AuthenticateFailure("password required")),
                         Some(p) => {
                             if *h == t.hash(p) {
+                                if !user.password_fails.is_empty() || user.lockout_time.is_some() {
+                                    user_api
+                                        .clear_user_login_fail(&tenant, user.identity())
+                                        .await?;
+                                }
                                 user
                             } else {
+                                user_api.update_user_login_fail(&tenant, &user).await?;
                                 return Err(ErrorCode::AuthenticateFailure("wrong password"));
                             }
                         }
                     },
+                    AuthInfo::Ldap => self.auth_ldap_user(&tenant, n, p, Some(user)).await?,
                     _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
                 session.set_authed_user(user, None).await?;
@@ -155,4 +232,99 @@ impl AuthMgr {
         };
         Ok(())
     }
+
+    // Check the password of a user by the LDAP server, and grant the user the roles mapped from
+    // its LDAP groups. The user is created if `user` is None.
+    //
+    // A wrong password is counted as a failed login of the user like a local password, so the
+    // lockout of the password policy of the user applies.
+    #[async_backtrace::framed]
+    async fn auth_ldap_user(
+        &self,
+        tenant: &str,
+        name: &str,
+        password: &Option<Vec<u8>>,
+        user: Option<UserInfo>,
+    ) -> Result<UserInfo> {
+        let ldap_auth = self
+            .ldap_auth
+            .as_ref()
+            .ok_or_else(|| ErrorCode::AuthenticateFailure("ldap auth not configured."))?;
+        let password = match password {
+            None => return Err(ErrorCode::AuthenticateFailure("password required")),
+            Some(p) => String::from_utf8(p.clone())
+                .map_err(|_| ErrorCode::AuthenticateFailure("password is not valid utf-8"))?,
+        };
+        let user_api = UserApiProvider::instance();
+        let Some(ldap_user) = ldap_auth.authenticate(name, &password).await? else {
+            if let Some(user) = &user {
+                user_api.update_user_login_fail(tenant, user).await?;
+            }
+            return Err(ErrorCode::AuthenticateFailure(
+                "wrong ldap user name or password",
+            ));
+        };
+        let roles = ldap_auth.map_roles(&ldap_user.groups);
+
+        let Some(mut user) = user else {
+            let mut user = UserInfo::new(name, "%", AuthInfo::Ldap);
+            for role in &roles {
+                user.grants.grant_role(role.clone());
+            }
+            user.option.set_default_role(roles.first().cloned());
+            user_api.add_user(tenant, user.clone(), true).await?;
+            return Ok(user);
+        };
+
+        if !user.password_fails.is_empty() || user.lockout_time.is_some() {
+            user_api
+                .clear_user_login_fail(tenant, user.identity())
+                .await?;
+        }
+
+        self.sync_mapped_roles(tenant, &mut user, &roles, ldap_auth.mapped_roles())
+            .await?;
+        Ok(user)
+    }
+
+    // Grant the user the roles mapped from its groups, and revoke the mapped roles of the
+    // groups it no longer belongs to.
+    #[async_backtrace::framed]
+    async fn sync_mapped_roles(
+        &self,
+        tenant: &str,
+        user: &mut UserInfo,
+        roles: &[String],
+        mapped_roles: Vec<String>,
+    ) -> Result<()> {
+        // only the roles in the group role mapping are refreshed, the roles granted by
+        // `GRANT ROLE` are kept.
+        let current_roles = user.grants.roles();
+        let grant_roles = roles
+            .iter()
+            .filter(|role| !current_roles.contains(role))
+            .cloned()
+            .collect::<Vec<_>>();
+        let revoke_roles = mapped_roles
+            .into_iter()
+            .filter(|role| current_roles.contains(role) && !roles.contains(role))
+            .collect::<Vec<_>>();
+        if !grant_roles.is_empty() || !revoke_roles.is_empty() {
+            UserApiProvider::instance()
+                .update_user_roles(
+                    tenant,
+                    user.identity(),
+                    grant_roles.clone(),
+                    revoke_roles.clone(),
+                )
+                .await?;
+            for role in &revoke_roles {
+                user.grants.revoke_role(role);
+            }
+            for role in grant_roles {
+                user.grants.grant_role(role);
+            }
+        }
+        Ok(())
+    }
 }
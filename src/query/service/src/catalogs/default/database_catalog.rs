@@ -68,6 +68,8 @@ use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyReply;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -518,6 +520,14 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.set_table_column_mask_policy(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        self.mutable_catalog.set_table_row_access_policy(req).await
+    }
+
     // Table index
 
     #[async_backtrace::framed]
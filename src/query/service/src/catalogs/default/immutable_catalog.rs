@@ -60,6 +60,8 @@ use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyReply;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -317,6 +319,17 @@ impl Catalog for ImmutableCatalog {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Err(ErrorCode::Unimplemented(format!(
+            "set_table_row_access_policy not allowed for system database {:?}",
+            req
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn list_lock_revisions(&self, _req: ListLockRevReq) -> Result<Vec<(u64, LockMeta)>> {
         Err(ErrorCode::Unimplemented(
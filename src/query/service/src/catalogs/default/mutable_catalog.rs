@@ -71,6 +71,8 @@ use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::SetTableRowAccessPolicyReply;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
@@ -479,6 +481,13 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.set_table_column_mask_policy(req).await?)
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Ok(self.ctx.meta.set_table_row_access_policy(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
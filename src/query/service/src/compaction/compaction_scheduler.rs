@@ -0,0 +1,530 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_sql::plans::OptimizeTableAction;
+use common_sql::plans::OptimizeTablePlan;
+use common_storages_fuse::operations::TableFragmentation;
+use common_storages_fuse::FuseTable;
+use common_storages_system::CompactionHistoryLogElement;
+use common_storages_system::CompactionHistoryQueue;
+use common_users::UserApiProvider;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures::future::select;
+use futures::future::Either;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::OptimizeTableInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The longest time a table is skipped after its compactions failed.
+const MAX_BACKOFF: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Prefix of the meta key of the lease held by the node running the rounds of a tenant.
+const LEASE_KEY_PREFIX: &str = "__fd_compaction_scheduler";
+
+const SKIPPED_DATABASES: [&str; 2] = ["system", "information_schema"];
+
+pub struct Candidate {
+    pub database: String,
+    pub table: String,
+    pub table_id: u64,
+    pub fragmentation: TableFragmentation,
+}
+
+/// What a round does with a candidate table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Decision {
+    Compact,
+    /// the compactions of the table failed recently
+    Backoff,
+    /// the budget of tables of the round is used up
+    Deferred,
+}
+
+impl Decision {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Decision::Compact => "compact",
+            Decision::Backoff => "backoff",
+            Decision::Deferred => "deferred",
+        }
+    }
+}
+
+struct Backoff {
+    failures: u64,
+    until: Instant,
+}
+
+/// Compacts the fragmented fuse tables in the background, see `enable_compaction_scheduler`.
+///
+/// Every round, the tables of the default catalog whose fragmentation reaches the thresholds
+/// are compacted, the most fragmented first, within the budget of threads and IO requests.
+/// A table whose compaction failed, e.g. conflicted with a concurrent write, is backed off
+/// exponentially. Each decision is logged to `system.compaction_history`.
+///
+/// The scheduler is enabled on every node of the tenant, but only the node holding the lease
+/// in the meta service runs the rounds. The lease expires after three intervals, so another
+/// node takes over if the holder is down.
+pub struct CompactionScheduler {
+    tenant: String,
+    node_id: String,
+    interval: Duration,
+    tables_per_round: usize,
+    max_threads: u64,
+    max_io_requests: u64,
+    segment_limit: usize,
+    segment_threshold: u64,
+    block_threshold: u64,
+    backoffs: HashMap<u64, Backoff>,
+}
+
+/// Stops the rounds of a started [CompactionScheduler].
+pub struct CompactionSchedulerHandle {
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    join_handle: JoinHandle<()>,
+}
+
+impl CompactionSchedulerHandle {
+    #[async_backtrace::framed]
+    pub async fn shutdown(self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        self.shutdown_notify.notify_waiters();
+        if let Err(e) = self.join_handle.await {
+            warn!("cannot shutdown compaction scheduler, cause {:?}", e);
+        }
+    }
+}
+
+impl CompactionScheduler {
+    pub fn create(conf: &InnerConfig) -> Self {
+        CompactionScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            node_id: conf.query.node_id.clone(),
+            interval: Duration::from_secs(conf.query.compaction_scheduler_interval_secs.max(1)),
+            tables_per_round: conf.query.compaction_scheduler_tables_per_round as usize,
+            max_threads: conf.query.compaction_scheduler_max_threads.max(1),
+            max_io_requests: conf.query.compaction_scheduler_max_io_requests.max(1),
+            segment_limit: conf.query.compaction_scheduler_segment_limit.max(1) as usize,
+            segment_threshold: conf.query.compaction_scheduler_segment_threshold,
+            block_threshold: conf.query.compaction_scheduler_block_threshold,
+            backoffs: HashMap::new(),
+        }
+    }
+
+    pub fn start(conf: &InnerConfig) -> CompactionSchedulerHandle {
+        let mut scheduler = Self::create(conf);
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let shutdown_notify = Arc::new(Notify::new());
+
+        let join_handle = GlobalIORuntime::instance().spawn("compaction-scheduler", {
+            let shutdown = shutdown.clone();
+            let shutdown_notify = shutdown_notify.clone();
+            async move {
+                let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+                while !shutdown.load(Ordering::Relaxed) {
+                    match select(shutdown_notified, Box::pin(sleep(scheduler.interval))).await {
+                        Either::Left((_, _)) => break,
+                        Either::Right((_, notified)) => shutdown_notified = notified,
+                    }
+                    match scheduler.acquire_lease().await {
+                        Ok(true) => {}
+                        Ok(false) => continue,
+                        Err(e) => {
+                            warn!("compaction scheduler cannot acquire the lease: {}", e);
+                            continue;
+                        }
+                    }
+                    if let Err(e) = scheduler.run_round(&shutdown).await {
+                        warn!("compaction scheduler round failed: {}", e);
+                    }
+                }
+            }
+        });
+        CompactionSchedulerHandle {
+            shutdown,
+            shutdown_notify,
+            join_handle,
+        }
+    }
+
+    /// Acquires or renews the lease of running the rounds, returns false if it is held by
+    /// another node.
+    async fn acquire_lease(&self) -> Result<bool> {
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        let key = format!("{}/{}", LEASE_KEY_PREFIX, escape_for_key(&self.tenant)?);
+        let seq = match meta.get_kv(&key).await? {
+            Some(holder) if holder.data != self.node_id.as_bytes() => return Ok(false),
+            Some(holder) => MatchSeq::Exact(holder.seq),
+            None => MatchSeq::Exact(0),
+        };
+
+        let expire_at = (SystemTime::now() + self.interval * 3)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        let reply = meta
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                seq,
+                Operation::Update(self.node_id.as_bytes().to_vec()),
+                Some(KVMeta {
+                    expire_at: Some(expire_at.as_secs()),
+                }),
+            ))
+            .await?;
+        Ok(reply.is_changed())
+    }
+
+    async fn run_round(&mut self, shutdown: &AtomicBool) -> Result<()> {
+        let session = Self::create_session().await?;
+        let ctx = self.create_query_context(&session).await?;
+
+        let candidates = self.collect_candidates(&ctx).await?;
+        for (candidate, decision) in self.schedule(candidates, Instant::now()) {
+            if shutdown.load(Ordering::Relaxed) {
+                break;
+            }
+            let start = SystemTime::now();
+            let failures = self.failures(candidate.table_id);
+            if decision != Decision::Compact {
+                Self::write_log(
+                    start,
+                    &candidate,
+                    decision,
+                    (false, false),
+                    "skipped",
+                    failures,
+                    "",
+                );
+                continue;
+            }
+
+            // the lease may have expired while the previous tables were compacted, and another
+            // node may be running the rounds now.
+            match self.acquire_lease().await {
+                Ok(true) => {}
+                Ok(false) => {
+                    warn!("compaction scheduler lost the lease, the round is stopped");
+                    break;
+                }
+                Err(e) => {
+                    warn!(
+                        "compaction scheduler cannot renew the lease, the round is stopped: {}",
+                        e
+                    );
+                    break;
+                }
+            }
+
+            let actions = (
+                candidate.fragmentation.excess_segments >= self.segment_threshold,
+                candidate.fragmentation.undersized_blocks >= self.block_threshold,
+            );
+            match self.compact(&session, &candidate, actions).await {
+                Ok(_) => {
+                    self.backoffs.remove(&candidate.table_id);
+                    Self::write_log(start, &candidate, decision, actions, "success", 0, "");
+                }
+                Err(e) => {
+                    let status = if Self::is_conflict(&e) {
+                        "conflict"
+                    } else {
+                        "failed"
+                    };
+                    let (failures, backoff) = self.back_off(candidate.table_id, Instant::now());
+                    info!(
+                        "compaction of table {}.{} {}, backoff {}s: {}",
+                        candidate.database,
+                        candidate.table,
+                        status,
+                        backoff.as_secs(),
+                        e
+                    );
+                    Self::write_log(
+                        start,
+                        &candidate,
+                        decision,
+                        actions,
+                        status,
+                        failures,
+                        &e.message(),
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether the fragmentation of a table reaches one of the thresholds.
+    pub fn is_fragmented(&self, fragmentation: &TableFragmentation) -> bool {
+        fragmentation.excess_segments >= self.segment_threshold
+            || fragmentation.undersized_blocks >= self.block_threshold
+    }
+
+    /// Decides what to do with the `candidates` of a round, the most fragmented first.
+    ///
+    /// The backoffs of the tables which are no longer candidates, e.g. dropped or compacted
+    /// by others, are forgotten.
+    pub fn schedule(
+        &mut self,
+        mut candidates: Vec<Candidate>,
+        now: Instant,
+    ) -> Vec<(Candidate, Decision)> {
+        let table_ids = candidates
+            .iter()
+            .map(|c| c.table_id)
+            .collect::<HashSet<_>>();
+        self.backoffs
+            .retain(|table_id, _| table_ids.contains(table_id));
+
+        candidates.sort_by_key(|c| {
+            std::cmp::Reverse(c.fragmentation.excess_segments + c.fragmentation.undersized_blocks)
+        });
+
+        let mut compacted = 0;
+        candidates
+            .into_iter()
+            .map(|candidate| {
+                let decision = if self
+                    .backoffs
+                    .get(&candidate.table_id)
+                    .is_some_and(|backoff| backoff.until > now)
+                {
+                    Decision::Backoff
+                } else if compacted >= self.tables_per_round {
+                    Decision::Deferred
+                } else {
+                    compacted += 1;
+                    Decision::Compact
+                };
+                (candidate, decision)
+            })
+            .collect()
+    }
+
+    /// Records a failed compaction of the table, returns the number of the consecutive
+    /// failures and how long the table is backed off.
+    pub fn back_off(&mut self, table_id: u64, now: Instant) -> (u64, Duration) {
+        let failures = self.failures(table_id) + 1;
+        let backoff = self
+            .interval
+            .saturating_mul(1 << failures.min(16) as u32)
+            .min(MAX_BACKOFF);
+        self.backoffs.insert(table_id, Backoff {
+            failures,
+            until: now + backoff,
+        });
+        (failures, backoff)
+    }
+
+    pub fn failures(&self, table_id: u64) -> u64 {
+        self.backoffs
+            .get(&table_id)
+            .map_or(0, |backoff| backoff.failures)
+    }
+
+    async fn collect_candidates(&self, ctx: &Arc<QueryContext>) -> Result<Vec<Candidate>> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_default_catalog()?;
+
+        let mut candidates = vec![];
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            if SKIPPED_DATABASES.contains(&database.name()) {
+                continue;
+            }
+            for table in database.list_tables().await? {
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                if table.check_mutable().is_err() {
+                    continue;
+                }
+                let fragmentation = match fuse_table.fragmentation().await {
+                    Ok(Some(fragmentation)) => fragmentation,
+                    Ok(None) => continue,
+                    Err(e) => {
+                        warn!(
+                            "cannot get the fragmentation of table {}.{}, skipped: {}",
+                            database.name(),
+                            table.name(),
+                            e
+                        );
+                        continue;
+                    }
+                };
+                if !self.is_fragmented(&fragmentation) {
+                    continue;
+                }
+                candidates.push(Candidate {
+                    database: database.name().to_string(),
+                    table: table.name().to_string(),
+                    table_id: table.get_id(),
+                    fragmentation,
+                });
+            }
+        }
+        Ok(candidates)
+    }
+
+    async fn compact(
+        &self,
+        session: &Arc<Session>,
+        candidate: &Candidate,
+        (compact_segment, compact_block): (bool, bool),
+    ) -> Result<()> {
+        if compact_segment {
+            self.optimize(session, candidate, OptimizeTableAction::CompactSegments)
+                .await?;
+        }
+        if compact_block {
+            self.optimize(session, candidate, OptimizeTableAction::CompactBlocks)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn optimize(
+        &self,
+        session: &Arc<Session>,
+        candidate: &Candidate,
+        action: OptimizeTableAction,
+    ) -> Result<()> {
+        let ctx = self.create_query_context(session).await?;
+        let interpreter = OptimizeTableInterpreter::try_create(ctx.clone(), OptimizeTablePlan {
+            catalog: ctx.get_current_catalog(),
+            database: candidate.database.clone(),
+            table: candidate.table.clone(),
+            action,
+            limit: Some(self.segment_limit),
+            need_lock: true,
+        })?;
+
+        let mut build_res = interpreter.execute2().await?;
+        if build_res.main_pipeline.is_empty() || !build_res.main_pipeline.is_complete_pipeline()? {
+            return Ok(());
+        }
+
+        let settings = ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
+        ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+
+    async fn create_session() -> Result<Arc<Session>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Local)
+            .await?;
+        let mut user = UserInfo::new_no_auth("compaction-scheduler", "0.0.0.0");
+        user.grants.grant_privileges(
+            &GrantObject::Global,
+            UserPrivilegeSet::available_privileges_on_global(),
+        );
+        session
+            .set_authed_user(user, Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()))
+            .await?;
+        Ok(session)
+    }
+
+    async fn create_query_context(&self, session: &Arc<Session>) -> Result<Arc<QueryContext>> {
+        let ctx = session.create_query_context().await?;
+        let settings = ctx.get_settings();
+        settings.set_max_threads(self.max_threads)?;
+        settings.set_max_storage_io_requests(self.max_io_requests)?;
+        Ok(ctx)
+    }
+
+    fn is_conflict(e: &ErrorCode) -> bool {
+        e.code() == ErrorCode::UNRESOLVABLE_CONFLICT
+            || e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+            || e.code() == ErrorCode::TABLE_ALREADY_LOCKED
+            || e.code() == ErrorCode::TABLE_LOCK_EXPIRED
+    }
+
+    fn write_log(
+        start: SystemTime,
+        candidate: &Candidate,
+        decision: Decision,
+        (compact_segment, compact_block): (bool, bool),
+        status: &str,
+        failures: u64,
+        message: &str,
+    ) {
+        let to_micros = |time: SystemTime| {
+            time.duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_micros() as i64
+        };
+        let element = CompactionHistoryLogElement {
+            start_time: to_micros(start),
+            end_time: to_micros(SystemTime::now()),
+            database: candidate.database.clone(),
+            table: candidate.table.clone(),
+            segment_count: candidate.fragmentation.segment_count,
+            block_count: candidate.fragmentation.block_count,
+            excess_segments: candidate.fragmentation.excess_segments,
+            undersized_blocks: candidate.fragmentation.undersized_blocks,
+            decision: decision.as_str().to_string(),
+            compact_segment,
+            compact_block,
+            status: status.to_string(),
+            failures,
+            message: message.to_string(),
+        };
+        if let Err(e) = CompactionHistoryQueue::instance().and_then(|q| q.append_data(element)) {
+            warn!("failed to write compaction history: {}", e);
+        }
+    }
+}
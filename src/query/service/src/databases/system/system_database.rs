@@ -20,6 +20,7 @@ use common_meta_app::schema::DatabaseIdent;
 use common_meta_app::schema::DatabaseInfo;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_storages_system::AuditLogTable;
 use common_storages_system::BackgroundJobTable;
 use common_storages_system::BackgroundTaskTable;
 use common_storages_system::BacktraceTable;
@@ -29,6 +30,7 @@ use common_storages_system::CatalogsTable;
 use common_storages_system::ClusteringHistoryTable;
 use common_storages_system::ClustersTable;
 use common_storages_system::ColumnsTable;
+use common_storages_system::CompactionHistoryTable;
 use common_storages_system::ConfigsTable;
 use common_storages_system::ContributorsTable;
 use common_storages_system::CreditsTable;
@@ -103,6 +105,14 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            Arc::new(CompactionHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            Arc::new(AuditLogTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
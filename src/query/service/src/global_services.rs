@@ -30,6 +30,7 @@ use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
 use common_storages_hive::HiveCreator;
 use common_storages_iceberg::IcebergCreator;
+use common_storages_result_cache::ResultCacheInvalidator;
 use common_tracing::GlobalLogger;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
@@ -37,11 +38,14 @@ use storages_common_cache_manager::CacheManager;
 use storages_common_locks::LockManager;
 
 use crate::api::DataExchangeManager;
+use crate::audit::AuditLogger;
 use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::sessions::UserResourcesManager;
+use crate::sessions::WorkloadGroupManager;
 
 pub struct GlobalServices;
 
@@ -101,6 +105,8 @@ impl GlobalServices {
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(&config)?;
+        UserResourcesManager::init()?;
+        WorkloadGroupManager::init()?;
         LockManager::init()?;
         AuthMgr::init(&config)?;
         UserApiProvider::init(
@@ -111,6 +117,11 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        AuditLogger::init(&config).await?;
+        ResultCacheInvalidator::init(
+            UserApiProvider::instance().get_meta_store_client(),
+            config.query.node_id.clone(),
+        )?;
         ShareEndpointManager::init()?;
         QueryProfileManager::init();
 
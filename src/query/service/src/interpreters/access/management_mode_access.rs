@@ -99,6 +99,14 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
                 | Plan::DropNetworkPolicy(_)
+                // Password policy.
+                | Plan::CreatePasswordPolicy(_)
+                | Plan::AlterPasswordPolicy(_)
+                | Plan::DropPasswordPolicy(_)
+                // Workload group.
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
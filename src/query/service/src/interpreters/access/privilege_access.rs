@@ -408,6 +408,86 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateTableTag(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::DropTableTag(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::MergeTableBranch(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Alter],
+                    true,
+                )
+                    .await?;
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.branch.clone(),
+                    ),
+                    vec![UserPrivilegeType::Select],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::DropTablePartition(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Delete],
+                    true,
+                )
+                    .await?;
+            }
+            Plan::ReplaceTablePartition(plan) => {
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.database.clone(),
+                        plan.table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Delete],
+                    true,
+                )
+                    .await?;
+                self.validate_access(
+                    &GrantObject::Table(
+                        plan.catalog.clone(),
+                        plan.source_database.clone(),
+                        plan.source_table.clone(),
+                    ),
+                    vec![UserPrivilegeType::Select],
+                    true,
+                )
+                    .await?;
+            }
             Plan::AlterTableClusterKey(plan) => {
                 self.validate_access(
                     &GrantObject::Table(
@@ -697,6 +777,15 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
             | Plan::ShowNetworkPolicies(_)
+            | Plan::CreatePasswordPolicy(_)
+            | Plan::AlterPasswordPolicy(_)
+            | Plan::DropPasswordPolicy(_)
+            | Plan::DescPasswordPolicy(_)
+            | Plan::ShowPasswordPolicies(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
+            | Plan::ShowWorkloadGroups(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -718,6 +807,15 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            // Attaching or dropping the policy of a table changes the rows visible to all the
+            // users, so it is not allowed by the privileges on the table.
+            Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_) => {
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
+                    .await?;
+            }
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
@@ -752,6 +850,7 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
         }
 
         Ok(())
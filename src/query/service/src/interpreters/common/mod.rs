@@ -16,6 +16,7 @@ mod compact_hook;
 mod grant;
 mod metrics;
 mod query_log;
+mod quota;
 mod refresh_aggregating_index;
 mod table;
 mod task;
@@ -24,9 +25,15 @@ mod util;
 pub use compact_hook::*;
 pub use grant::validate_grant_object_exists;
 pub use query_log::InterpreterQueryLog;
+pub use quota::check_user_storage_quota;
 pub use refresh_aggregating_index::hook_refresh_agg_index;
 pub use refresh_aggregating_index::RefreshAggIndexDesc;
+pub use table::check_mutation_constraints;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_constraints;
+pub use table::check_referenced_partition_keys;
+pub use table::check_referenced_ttl;
+pub use table::check_replace_constraints;
 pub use task::get_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
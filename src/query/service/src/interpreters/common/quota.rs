@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_users::UserApiProvider;
+use log::warn;
+use storages_common_table_meta::table::OPT_KEY_OWNER_USER;
+
+use crate::sessions::UserResourcesManager;
+
+/// Checks that the owner of `table` has not used up the `max_storage_in_bytes` quota before
+/// writing more data into the table.
+///
+/// The storage of a user is the size of the data and index of the tables of the default
+/// catalog created by the user, as of the latest snapshots. The usage of all the users of
+/// the tenant is collected at once and cached for a while, so the quota may be exceeded by
+/// the writes within the cache period.
+///
+/// A fuse table created before the owners were recorded is charged to the first user who
+/// writes into it.
+pub async fn check_user_storage_quota(
+    ctx: Arc<dyn TableContext>,
+    database: &str,
+    table: &dyn Table,
+) -> Result<()> {
+    let owner = match table.options().get(OPT_KEY_OWNER_USER) {
+        Some(owner) => owner.clone(),
+        None if table.engine() == "FUSE" => adopt_table(&ctx, database, table).await?,
+        None => return Ok(()),
+    };
+
+    let tenant = ctx.get_tenant();
+    let identity = UserIdentity::new(&owner, "%");
+    // the owner may have been dropped, its tables are not limited any more.
+    let Ok(user) = UserApiProvider::instance()
+        .get_user(&tenant, identity)
+        .await
+    else {
+        return Ok(());
+    };
+    let limit = user.quota.max_storage_in_bytes;
+    if limit == 0 {
+        return Ok(());
+    }
+
+    let manager = UserResourcesManager::instance();
+    let usage = match manager.get_storage_usage(&tenant) {
+        Some(usage) => usage,
+        None => manager.set_storage_usage(&tenant, collect_storage_usage(&ctx).await?),
+    };
+    let used = usage.get(&owner).copied().unwrap_or(0);
+    if used >= limit {
+        return Err(ErrorCode::UserQuotaExceeded(format!(
+            "storage quota of user {} exceeded, used {} bytes, max_storage_in_bytes {}",
+            owner, used, limit
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the storage used by each user, i.e. the owner of the tables.
+async fn collect_storage_usage(ctx: &Arc<dyn TableContext>) -> Result<HashMap<String, u64>> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    let mut usage = HashMap::new();
+    for database in catalog.list_databases(&tenant).await? {
+        for table in database.list_tables().await? {
+            let Some(owner) = table.options().get(OPT_KEY_OWNER_USER) else {
+                continue;
+            };
+            let stats = &table.get_table_info().meta.statistics;
+            *usage.entry(owner.clone()).or_default() +=
+                stats.compressed_data_bytes + stats.index_data_bytes;
+        }
+    }
+    Ok(usage)
+}
+
+/// Records the current user as the owner of `table`, returns the name of the user.
+async fn adopt_table(
+    ctx: &Arc<dyn TableContext>,
+    database: &str,
+    table: &dyn Table,
+) -> Result<String> {
+    let user = ctx.get_current_user()?;
+    let req = UpsertTableOptionReq {
+        table_id: table.get_id(),
+        seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+        options: HashMap::from([(OPT_KEY_OWNER_USER.to_string(), Some(user.name.clone()))]),
+    };
+    let catalog = ctx.get_catalog(table.get_table_info().catalog()).await?;
+    // the table is adopted by the next write if it has been modified concurrently.
+    if let Err(e) = catalog
+        .upsert_table_option(&ctx.get_tenant(), database, req)
+        .await
+    {
+        warn!(
+            "cannot record the owner of table {}: {}",
+            table.get_table_info().desc,
+            e
+        );
+    }
+    Ok(user.name)
+}
@@ -12,14 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ComputedExpr;
 use common_expression::DataSchemaRef;
+use common_expression::FieldIndex;
+use common_expression::TableField;
+use common_expression::TableSchema;
 use common_sql::parse_computed_expr;
+use common_sql::parse_exprs;
+use storages_common_table_meta::table::UniqueConstraint;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +64,134 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Check if `column` is referenced by the ttl of the table, with the altered `schema`.
+pub fn check_referenced_ttl(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: DataSchemaRef,
+    column: &str,
+) -> Result<()> {
+    if let Some(ttl) = options.get(OPT_KEY_TTL).filter(|ttl| !ttl.is_empty()) {
+        if parse_computed_expr(ctx, schema, ttl).is_err() {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid ttl option, column `{}` is referenced by the ttl `{}` of the table",
+                column, ttl
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check if `column` is a key column of the PRIMARY KEY or UNIQUE constraints of the table.
+pub fn check_referenced_constraints(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+    column: &str,
+) -> Result<()> {
+    let column_id = schema.field_with_name(column)?.column_id();
+    for constraint in UniqueConstraint::from_table_options(options)? {
+        if constraint.column_ids.contains(&column_id) {
+            let kind = if constraint.is_primary_key {
+                "PRIMARY KEY"
+            } else {
+                "UNIQUE"
+            };
+            return Err(ErrorCode::BadArguments(format!(
+                "column `{}` is referenced by the {} constraint of the table",
+                column, kind
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check the mutation `operation` keeps the PRIMARY KEY and UNIQUE constraints of the table.
+///
+/// Only the rows appended by INSERT and COPY are checked against the constraints on commit, so
+/// a mutation can neither modify the key columns (`updated_columns`) of the existing rows, nor
+/// append rows (`appends_rows`) without going through the check.
+pub fn check_mutation_constraints(
+    table: &dyn Table,
+    operation: &str,
+    updated_columns: &[FieldIndex],
+    appends_rows: bool,
+) -> Result<()> {
+    let constraints = UniqueConstraint::from_table_options(table.options())?;
+    if constraints.is_empty() {
+        return Ok(());
+    }
+    if appends_rows {
+        return Err(ErrorCode::Unimplemented(format!(
+            "{} which inserts rows is not supported on table {} with PRIMARY KEY or UNIQUE constraints",
+            operation,
+            table.name()
+        )));
+    }
+    let schema = table.schema();
+    for index in updated_columns {
+        let field = schema.field(*index);
+        if constraints
+            .iter()
+            .any(|constraint| constraint.column_ids.contains(&field.column_id()))
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "{} of column `{}` is not supported, it is a key column of the PRIMARY KEY or UNIQUE constraints of table {}",
+                operation,
+                field.name(),
+                table.name()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check REPLACE INTO keeps the PRIMARY KEY and UNIQUE constraints of the table.
+///
+/// The replaced rows are not checked against the constraints on commit, the constraints are
+/// kept only if the key columns of each constraint contain all the `on_conflict_fields`: the
+/// existing rows which have the same keys as a replaced row are deleted then.
+pub fn check_replace_constraints(
+    table: &dyn Table,
+    on_conflict_fields: &[TableField],
+) -> Result<()> {
+    for constraint in UniqueConstraint::from_table_options(table.options())? {
+        if on_conflict_fields
+            .iter()
+            .any(|field| !constraint.column_ids.contains(&field.column_id()))
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "REPLACE INTO is not supported on table {}, unless the ON CONFLICT columns are key columns of all its PRIMARY KEY and UNIQUE constraints",
+                table.name()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Check if `column` is referenced by the partition keys of the table.
+pub fn check_referenced_partition_keys(
+    ctx: Arc<dyn TableContext>,
+    table: Arc<dyn Table>,
+    column: &str,
+) -> Result<()> {
+    let Some(partition_by) = table
+        .options()
+        .get(OPT_KEY_PARTITION_BY)
+        .filter(|partition_by| !partition_by.is_empty())
+    else {
+        return Ok(());
+    };
+    let index = table.schema().index_of(column)?;
+    let exprs = parse_exprs(ctx, table.clone(), partition_by)?;
+    if exprs
+        .iter()
+        .any(|expr| expr.column_refs().contains_key(&index))
+    {
+        return Err(ErrorCode::ColumnReferencedByComputedColumn(format!(
+            "column `{}` is referenced by the partition keys `{}` of the table",
+            column, partition_by
+        )));
+    }
+    Ok(())
+}
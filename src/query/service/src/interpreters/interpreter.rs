@@ -15,6 +15,7 @@
 use std::sync::Arc;
 use std::time::SystemTime;
 
+use common_catalog::query_kind::QueryKind;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -22,6 +23,7 @@ use common_expression::SendableDataBlockStream;
 use common_pipeline_core::SourcePipeBuilder;
 use log::error;
 
+use crate::audit::AuditLogger;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
@@ -30,6 +32,7 @@ use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadGroupManager;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
@@ -53,6 +56,30 @@ pub trait Interpreter: Sync + Send {
             log_query_finished(&ctx, Some(err.clone()));
             return Err(err);
         }
+        let user_resources = ctx.get_current_session().get_user_resources();
+        if let Some(Err(err)) = user_resources.as_ref().map(|r| r.check_memory()) {
+            log_query_finished(&ctx, Some(err.clone()));
+            return Err(err);
+        }
+
+        // the query waits in the queue of the workload group of the user before running, the
+        // nested queries of an admitted query are not queued again.
+        let workload_ticket = match ctx.get_query_kind() {
+            QueryKind::Query | QueryKind::Insert | QueryKind::CopyIntoTable | QueryKind::Update
+                if ctx.get_workload_group().is_none() =>
+            {
+                match WorkloadGroupManager::instance().admit(&ctx).await {
+                    Ok(ticket) => ticket,
+                    Err(err) => {
+                        InterpreterMetrics::record_query_error(&ctx);
+                        log_query_finished(&ctx, Some(err.clone()));
+                        return Err(err);
+                    }
+                }
+            }
+            _ => None,
+        };
+
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
@@ -69,8 +96,23 @@ pub trait Interpreter: Sync + Send {
             return Ok(Box::pin(DataBlockStream::create(None, vec![])));
         }
 
+        // the thread slots of the user quota are given back when the query is finished.
+        let settings = ctx.get_settings();
+        let mut max_threads = settings.get_max_threads()?;
+        let thread_slots = user_resources
+            .as_ref()
+            .map(|r| r.acquire_threads(max_threads));
+        if let Some(slots) = &thread_slots {
+            max_threads = slots.threads();
+        }
+        if let Some(ticket) = &workload_ticket {
+            max_threads = max_threads.min(ticket.max_threads());
+        }
+
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            drop(thread_slots);
+            drop(workload_ticket);
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
             log_query_finished(&query_ctx, may_error.clone());
 
@@ -82,9 +124,8 @@ pub trait Interpreter: Sync + Send {
 
         ctx.set_status_info("executing pipeline");
 
-        let settings = ctx.get_settings();
         let query_id = ctx.get_id();
-        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        build_res.set_max_threads(max_threads as usize);
         let settings = ExecutorSettings::try_create(&settings, query_id)?;
 
         if build_res.main_pipeline.is_complete_pipeline()? {
@@ -146,6 +187,10 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    if let Some(action) = ctx.take_audit_action() {
+        AuditLogger::instance().log_query(ctx, action, error.as_ref());
+    }
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
         error!("interpreter.finish.error: {:?}", error)
     }
@@ -49,14 +49,13 @@ impl CopyIntoLocationInterpreter {
 
     #[async_backtrace::framed]
     async fn build_query(&self, query: &Plan) -> Result<(SelectInterpreter, DataSchemaRef)> {
-        let (s_expr, metadata, bind_context, formatted_ast) = match query {
+        let (s_expr, metadata, bind_context) = match query {
             Plan::Query {
                 s_expr,
                 metadata,
                 bind_context,
-                formatted_ast,
                 ..
-            } => (s_expr, metadata, bind_context, formatted_ast),
+            } => (s_expr, metadata, bind_context),
             v => unreachable!("Input plan must be Query, but it's {}", v),
         };
 
@@ -65,7 +64,6 @@ impl CopyIntoLocationInterpreter {
             *(bind_context.clone()),
             *s_expr.clone(),
             metadata.clone(),
-            formatted_ast.clone(),
             false,
         )?;
 
@@ -40,6 +40,7 @@ use log::debug;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::common::hook_compact;
 use crate::interpreters::common::hook_refresh_agg_index;
 use crate::interpreters::common::CompactHookTraceCtx;
@@ -69,14 +70,13 @@ impl CopyIntoTableInterpreter {
 
     #[async_backtrace::framed]
     async fn build_query(&self, query: &Plan) -> Result<(SelectInterpreter, DataSchemaRef)> {
-        let (s_expr, metadata, bind_context, formatted_ast) = match query {
+        let (s_expr, metadata, bind_context) = match query {
             Plan::Query {
                 s_expr,
                 metadata,
                 bind_context,
-                formatted_ast,
                 ..
-            } => (s_expr, metadata, bind_context, formatted_ast),
+            } => (s_expr, metadata, bind_context),
             v => unreachable!("Input plan must be Query, but it's {}", v),
         };
 
@@ -85,7 +85,6 @@ impl CopyIntoTableInterpreter {
             *(bind_context.clone()),
             *s_expr.clone(),
             metadata.clone(),
-            formatted_ast.clone(),
             false,
         )?;
 
@@ -273,6 +272,7 @@ impl CopyIntoTableInterpreter {
                 &plan.table_name,
             )
             .await?;
+        check_user_storage_quota(ctx.clone(), &plan.database_name, to_table.as_ref()).await?;
 
         // Commit.
         {
@@ -355,14 +355,8 @@ pub async fn subquery_filter(
     expr = heuristic.optimize(expr, &RESIDUAL_RULES)?;
 
     // Create `input_expr` pipeline and execute it to get `_row_id` data block.
-    let select_interpreter = SelectInterpreter::try_create(
-        ctx.clone(),
-        *bind_context,
-        expr,
-        metadata.clone(),
-        None,
-        false,
-    )?;
+    let select_interpreter =
+        SelectInterpreter::try_create(ctx.clone(), *bind_context, expr, metadata.clone(), false)?;
     // Build physical plan
     let physical_plan = select_interpreter.build_physical_plan().await?;
     // Create pipeline for physical plan
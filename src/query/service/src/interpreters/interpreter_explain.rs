@@ -26,8 +26,9 @@ use common_profile::QueryProfileManager;
 use common_profile::SharedProcessorProfiles;
 use common_sql::executor::ProfileHelper;
 use common_sql::optimizer::ColumnSet;
+use common_sql::plan_result_cache_key;
+use common_sql::ColumnBinding;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_users::UserApiProvider;
 
@@ -68,19 +69,20 @@ impl Interpreter for ExplainInterpreter {
                     s_expr,
                     metadata,
                     bind_context,
-                    formatted_ast,
                     ..
                 } => {
-                    let ctx = self.ctx.clone();
-                    // If `formatted_ast` is Some, it means we may use query result cache.
-                    // If we use result cache for this query,
-                    // we should not use `dry_run` mode to build the physical plan.
-                    // It's because we need to get the same partitions as the original selecting plan.
-                    let mut builder =
-                        PhysicalPlanBuilder::new(metadata.clone(), ctx, formatted_ast.is_none());
-                    let plan = builder.build(s_expr, bind_context.column_set()).await?;
-                    self.explain_physical_plan(&plan, metadata, formatted_ast)
+                    match self
+                        .explain_result_cache(s_expr, metadata, &bind_context.columns)
                         .await?
+                    {
+                        Some(blocks) => blocks,
+                        None => {
+                            let ctx = self.ctx.clone();
+                            let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, true);
+                            let plan = builder.build(s_expr, bind_context.column_set()).await?;
+                            self.explain_physical_plan(&plan, metadata)?
+                        }
+                    }
                 }
                 _ => self.explain_plan(&self.plan)?,
             },
@@ -188,24 +190,29 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
-    pub async fn explain_physical_plan(
+    /// Returns the plan of reading the query result cache if the query hits it.
+    #[async_backtrace::framed]
+    async fn explain_result_cache(
         &self,
-        plan: &PhysicalPlan,
+        s_expr: &SExpr,
         metadata: &MetadataRef,
-        formatted_ast: &Option<String>,
-    ) -> Result<Vec<DataBlock>> {
+        columns: &[ColumnBinding],
+    ) -> Result<Option<Vec<DataBlock>>> {
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(formatted_ast.as_ref().unwrap());
+            let key = match plan_result_cache_key(s_expr, metadata, columns)? {
+                Some(key) => key,
+                None => return Ok(None),
+            };
             let kv_store = UserApiProvider::instance().get_meta_store_client();
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
-                &key,
+                key,
                 kv_store.clone(),
                 self.ctx
                     .get_settings()
                     .get_query_result_cache_allow_inconsistent()?,
             );
-            if let Some(v) = cache_reader.check_cache().await? {
+            if let Some((_, v)) = cache_reader.check_cache().await? {
                 // Construct a format tree for result cache reading
                 let children = vec![
                     FormatTreeNode::new(format!("SQL: {}", v.sql)),
@@ -219,10 +226,19 @@ impl ExplainInterpreter {
                 let result = format_tree.format_pretty()?;
                 let line_split_result: Vec<&str> = result.lines().collect();
                 let formatted_plan = StringType::from_data(line_split_result);
-                return Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])]);
+                return Ok(Some(vec![DataBlock::new_from_columns(vec![
+                    formatted_plan,
+                ])]));
             }
         }
+        Ok(None)
+    }
 
+    pub fn explain_physical_plan(
+        &self,
+        plan: &PhysicalPlan,
+        metadata: &MetadataRef,
+    ) -> Result<Vec<DataBlock>> {
         let result = plan
             .format(metadata.clone(), SharedProcessorProfiles::default())?
             .format_pretty()?;
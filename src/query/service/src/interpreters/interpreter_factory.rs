@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use log::error;
 
@@ -27,6 +28,11 @@ use super::interpreter_share_desc::DescShareInterpreter;
 use super::interpreter_table_set_options::SetOptionsInterpreter;
 use super::interpreter_user_stage_drop::DropUserStageInterpreter;
 use super::*;
+use crate::audit::audit_action;
+use crate::audit::audit_plan;
+use crate::audit::AuditAction;
+use crate::audit::AuditEventType;
+use crate::audit::AuditLogger;
 use crate::interpreters::access::Accessor;
 use crate::interpreters::interpreter_catalog_drop::DropCatalogInterpreter;
 use crate::interpreters::interpreter_connection_create::CreateConnectionInterpreter;
@@ -68,11 +74,30 @@ impl InterpreterFactory {
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
-        access_checker.check(plan).await.map_err(|e| {
+        if let Err(e) = access_checker.check(plan).await {
             error!("Access.denied(v2): {:?}", e);
-            e
-        })?;
-        Self::get_inner(ctx, plan)
+            if e.code() == ErrorCode::PERMISSION_DENIED {
+                let action = AuditAction {
+                    event_type: AuditEventType::AccessDenied,
+                    action: audit_action(plan),
+                    object: audit_plan(plan)
+                        .map(|(_, object)| object)
+                        .unwrap_or_default(),
+                };
+                AuditLogger::instance().log_query(&ctx, action, Some(&e));
+            }
+            return Err(e);
+        }
+
+        let interpreter = Self::get_inner(ctx.clone(), plan)?;
+        if let Some((event_type, object)) = audit_plan(plan) {
+            ctx.set_audit_action(AuditAction {
+                event_type,
+                action: audit_action(plan),
+                object,
+            });
+        }
+        Ok(interpreter)
     }
 
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
@@ -82,14 +107,12 @@ impl InterpreterFactory {
                 bind_context,
                 metadata,
                 ignore_result,
-                formatted_ast,
                 ..
             } => Ok(Arc::new(SelectInterpreter::try_create(
                 ctx,
                 *bind_context.clone(),
                 *s_expr.clone(),
                 metadata.clone(),
-                formatted_ast.clone(),
                 *ignore_result,
             )?)),
             Plan::Explain { kind, plan } => Ok(Arc::new(ExplainInterpreter::try_create(
@@ -191,6 +214,36 @@ impl InterpreterFactory {
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
+            Plan::CreateTableTag(create_table_tag) => Ok(Arc::new(
+                CreateTableTagInterpreter::try_create(ctx, *create_table_tag.clone())?,
+            )),
+            Plan::DropTableTag(drop_table_tag) => Ok(Arc::new(
+                DropTableTagInterpreter::try_create(ctx, *drop_table_tag.clone())?,
+            )),
+            Plan::MergeTableBranch(merge_table_branch) => Ok(Arc::new(
+                MergeTableBranchInterpreter::try_create(ctx, *merge_table_branch.clone())?,
+            )),
+            Plan::DropTablePartition(drop_table_partition) => Ok(Arc::new(
+                DropTablePartitionInterpreter::try_create(ctx, *drop_table_partition.clone())?,
+            )),
+            Plan::ReplaceTablePartition(replace_table_partition) => {
+                Ok(Arc::new(ReplaceTablePartitionInterpreter::try_create(
+                    ctx,
+                    *replace_table_partition.clone(),
+                )?))
+            }
+            Plan::AddTableRowAccessPolicy(add_table_row_access_policy) => {
+                Ok(Arc::new(AddTableRowAccessPolicyInterpreter::try_create(
+                    ctx,
+                    *add_table_row_access_policy.clone(),
+                )?))
+            }
+            Plan::DropTableRowAccessPolicy(drop_table_row_access_policy) => {
+                Ok(Arc::new(DropTableRowAccessPolicyInterpreter::try_create(
+                    ctx,
+                    *drop_table_row_access_policy.clone(),
+                )?))
+            }
             Plan::AlterTableClusterKey(alter_table_cluster_key) => Ok(Arc::new(
                 AlterTableClusterKeyInterpreter::try_create(ctx, *alter_table_cluster_key.clone())?,
             )),
@@ -440,6 +493,15 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
 
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
@@ -460,6 +522,39 @@ impl InterpreterFactory {
                 Ok(Arc::new(ShowNetworkPoliciesInterpreter::try_create(ctx)?))
             }
 
+            Plan::CreatePasswordPolicy(p) => Ok(Arc::new(
+                CreatePasswordPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterPasswordPolicy(p) => Ok(Arc::new(
+                AlterPasswordPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropPasswordPolicy(p) => Ok(Arc::new(DropPasswordPolicyInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescPasswordPolicy(p) => Ok(Arc::new(DescPasswordPolicyInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowPasswordPolicies(_) => {
+                Ok(Arc::new(ShowPasswordPoliciesInterpreter::try_create(ctx)?))
+            }
+
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowWorkloadGroups(_) => {
+                Ok(Arc::new(ShowWorkloadGroupsInterpreter::try_create(ctx)?))
+            }
+
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -31,6 +31,7 @@ use common_sql::plans::Plan;
 use common_sql::NameResolutionContext;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::common::hook_refresh_agg_index;
 use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::Interpreter;
@@ -90,6 +91,7 @@ impl Interpreter for InsertInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        check_user_storage_quota(self.ctx.clone(), &self.plan.database, table.as_ref()).await?;
 
         let mut build_res = PipelineBuildResult::create();
 
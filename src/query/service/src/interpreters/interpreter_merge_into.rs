@@ -53,6 +53,7 @@ use storages_common_table_meta::meta::TableSnapshot;
 
 use super::Interpreter;
 use super::InterpreterPtr;
+use crate::interpreters::common::check_mutation_constraints;
 use crate::interpreters::common::hook_compact;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
@@ -141,6 +142,17 @@ impl MergeIntoInterpreter {
         // check mutability
         let check_table = self.ctx.get_table(catalog, database, table_name).await?;
         check_table.check_mutable()?;
+        let updated_columns = matched_evaluators
+            .iter()
+            .filter_map(|evaluator| evaluator.update.as_ref())
+            .flat_map(|update| update.keys().cloned())
+            .collect::<Vec<_>>();
+        check_mutation_constraints(
+            check_table.as_ref(),
+            "MERGE INTO",
+            &updated_columns,
+            !unmatched_evaluators.is_empty(),
+        )?;
 
         let table_name = table_name.clone();
         let input = input.clone();
@@ -211,6 +223,12 @@ impl MergeIntoInterpreter {
                     table.name(),
                     table.get_table_info().engine(),
                 )))?;
+        if fuse_table.partition_by().is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "table {} is partitioned, which does not support MERGE INTO",
+                table.name(),
+            )));
+        }
 
         let table_info = fuse_table.get_table_info().clone();
         let catalog_ = self.ctx.get_catalog(catalog).await?;
@@ -551,7 +551,6 @@ impl MergeIntoInterpreter {
             metadata: metadata.clone(),
             bind_context,
             rewrite_kind: None,
-            formatted_ast: None,
             ignore_result: false,
         })
     }
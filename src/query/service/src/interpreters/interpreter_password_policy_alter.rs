@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::AlterPasswordPolicyPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPasswordPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPasswordPolicyPlan,
+}
+
+impl AlterPasswordPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPasswordPolicyPlan) -> Result<Self> {
+        Ok(AlterPasswordPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPasswordPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterPasswordPolicyInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_password_policy_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
+            .update_password_policy(&tenant, &plan.name, plan.if_exists, |policy| {
+                let fields = [
+                    (plan.min_length, &mut policy.min_length),
+                    (plan.max_length, &mut policy.max_length),
+                    (plan.min_upper_case_chars, &mut policy.min_upper_case_chars),
+                    (plan.min_lower_case_chars, &mut policy.min_lower_case_chars),
+                    (plan.min_numeric_chars, &mut policy.min_numeric_chars),
+                    (plan.min_special_chars, &mut policy.min_special_chars),
+                    (plan.min_age_days, &mut policy.min_age_days),
+                    (plan.max_age_days, &mut policy.max_age_days),
+                    (plan.max_retries, &mut policy.max_retries),
+                    (plan.lockout_time_mins, &mut policy.lockout_time_mins),
+                    (plan.history, &mut policy.history),
+                ];
+                for (value, field) in fields {
+                    if let Some(value) = value {
+                        *field = value;
+                    }
+                }
+                if let Some(comment) = plan.comment {
+                    policy.comment = comment;
+                }
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::principal::PasswordPolicy;
+use common_sql::plans::CreatePasswordPolicyPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreatePasswordPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreatePasswordPolicyPlan,
+}
+
+impl CreatePasswordPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePasswordPolicyPlan) -> Result<Self> {
+        Ok(CreatePasswordPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePasswordPolicyInterpreter {
+    fn name(&self) -> &str {
+        "CreatePasswordPolicyInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_password_policy_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let password_policy = PasswordPolicy {
+            name: plan.name,
+            min_length: plan.min_length,
+            max_length: plan.max_length,
+            min_upper_case_chars: plan.min_upper_case_chars,
+            min_lower_case_chars: plan.min_lower_case_chars,
+            min_numeric_chars: plan.min_numeric_chars,
+            min_special_chars: plan.min_special_chars,
+            min_age_days: plan.min_age_days,
+            max_age_days: plan.max_age_days,
+            max_retries: plan.max_retries,
+            lockout_time_mins: plan.lockout_time_mins,
+            history: plan.history,
+            comment: plan.comment,
+            create_on: Utc::now(),
+            update_on: None,
+        };
+        user_mgr
+            .add_password_policy(&tenant, password_policy, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -47,6 +47,8 @@ use parking_lot::RwLock;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_replace_constraints;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::common::hook_compact;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
@@ -141,6 +143,8 @@ impl ReplaceInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        check_replace_constraints(table.as_ref(), &plan.on_conflict_fields)?;
+        check_user_storage_quota(self.ctx.clone(), &plan.database, table.as_ref()).await?;
 
         let catalog = self.ctx.get_catalog(&plan.catalog).await?;
         let schema = table.schema();
@@ -169,6 +173,12 @@ impl ReplaceInterpreter {
                     table.name(),
                     table.get_table_info().engine(),
                 )))?;
+        if fuse_table.partition_by().is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "table {} is partitioned, which does not support REPLACE INTO",
+                table.name(),
+            )));
+        }
 
         let table_info = fuse_table.get_table_info();
         let base_snapshot = fuse_table.read_table_snapshot().await?.unwrap_or_else(|| {
@@ -403,14 +413,13 @@ impl ReplaceInterpreter {
         Option<ReplaceSelectCtx>,
         Option<BindContext>,
     )> {
-        let (s_expr, metadata, bind_context, formatted_ast) = match query_plan {
+        let (s_expr, metadata, bind_context) = match query_plan {
             Plan::Query {
                 s_expr,
                 metadata,
                 bind_context,
-                formatted_ast,
                 ..
-            } => (s_expr, metadata, bind_context, formatted_ast),
+            } => (s_expr, metadata, bind_context),
             v => unreachable!("Input plan must be Query, but it's {}", v),
         };
 
@@ -419,7 +428,6 @@ impl ReplaceInterpreter {
             *(bind_context.clone()),
             *s_expr.clone(),
             metadata.clone(),
-            formatted_ast.clone(),
             false,
         )?;
 
@@ -30,9 +30,10 @@ use common_profile::SharedProcessorProfiles;
 use common_sql::executor::physical_plans::FragmentKind;
 use common_sql::executor::PhysicalPlan;
 use common_sql::parse_result_scan_args;
+use common_sql::plan_result_cache_key;
 use common_sql::ColumnBinding;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
+use common_storages_result_cache::ResultCacheKey;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::WriteResultCacheSink;
 use common_users::UserApiProvider;
@@ -54,7 +55,6 @@ pub struct SelectInterpreter {
     s_expr: SExpr,
     bind_context: BindContext,
     metadata: MetadataRef,
-    formatted_ast: Option<String>,
     ignore_result: bool,
 }
 
@@ -64,7 +64,6 @@ impl SelectInterpreter {
         bind_context: BindContext,
         s_expr: SExpr,
         metadata: MetadataRef,
-        formatted_ast: Option<String>,
         ignore_result: bool,
     ) -> Result<Self> {
         Ok(SelectInterpreter {
@@ -72,7 +71,6 @@ impl SelectInterpreter {
             s_expr,
             bind_context,
             metadata,
-            formatted_ast,
             ignore_result,
         })
     }
@@ -118,7 +116,7 @@ impl SelectInterpreter {
     /// Add pipelines for writing query result cache.
     fn add_result_cache(
         &self,
-        key: &str,
+        key: ResultCacheKey,
         schema: TableSchemaRef,
         pipeline: &mut Pipeline,
         kv_store: Arc<MetaStore>,
@@ -185,6 +183,18 @@ impl SelectInterpreter {
         Ok(())
     }
 
+    fn log_query_plan(&self, physical_plan: &PhysicalPlan) -> Result<()> {
+        let query_plan = physical_plan
+            .format(self.metadata.clone(), SharedProcessorProfiles::default())?
+            .format_pretty()?;
+        info!(
+            "Query id: {}, query plan: \n{}",
+            self.ctx.get_id(),
+            query_plan
+        );
+        Ok(())
+    }
+
     fn result_scan_table(&self) -> Result<Option<Arc<dyn Table>>> {
         let r_lock = self.metadata.read();
         let tables = r_lock.tables();
@@ -230,18 +240,7 @@ impl Interpreter for SelectInterpreter {
 
         self.ctx.set_status_info("preparing plan");
 
-        // 0. Need to build physical plan first to get the partitions.
-        let physical_plan = self.build_physical_plan().await?;
-        let query_plan = physical_plan
-            .format(self.metadata.clone(), SharedProcessorProfiles::default())?
-            .format_pretty()?;
-        info!(
-            "Query id: {}, query plan: \n{}",
-            self.ctx.get_id(),
-            query_plan
-        );
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
             // 1. Try to get result from cache.
             let kv_store = UserApiProvider::instance().get_meta_store_client();
 
@@ -262,40 +261,55 @@ impl Interpreter for SelectInterpreter {
                     self.ctx
                         .set_query_id_result_cache(self.ctx.get_id(), meta_key);
                 }
+                let physical_plan = self.build_physical_plan().await?;
+                self.log_query_plan(&physical_plan)?;
                 return self.build_pipeline(physical_plan).await;
             }
 
-            let cache_reader = ResultCacheReader::create(
-                self.ctx.clone(),
-                &key,
-                kv_store.clone(),
-                self.ctx
-                    .get_settings()
-                    .get_query_result_cache_allow_inconsistent()?,
-            );
-
-            // 2. Check the cache.
-            match cache_reader.try_read_cached_result().await {
-                Ok(Some(blocks)) => {
-                    // 2.0 update query_id -> result_cache_meta_key in session.
+            // The key is generated from the optimized plan and the versions of the tables.
+            if let Some(key) =
+                plan_result_cache_key(&self.s_expr, &self.metadata, &self.bind_context.columns)?
+            {
+                let cache_reader = ResultCacheReader::create(
+                    self.ctx.clone(),
+                    key.clone(),
+                    kv_store.clone(),
                     self.ctx
-                        .set_query_id_result_cache(self.ctx.get_id(), cache_reader.get_meta_key());
-                    // 2.1 If found, return the result directly.
-                    return PipelineBuildResult::from_blocks(blocks);
-                }
-                Ok(None) => {
-                    let mut build_res = self.build_pipeline(physical_plan).await?;
-                    // 2.2 If not found result in cache, add pipelines to write the result to cache.
-                    let schema = infer_table_schema(&self.bind_context.output_schema())?;
-                    self.add_result_cache(&key, schema, &mut build_res.main_pipeline, kv_store)?;
-                    return Ok(build_res);
-                }
-                Err(e) => {
-                    // 2.3 If an error occurs, turn back to the normal pipeline.
-                    error!("Failed to read query result cache. {}", e);
+                        .get_settings()
+                        .get_query_result_cache_allow_inconsistent()?,
+                );
+
+                // 2. Check the cache.
+                match cache_reader.try_read_cached_result().await {
+                    Ok(Some((meta_key, blocks))) => {
+                        // 2.0 update query_id -> result_cache_meta_key in session, only if the
+                        // cached rows are exactly the result, since `RESULT_SCAN` reads them all.
+                        if meta_key == cache_reader.get_meta_key() {
+                            self.ctx
+                                .set_query_id_result_cache(self.ctx.get_id(), meta_key);
+                        }
+                        // 2.1 If found, return the result directly.
+                        return PipelineBuildResult::from_blocks(blocks);
+                    }
+                    Ok(None) => {
+                        let physical_plan = self.build_physical_plan().await?;
+                        self.log_query_plan(&physical_plan)?;
+                        let mut build_res = self.build_pipeline(physical_plan).await?;
+                        // 2.2 If not found result in cache, add pipelines to write the result to cache.
+                        let schema = infer_table_schema(&self.bind_context.output_schema())?;
+                        self.add_result_cache(key, schema, &mut build_res.main_pipeline, kv_store)?;
+                        return Ok(build_res);
+                    }
+                    Err(e) => {
+                        // 2.3 If an error occurs, turn back to the normal pipeline.
+                        error!("Failed to read query result cache. {}", e);
+                    }
                 }
             }
         }
+
+        let physical_plan = self.build_physical_plan().await?;
+        self.log_query_plan(&physical_plan)?;
         // Not use query cache.
         self.build_pipeline(physical_plan).await
     }
@@ -27,6 +27,7 @@ use common_sql::plans::AddColumnOption;
 use common_sql::plans::AddTableColumnPlan;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
+use storages_common_table_meta::table::ColumnCodec;
 
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
@@ -103,6 +104,12 @@ impl Interpreter for AddTableColumnInterpreter {
                 AddColumnOption::End => new_table_meta.schema.num_fields(),
             };
             new_table_meta.add_column(&field, &self.plan.comment, index)?;
+            if let Some(codec) = self.plan.codec {
+                let mut schema = new_table_meta.schema.as_ref().clone();
+                let column_id = schema.field_with_name(field.name())?.column_id();
+                ColumnCodec::set(&mut schema, column_id, codec)?;
+                new_table_meta.schema = Arc::new(schema);
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_license::license::Feature;
+use common_license::license_manager::get_license_manager;
+use common_meta_api::RowAccessPolicyApi;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_meta_app::row_access_policy::TableRowAccessPolicy;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::SetTableRowAccessPolicyAction;
+use common_meta_app::schema::SetTableRowAccessPolicyReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::AddTableRowAccessPolicyPlan;
+use common_sql::resolve_type_name_by_str;
+use common_storages_share::save_share_table_info;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        if table_info.engine() == VIEW_ENGINE || table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support row access policy",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+        if let Some(attached) = &table_info.meta.row_access_policy {
+            return Err(ErrorCode::BadArguments(format!(
+                "table {}.{} already has row access policy {}, drop it first",
+                &self.plan.database, &self.plan.table, attached.name
+            )));
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy = meta_api
+            .get_row_access_policy(GetRowAccessPolicyReq {
+                name: RowAccessPolicyNameIdent {
+                    tenant: self.plan.tenant.clone(),
+                    name: self.plan.policy.clone(),
+                },
+            })
+            .await?
+            .policy;
+
+        // the columns are bound to the args of the policy in order
+        if policy.args.len() != self.plan.columns.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "row access policy {} has {} args, but got {} columns",
+                self.plan.policy,
+                policy.args.len(),
+                self.plan.columns.len()
+            )));
+        }
+        let schema = table.schema();
+        for ((arg_name, arg_type), column) in policy.args.iter().zip(self.plan.columns.iter()) {
+            let field = schema
+                .field_with_name(column)
+                .map_err(|_| ErrorCode::UnknownColumn(format!("Cannot find column {}", column)))?;
+            let arg_type = resolve_type_name_by_str(arg_type, true)?;
+            if field.data_type().remove_nullable() != arg_type.remove_nullable() {
+                return Err(ErrorCode::UnmatchColumnDataType(format!(
+                    "Column '{}' data type {} does not match to the type {} of arg '{}' of row access policy {}",
+                    column,
+                    field.data_type(),
+                    arg_type,
+                    arg_name,
+                    self.plan.policy
+                )));
+            }
+        }
+
+        let req = SetTableRowAccessPolicyReq {
+            tenant: self.plan.tenant.clone(),
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            action: SetTableRowAccessPolicyAction::Add(
+                TableRowAccessPolicy {
+                    name: self.plan.policy.clone(),
+                    columns: self.plan.columns.clone(),
+                },
+                None,
+            ),
+        };
+        let res = catalog.set_table_row_access_policy(req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.plan.tenant,
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -14,13 +14,18 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::str::FromStr;
 use std::sync::Arc;
 
+use common_ast::ast::Engine;
+use common_catalog::table::Table;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
-use common_expression::TableSchemaRefExt;
 use common_expression::BLOCK_NAME_COL_NAME;
 use common_expression::ROW_ID_COL_NAME;
 use common_expression::SEGMENT_NAME_COL_NAME;
@@ -37,13 +42,18 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
 use common_meta_types::MatchSeq;
 use common_sql::field_default_value;
+use common_sql::parse_computed_expr;
+use common_sql::plans::CloneTableSource;
 use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
 use common_storage::DataOperator;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseDeleteMode;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_DELETE_MODE;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -55,15 +65,21 @@ use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::ColumnCodec;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_OWNER_USER;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TTL;
+use storages_common_table_meta::table::OPT_KEY_UNIQUE_KEYS;
 
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
@@ -245,8 +261,15 @@ impl CreateTableInterpreter {
                 });
             }
         }
+        let clone_source = match &self.plan.clone_source {
+            Some(source) => Some(self.get_clone_source_table(source).await?),
+            None => None,
+        };
         let mut req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
             self.build_attach_request(storage_prefix).await
+        } else if let Some(source) = &clone_source {
+            self.build_clone_request(FuseTable::try_from_table(source.as_ref())?)
+                .await
         } else {
             self.build_request(stat)
         }?;
@@ -257,6 +280,24 @@ impl CreateTableInterpreter {
 
         let reply = catalog.create_table(req.clone()).await?;
 
+        if let Some(source) = &clone_source {
+            if reply.new_table {
+                let table = catalog
+                    .get_table(
+                        self.ctx.get_tenant().as_str(),
+                        &self.plan.database,
+                        &self.plan.table,
+                    )
+                    .await?;
+                FuseTable::try_from_table(table.as_ref())?
+                    .do_clone_from(
+                        self.ctx.as_ref(),
+                        FuseTable::try_from_table(source.as_ref())?,
+                    )
+                    .await?;
+            }
+        }
+
         // grant the ownership of the table to the current role, the above req.table_meta.owner could be removed in future.
         if let Some(current_role) = self.ctx.get_current_role() {
             let tenant = self.ctx.get_tenant();
@@ -298,7 +339,10 @@ impl CreateTableInterpreter {
         } else {
             self.plan.field_comments.clone()
         };
-        let schema = TableSchemaRefExt::create(fields);
+        // the column ids are reassigned, so do the column codecs
+        let mut schema = TableSchema::new(fields);
+        ColumnCodec::remap(&self.plan.schema, &mut schema)?;
+        let schema: TableSchemaRef = Arc::new(schema);
 
         let mut table_meta = TableMeta {
             schema: schema.clone(),
@@ -319,8 +363,12 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        // check delete_mode
+        is_valid_delete_mode(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check ttl
+        is_valid_ttl(self.ctx.clone(), &table_meta.options, schema)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -336,6 +384,14 @@ impl CreateTableInterpreter {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
 
+        // the storage of the table is charged to the quota of the user who created it.
+        if self.plan.engine == Engine::Fuse {
+            let user = self.ctx.get_current_user()?;
+            table_meta
+                .options
+                .insert(OPT_KEY_OWNER_USER.to_string(), user.name);
+        }
+
         let req = CreateTableReq {
             if_not_exists: self.plan.if_not_exists,
             name_ident: TableNameIdent {
@@ -349,6 +405,31 @@ impl CreateTableInterpreter {
         Ok(req)
     }
 
+    /// Get the source table of `CREATE TABLE ... CLONE`, at the point to be cloned.
+    async fn get_clone_source_table(&self, source: &CloneTableSource) -> Result<Arc<dyn Table>> {
+        let table = self
+            .ctx
+            .get_table(&source.catalog, &source.database, &source.table)
+            .await?;
+        match &source.point {
+            Some(point) => table.navigate_to(point).await,
+            None => Ok(table),
+        }
+    }
+
+    async fn build_clone_request(&self, source: &FuseTable) -> Result<CreateTableReq> {
+        let mut req = self.build_request(None)?;
+        if let Some(snapshot) = source.read_table_snapshot().await? {
+            // the column ids of the shared blocks must be kept
+            let table_meta = &mut req.table_meta;
+            if table_meta.field_comments.len() != snapshot.schema.num_fields() {
+                table_meta.field_comments = vec!["".to_string(); snapshot.schema.num_fields()];
+            }
+            table_meta.schema = Arc::new(snapshot.schema.clone());
+        }
+        Ok(req)
+    }
+
     async fn build_attach_request(&self, storage_prefix: &str) -> Result<CreateTableReq> {
         // Safe to unwrap in this function, as attach table must have storage params.
         let sp = self.plan.storage_params.as_ref().unwrap();
@@ -424,12 +505,17 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_DELETE_MODE);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_PRIMARY_KEY);
+    r.insert(OPT_KEY_UNIQUE_KEYS);
+    r.insert(OPT_KEY_PARTITION_BY);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -494,12 +580,48 @@ pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()>
     Ok(())
 }
 
+pub fn is_valid_delete_mode(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_DELETE_MODE) {
+        FuseDeleteMode::from_str(value)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_bloom_index_columns(
     options: &BTreeMap<String, String>,
     schema: TableSchemaRef,
 ) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_type)?;
+        BloomIndexColumns::verify_definition(
+            value,
+            schema,
+            BloomIndex::supported_type,
+            BloomIndex::supported_ngram_type,
+        )?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_ttl(
+    ctx: Arc<QueryContext>,
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    // an empty ttl means the table has no ttl
+    if let Some(value) = options.get(OPT_KEY_TTL).filter(|v| !v.is_empty()) {
+        let schema = Arc::new(DataSchema::from(schema));
+        let expr = parse_computed_expr(ctx, schema, value).map_err(|e| {
+            ErrorCode::TableOptionInvalid(format!("invalid ttl option '{value}': {}", e.message()))
+        })?;
+        if !matches!(
+            expr.data_type().remove_nullable(),
+            DataType::Date | DataType::Timestamp
+        ) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid ttl option '{value}', expect a DATE or TIMESTAMP expression, but got {}",
+                expr.data_type()
+            )));
+        }
     }
     Ok(())
 }
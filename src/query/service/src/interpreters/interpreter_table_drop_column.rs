@@ -25,9 +25,13 @@ use common_sql::plans::DropTableColumnPlan;
 use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
+use itertools::Itertools;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_constraints;
+use crate::interpreters::common::check_referenced_partition_keys;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -79,14 +83,35 @@ impl Interpreter for DropTableColumnInterpreter {
             )));
         }
 
+        // Check if this column is a key column of the constraints.
+        check_referenced_constraints(
+            table_info.options(),
+            &table_info.schema(),
+            self.plan.column.as_str(),
+        )?;
+        // Check if this column is referenced by the partition keys.
+        check_referenced_partition_keys(
+            self.ctx.clone(),
+            table.clone(),
+            self.plan.column.as_str(),
+        )?;
+
         let mut schema: DataSchema = table_info.schema().into();
         let field = schema.field_with_name(self.plan.column.as_str())?;
         if field.computed_expr().is_none() {
             schema.drop_column(self.plan.column.as_str())?;
+            let schema = Arc::new(schema);
             // Check if this column is referenced by computed columns.
             check_referenced_computed_columns(
                 self.ctx.clone(),
-                Arc::new(schema),
+                schema.clone(),
+                self.plan.column.as_str(),
+            )?;
+            // Check if this column is referenced by the ttl.
+            check_referenced_ttl(
+                self.ctx.clone(),
+                table_info.options(),
+                schema,
                 self.plan.column.as_str(),
             )?;
         }
@@ -100,10 +125,10 @@ impl Interpreter for DropTableColumnInterpreter {
         if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
             let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
             if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                if cols.iter().any(|x| x.name == self.plan.column) {
                     // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+                    cols.retain(|x| x.name != self.plan.column);
+                    *value = cols.iter().join(",");
                 }
             }
         }
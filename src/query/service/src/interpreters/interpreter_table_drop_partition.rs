@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::DropTablePartitionPlan;
+use common_storages_fuse::FuseTable;
+use storages_common_locks::LockManager;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTablePartitionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTablePartitionPlan,
+}
+
+impl DropTablePartitionInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTablePartitionPlan) -> Result<Self> {
+        Ok(DropTablePartitionInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTablePartitionInterpreter {
+    fn name(&self) -> &str {
+        "DropTablePartitionInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+
+        // check mutability
+        table.check_mutable()?;
+
+        // Add table lock.
+        let table_lock = LockManager::create_table_lock(table.get_table_info().clone())?;
+        let _guard = table_lock.try_lock(self.ctx.clone()).await?;
+
+        let ctx = self.ctx.clone() as Arc<dyn TableContext>;
+        let dropped = FuseTable::try_from_table(table.as_ref())?
+            .drop_partition(&ctx, &self.plan.values)
+            .await?;
+        if !dropped && !self.plan.if_exists {
+            return Err(ErrorCode::BadArguments(format!(
+                "partition ({}) does not exist in table {}.{}",
+                self.plan
+                    .values
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                self.plan.database,
+                self.plan.table
+            )));
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -30,6 +30,7 @@ use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
@@ -49,9 +50,13 @@ use common_users::UserApiProvider;
 use data_mask_feature::get_datamask_handler;
 use storages_common_index::BloomIndex;
 use storages_common_locks::LockManager;
+use storages_common_table_meta::table::is_lazy_evolvable;
+use storages_common_table_meta::table::ColumnCodec;
+use storages_common_table_meta::table::ColumnEvolution;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use super::common::check_referenced_computed_columns;
+use super::common::check_referenced_partition_keys;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -244,10 +249,14 @@ impl ModifyTableColumnInterpreter {
                         Arc::new(data_schema),
                         column,
                     )?;
+                    // Check if this column is referenced by the partition keys.
+                    check_referenced_partition_keys(self.ctx.clone(), table.clone(), column)?;
 
                     // If the column is defined in bloom index columns,
                     // check whether the data type is supported for bloom index.
-                    if bloom_index_cols.iter().any(|v| v.as_str() == column)
+                    if bloom_index_cols
+                        .iter()
+                        .any(|v| v.name == *column && v.ngram.is_none())
                         && !BloomIndex::supported_type(data_type)
                     {
                         return Err(ErrorCode::TableOptionInvalid(format!(
@@ -255,6 +264,16 @@ impl ModifyTableColumnInterpreter {
                             data_type
                         )));
                     }
+                    if bloom_index_cols
+                        .iter()
+                        .any(|v| v.name == *column && v.ngram.is_some())
+                        && !BloomIndex::supported_ngram_type(data_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for ngram bloom index",
+                            data_type
+                        )));
+                    }
                     new_schema.fields[i].data_type = data_type.clone();
                     table_info.meta.field_comments[i] = comment.to_string();
                 }
@@ -270,6 +289,16 @@ impl ModifyTableColumnInterpreter {
             return Ok(PipelineBuildResult::create());
         }
 
+        // Compatible type changes only update the table meta, the existing blocks
+        // will be cast to the new types while being read.
+        if let Some(evolved_schema) = Self::lazy_evolved_schema(fuse_table, &schema, &new_schema)? {
+            let mut new_table_meta = table_info.meta.clone();
+            new_table_meta.schema = evolved_schema.into();
+            return self
+                .update_table_meta(catalog, table.get_table_info(), new_table_meta)
+                .await;
+        }
+
         // Add table lock.
         let table_lock = LockManager::create_table_lock(table_info.clone())?;
         let lock_guard = table_lock.try_lock(self.ctx.clone()).await?;
@@ -348,6 +377,65 @@ impl ModifyTableColumnInterpreter {
         Ok(build_res)
     }
 
+    // Returns the schema with the data types of columns changed lazily, if all the type changes
+    // are compatible (e.g. INT32 to INT64), otherwise the table has to be rewritten.
+    //
+    // Tables in the native storage format and tables with a cluster key are always rewritten:
+    // the native block reader does not look up the columns a column was evolved from, and the
+    // cluster statistics of the existing blocks would keep the domains of the previous types.
+    fn lazy_evolved_schema(
+        fuse_table: &FuseTable,
+        schema: &TableSchema,
+        new_schema: &TableSchema,
+    ) -> Result<Option<TableSchema>> {
+        if fuse_table.is_native() || fuse_table.cluster_key_str().is_some() {
+            return Ok(None);
+        }
+
+        let mut evolved_schema = schema.clone();
+        for (i, (field, new_field)) in schema.fields().iter().zip(new_schema.fields()).enumerate() {
+            if field == new_field {
+                continue;
+            }
+            if field.default_expr() != new_field.default_expr()
+                || field.computed_expr().is_some()
+                || !is_lazy_evolvable(field.data_type(), new_field.data_type())
+            {
+                return Ok(None);
+            }
+            ColumnEvolution::evolve(&mut evolved_schema, i, new_field.data_type().clone())?;
+        }
+        Ok(Some(evolved_schema))
+    }
+
+    async fn update_table_meta(
+        &self,
+        catalog: Arc<dyn Catalog>,
+        table_info: &TableInfo,
+        new_table_meta: TableMeta,
+    ) -> Result<PipelineBuildResult> {
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+
     async fn do_convert_stored_computed_column(
         &self,
         catalog: Arc<dyn Catalog>,
@@ -387,29 +475,33 @@ impl ModifyTableColumnInterpreter {
         let mut new_table_meta = table_meta;
         new_table_meta.schema = new_schema.into();
 
-        let table_id = table_info.ident.table_id;
-        let table_version = table_info.ident.seq;
-
-        let req = UpdateTableMetaReq {
-            table_id,
-            seq: MatchSeq::Exact(table_version),
-            new_table_meta,
-            copied_files: None,
-            deduplicated_label: None,
-        };
+        self.update_table_meta(catalog, table_info, new_table_meta)
+            .await
+    }
 
-        let res = catalog.update_table_meta(table_info, req).await?;
+    // The codec only applies to the blocks written afterwards, the existing blocks are kept.
+    async fn do_set_codec(
+        &self,
+        catalog: Arc<dyn Catalog>,
+        table: &Arc<dyn Table>,
+        table_meta: TableMeta,
+        column: &str,
+        codec: ColumnCodec,
+    ) -> Result<PipelineBuildResult> {
+        let table_info = table.get_table_info();
+        let mut new_schema = table.schema().as_ref().clone();
+        let field = new_schema
+            .field_with_name(column)
+            .map_err(|_| ErrorCode::UnknownColumn(format!("Cannot find column {}", column)))?;
+        codec.check(column, field.data_type())?;
+        let column_id = field.column_id();
+        ColumnCodec::set(&mut new_schema, column_id, codec)?;
 
-        if let Some(share_table_info) = res.share_table_info {
-            save_share_table_info(
-                &self.ctx.get_tenant(),
-                self.ctx.get_data_operator()?.operator(),
-                share_table_info,
-            )
-            .await?;
-        }
+        let mut new_table_meta = table_meta;
+        new_table_meta.schema = new_schema.into();
 
-        Ok(PipelineBuildResult::create())
+        self.update_table_meta(catalog, table_info, new_table_meta)
+            .await
     }
 }
 
@@ -481,6 +573,10 @@ impl Interpreter for ModifyTableColumnInterpreter {
                 )
                 .await
             }
+            ModifyColumnAction::SetCodec(column, codec) => {
+                self.do_set_codec(catalog, table, table_meta, column, *codec)
+                    .await
+            }
         }
     }
 }
@@ -41,6 +41,7 @@ use storages_common_locks::LockManager;
 use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::interpreters::interpreter_table_recluster::build_recluster_physical_plan;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
 use crate::pipelines::executor::ExecutorSettings;
@@ -90,6 +91,7 @@ impl Interpreter for OptimizeTableInterpreter {
                     .await
             }
             OptimizeTableAction::Purge(point) => {
+                Self::drop_expired_blocks(self.ctx.clone(), catalog.clone(), table).await?;
                 purge(ctx, catalog, plan, point).await?;
                 Ok(PipelineBuildResult::create())
             }
@@ -139,6 +141,63 @@ impl OptimizeTableInterpreter {
         })))
     }
 
+    /// Remove the blocks whose rows are all expired according to the TTL of the table,
+    /// without rewriting the partially expired blocks.
+    ///
+    /// The dropped blocks are still kept by the previous snapshots of the table, which are
+    /// left to the purging of the table.
+    #[async_backtrace::framed]
+    pub async fn drop_expired_blocks(
+        ctx: Arc<QueryContext>,
+        catalog: Arc<dyn Catalog>,
+        table: Arc<dyn Table>,
+    ) -> Result<()> {
+        let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+            return Ok(());
+        };
+        let Some((filters, col_indices)) = fuse_table.ttl_expired_filters(ctx.clone())? else {
+            return Ok(());
+        };
+
+        let table_lock = LockManager::create_table_lock(table.get_table_info().clone())?;
+        let lock_guard = table_lock.try_lock(ctx.clone()).await?;
+
+        let Some(snapshot) = fuse_table.read_table_snapshot().await? else {
+            return Ok(());
+        };
+        let partitions = fuse_table
+            .expired_blocks_pruning(ctx.clone(), &snapshot, filters.clone(), col_indices.clone())
+            .await?;
+        if partitions.is_empty() {
+            return Ok(());
+        }
+
+        let physical_plan = DeleteInterpreter::build_physical_plan(
+            filters,
+            partitions,
+            table.get_table_info().clone(),
+            col_indices,
+            snapshot,
+            catalog.info(),
+            false,
+            false,
+        )?;
+        let mut build_res =
+            build_query_pipeline_without_render_result_set(&ctx, &physical_plan, false).await?;
+        build_res.main_pipeline.add_lock_guard(lock_guard);
+
+        let settings = ctx.get_settings();
+        build_res
+            .main_pipeline
+            .set_max_threads(settings.get_max_threads()? as usize);
+        let query_id = ctx.get_id();
+        let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let executor =
+            PipelineCompleteExecutor::try_create(build_res.main_pipeline, executor_settings)?;
+        ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+
     async fn build_pipeline(
         &self,
         catalog: Arc<dyn Catalog>,
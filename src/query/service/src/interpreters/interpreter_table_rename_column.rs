@@ -25,9 +25,12 @@ use common_sql::plans::RenameTableColumnPlan;
 use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
+use itertools::Itertools;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_partition_keys;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -82,6 +85,13 @@ impl Interpreter for RenameTableColumnInterpreter {
                 )));
             }
 
+            // Check if old column is referenced by the partition keys.
+            check_referenced_partition_keys(
+                self.ctx.clone(),
+                table.clone(),
+                self.plan.old_column.as_str(),
+            )?;
+
             let catalog = self.ctx.get_catalog(catalog_name).await?;
             let mut new_table_meta = table.get_table_info().meta.clone();
 
@@ -92,10 +102,18 @@ impl Interpreter for RenameTableColumnInterpreter {
             if field.computed_expr().is_none() {
                 let index = schema.index_of(self.plan.old_column.as_str())?;
                 schema.rename_field(index, self.plan.new_column.as_str());
+                let schema = Arc::new(schema);
                 // Check if old column is referenced by computed columns.
                 check_referenced_computed_columns(
                     self.ctx.clone(),
-                    Arc::new(schema),
+                    schema.clone(),
+                    self.plan.old_column.as_str(),
+                )?;
+                // Check if old column is referenced by the ttl.
+                check_referenced_ttl(
+                    self.ctx.clone(),
+                    table_info.options(),
+                    schema,
                     self.plan.old_column.as_str(),
                 )?;
             }
@@ -107,10 +125,12 @@ impl Interpreter for RenameTableColumnInterpreter {
             if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
                 let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
                 if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
+                    if cols.iter().any(|x| x.name == self.plan.old_column) {
                         // replace the bloom index columns with new column name.
-                        cols[pos] = self.plan.new_column.clone();
-                        *value = cols.join(",");
+                        for col in cols.iter_mut().filter(|x| x.name == self.plan.old_column) {
+                            col.name = self.plan.new_column.clone();
+                        }
+                        *value = cols.iter().join(",");
                     }
                 }
             }
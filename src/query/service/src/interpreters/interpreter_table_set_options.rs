@@ -23,13 +23,17 @@ use common_meta_types::MatchSeq;
 use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
 use log::error;
+use storages_common_table_meta::table::is_reserved_opt_key;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_delete_mode;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -59,6 +63,8 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check delete_mode
+        is_valid_delete_mode(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -77,12 +83,17 @@ impl Interpreter for SetOptionsInterpreter {
         }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
-            if !is_valid_create_opt(&key) {
+            if !is_valid_create_opt(&key) || is_reserved_opt_key(&key) {
                 error!("{}", &error_str);
                 return Err(ErrorCode::TableOptionInvalid(format!(
                     "table option {key} is invalid for alter table statement",
                 )));
             }
+            // an empty ttl removes the ttl of the table
+            if key == OPT_KEY_TTL && table_option.1.is_empty() {
+                options_map.insert(key, None);
+                continue;
+            }
             options_map.insert(key, Some(table_option.1.clone()));
         }
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
@@ -108,6 +119,8 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ttl
+        is_valid_ttl(self.ctx.clone(), &self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
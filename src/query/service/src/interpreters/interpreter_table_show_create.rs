@@ -28,6 +28,9 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::ColumnCodec;
+use storages_common_table_meta::table::UniqueConstraint;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
@@ -90,6 +93,7 @@ impl ShowCreateTableInterpreter {
 
         // Append columns.
         {
+            let codecs = ColumnCodec::load_fields(&schema)?;
             let mut columns = vec![];
             for (idx, field) in schema.fields().iter().enumerate() {
                 let nullable = if field.is_nullable() {
@@ -112,6 +116,10 @@ impl ShowCreateTableInterpreter {
                     }
                     _ => "".to_string(),
                 };
+                let codec = match &codecs[idx] {
+                    Some(codec) => format!(" CODEC({codec})"),
+                    None => "".to_string(),
+                };
                 // compatibility: creating table in the old planner will not have `fields_comments`
                 let comment = if field_comments.len() == n_fields && !field_comments[idx].is_empty()
                 {
@@ -124,17 +132,31 @@ impl ShowCreateTableInterpreter {
                     "".to_string()
                 };
                 let column = format!(
-                    "  `{}` {}{}{}{}{}",
+                    "  `{}` {}{}{}{}{}{}",
                     field.name(),
                     field.data_type().remove_recursive_nullable().sql_name(),
                     nullable,
                     default_expr,
                     computed_expr,
+                    codec,
                     comment
                 );
 
                 columns.push(column);
             }
+            for constraint in UniqueConstraint::from_table_options(table.options())? {
+                let keys = constraint
+                    .column_names(&schema)?
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if constraint.is_primary_key {
+                    columns.push(format!("  PRIMARY KEY ({keys})"));
+                } else {
+                    columns.push(format!("  UNIQUE ({keys})"));
+                }
+            }
             // Format is:
             //  (
             //      x,
@@ -151,6 +173,9 @@ impl ShowCreateTableInterpreter {
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
+        if let Some(partition_keys_str) = table_info.options().get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(format!(" PARTITION BY ({})", partition_keys_str).as_str());
+        }
 
         let settings = self.ctx.get_settings();
         let hide_options_in_show_create_table = settings
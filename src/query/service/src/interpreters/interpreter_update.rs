@@ -32,6 +32,8 @@ use log::debug;
 use storages_common_locks::LockManager;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_mutation_constraints;
+use crate::interpreters::common::check_referenced_partition_keys;
 use crate::interpreters::common::hook_refresh_agg_index;
 use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::interpreter_delete::replace_subquery;
@@ -83,6 +85,15 @@ impl Interpreter for UpdateInterpreter {
         // check mutability
         tbl.check_mutable()?;
 
+        let updated_columns = self.plan.update_list.keys().cloned().collect::<Vec<_>>();
+        check_mutation_constraints(tbl.as_ref(), "UPDATE", &updated_columns, false)?;
+
+        // the rows can not be moved between the partitions.
+        for index in self.plan.update_list.keys() {
+            let field = tbl.schema().field(*index).clone();
+            check_referenced_partition_keys(self.ctx.clone(), tbl.clone(), field.name())?;
+        }
+
         // Add table lock.
         let table_lock = LockManager::create_table_lock(tbl.get_table_info().clone())?;
         let lock_guard = table_lock.try_lock(self.ctx.clone()).await?;
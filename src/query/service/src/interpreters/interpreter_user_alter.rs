@@ -51,7 +51,17 @@ impl Interpreter for AlterUserInterpreter {
         let tenant = self.ctx.get_tenant();
         if plan.auth_info.is_some() || plan.user_option.is_some() {
             UserApiProvider::instance()
-                .update_user(&tenant, plan.user, plan.auth_info, plan.user_option)
+                .update_user(&tenant, plan.user.clone(), plan.auth_info, plan.user_option)
+                .await?;
+        }
+        if let Some(quota) = plan.quota {
+            UserApiProvider::instance()
+                .update_user_quota(&tenant, plan.user.clone(), quota)
+                .await?;
+        }
+        if plan.unlock {
+            UserApiProvider::instance()
+                .clear_user_login_fail(&tenant, plan.user)
                 .await?;
         }
 
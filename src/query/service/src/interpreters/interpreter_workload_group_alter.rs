@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::AlterWorkloadGroupPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupManager;
+
+#[derive(Debug)]
+pub struct AlterWorkloadGroupInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterWorkloadGroupPlan,
+}
+
+impl AlterWorkloadGroupInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterWorkloadGroupPlan) -> Result<Self> {
+        Ok(AlterWorkloadGroupInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterWorkloadGroupInterpreter {
+    fn name(&self) -> &str {
+        "AlterWorkloadGroupInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_workload_group_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
+            .update_workload_group(&tenant, &plan.name, plan.if_exists, |group| {
+                if let Some(max_concurrency) = plan.max_concurrency {
+                    group.max_concurrency = max_concurrency;
+                }
+                if let Some(memory_percentage) = plan.memory_percentage {
+                    group.memory_percentage = memory_percentage;
+                }
+                if let Some(cpu_weight) = plan.cpu_weight {
+                    group.cpu_weight = cpu_weight;
+                }
+                if let Some(queue_timeout_secs) = plan.queue_timeout_secs {
+                    group.queue_timeout_secs = queue_timeout_secs;
+                }
+                if let Some(users) = plan.users {
+                    group.users = users;
+                }
+                if let Some(roles) = plan.roles {
+                    group.roles = roles;
+                }
+            })
+            .await?;
+
+        WorkloadGroupManager::instance().invalidate(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
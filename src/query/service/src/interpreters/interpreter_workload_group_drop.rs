@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::DropWorkloadGroupPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupManager;
+
+#[derive(Debug)]
+pub struct DropWorkloadGroupInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropWorkloadGroupPlan,
+}
+
+impl DropWorkloadGroupInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropWorkloadGroupPlan) -> Result<Self> {
+        Ok(DropWorkloadGroupInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropWorkloadGroupInterpreter {
+    fn name(&self) -> &str {
+        "DropWorkloadGroupInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "drop_workload_group_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
+            .drop_workload_group(&tenant, &plan.name, plan.if_exists)
+            .await?;
+
+        WorkloadGroupManager::instance().invalidate(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct ShowWorkloadGroupsInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl ShowWorkloadGroupsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(ShowWorkloadGroupsInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ShowWorkloadGroupsInterpreter {
+    fn name(&self) -> &str {
+        "ShowWorkloadGroupsInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+        let mut workload_groups = user_mgr.get_workload_groups(&tenant).await?;
+        workload_groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut names = Vec::with_capacity(workload_groups.len());
+        let mut max_concurrencies = Vec::with_capacity(workload_groups.len());
+        let mut memory_percentages = Vec::with_capacity(workload_groups.len());
+        let mut cpu_weights = Vec::with_capacity(workload_groups.len());
+        let mut queue_timeouts = Vec::with_capacity(workload_groups.len());
+        let mut users = Vec::with_capacity(workload_groups.len());
+        let mut roles = Vec::with_capacity(workload_groups.len());
+        for workload_group in workload_groups {
+            names.push(workload_group.name.as_bytes().to_vec());
+            max_concurrencies.push(workload_group.max_concurrency);
+            memory_percentages.push(workload_group.memory_percentage);
+            cpu_weights.push(workload_group.cpu_weight);
+            queue_timeouts.push(workload_group.queue_timeout_secs);
+            users.push(workload_group.users.join(",").as_bytes().to_vec());
+            roles.push(workload_group.roles.join(",").as_bytes().to_vec());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(max_concurrencies),
+            UInt64Type::from_data(memory_percentages),
+            UInt64Type::from_data(cpu_weights),
+            UInt64Type::from_data(queue_timeouts),
+            StringType::from_data(users),
+            StringType::from_data(roles),
+        ])])
+    }
+}
@@ -54,6 +54,11 @@ mod interpreter_network_policy_alter;
 mod interpreter_network_policy_create;
 mod interpreter_network_policy_desc;
 mod interpreter_network_policy_drop;
+mod interpreter_password_policies_show;
+mod interpreter_password_policy_alter;
+mod interpreter_password_policy_create;
+mod interpreter_password_policy_desc;
+mod interpreter_password_policy_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -65,6 +70,9 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
@@ -81,17 +89,24 @@ mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
+mod interpreter_table_create_tag;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_partition;
+mod interpreter_table_drop_row_access_policy;
+mod interpreter_table_drop_tag;
 mod interpreter_table_exists;
+mod interpreter_table_merge_branch;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
+mod interpreter_table_replace_partition;
 mod interpreter_table_revert;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
@@ -124,6 +139,10 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
+mod interpreter_workload_groups_show;
 
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
@@ -152,6 +171,11 @@ pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
 pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
 pub use interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
 pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_password_policies_show::ShowPasswordPoliciesInterpreter;
+pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
+pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
+pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
+pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
@@ -161,6 +185,9 @@ pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
@@ -176,17 +203,24 @@ pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
+pub use interpreter_table_create_tag::CreateTableTagInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_partition::DropTablePartitionInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
+pub use interpreter_table_drop_tag::DropTableTagInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
+pub use interpreter_table_merge_branch::MergeTableBranchInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_replace_partition::ReplaceTablePartitionInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
@@ -211,3 +245,7 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
+pub use interpreter_workload_groups_show::ShowWorkloadGroupsInterpreter;
@@ -39,9 +39,11 @@
 extern crate core;
 
 pub mod api;
+pub mod audit;
 pub mod auth;
 pub mod catalogs;
 pub mod clusters;
+pub mod compaction;
 pub mod databases;
 pub mod interpreters;
 pub mod local;
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+
+pub const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+/// The key of the schema metadata of an Arrow result, holds the JSON response without `data`.
+pub const ARROW_METADATA_QUERY_RESPONSE: &str = "query_response";
+
+/// The rows of a result page, kept as [DataBlock]s and encoded as an Arrow IPC stream
+/// only when the page is sent to the client.
+#[derive(Debug, Clone)]
+pub struct ArrowBlock {
+    pub(crate) blocks: Vec<DataBlock>,
+    pub(crate) schema: DataSchemaRef,
+}
+
+impl ArrowBlock {
+    pub fn new(schema: DataSchemaRef, blocks: Vec<DataBlock>) -> Self {
+        ArrowBlock { blocks, schema }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.blocks.iter().map(|b| b.num_rows()).sum()
+    }
+
+    pub fn schema(&self) -> &DataSchemaRef {
+        &self.schema
+    }
+
+    /// Encodes the blocks as an Arrow IPC stream, one record batch per block. The `metadata`
+    /// is attached to the schema message of the stream.
+    pub fn encode(&self, metadata: HashMap<String, String>) -> Result<Vec<u8>> {
+        let to_error = |e| ErrorCode::Internal(format!("fail to encode arrow ipc stream: {e:?}"));
+
+        let schema = ArrowSchema::from(self.schema.as_ref()).with_metadata(metadata);
+        let mut writer = StreamWriter::try_new(vec![], &schema).map_err(to_error)?;
+        // a record batch needs at least one column, e.g. the results of DDL have no column.
+        if !schema.fields().is_empty() {
+            for block in &self.blocks {
+                if block.is_empty() {
+                    continue;
+                }
+                let batch = block
+                    .clone()
+                    .to_record_batch(&self.schema)
+                    .map_err(to_error)?;
+                writer.write(&batch).map_err(to_error)?;
+            }
+        }
+        writer.finish().map_err(to_error)?;
+        writer.into_inner().map_err(to_error)
+    }
+}
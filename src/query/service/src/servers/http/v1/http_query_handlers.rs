@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_base::base::mask_connection_info;
 use common_exception::ErrorCode;
 use common_expression::DataSchemaRef;
@@ -24,12 +26,15 @@ use minitrace::prelude::*;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::http::header;
+use poem::http::HeaderMap;
 use poem::http::StatusCode;
 use poem::post;
 use poem::web::Json;
 use poem::web::Path;
 use poem::EndpointExt;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -39,12 +44,19 @@ use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
 use crate::servers::http::middleware::MetricsMiddleware;
+use crate::servers::http::v1::arrow_block::ARROW_METADATA_QUERY_RESPONSE;
+use crate::servers::http::v1::arrow_block::ARROW_STREAM_CONTENT_TYPE;
+use crate::servers::http::v1::query::PageData;
+use crate::servers::http::v1::query::PersistedResult;
+use crate::servers::http::v1::query::PersistedResultMeta;
 use crate::servers::http::v1::query::Progresses;
+use crate::servers::http::v1::query::ResultFormat;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::http::v1::JsonBlock;
 use crate::sessions::QueryAffect;
+use crate::sessions::SessionType;
 
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
@@ -137,28 +149,28 @@ impl QueryResponse {
     ) -> impl IntoResponse {
         let state = r.state.clone();
         let (data, next_uri) = if is_final {
-            (JsonBlock::empty(), None)
+            (None, None)
         } else {
             match state.state {
                 ExecuteStateKind::Running => match r.data {
-                    None => (JsonBlock::empty(), Some(make_state_uri(&id))),
+                    None => (None, Some(make_state_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_state_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d.page.data), uri)
                     }
                 },
-                ExecuteStateKind::Failed => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                ExecuteStateKind::Failed => (None, Some(make_final_uri(&id))),
                 ExecuteStateKind::Succeeded => match r.data {
-                    None => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                    None => (None, Some(make_final_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_final_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d.page.data), uri)
                     }
                 },
             }
@@ -168,15 +180,23 @@ impl QueryResponse {
             metrics_incr_http_response_errors_count(err.name(), err.code());
         }
 
-        let schema = data.schema().clone();
+        // the responses without a page, e.g. of a failed query, are always in JSON.
+        let (data, arrow_block) = match data {
+            Some(PageData::Json(block)) => (block, None),
+            Some(PageData::Arrow(block)) => (JsonBlock::empty(), Some(block)),
+            None => (JsonBlock::empty(), None),
+        };
+        let (schema, rows) = match &arrow_block {
+            Some(block) => (block.schema().clone(), block.num_rows()),
+            None => (data.schema().clone(), data.num_rows()),
+        };
         let session_id = r.session_id.clone();
         let stats = QueryStats {
             progresses: state.progresses.clone(),
             running_time_ms: state.running_time_ms,
         };
-        let rows = data.data.len();
 
-        Json(QueryResponse {
+        let response = QueryResponse {
             data: data.into(),
             state: state.state,
             schema: QueryResponseField::from_schema(schema),
@@ -191,10 +211,33 @@ impl QueryResponse {
             final_uri: Some(make_final_uri(&id)),
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
-        })
-        .with_header(HEADER_QUERY_ID, id.clone())
-        .with_header(HEADER_QUERY_STATE, state.state.to_string())
-        .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        };
+        let response = match arrow_block {
+            None => Json(response).into_response(),
+            Some(block) => {
+                let body = serde_json::to_string(&response)
+                    .map_err(ErrorCode::from)
+                    .and_then(|response| {
+                        block.encode(HashMap::from([(
+                            ARROW_METADATA_QUERY_RESPONSE.to_string(),
+                            response,
+                        )]))
+                    });
+                match body {
+                    Ok(body) => Response::builder()
+                        .content_type(ARROW_STREAM_CONTENT_TYPE)
+                        .body(body),
+                    Err(e) => {
+                        PoemError::from_string(e.message(), StatusCode::INTERNAL_SERVER_ERROR)
+                            .into_response()
+                    }
+                }
+            }
+        };
+        response
+            .with_header(HEADER_QUERY_ID, id.clone())
+            .with_header(HEADER_QUERY_STATE, state.state.to_string())
+            .with_header(HEADER_QUERY_PAGE_ROWS, rows)
     }
 
     pub(crate) fn fail_to_start_sql(err: &ErrorCode) -> impl IntoResponse {
@@ -243,7 +286,20 @@ async fn query_final_handler(
                 }
                 Ok(QueryResponse::from_internal(query_id, response, true))
             }
-            None => Err(query_id_not_found(&query_id, &ctx.node_id)),
+            None => {
+                let (_, meta) = load_persisted_result(ctx, &query_id).await?;
+                if meta.state == ExecuteStateKind::Running {
+                    return Err(PoemError::from_string(
+                        format!("query {} is still running, can not final it", query_id),
+                        StatusCode::BAD_REQUEST,
+                    ));
+                }
+                Ok(QueryResponse::from_internal(
+                    query_id,
+                    meta.to_response(None),
+                    true,
+                ))
+            }
         }
     }
     .in_span(root)
@@ -253,7 +309,7 @@ async fn query_final_handler(
 // currently implementation only support kill http query
 #[poem::handler]
 async fn query_cancel_handler(
-    _ctx: &HttpQueryContext,
+    ctx: &HttpQueryContext,
     Path(query_id): Path<String>,
 ) -> impl IntoResponse {
     let trace_id = query_id_to_trace_id(&query_id);
@@ -271,7 +327,23 @@ async fn query_cancel_handler(
                 http_query_manager.remove_query(&query_id).await;
                 StatusCode::OK
             }
-            None => StatusCode::NOT_FOUND,
+            // the async query may run on another node, which picks up the kill request from the storage.
+            None => match load_persisted_result(ctx, &query_id).await {
+                Ok((store, meta)) if meta.state == ExecuteStateKind::Running => {
+                    match store.request_kill().await {
+                        Ok(_) => StatusCode::OK,
+                        Err(e) => {
+                            error!(
+                                "{}: fail to request to kill async http query: {:?}",
+                                query_id, e
+                            );
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        }
+                    }
+                }
+                Ok(_) => StatusCode::OK,
+                Err(_) => StatusCode::NOT_FOUND,
+            },
         }
     }
     .in_span(root)
@@ -296,7 +368,11 @@ async fn query_state_handler(
                 let response = query.get_response_state_only().await;
                 Ok(QueryResponse::from_internal(query_id, response, false))
             }
-            None => Err(query_id_not_found(&query_id, &ctx.node_id)),
+            None => {
+                let (_, meta) = load_persisted_result(ctx, &query_id).await?;
+                let response = meta.response_state_only();
+                Ok(QueryResponse::from_internal(query_id, response, false))
+            }
         }
     }
     .in_span(root)
@@ -325,23 +401,79 @@ async fn query_page_handler(
                 query.update_expire_time(false).await;
                 Ok(QueryResponse::from_internal(query_id, resp, false))
             }
-            None => Err(query_id_not_found(&query_id, &ctx.node_id)),
+            None => {
+                // the async query may be submitted to another node, or removed after finished.
+                let (store, meta) = load_persisted_result(ctx, &query_id).await?;
+                let data = store.get_page(&meta, page_no).await.map_err(|err| {
+                    poem::Error::from_string(err.message(), StatusCode::NOT_FOUND)
+                })?;
+                let resp = meta.to_response(Some(data));
+                Ok(QueryResponse::from_internal(query_id, resp, false))
+            }
         }
     }
     .in_span(root)
     .await
 }
 
+/// Returns the whole result of a finished async query at once.
+#[poem::handler]
+async fn query_result_handler(
+    ctx: &HttpQueryContext,
+    Path(query_id): Path<String>,
+) -> PoemResult<impl IntoResponse> {
+    let trace_id = query_id_to_trace_id(&query_id);
+    let root = Span::root(
+        full_name!(),
+        SpanContext::new(trace_id, SpanId(rand::random())),
+    );
+
+    async {
+        let (store, meta) = load_persisted_result(ctx, &query_id).await?;
+        let data = match meta.state {
+            ExecuteStateKind::Running => {
+                return Err(PoemError::from_string(
+                    format!(
+                        "query {} is still running, can not fetch its result",
+                        query_id
+                    ),
+                    StatusCode::BAD_REQUEST,
+                ));
+            }
+            ExecuteStateKind::Failed => None,
+            ExecuteStateKind::Succeeded => Some(store.get_all(&meta).await.map_err(|err| {
+                let status = if err.code() == ErrorCode::BAD_ARGUMENTS {
+                    StatusCode::BAD_REQUEST
+                } else {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                };
+                PoemError::from_string(err.message(), status)
+            })?),
+        };
+        Ok(QueryResponse::from_internal(
+            query_id,
+            meta.to_response(data),
+            false,
+        ))
+    }
+    .in_span(root)
+    .await
+}
+
 #[poem::handler]
 #[async_backtrace::framed]
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
-    Json(req): Json<HttpQueryRequest>,
+    headers: &HeaderMap,
+    Json(mut req): Json<HttpQueryRequest>,
 ) -> PoemResult<impl IntoResponse> {
     let trace_id = query_id_to_trace_id(&ctx.query_id);
     let root = Span::root(full_name!(), SpanContext::new(trace_id, SpanId::default()));
 
     async {
+        if accepts_arrow(headers) {
+            req.result_format = ResultFormat::Arrow;
+        }
         info!("http query new request: {:}", mask_connection_info(&format!("{:?}", req)));
         let http_query_manager = HttpQueryManager::instance();
         let sql = req.sql.clone();
@@ -351,6 +483,14 @@ pub(crate) async fn query_handler(
             .await
             .map_err(|err| err.display_with_sql(&sql));
         match query {
+            Ok(query) if query.is_async() => {
+                let resp = query.get_response_state_only().await;
+                info!(
+                    "http query submitted async, query_id={}, sql='{}'",
+                    &query.id, mask_connection_info(&sql)
+                );
+                Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
+            }
             Ok(query) => {
                 query.update_expire_time(true).await;
                 let resp = query
@@ -385,6 +525,7 @@ pub fn query_route() -> Route {
         ("/", post(query_handler)),
         ("/:id", get(query_state_handler)),
         ("/:id/page/:page_no", get(query_page_handler)),
+        ("/:id/result", get(query_result_handler)),
         (
             "/:id/kill",
             get(query_cancel_handler).post(query_cancel_handler),
@@ -409,6 +550,38 @@ fn query_id_not_found(query_id: &str, node_id: &str) -> PoemError {
     )
 }
 
+/// Loads the persisted result of an async query, which is only visible to the user who submitted it.
+async fn load_persisted_result(
+    ctx: &HttpQueryContext,
+    query_id: &str,
+) -> PoemResult<(PersistedResult, PersistedResultMeta)> {
+    let session = ctx.get_session(SessionType::HTTPQuery);
+    let store = PersistedResult::create(&session.get_current_tenant(), query_id);
+    let meta = store
+        .read_meta()
+        .await
+        .map_err(|err| PoemError::from_string(err.message(), StatusCode::INTERNAL_SERVER_ERROR))?;
+    let user = session.get_current_user().map(|u| u.identity().to_string());
+    match meta {
+        Some(meta) if user.as_ref().ok() == Some(&meta.user) => Ok((store, meta)),
+        _ => Err(query_id_not_found(query_id, &ctx.node_id)),
+    }
+}
+
+fn accepts_arrow(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media_type| {
+            let media_type = media_type.split(';').next().unwrap_or_default();
+            media_type
+                .trim()
+                .eq_ignore_ascii_case(ARROW_STREAM_CONTENT_TYPE)
+        })
+}
+
 fn query_id_to_trace_id(query_id: &str) -> TraceId {
     let [hash_high, hash_low] = highway::PortableHash::default().hash128(query_id.as_bytes());
     TraceId(((hash_high as u128) << 64) + (hash_low as u128))
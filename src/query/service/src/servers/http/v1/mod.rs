@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod arrow_block;
 mod http_query_handlers;
 pub mod json_block;
 mod load;
@@ -19,6 +20,7 @@ mod query;
 mod stage;
 mod suggestions;
 
+pub(crate) use arrow_block::ArrowBlock;
 pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
@@ -28,12 +30,15 @@ pub use http_query_handlers::QueryStats;
 pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
 pub use load::LoadResponse;
+pub use query::sweep_all_expired_results;
+pub use query::sweep_expired_results;
 pub use query::ExecuteStateKind;
 pub use query::ExpiringMap;
 pub use query::ExpiringState;
 pub use query::HttpQueryContext;
 pub use query::HttpQueryManager;
 pub use query::HttpSessionConf;
+pub use query::PersistedResult;
 pub use stage::upload_to_stage;
 pub use stage::UploadToStageResponse;
 pub use suggestions::list_suggestions;
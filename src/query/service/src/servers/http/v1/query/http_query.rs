@@ -18,14 +18,18 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+use chrono::Utc;
 use common_base::base::tokio;
 use common_base::base::tokio::sync::Mutex as TokioMutex;
 use common_base::base::tokio::sync::RwLock;
+use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::GlobalQueryRuntime;
 use common_base::runtime::TrySpawn;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::DataSchemaRef;
+use log::error;
 use log::info;
 use log::warn;
 use minitrace::prelude::*;
@@ -44,10 +48,15 @@ use crate::servers::http::v1::query::sized_spsc::sized_spsc;
 use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
+use crate::servers::http::v1::query::Page;
+use crate::servers::http::v1::query::PageData;
 use crate::servers::http::v1::query::PageManager;
+use crate::servers::http::v1::query::PersistedResult;
+use crate::servers::http::v1::query::PersistedResultMeta;
 use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::servers::http::v1::JsonBlock;
 use crate::sessions::short_sql;
 use crate::sessions::QueryAffect;
 use crate::sessions::SessionType;
@@ -67,6 +76,13 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    /// The encoding of the result pages, may also be negotiated by the `Accept` header.
+    #[serde(default)]
+    pub result_format: ResultFormat,
+    /// Returns the query id immediately and persists the result pages to the storage, they
+    /// can be fetched from any query node until the retention timeout, always in JSON.
+    #[serde(default)]
+    pub r#async: bool,
 }
 
 impl Debug for HttpQueryRequest {
@@ -78,6 +94,8 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("result_format", &self.result_format)
+            .field("async", &self.r#async)
             .finish()
     }
 }
@@ -129,6 +147,17 @@ impl PaginationConf {
     }
 }
 
+#[derive(Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultFormat {
+    /// Rows of strings in the `data` field of the JSON response.
+    #[default]
+    Json,
+    /// An Arrow IPC stream in the response body, the other fields of the response are in the
+    /// metadata of its schema.
+    Arrow,
+}
+
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq, Eq, Clone)]
 pub struct HttpSessionConf {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -190,6 +219,9 @@ pub struct HttpQuery {
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
     page_manager: Arc<TokioMutex<PageManager>>,
+    schema: DataSchemaRef,
+    /// Set for the async query, the pages are served from the storage.
+    result_store: Option<Arc<PersistedResult>>,
     expire_state: Arc<TokioMutex<ExpireState>>,
     /// The timeout for the query result polling. In the normal case, the client driver
     /// should fetch the paginated result in a timely manner, and the interval should not
@@ -281,6 +313,9 @@ impl HttpQuery {
 
         let settings = session.get_settings();
         let result_timeout_secs = settings.get_http_handler_result_timeout_secs()?;
+        let retention_secs = settings.get_http_handler_async_result_retention_secs()?;
+        let tenant = session.get_current_tenant();
+        let user = session.get_current_user()?.identity().to_string();
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
         let query_id = ctx.query_id.clone();
@@ -330,6 +365,32 @@ impl HttpQuery {
         let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
         let schema = plan.schema();
 
+        // The initial meta makes the async query visible to the other query nodes before it starts.
+        let result_store = if request.r#async {
+            let store = PersistedResult::create(&tenant, &query_id);
+            let meta = PersistedResultMeta {
+                query_id: query_id.clone(),
+                session_id: session_id.clone(),
+                node_id: node_id.clone(),
+                user,
+                schema: schema.as_ref().clone(),
+                state: ExecuteStateKind::Running,
+                error: None,
+                progresses: Progresses::default(),
+                affect: None,
+                running_time_ms: 0,
+                num_pages: 0,
+                total_rows: 0,
+                total_bytes: 0,
+                heartbeat_at: Utc::now().timestamp(),
+                expire_at: Utc::now().timestamp() + retention_secs as i64,
+            };
+            store.write_meta(&meta).await?;
+            Some((Arc::new(store), meta))
+        } else {
+            None
+        };
+
         let span = if let Some(parent) = SpanContext::current_local_parent() {
             Span::root(std::any::type_name::<ExecuteState>(), parent)
         } else {
@@ -372,13 +433,49 @@ impl HttpQuery {
         )?;
 
         let format_settings = ctx.get_format_settings()?;
+        let result_format = if request.r#async {
+            ResultFormat::Json
+        } else {
+            request.result_format
+        };
         let data = Arc::new(TokioMutex::new(PageManager::new(
             query_id.clone(),
             request.pagination.max_rows_per_page,
             block_receiver,
-            schema,
+            schema.clone(),
             format_settings,
+            result_format,
         )));
+        let expire_state = Arc::new(TokioMutex::new(ExpireState::Working));
+
+        let result_store = match result_store {
+            Some((store, meta)) => {
+                let store_clone = store.clone();
+                let page_manager = data.clone();
+                let executor = state.clone();
+                let expire_state = expire_state.clone();
+                let query_id_clone = query_id.clone();
+                GlobalIORuntime::instance().spawn(&query_id, async move {
+                    if let Err(e) = store_clone
+                        .persist(meta, page_manager, executor.clone(), retention_secs)
+                        .await
+                    {
+                        error!(
+                            "{}: fail to persist the result of async http query: {:?}",
+                            &query_id_clone, e
+                        );
+                        Executor::stop(&executor, Err(e), true).await;
+                    }
+                    // the result is in the storage now, the query itself expires as usual.
+                    let mut t = expire_state.lock().await;
+                    *t = ExpireState::ExpireAt(
+                        Instant::now() + Duration::from_secs(result_timeout_secs),
+                    );
+                });
+                Some(store)
+            }
+            None => None,
+        };
 
         let query = HttpQuery {
             id: query_id,
@@ -387,8 +484,10 @@ impl HttpQuery {
             request,
             state,
             page_manager: data,
+            schema,
+            result_store,
             result_timeout_secs,
-            expire_state: Arc::new(TokioMutex::new(ExpireState::Working)),
+            expire_state,
         };
 
         Ok(Arc::new(query))
@@ -397,6 +496,16 @@ impl HttpQuery {
     #[async_backtrace::framed]
     #[minitrace::trace]
     pub async fn get_response_page(&self, page_no: usize) -> Result<HttpQueryResponseInternal> {
+        if let Some(store) = &self.result_store {
+            let meta = store.read_meta().await?.ok_or_else(|| {
+                ErrorCode::HttpNotFound(format!("result of query {} expired", self.id))
+            })?;
+            let data = store.get_page(&meta, page_no).await?;
+            let mut response = meta.to_response(Some(data));
+            response.session = Some(self.get_response_session().await);
+            return Ok(response);
+        }
+
         let data = Some(self.get_page(page_no).await?);
         let state = self.get_state().await;
         let session = self.get_response_session().await;
@@ -415,8 +524,25 @@ impl HttpQuery {
         let state = self.get_state().await;
         let session = self.get_response_session().await;
 
+        // the async query points the client to its first page.
+        let data = match (&self.result_store, state.state) {
+            (Some(_), ExecuteStateKind::Running | ExecuteStateKind::Succeeded) => {
+                Some(ResponseData {
+                    page: Page {
+                        data: PageData::Json(JsonBlock {
+                            data: vec![],
+                            schema: self.schema.clone(),
+                        }),
+                        total_rows: 0,
+                    },
+                    next_page_no: Some(0),
+                })
+            }
+            _ => None,
+        };
+
         HttpQueryResponseInternal {
-            data: None,
+            data,
             session_id: self.session_id.clone(),
             node_id: self.node_id.clone(),
             state,
@@ -506,8 +632,16 @@ impl HttpQuery {
         data.detach().await
     }
 
+    pub fn is_async(&self) -> bool {
+        self.result_store.is_some()
+    }
+
     #[async_backtrace::framed]
     pub async fn update_expire_time(&self, before_wait: bool) {
+        // the async query does not depend on the polls of the client.
+        if self.is_async() {
+            return;
+        }
         let duration = Duration::from_secs(self.result_timeout_secs)
             + if before_wait {
                 Duration::from_secs(self.request.pagination.wait_time_secs as u64)
@@ -30,9 +30,12 @@ use super::expiring_map::ExpiringMap;
 use super::HttpQueryContext;
 use crate::servers::http::v1::query::http_query::ExpireResult;
 use crate::servers::http::v1::query::http_query::HttpQuery;
+use crate::servers::http::v1::query::sweep_all_expired_results;
 use crate::servers::http::v1::query::HttpQueryRequest;
 use crate::sessions::Session;
 
+const ASYNC_RESULT_SWEEP_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
 pub struct HttpQueryManager {
     #[allow(clippy::type_complexity)]
     pub(crate) queries: Arc<RwLock<HashMap<String, Arc<HttpQuery>>>>,
@@ -47,6 +50,20 @@ impl HttpQueryManager {
             sessions: Mutex::new(ExpiringMap::default()),
         }));
 
+        // the results of the async queries are also removed when they are read after expired,
+        // the sweep removes the ones nobody reads again.
+        GlobalIORuntime::instance().spawn("async-result-sweeper", async move {
+            loop {
+                sleep(ASYNC_RESULT_SWEEP_INTERVAL).await;
+                if let Err(e) = sweep_all_expired_results().await {
+                    warn!(
+                        "fail to sweep the expired results of async http queries: {}",
+                        e
+                    );
+                }
+            }
+        });
+
         Ok(())
     }
 
@@ -19,6 +19,7 @@ mod http_query;
 mod http_query_context;
 mod http_query_manager;
 mod page_manager;
+mod persisted_result;
 pub mod sized_spsc;
 
 pub(crate) use execute_state::ExecuteState;
@@ -33,9 +34,15 @@ pub use http_query::HttpQueryResponseInternal;
 pub use http_query::HttpSessionConf;
 pub use http_query::PaginationConf;
 pub use http_query::ResponseState;
+pub use http_query::ResultFormat;
 pub use http_query_context::HttpQueryContext;
 pub use http_query_manager::HttpQueryManager;
 pub use page_manager::Page;
+pub use page_manager::PageData;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
 pub use page_manager::Wait;
+pub use persisted_result::sweep_all_expired_results;
+pub use persisted_result::sweep_expired_results;
+pub use persisted_result::PersistedResult;
+pub use persisted_result::PersistedResultMeta;
@@ -23,10 +23,11 @@ use common_expression::DataSchemaRef;
 use common_io::prelude::FormatSettings;
 use log::debug;
 use log::info;
-use serde_json::Value as JsonValue;
 
 use crate::servers::http::v1::json_block::block_to_json_value;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
+use crate::servers::http::v1::query::ResultFormat;
+use crate::servers::http::v1::ArrowBlock;
 use crate::servers::http::v1::JsonBlock;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -35,9 +36,24 @@ pub enum Wait {
     Deadline(Instant),
 }
 
+#[derive(Clone)]
+pub enum PageData {
+    Json(JsonBlock),
+    Arrow(ArrowBlock),
+}
+
+impl PageData {
+    pub fn num_rows(&self) -> usize {
+        match self {
+            PageData::Json(block) => block.num_rows(),
+            PageData::Arrow(block) => block.num_rows(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Page {
-    pub data: JsonBlock,
+    pub data: PageData,
     pub total_rows: usize,
 }
 
@@ -55,9 +71,10 @@ pub struct PageManager {
     block_end: bool,
     schema: DataSchemaRef,
     last_page: Option<Page>,
-    row_buffer: VecDeque<Vec<JsonValue>>,
+    block_buffer: VecDeque<DataBlock>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: FormatSettings,
+    result_format: ResultFormat,
 }
 
 impl PageManager {
@@ -67,6 +84,7 @@ impl PageManager {
         block_receiver: SizedChannelReceiver<DataBlock>,
         schema: DataSchemaRef,
         format_settings: FormatSettings,
+        result_format: ResultFormat,
     ) -> PageManager {
         PageManager {
             query_id,
@@ -75,11 +93,12 @@ impl PageManager {
             total_pages: 0,
             end: false,
             block_end: false,
-            row_buffer: Default::default(),
+            block_buffer: Default::default(),
             schema,
             block_receiver,
             max_rows_per_page,
             format_settings,
+            result_format,
         }
     }
 
@@ -132,39 +151,41 @@ impl PageManager {
 
     fn append_block(
         &mut self,
-        rows: &mut Vec<Vec<JsonValue>>,
+        blocks: &mut Vec<DataBlock>,
         block: DataBlock,
         remain: usize,
-    ) -> Result<()> {
-        let format_settings = &self.format_settings;
-        let mut iter = block_to_json_value(&block, format_settings)?
-            .into_iter()
-            .peekable();
-        let chunk: Vec<_> = iter.by_ref().take(remain).collect();
-        rows.extend(chunk);
-        self.row_buffer = iter.by_ref().collect();
-        Ok(())
+    ) -> usize {
+        let num_rows = block.num_rows();
+        if num_rows > remain {
+            blocks.push(block.slice(0..remain));
+            self.block_buffer.push_front(block.slice(remain..num_rows));
+            remain
+        } else {
+            blocks.push(block);
+            num_rows
+        }
     }
 
     #[async_backtrace::framed]
-    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(JsonBlock, bool)> {
-        let mut res: Vec<Vec<JsonValue>> = Vec::with_capacity(self.max_rows_per_page);
-        while res.len() < self.max_rows_per_page {
-            if let Some(row) = self.row_buffer.pop_front() {
-                res.push(row)
+    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(PageData, bool)> {
+        let mut blocks = vec![];
+        let mut rows = 0;
+        while rows < self.max_rows_per_page {
+            if let Some(block) = self.block_buffer.pop_front() {
+                rows += self.append_block(&mut blocks, block, self.max_rows_per_page - rows);
             } else {
                 break;
             }
         }
         loop {
-            assert!(self.max_rows_per_page >= res.len());
-            let remain = self.max_rows_per_page - res.len();
+            assert!(self.max_rows_per_page >= rows);
+            let remain = self.max_rows_per_page - rows;
             if remain == 0 {
                 break;
             }
             match tp {
                 Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => self.append_block(&mut res, block, remain)?,
+                    Some(block) => rows += self.append_block(&mut blocks, block, remain),
                     None => break,
                 },
                 Wait::Deadline(t) => {
@@ -177,7 +198,7 @@ impl PageManager {
                                 &self.query_id,
                                 block.num_rows()
                             );
-                            self.append_block(&mut res, block, remain)?;
+                            rows += self.append_block(&mut blocks, block, remain);
                         }
                         Ok(None) => {
                             info!("{}: http query reach end of blocks", &self.query_id);
@@ -192,17 +213,26 @@ impl PageManager {
             }
         }
 
-        let block = JsonBlock {
-            schema: self.schema.clone(),
-            data: res,
+        let data = match self.result_format {
+            ResultFormat::Json => {
+                let mut data = Vec::with_capacity(rows);
+                for block in &blocks {
+                    data.extend(block_to_json_value(block, &self.format_settings)?);
+                }
+                PageData::Json(JsonBlock {
+                    schema: self.schema.clone(),
+                    data,
+                })
+            }
+            ResultFormat::Arrow => PageData::Arrow(ArrowBlock::new(self.schema.clone(), blocks)),
         };
 
         // try to report 'no more data' earlier to client to avoid unnecessary http call
         if !self.block_end {
             self.block_end = self.block_receiver.is_empty();
         }
-        let end = self.block_end && self.row_buffer.is_empty();
-        Ok((block, end))
+        let end = self.block_end && self.block_buffer.is_empty();
+        Ok((data, end))
     }
 
     #[async_backtrace::framed]
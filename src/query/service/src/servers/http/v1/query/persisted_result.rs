@@ -0,0 +1,444 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use common_base::base::tokio::sync::Mutex as TokioMutex;
+use common_base::base::tokio::sync::RwLock;
+use common_base::base::tokio::time::sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_storage::DataOperator;
+use futures::TryStreamExt;
+use log::info;
+use opendal::EntryMode;
+use opendal::Metakey;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::servers::http::v1::query::ExecuteStateKind;
+use crate::servers::http::v1::query::Executor;
+use crate::servers::http::v1::query::HttpQueryResponseInternal;
+use crate::servers::http::v1::query::Page;
+use crate::servers::http::v1::query::PageData;
+use crate::servers::http::v1::query::PageManager;
+use crate::servers::http::v1::query::Progresses;
+use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResponseState;
+use crate::servers::http::v1::query::Wait;
+use crate::servers::http::v1::JsonBlock;
+use crate::sessions::QueryAffect;
+
+const PERSIST_WAIT_TIME_SECS: u64 = 1;
+/// The meta of a running query is refreshed at least this often, and the kill requests
+/// from the other nodes are checked at the same pace.
+const HEARTBEAT_INTERVAL_SECS: i64 = 10;
+/// A running query whose meta is not refreshed for this long is reported as failed,
+/// its node is most likely gone.
+const HEARTBEAT_TIMEOUT_SECS: i64 = 60;
+/// The whole result is only returned at once below this size, larger ones must be
+/// fetched page by page.
+const MAX_RESULT_BYTES: usize = 64 * 1024 * 1024;
+
+/// The storage prefix of the persisted results of the async http queries of all tenants.
+const ASYNC_RESULT_PREFIX: &str = "_async_result";
+
+/// The storage prefix of the persisted results of the async http queries of a tenant.
+pub fn async_result_prefix(tenant: &str) -> String {
+    format!("{}/{}", ASYNC_RESULT_PREFIX, tenant)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PersistedError {
+    pub code: u16,
+    pub name: String,
+    pub message: String,
+    pub detail: String,
+}
+
+impl From<&ErrorCode> for PersistedError {
+    fn from(e: &ErrorCode) -> Self {
+        PersistedError {
+            code: e.code(),
+            name: e.name(),
+            message: e.display_text(),
+            detail: e.detail(),
+        }
+    }
+}
+
+/// The state of an async http query, written along with its result pages so that
+/// any query node of the cluster can serve the result.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PersistedResultMeta {
+    pub query_id: String,
+    pub session_id: String,
+    pub node_id: String,
+    /// Only the user who submitted the query can fetch its result.
+    pub user: String,
+    pub schema: DataSchema,
+    pub state: ExecuteStateKind,
+    pub error: Option<PersistedError>,
+    pub progresses: Progresses,
+    pub affect: Option<QueryAffect>,
+    pub running_time_ms: i64,
+    pub num_pages: usize,
+    pub total_rows: usize,
+    /// The size of the persisted pages in bytes.
+    #[serde(default)]
+    pub total_bytes: usize,
+    /// Unix timestamp in seconds of the last refresh by the node running the query.
+    #[serde(default)]
+    pub heartbeat_at: i64,
+    /// Unix timestamp in seconds, the result is removed on the first access or the
+    /// periodic sweep after it.
+    pub expire_at: i64,
+}
+
+impl PersistedResultMeta {
+    pub fn response_state(&self) -> ResponseState {
+        ResponseState {
+            running_time_ms: self.running_time_ms,
+            progresses: self.progresses.clone(),
+            state: self.state,
+            affect: self.affect.clone(),
+            error: self.error.as_ref().map(|e| {
+                ErrorCode::create(
+                    e.code,
+                    &e.name,
+                    e.message.clone(),
+                    e.detail.clone(),
+                    None,
+                    None,
+                )
+            }),
+        }
+    }
+
+    /// The state-only response of an async query points the client to the first page,
+    /// unless there is nothing to fetch.
+    pub fn response_state_only(&self) -> HttpQueryResponseInternal {
+        let data = match self.state {
+            ExecuteStateKind::Failed => None,
+            _ => Some(ResponseData {
+                page: self.empty_page(),
+                next_page_no: Some(0),
+            }),
+        };
+        self.to_response(data)
+    }
+
+    pub fn to_response(&self, data: Option<ResponseData>) -> HttpQueryResponseInternal {
+        HttpQueryResponseInternal {
+            data,
+            session_id: self.session_id.clone(),
+            session: None,
+            state: self.response_state(),
+            node_id: self.node_id.clone(),
+        }
+    }
+
+    fn empty_page(&self) -> Page {
+        Page {
+            data: PageData::Json(JsonBlock {
+                data: vec![],
+                schema: Arc::new(self.schema.clone()),
+            }),
+            total_rows: self.total_rows,
+        }
+    }
+
+    async fn update_state(&mut self, executor: &Arc<RwLock<Executor>>, retention_secs: u64) {
+        let executor = executor.read().await;
+        let (state, error) = executor.state.extract();
+        self.state = state;
+        self.error = error.as_ref().map(PersistedError::from);
+        self.progresses = executor.get_progress();
+        self.affect = executor.get_affect();
+        self.running_time_ms = executor.get_query_duration_ms();
+        let now = Utc::now().timestamp();
+        self.heartbeat_at = now;
+        self.expire_at = now + retention_secs as i64;
+    }
+
+    fn is_lost(&self, now: i64) -> bool {
+        self.state == ExecuteStateKind::Running && self.heartbeat_at + HEARTBEAT_TIMEOUT_SECS <= now
+    }
+}
+
+/// The result of an async http query, spilled page by page to the storage of the tenant.
+pub struct PersistedResult {
+    operator: Operator,
+    prefix: String,
+}
+
+impl PersistedResult {
+    pub fn create(tenant: &str, query_id: &str) -> Self {
+        PersistedResult {
+            operator: DataOperator::instance().operator(),
+            prefix: format!("{}/{}/", async_result_prefix(tenant), query_id),
+        }
+    }
+
+    fn meta_location(&self) -> String {
+        format!("{}meta.json", self.prefix)
+    }
+
+    fn page_location(&self, page_no: usize) -> String {
+        format!("{}page_{}.json", self.prefix, page_no)
+    }
+
+    fn kill_location(&self) -> String {
+        format!("{}kill", self.prefix)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write_meta(&self, meta: &PersistedResultMeta) -> Result<()> {
+        let bytes = serde_json::to_vec(meta)?;
+        self.operator.write(&self.meta_location(), bytes).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn write_page(&self, page_no: usize, data: &[Vec<JsonValue>]) -> Result<usize> {
+        let bytes = serde_json::to_vec(data)?;
+        let len = bytes.len();
+        self.operator
+            .write(&self.page_location(page_no), bytes)
+            .await?;
+        Ok(len)
+    }
+
+    /// Asks the node running the query to kill it, the request is picked up with the next
+    /// heartbeat of the query.
+    #[async_backtrace::framed]
+    pub async fn request_kill(&self) -> Result<()> {
+        self.operator.write(&self.kill_location(), vec![]).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn is_kill_requested(&self) -> Result<bool> {
+        Ok(self.operator.is_exist(&self.kill_location()).await?)
+    }
+
+    /// Returns None if the result does not exist or has expired, the expired result is removed.
+    /// A running query whose node stopped refreshing its meta is reported as failed.
+    #[async_backtrace::framed]
+    pub async fn read_meta(&self) -> Result<Option<PersistedResultMeta>> {
+        let bytes = match self.operator.read(&self.meta_location()).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut meta: PersistedResultMeta = serde_json::from_slice(&bytes)?;
+        let now = Utc::now().timestamp();
+        if meta.expire_at <= now {
+            info!("{}: async http query result expired", &meta.query_id);
+            self.operator.remove_all(&self.prefix).await?;
+            return Ok(None);
+        }
+        if meta.is_lost(now) {
+            let e = ErrorCode::AbortedQuery(format!(
+                "query node {} stopped running the query {}s ago",
+                meta.node_id,
+                now - meta.heartbeat_at
+            ));
+            meta.state = ExecuteStateKind::Failed;
+            meta.error = Some(PersistedError::from(&e));
+        }
+        Ok(Some(meta))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_page(&self, meta: &PersistedResultMeta, page_no: usize) -> Result<JsonBlock> {
+        let bytes = self.operator.read(&self.page_location(page_no)).await?;
+        Ok(JsonBlock {
+            data: serde_json::from_slice(&bytes)?,
+            schema: Arc::new(meta.schema.clone()),
+        })
+    }
+
+    /// Reads a page without waiting, an empty page pointing to itself is returned if the
+    /// page is not yet produced by the running query.
+    #[async_backtrace::framed]
+    pub async fn get_page(
+        &self,
+        meta: &PersistedResultMeta,
+        page_no: usize,
+    ) -> Result<ResponseData> {
+        let running = meta.state == ExecuteStateKind::Running;
+        if page_no < meta.num_pages {
+            let block = self.read_page(meta, page_no).await?;
+            let next_page_no = if running || page_no + 1 < meta.num_pages {
+                Some(page_no + 1)
+            } else {
+                None
+            };
+            Ok(ResponseData {
+                page: Page {
+                    data: PageData::Json(block),
+                    total_rows: meta.total_rows,
+                },
+                next_page_no,
+            })
+        } else if page_no == meta.num_pages && (running || page_no == 0) {
+            Ok(ResponseData {
+                page: meta.empty_page(),
+                next_page_no: if running { Some(page_no) } else { None },
+            })
+        } else {
+            let message = format!("wrong page number {}", page_no);
+            Err(ErrorCode::HttpNotFound(message))
+        }
+    }
+
+    /// Concatenates all the pages of a finished query, up to `MAX_RESULT_BYTES`.
+    #[async_backtrace::framed]
+    pub async fn get_all(&self, meta: &PersistedResultMeta) -> Result<ResponseData> {
+        if meta.total_bytes > MAX_RESULT_BYTES {
+            return Err(ErrorCode::BadArguments(format!(
+                "result of query {} is too large to fetch at once ({} bytes, max {} bytes), fetch it page by page",
+                meta.query_id, meta.total_bytes, MAX_RESULT_BYTES
+            )));
+        }
+        let mut blocks = Vec::with_capacity(meta.num_pages);
+        for page_no in 0..meta.num_pages {
+            blocks.push(self.read_page(meta, page_no).await?);
+        }
+        let mut block = JsonBlock::concat(blocks);
+        block.schema = Arc::new(meta.schema.clone());
+        Ok(ResponseData {
+            page: Page {
+                data: PageData::Json(block),
+                total_rows: meta.total_rows,
+            },
+            next_page_no: None,
+        })
+    }
+
+    /// Drains the pages of the query to the storage as they are produced, and refreshes
+    /// the meta after each page or heartbeat, until the query stops.
+    #[async_backtrace::framed]
+    pub(crate) async fn persist(
+        &self,
+        mut meta: PersistedResultMeta,
+        page_manager: Arc<TokioMutex<PageManager>>,
+        executor: Arc<RwLock<Executor>>,
+        retention_secs: u64,
+    ) -> Result<()> {
+        let mut last_kill_check = Instant::now();
+        loop {
+            let page = {
+                let mut page_manager = page_manager.lock().await;
+                let page_no = match page_manager.next_page_no() {
+                    Some(page_no) => page_no,
+                    None => break,
+                };
+                let wait =
+                    Wait::Deadline(Instant::now() + Duration::from_secs(PERSIST_WAIT_TIME_SECS));
+                page_manager.get_a_page(page_no, &wait).await?
+            };
+            if last_kill_check.elapsed() >= Duration::from_secs(HEARTBEAT_INTERVAL_SECS as u64) {
+                last_kill_check = Instant::now();
+                if self.is_kill_requested().await? {
+                    info!("{}: async http query is killed by request", &meta.query_id);
+                    Executor::stop(
+                        &executor,
+                        Err(ErrorCode::AbortedQuery("killed by http")),
+                        true,
+                    )
+                    .await;
+                }
+            }
+            if page.data.num_rows() == 0 {
+                if meta.heartbeat_at + HEARTBEAT_INTERVAL_SECS <= Utc::now().timestamp() {
+                    meta.update_state(&executor, retention_secs).await;
+                    self.write_meta(&meta).await?;
+                }
+                continue;
+            }
+            match &page.data {
+                PageData::Json(block) => {
+                    meta.total_bytes += self.write_page(meta.num_pages, block.data()).await?;
+                }
+                PageData::Arrow(_) => {
+                    return Err(ErrorCode::Internal(
+                        "the result of async http query must be persisted in json",
+                    ));
+                }
+            }
+            meta.num_pages += 1;
+            meta.total_rows = page.total_rows;
+            meta.update_state(&executor, retention_secs).await;
+            self.write_meta(&meta).await?;
+        }
+
+        // the blocks end right before the executor stops
+        while executor.read().await.state.extract().0 == ExecuteStateKind::Running {
+            sleep(Duration::from_millis(10)).await;
+        }
+        meta.update_state(&executor, retention_secs).await;
+        self.write_meta(&meta).await?;
+        info!(
+            "{}: async http query result persisted, pages={}, rows={}",
+            &meta.query_id, meta.num_pages, meta.total_rows
+        );
+        Ok(())
+    }
+}
+
+/// Removes the expired results of the async http queries of the tenant, including the
+/// results nobody reads again. The removal is idempotent, so every node of the tenant
+/// may sweep at the same time.
+#[async_backtrace::framed]
+pub async fn sweep_expired_results(tenant: &str) -> Result<()> {
+    let operator = DataOperator::instance().operator();
+    let prefix = format!("{}/", async_result_prefix(tenant));
+    let mut lister = operator.lister_with(&prefix).metakey(Metakey::Mode).await?;
+    while let Some(entry) = lister.try_next().await? {
+        if entry.metadata().mode() != EntryMode::DIR {
+            continue;
+        }
+        let query_id = entry.name().trim_end_matches('/');
+        let store = PersistedResult::create(tenant, query_id);
+        // also removes the pages left by an interrupted removal, which has no meta
+        if store.read_meta().await?.is_none() {
+            operator.remove_all(&store.prefix).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes the expired results of the async http queries of every tenant which has persisted
+/// results, the tenants sharing the storage may be served by other clusters.
+#[async_backtrace::framed]
+pub async fn sweep_all_expired_results() -> Result<()> {
+    let operator = DataOperator::instance().operator();
+    let prefix = format!("{}/", ASYNC_RESULT_PREFIX);
+    let mut lister = operator.lister_with(&prefix).metakey(Metakey::Mode).await?;
+    while let Some(entry) = lister.try_next().await? {
+        if entry.metadata().mode() != EntryMode::DIR {
+            continue;
+        }
+        sweep_expired_results(entry.name().trim_end_matches('/')).await?;
+    }
+    Ok(())
+}
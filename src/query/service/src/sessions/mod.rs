@@ -23,6 +23,8 @@ mod session_mgr_status;
 mod session_privilege_mgr;
 mod session_status;
 mod session_type;
+mod user_resources;
+mod workload_groups;
 
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -37,3 +39,9 @@ pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use user_resources::ThreadSlots;
+pub use user_resources::UserResources;
+pub use user_resources::UserResourcesManager;
+pub use workload_groups::WorkloadGroupManager;
+pub use workload_groups::WorkloadGroupResources;
+pub use workload_groups::WorkloadGroupTicket;
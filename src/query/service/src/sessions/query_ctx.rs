@@ -86,6 +86,7 @@ use parking_lot::RwLock;
 use storages_common_table_meta::meta::Location;
 
 use crate::api::DataExchangeManager;
+use crate::audit::AuditAction;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
@@ -269,6 +270,26 @@ impl QueryContext {
         ua.clone()
     }
 
+    pub fn set_workload_group(&self, name: Option<String>) {
+        *self.shared.workload_group.write() = name;
+    }
+
+    pub fn get_workload_group(&self) -> Option<String> {
+        self.shared.get_workload_group()
+    }
+
+    pub fn set_audit_action(&self, action: AuditAction) {
+        *self.shared.audit_action.lock() = Some(action);
+    }
+
+    pub fn take_audit_action(&self) -> Option<AuditAction> {
+        self.shared.audit_action.lock().take()
+    }
+
+    pub fn set_queued(&self, queued: bool) {
+        self.shared.queued.store(queued, Ordering::Relaxed);
+    }
+
     pub fn get_query_duration_ms(&self) -> i64 {
         let query_start_time = convert_query_log_timestamp(self.shared.created_time);
         let finish_time = *self.shared.finish_time.read();
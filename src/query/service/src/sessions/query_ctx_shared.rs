@@ -21,6 +21,7 @@ use std::sync::Weak;
 use std::time::SystemTime;
 
 use common_base::base::Progress;
+use common_base::runtime::MemStat;
 use common_base::runtime::Runtime;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::query_kind::QueryKind;
@@ -42,6 +43,7 @@ use parking_lot::Mutex;
 use parking_lot::RwLock;
 use uuid::Uuid;
 
+use crate::audit::AuditAction;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
@@ -69,6 +71,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
+    /// The memory tracker of the user and the workload group of the user, the parent of the
+    /// memory tracker of the query runtime.
+    pub(in crate::sessions) parent_mem_stat: Option<Arc<MemStat>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
@@ -101,16 +106,23 @@ pub struct QueryContextShared {
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
+    /// The workload group the query is admitted by, and whether it is waiting in the queue.
+    pub(in crate::sessions) workload_group: Arc<RwLock<Option<String>>>,
+    pub(in crate::sessions) queued: Arc<AtomicBool>,
+    /// The DDL or privilege change of the query, written to the audit log when it finishes.
+    pub(in crate::sessions) audit_action: Arc<Mutex<Option<AuditAction>>>,
 }
 
 impl QueryContextShared {
     pub fn try_create(
         session: Arc<Session>,
         cluster_cache: Arc<Cluster>,
+        parent_mem_stat: Option<Arc<MemStat>>,
     ) -> Result<Arc<QueryContextShared>> {
         Ok(Arc::new(QueryContextShared {
             session,
             cluster_cache,
+            parent_mem_stat,
             catalog_manager: CatalogManager::instance(),
             data_operator: DataOperator::instance(),
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
@@ -141,6 +153,9 @@ impl QueryContextShared {
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
+            workload_group: Arc::new(RwLock::new(None)),
+            queued: Arc::new(AtomicBool::new(false)),
+            audit_action: Arc::new(Mutex::new(None)),
         }))
     }
 
@@ -322,9 +337,13 @@ impl QueryContextShared {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
                 // To avoid possible deadlock, we should keep at least two threads.
-                let runtime = Arc::new(Runtime::with_worker_threads(
+                // the threads of the query executor are tracked by the children of the query
+                // runtime, so that the memory is also accounted to the user and its group.
+                let parent_mem_stat = self.parent_mem_stat.clone().or_else(MemStat::current);
+                let runtime = Arc::new(Runtime::with_worker_threads_and_parent(
                     2,
                     Some("query-ctx".to_string()),
+                    parent_mem_stat,
                 )?);
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
@@ -407,6 +426,14 @@ impl QueryContextShared {
         let status = self.status.read();
         status.clone()
     }
+
+    pub fn get_workload_group(&self) -> Option<String> {
+        self.workload_group.read().clone()
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.queued.load(Ordering::Relaxed)
+    }
 }
 
 impl Drop for QueryContextShared {
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_base::runtime::MemStat;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -41,6 +42,9 @@ use crate::sessions::SessionContext;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionStatus;
 use crate::sessions::SessionType;
+use crate::sessions::UserResources;
+use crate::sessions::UserResourcesManager;
+use crate::sessions::WorkloadGroupManager;
 
 pub struct Session {
     pub(in crate::sessions) id: String,
@@ -50,6 +54,7 @@ pub struct Session {
     status: Arc<RwLock<SessionStatus>>,
     pub(in crate::sessions) mysql_connection_id: Option<u32>,
     format_settings: FormatSettings,
+    user_resources: RwLock<Option<Arc<UserResources>>>,
 }
 
 impl Session {
@@ -69,6 +74,7 @@ impl Session {
             privilege_mgr,
             mysql_connection_id,
             format_settings: FormatSettings::default(),
+            user_resources: RwLock::new(None),
         }))
     }
 
@@ -134,13 +140,34 @@ impl Session {
         let config = GlobalConfig::instance();
         let session = self.clone();
         let cluster = ClusterDiscovery::instance().discover(&config).await?;
-        let shared = QueryContextShared::try_create(session, cluster)?;
+        let parent_mem_stat = self.get_query_parent_mem_stat().await?;
+        let shared = QueryContextShared::try_create(session, cluster, parent_mem_stat)?;
 
         self.session_ctx
             .set_query_context_shared(Arc::downgrade(&shared));
         Ok(QueryContext::create_from_shared(shared))
     }
 
+    /// The memory of the queries of the session is also accounted to the user, see
+    /// `max_memory_in_bytes`, and to the workload group of the user, see `MEMORY_PERCENTAGE`.
+    ///
+    /// The group is resolved again when the query is admitted, a query keeps being accounted
+    /// to the former group if the group of the user is changed in between.
+    #[async_backtrace::framed]
+    async fn get_query_parent_mem_stat(self: &Arc<Self>) -> Result<Option<Arc<MemStat>>> {
+        let Some(user_resources) = self.get_user_resources() else {
+            return Ok(None);
+        };
+
+        let workload_mem_stat = WorkloadGroupManager::instance()
+            .get_session_resources(self)
+            .await?
+            .and_then(|resources| resources.mem_stat());
+        Ok(user_resources
+            .mem_stat(workload_mem_stat.clone())
+            .or(workload_mem_stat))
+    }
+
     // only used for values and mysql output
     pub fn set_format_settings(&mut self, other: FormatSettings) {
         self.format_settings = other
@@ -194,11 +221,18 @@ impl Session {
         user: UserInfo,
         restricted_role: Option<String>,
     ) -> Result<()> {
+        let resources = UserResourcesManager::instance().get_resources(&user);
+        *self.user_resources.write() = Some(resources);
         self.privilege_mgr
             .set_authed_user(user, restricted_role)
             .await
     }
 
+    /// The resources shared by the sessions of the authed user, see [UserResources].
+    pub fn get_user_resources(self: &Arc<Self>) -> Option<Arc<UserResources>> {
+        self.user_resources.read().clone()
+    }
+
     #[async_backtrace::framed]
     pub async fn validate_available_role(self: &Arc<Self>, role_name: &str) -> Result<RoleInfo> {
         self.privilege_mgr.validate_available_role(role_name).await
@@ -148,20 +148,24 @@ impl SessionPrivilegeManager for SessionPrivilegeManagerImpl {
         Ok(())
     }
 
-    /// If secondary_roles is set, it must be ALL or NONE:
+    /// If secondary_roles is set, it must be ALL, NONE or a subset of the granted roles:
     /// 1. ALL: all the roles granted to the current user will have effects on validate_privilege,
     ///    `secondary_roles` will be set to None, which is default.
     /// 2. NONE: only the current_role has effects on validate_privilge, `secondary_roles`
     ///    will be set to Some([]).
-    /// We can release this restriction in the future if any user needed. If an user want to set
-    /// secondary_roles to be a subset of the roles granted to the current user, he can pass
-    /// `secondary_roles` as Some([role1, role2, .. etc.]).
+    /// 3. Some([role1, role2, .. etc.]): the current_role and the listed roles have effects on
+    ///    validate_privilege, such as the roles mapped from the groups of an external identity
+    ///    provider. Every listed role must be granted to the current user.
     #[async_backtrace::framed]
     async fn set_secondary_roles(&self, secondary_roles: Option<Vec<String>>) -> Result<()> {
-        if secondary_roles.is_some() && !secondary_roles.as_ref().unwrap().is_empty() {
-            return Err(ErrorCode::InvalidArgument(
-                "only ALL or NONE is allowed on setting secondary roles",
-            ));
+        if let Some(roles) = &secondary_roles {
+            let granted_roles = self.get_current_user()?.grants.roles();
+            if let Some(role) = roles.iter().find(|role| !granted_roles.contains(role)) {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "secondary role {} is not granted to the current user",
+                    role
+                )));
+            }
         }
         self.session_ctx.set_secondary_roles(secondary_roles);
         Ok(())
@@ -0,0 +1,229 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::GlobalInstance;
+use common_base::runtime::MemStat;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserQuota;
+use parking_lot::Mutex;
+
+/// The resources used by the running queries of a user on this node, shared by all the
+/// sessions of the user, see [UserQuota].
+pub struct UserResources {
+    user: String,
+    max_cpu: AtomicU64,
+    max_memory_in_bytes: AtomicU64,
+    used_threads: AtomicU64,
+    mem_stat: Mutex<UserMemStat>,
+}
+
+/// The memory tracker of a user, a child of the memory tracker of the workload group of the
+/// user if any.
+struct UserMemStat {
+    parent: Option<Arc<MemStat>>,
+    mem_stat: Arc<MemStat>,
+}
+
+impl UserResources {
+    fn create(user: &UserInfo) -> Arc<UserResources> {
+        let resources = Arc::new(UserResources {
+            user: user.identity().to_string(),
+            max_cpu: AtomicU64::new(0),
+            max_memory_in_bytes: AtomicU64::new(0),
+            used_threads: AtomicU64::new(0),
+            mem_stat: Mutex::new(UserMemStat {
+                parent: None,
+                mem_stat: MemStat::create_child(format!("User({})", user.name), None),
+            }),
+        });
+        resources.set_quota(&user.quota);
+        resources
+    }
+
+    /// The quota may be altered, the latest one seen by a session of the user takes effect.
+    fn set_quota(&self, quota: &UserQuota) {
+        self.max_cpu.store(quota.max_cpu, Ordering::Relaxed);
+        self.max_memory_in_bytes
+            .store(quota.max_memory_in_bytes, Ordering::Relaxed);
+        // note that a limit lower than 256MiB is raised to 256MiB by MemStat.
+        self.mem_stat
+            .lock()
+            .mem_stat
+            .set_limit(quota.max_memory_in_bytes as i64);
+    }
+
+    /// The memory tracker of the queries of the user, None if the memory is not limited.
+    ///
+    /// The memory used by the user is also accounted to `parent`, the memory tracker of the
+    /// workload group of the user, so that the user can not exceed the share of the group.
+    pub fn mem_stat(&self, parent: Option<Arc<MemStat>>) -> Option<Arc<MemStat>> {
+        let limit = self.max_memory_in_bytes.load(Ordering::Relaxed);
+        if limit == 0 {
+            return None;
+        }
+
+        let mut mem_stat = self.mem_stat.lock();
+        let same_parent = match (&mem_stat.parent, &parent) {
+            (None, None) => true,
+            (Some(current), Some(parent)) => Arc::ptr_eq(current, parent),
+            _ => false,
+        };
+        if !same_parent {
+            // the user is moved to another group, or the group is altered. The running queries
+            // keep being accounted to the former tracker until they finish.
+            let child = MemStat::create_child(format!("User({})", self.user), parent.clone());
+            child.set_limit(limit as i64);
+            *mem_stat = UserMemStat {
+                parent,
+                mem_stat: child,
+            };
+        }
+        Some(mem_stat.mem_stat.clone())
+    }
+
+    /// Checks that the queries of the user are not using up the memory quota before starting
+    /// a new query.
+    pub fn check_memory(&self) -> Result<()> {
+        let limit = self.max_memory_in_bytes.load(Ordering::Relaxed) as i64;
+        let used = self.mem_stat.lock().mem_stat.get_memory_usage();
+        if limit > 0 && used >= limit {
+            return Err(ErrorCode::UserQuotaExceeded(format!(
+                "memory quota of user {} exceeded, used {} bytes, max_memory_in_bytes {}",
+                self.user, used, limit
+            )));
+        }
+        Ok(())
+    }
+
+    /// Takes the slots of at most `max_threads` threads for a query.
+    ///
+    /// The running queries of the user take at most `max_cpu` threads in total, but a query
+    /// always gets at least one thread, so that it is slowed down rather than rejected.
+    pub fn acquire_threads(self: &Arc<Self>, max_threads: u64) -> ThreadSlots {
+        let max_cpu = self.max_cpu.load(Ordering::Relaxed);
+        let max_threads = max_threads.max(1);
+        let mut used = self.used_threads.load(Ordering::Relaxed);
+        loop {
+            let threads = match max_cpu {
+                0 => max_threads,
+                _ => max_threads.min(max_cpu.saturating_sub(used).max(1)),
+            };
+            match self.used_threads.compare_exchange_weak(
+                used,
+                used + threads,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    return ThreadSlots {
+                        resources: self.clone(),
+                        threads,
+                    };
+                }
+                Err(actual) => used = actual,
+            }
+        }
+    }
+}
+
+/// The thread slots taken by a running query, given back when dropped.
+pub struct ThreadSlots {
+    resources: Arc<UserResources>,
+    threads: u64,
+}
+
+impl ThreadSlots {
+    pub fn threads(&self) -> u64 {
+        self.threads
+    }
+}
+
+impl Drop for ThreadSlots {
+    fn drop(&mut self) {
+        self.resources
+            .used_threads
+            .fetch_sub(self.threads, Ordering::Relaxed);
+    }
+}
+
+/// How long the storage used by the users of a tenant is cached.
+const STORAGE_USAGE_TTL: Duration = Duration::from_secs(60);
+
+/// Keeps the [UserResources] of the users who have sessions on this node.
+pub struct UserResourcesManager {
+    users: Mutex<HashMap<String, Weak<UserResources>>>,
+    /// The storage used by the users of each tenant, by user name.
+    storage_usage: Mutex<HashMap<String, (Instant, Arc<HashMap<String, u64>>)>>,
+}
+
+impl UserResourcesManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(UserResourcesManager {
+            users: Mutex::new(HashMap::new()),
+            storage_usage: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<UserResourcesManager> {
+        GlobalInstance::get()
+    }
+
+    /// Returns the resources of `user`, which are released when no session refers to them.
+    pub fn get_resources(&self, user: &UserInfo) -> Arc<UserResources> {
+        let key = user.identity().to_string();
+        let mut users = self.users.lock();
+        if let Some(resources) = users.get(&key).and_then(|r| r.upgrade()) {
+            resources.set_quota(&user.quota);
+            return resources;
+        }
+
+        users.retain(|_, resources| resources.strong_count() > 0);
+        let resources = UserResources::create(user);
+        users.insert(key, Arc::downgrade(&resources));
+        resources
+    }
+
+    /// Returns the storage used by the users of `tenant`, None if it is not cached or
+    /// the cache has expired.
+    pub fn get_storage_usage(&self, tenant: &str) -> Option<Arc<HashMap<String, u64>>> {
+        let storage_usage = self.storage_usage.lock();
+        storage_usage
+            .get(tenant)
+            .filter(|(updated_on, _)| updated_on.elapsed() < STORAGE_USAGE_TTL)
+            .map(|(_, usage)| usage.clone())
+    }
+
+    pub fn set_storage_usage(
+        &self,
+        tenant: &str,
+        usage: HashMap<String, u64>,
+    ) -> Arc<HashMap<String, u64>> {
+        let usage = Arc::new(usage);
+        self.storage_usage
+            .lock()
+            .insert(tenant.to_string(), (Instant::now(), usage.clone()));
+        usage
+    }
+}
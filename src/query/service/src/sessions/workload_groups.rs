@@ -0,0 +1,328 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio::sync::OwnedSemaphorePermit;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::tokio::time::sleep;
+use common_base::base::tokio::time::timeout;
+use common_base::base::GlobalInstance;
+use common_base::runtime::MemStat;
+use common_catalog::table_context::TableContext;
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+use common_users::UserApiProvider;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+
+/// How often a queued query checks whether it is killed or timed out.
+const QUEUE_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long the workload groups of a tenant are cached, the groups altered on another node
+/// take effect on this node after it.
+const GROUPS_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// The admission state of a workload group on this node.
+pub struct WorkloadGroupResources {
+    group: WorkloadGroup,
+    semaphore: Option<Arc<Semaphore>>,
+    running: AtomicU64,
+    queued: AtomicU64,
+    mem_stat: Arc<MemStat>,
+    memory_limit: i64,
+}
+
+impl WorkloadGroupResources {
+    fn create(group: WorkloadGroup) -> Arc<WorkloadGroupResources> {
+        let semaphore = match group.max_concurrency {
+            0 => None,
+            n => Some(Arc::new(Semaphore::new(n as usize))),
+        };
+        let max_server_memory_usage = GlobalConfig::instance().query.max_server_memory_usage;
+        let memory_limit = (max_server_memory_usage * group.memory_percentage / 100) as i64;
+        let mem_stat = MemStat::create_child(format!("WorkloadGroup({})", group.name), None);
+        mem_stat.set_limit(memory_limit);
+
+        Arc::new(WorkloadGroupResources {
+            group,
+            semaphore,
+            running: AtomicU64::new(0),
+            queued: AtomicU64::new(0),
+            mem_stat,
+            memory_limit,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.group.name
+    }
+
+    /// The memory tracker of the queries of the group, None if the memory is not limited.
+    pub fn mem_stat(&self) -> Option<Arc<MemStat>> {
+        match self.memory_limit {
+            0 => None,
+            _ => Some(self.mem_stat.clone()),
+        }
+    }
+
+    pub fn running_queries(&self) -> u64 {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    pub fn queued_queries(&self) -> u64 {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    fn memory_available(&self) -> bool {
+        self.memory_limit == 0 || self.mem_stat.get_memory_usage() < self.memory_limit
+    }
+
+    /// Whether the admission limits of the group are the same as `group`.
+    fn same_limits(&self, group: &WorkloadGroup) -> bool {
+        self.group.max_concurrency == group.max_concurrency
+            && self.group.memory_percentage == group.memory_percentage
+            && self.group.cpu_weight == group.cpu_weight
+            && self.group.queue_timeout_secs == group.queue_timeout_secs
+    }
+}
+
+/// A query admitted by a workload group, which gives back its slot when dropped.
+pub struct WorkloadGroupTicket {
+    resources: Arc<WorkloadGroupResources>,
+    max_threads: u64,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl WorkloadGroupTicket {
+    pub fn resources(&self) -> &Arc<WorkloadGroupResources> {
+        &self.resources
+    }
+
+    /// The threads of the query, shared with the running queries of the other groups by the
+    /// cpu weights.
+    pub fn max_threads(&self) -> u64 {
+        self.max_threads
+    }
+}
+
+impl Drop for WorkloadGroupTicket {
+    fn drop(&mut self) {
+        self.resources.running.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Queues the queries in front of the executor by the workload groups of their users, see
+/// `CREATE WORKLOAD GROUP`.
+///
+/// The queues are kept by every query node on its own: `MAX_CONCURRENCY` and
+/// `MEMORY_PERCENTAGE` limit the queries of a group running on one node, a cluster of N
+/// nodes runs at most N times `MAX_CONCURRENCY` queries of a group.
+pub struct WorkloadGroupManager {
+    groups: Mutex<HashMap<String, Arc<WorkloadGroupResources>>>,
+    // the workload groups by tenant, with the time they are loaded from meta.
+    cached_groups: Mutex<HashMap<String, (Instant, Arc<Vec<WorkloadGroup>>)>>,
+}
+
+impl WorkloadGroupManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(WorkloadGroupManager {
+            groups: Mutex::new(HashMap::new()),
+            cached_groups: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupManager> {
+        GlobalInstance::get()
+    }
+
+    /// Returns the group of a user, a group assigned to the user by name goes before the
+    /// groups assigned to the roles of the user.
+    pub fn match_group<'a>(
+        groups: &'a [WorkloadGroup],
+        user: &str,
+        roles: &[String],
+    ) -> Option<&'a WorkloadGroup> {
+        groups
+            .iter()
+            .find(|group| group.users.iter().any(|u| u == user))
+            .or_else(|| {
+                groups
+                    .iter()
+                    .find(|group| group.roles.iter().any(|r| roles.contains(r)))
+            })
+    }
+
+    /// Drops the cached workload groups of a tenant, after they are created, altered or
+    /// dropped on this node.
+    pub fn invalidate(&self, tenant: &str) {
+        self.cached_groups.lock().remove(tenant);
+    }
+
+    /// The workload groups of a tenant sorted by name, cached for `GROUPS_CACHE_TTL`.
+    #[async_backtrace::framed]
+    async fn get_groups(&self, tenant: &str) -> Result<Arc<Vec<WorkloadGroup>>> {
+        if let Some((loaded_at, groups)) = self.cached_groups.lock().get(tenant) {
+            if loaded_at.elapsed() < GROUPS_CACHE_TTL {
+                return Ok(groups.clone());
+            }
+        }
+
+        let mut groups = UserApiProvider::instance()
+            .get_workload_groups(tenant)
+            .await?;
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+        let groups = Arc::new(groups);
+        self.cached_groups
+            .lock()
+            .insert(tenant.to_string(), (Instant::now(), groups.clone()));
+        Ok(groups)
+    }
+
+    fn get_resources(&self, group: &WorkloadGroup) -> Arc<WorkloadGroupResources> {
+        let mut groups = self.groups.lock();
+        if let Some(resources) = groups.get(&group.name) {
+            if resources.same_limits(group) {
+                return resources.clone();
+            }
+        }
+
+        // the queries admitted by the altered group keep their slots until they finish.
+        let resources = WorkloadGroupResources::create(group.clone());
+        groups.insert(group.name.clone(), resources.clone());
+        resources
+    }
+
+    /// The threads of a query of `resources`, by the cpu weight of the group against the
+    /// groups which have running queries.
+    fn share_threads(&self, resources: &WorkloadGroupResources, max_threads: u64) -> u64 {
+        let groups = self.groups.lock();
+        let total_weight: u64 = groups
+            .values()
+            .filter(|r| r.running_queries() > 0 && r.name() != resources.name())
+            .map(|r| r.group.cpu_weight)
+            .sum::<u64>()
+            + resources.group.cpu_weight;
+        let weight = resources.group.cpu_weight.max(1);
+        (max_threads * weight).div_ceil(total_weight.max(1)).max(1)
+    }
+
+    /// The admission state of the workload group of the current user of a session.
+    ///
+    /// Returns None if the user is not in any workload group.
+    #[async_backtrace::framed]
+    pub async fn get_session_resources(
+        &self,
+        session: &Arc<Session>,
+    ) -> Result<Option<Arc<WorkloadGroupResources>>> {
+        let groups = self.get_groups(&session.get_current_tenant()).await?;
+        if groups.is_empty() {
+            return Ok(None);
+        }
+
+        let user = session.get_current_user()?;
+        // the roles are only needed if the user is not assigned to a group by name.
+        let roles = match groups.iter().any(|group| group.users.contains(&user.name)) {
+            true => vec![],
+            false => session
+                .get_all_available_roles()
+                .await?
+                .into_iter()
+                .map(|role| role.name)
+                .collect::<Vec<_>>(),
+        };
+        Ok(Self::match_group(&groups, &user.name, &roles).map(|group| self.get_resources(group)))
+    }
+
+    /// Waits in the queue of the workload group of the current user until the query can run.
+    ///
+    /// Returns None if the user is not in any workload group.
+    #[async_backtrace::framed]
+    pub async fn admit(&self, ctx: &Arc<QueryContext>) -> Result<Option<WorkloadGroupTicket>> {
+        let Some(resources) = self
+            .get_session_resources(&ctx.get_current_session())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        ctx.set_workload_group(Some(resources.name().to_string()));
+        ctx.set_queued(true);
+        resources.queued.fetch_add(1, Ordering::Relaxed);
+        let permit = self.wait(ctx, &resources).await;
+        resources.queued.fetch_sub(1, Ordering::Relaxed);
+        ctx.set_queued(false);
+        let permit = permit?;
+
+        resources.running.fetch_add(1, Ordering::Relaxed);
+        let max_threads = self.share_threads(&resources, ctx.get_settings().get_max_threads()?);
+        Ok(Some(WorkloadGroupTicket {
+            resources,
+            max_threads,
+            _permit: permit,
+        }))
+    }
+
+    async fn wait(
+        &self,
+        ctx: &Arc<QueryContext>,
+        resources: &Arc<WorkloadGroupResources>,
+    ) -> Result<Option<OwnedSemaphorePermit>> {
+        let queue_timeout = resources.group.queue_timeout_secs;
+        let deadline =
+            (queue_timeout > 0).then(|| Instant::now() + Duration::from_secs(queue_timeout));
+        let check = || -> Result<()> {
+            ctx.check_aborting()?;
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                    "query is queued in workload group {} for more than {} seconds",
+                    resources.name(),
+                    queue_timeout
+                )));
+            }
+            Ok(())
+        };
+
+        // keep the place in the queue while checking the query is killed or timed out.
+        let permit = match &resources.semaphore {
+            None => None,
+            Some(semaphore) => {
+                let mut acquire = std::pin::pin!(semaphore.clone().acquire_owned());
+                loop {
+                    check()?;
+                    if let Ok(permit) = timeout(QUEUE_CHECK_INTERVAL, acquire.as_mut()).await {
+                        break Some(permit.map_err(|e| ErrorCode::Internal(e.to_string()))?);
+                    }
+                }
+            }
+        };
+
+        // the running queries of the group are using up the memory share.
+        while !resources.memory_available() {
+            check()?;
+            sleep(QUEUE_CHECK_INTERVAL).await;
+        }
+        Ok(permit)
+    }
+}
@@ -177,6 +177,7 @@ pub async fn create_query_context_with_cluster(
     let dummy_query_context = QueryContext::create_from_shared(QueryContextShared::try_create(
         dummy_session,
         Cluster::create(nodes, local_id),
+        None,
     )?);
 
     dummy_query_context.get_settings().set_max_threads(8)?;
@@ -283,6 +283,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -307,6 +308,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -342,6 +344,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -386,6 +389,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            clone_source: None,
             cluster_key: None,
         }
     }
@@ -12,20 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpListener;
+use common_base::base::tokio::net::TcpStream;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserOption;
 use common_users::CustomClaims;
 use common_users::EnsureUser;
+use common_users::JwtConfig;
+use common_users::LdapConfig;
 use common_users::UserApiProvider;
 use databend_query::auth::AuthMgr;
 use databend_query::auth::Credential;
 use databend_query::sessions::TableContext;
 use jwt_simple::prelude::*;
 use p256::EncodedPoint;
+use parking_lot::Mutex;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
 use wiremock::Mock;
@@ -391,6 +403,59 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_jwt_group_roles() -> Result<()> {
+    let (key_pair, j) = get_jwks_file_rs256("test_kid");
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    let template = ResponseTemplate::new(200).set_body_raw(j, "application/json");
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template)
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let mut conf = databend_query::test_kits::ConfigBuilder::create().config();
+    conf.query.jwt_key_file = format!("http://{}{}", server.address(), json_path);
+    conf.query.jwt = JwtConfig {
+        group_claim: "groups".to_string(),
+        group_role_mapping: vec!["analysts:analyst".to_string(), "admins:admin".to_string()],
+        ..Default::default()
+    };
+    let (_guard, ctx) =
+        databend_query::test_kits::create_query_context_with_config(conf, None).await?;
+    let auth_mgr = AuthMgr::instance();
+    let user_api = UserApiProvider::instance();
+    let tenant = ctx.get_tenant();
+
+    // the user of the mapped groups is created, the mapped roles are the secondary roles.
+    let custom_claims =
+        CustomClaims::new().with_claim("groups", serde_json::json!(["analysts", "admins"]));
+    let claims = Claims::with_custom_claims(custom_claims, Duration::from_hours(2))
+        .with_subject("carol".to_string());
+    let token = key_pair.sign(claims)?;
+    let session = ctx.get_current_session();
+    auth_mgr
+        .auth(session.clone(), &Credential::Jwt {
+            token,
+            client_ip: None,
+        })
+        .await?;
+    let user_info = user_api
+        .get_user(&tenant, UserIdentity::new("carol", "%"))
+        .await?;
+    assert_eq!(user_info.auth_info, AuthInfo::JWT);
+    let mut roles = user_info.grants.roles();
+    roles.sort();
+    assert_eq!(roles, vec!["admin", "analyst"]);
+    assert_eq!(
+        session.get_secondary_roles(),
+        Some(vec!["analyst".to_string(), "admin".to_string()])
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
     let kid = "test_kid";
@@ -647,3 +712,222 @@ async fn test_jwt_auth_mgr_with_management() -> Result<()> {
         Ok(())
     }
 }
+
+const ALICE_DN: &str = "uid=alice,ou=people,dc=example,dc=org";
+const ALICE_PASSWORD: &str = "alice-password";
+
+fn ber(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut buf = vec![tag];
+    if value.len() < 0x80 {
+        buf.push(value.len() as u8);
+    } else {
+        buf.push(0x82);
+        buf.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    }
+    buf.extend_from_slice(value);
+    buf
+}
+
+// Returns the tag, the value and the remaining bytes, or None if the value is incomplete.
+fn read_ber(buf: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (len, header) = match *buf.get(1)? {
+        n if n < 0x80 => (n as usize, 2),
+        0x81 => (*buf.get(2)? as usize, 3),
+        0x82 => (u16::from_be_bytes([*buf.get(2)?, *buf.get(3)?]) as usize, 4),
+        n => panic!("unexpected length {}", n),
+    };
+    let value = buf.get(header..header + len)?;
+    Some((buf[0], value, &buf[header + len..]))
+}
+
+fn ldap_result(op: u8, code: u8) -> Vec<u8> {
+    ber(
+        op,
+        &[ber(0x0a, &[code]), ber(0x04, b""), ber(0x04, b"")].concat(),
+    )
+}
+
+// A LDAP server with the only user alice, whose groups can be changed by the test.
+async fn serve_ldap(mut stream: TcpStream, groups: Arc<Mutex<Vec<String>>>) {
+    let mut buf = vec![];
+    loop {
+        let Some((_, message, rest)) = read_ber(&buf) else {
+            let mut chunk = [0u8; 4096];
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            }
+            continue;
+        };
+        let (_, id, op) = read_ber(message).unwrap();
+        let (op, value, _) = read_ber(op).unwrap();
+        let reply = |op: Vec<u8>| ber(0x30, &[ber(0x02, id), op].concat());
+
+        let mut replies = vec![];
+        match op {
+            // bind
+            0x60 => {
+                let (_, _, rest) = read_ber(value).unwrap();
+                let (_, dn, rest) = read_ber(rest).unwrap();
+                let (_, password, _) = read_ber(rest).unwrap();
+                let anonymous = dn.is_empty() && password.is_empty();
+                let alice = dn == ALICE_DN.as_bytes() && password == ALICE_PASSWORD.as_bytes();
+                let code = if anonymous || alice { 0 } else { 49 };
+                replies.push(reply(ldap_result(0x61, code)));
+            }
+            // search
+            0x63 => {
+                if value.windows(5).any(|w| w == b"alice") {
+                    let groups = groups
+                        .lock()
+                        .iter()
+                        .map(|group| ber(0x04, group.as_bytes()))
+                        .collect::<Vec<_>>()
+                        .concat();
+                    let attribute =
+                        ber(0x30, &[ber(0x04, b"memberOf"), ber(0x31, &groups)].concat());
+                    let entry = [ber(0x04, ALICE_DN.as_bytes()), ber(0x30, &attribute)].concat();
+                    replies.push(reply(ber(0x64, &entry)));
+                }
+                replies.push(reply(ldap_result(0x65, 0)));
+            }
+            // unbind
+            0x42 => return,
+            op => panic!("unexpected ldap op {}", op),
+        }
+        buf = rest.to_vec();
+        for reply in replies {
+            stream.write_all(&reply).await.unwrap();
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_ldap() -> Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let groups = Arc::new(Mutex::new(vec![
+        "cn=analysts,ou=groups,dc=example,dc=org".to_string(),
+    ]));
+    let server_groups = groups.clone();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(serve_ldap(stream, server_groups.clone()));
+        }
+    });
+
+    let mut conf = databend_query::test_kits::ConfigBuilder::create().config();
+    conf.query.ldap = LdapConfig {
+        url: format!("ldap://{}", address),
+        allow_insecure: true,
+        base_dn: "dc=example,dc=org".to_string(),
+        group_role_mapping: vec!["analysts:analyst".to_string(), "admins:admin".to_string()],
+        ..Default::default()
+    };
+    let (_guard, ctx) =
+        databend_query::test_kits::create_query_context_with_config(conf, None).await?;
+    let auth_mgr = AuthMgr::instance();
+    let user_api = UserApiProvider::instance();
+    let tenant = ctx.get_tenant();
+    let alice = UserIdentity::new("alice", "%");
+    let credential = |name: &str, password: &str| Credential::Password {
+        name: name.to_string(),
+        password: Some(password.as_bytes().to_vec()),
+        client_ip: None,
+    };
+
+    // the user is created on the first login
+    auth_mgr
+        .auth(
+            ctx.get_current_session(),
+            &credential("alice", ALICE_PASSWORD),
+        )
+        .await?;
+    let user_info = user_api.get_user(&tenant, alice.clone()).await?;
+    assert_eq!(user_info.auth_info, AuthInfo::Ldap);
+    assert_eq!(user_info.grants.roles(), vec!["analyst"]);
+    assert_eq!(
+        user_info.option.default_role(),
+        Some(&"analyst".to_string())
+    );
+
+    // wrong password
+    let res = auth_mgr
+        .auth(ctx.get_current_session(), &credential("alice", "wrong"))
+        .await;
+    assert!(
+        res.err()
+            .unwrap()
+            .to_string()
+            .contains("wrong ldap user name or password")
+    );
+
+    // the users not in the directory are not created
+    let res = auth_mgr
+        .auth(
+            ctx.get_current_session(),
+            &credential("bob", "bob-password"),
+        )
+        .await;
+    assert!(res.is_err());
+    assert!(
+        user_api
+            .get_user(&tenant, UserIdentity::new("bob", "%"))
+            .await
+            .is_err()
+    );
+
+    // the mapped roles are refreshed on each login, the other roles are kept
+    user_api
+        .grant_role_to_user(&tenant, alice.clone(), "manual".to_string())
+        .await?;
+    *groups.lock() = vec!["cn=admins,ou=groups,dc=example,dc=org".to_string()];
+    auth_mgr
+        .auth(
+            ctx.get_current_session(),
+            &credential("alice", ALICE_PASSWORD),
+        )
+        .await?;
+    let mut roles = ctx.get_current_session().get_current_user()?.grants.roles();
+    roles.sort();
+    assert_eq!(roles, vec!["admin", "manual"]);
+    let mut roles = user_api
+        .get_user(&tenant, alice.clone())
+        .await?
+        .grants
+        .roles();
+    roles.sort();
+    assert_eq!(roles, vec!["admin", "manual"]);
+
+    // the wrong passwords lock the user out by its password policy
+    let policy = PasswordPolicy {
+        name: "ldap_policy".to_string(),
+        min_length: 8,
+        max_length: 256,
+        max_retries: 2,
+        lockout_time_mins: 10,
+        ..Default::default()
+    };
+    user_api.add_password_policy(&tenant, policy, false).await?;
+    let option = UserOption::empty().with_password_policy(Some("ldap_policy".to_string()));
+    user_api
+        .update_user(&tenant, alice.clone(), None, Some(option))
+        .await?;
+    for _ in 0..2 {
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &credential("alice", "wrong"))
+            .await;
+        assert!(res.is_err());
+    }
+    let user_info = user_api.get_user(&tenant, alice).await?;
+    assert!(user_info.lockout_time.is_some());
+    let res = auth_mgr
+        .auth(
+            ctx.get_current_session(),
+            &credential("alice", ALICE_PASSWORD),
+        )
+        .await;
+    assert!(res.err().unwrap().to_string().contains("is locked until"));
+
+    Ok(())
+}
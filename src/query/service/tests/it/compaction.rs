@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_config::InnerConfig;
+use common_storages_fuse::operations::TableFragmentation;
+use databend_query::compaction::Candidate;
+use databend_query::compaction::CompactionScheduler;
+use databend_query::compaction::Decision;
+use pretty_assertions::assert_eq;
+
+fn create_scheduler(tables_per_round: u64) -> CompactionScheduler {
+    let mut conf = InnerConfig::default();
+    conf.query.compaction_scheduler_interval_secs = 60;
+    conf.query.compaction_scheduler_tables_per_round = tables_per_round;
+    conf.query.compaction_scheduler_segment_threshold = 10;
+    conf.query.compaction_scheduler_block_threshold = 100;
+    CompactionScheduler::create(&conf)
+}
+
+fn candidate(table_id: u64, excess_segments: u64, undersized_blocks: u64) -> Candidate {
+    Candidate {
+        database: "db".to_string(),
+        table: format!("t{table_id}"),
+        table_id,
+        fragmentation: TableFragmentation {
+            segment_count: excess_segments + 1,
+            block_count: undersized_blocks + 1,
+            excess_segments,
+            undersized_blocks,
+        },
+    }
+}
+
+fn decisions(scheduled: &[(Candidate, Decision)]) -> Vec<(u64, Decision)> {
+    scheduled
+        .iter()
+        .map(|(candidate, decision)| (candidate.table_id, *decision))
+        .collect()
+}
+
+#[test]
+fn test_is_fragmented() {
+    let scheduler = create_scheduler(2);
+    assert!(!scheduler.is_fragmented(&candidate(1, 9, 99).fragmentation));
+    assert!(scheduler.is_fragmented(&candidate(1, 10, 0).fragmentation));
+    assert!(scheduler.is_fragmented(&candidate(1, 0, 100).fragmentation));
+}
+
+#[test]
+fn test_schedule_most_fragmented_first() {
+    let mut scheduler = create_scheduler(2);
+    let now = Instant::now();
+    let scheduled = scheduler.schedule(
+        vec![
+            candidate(1, 10, 0),
+            candidate(2, 50, 100),
+            candidate(3, 0, 120),
+        ],
+        now,
+    );
+    assert_eq!(decisions(&scheduled), vec![
+        (2, Decision::Compact),
+        (3, Decision::Compact),
+        (1, Decision::Deferred),
+    ]);
+}
+
+#[test]
+fn test_schedule_backoff() {
+    let mut scheduler = create_scheduler(2);
+    let now = Instant::now();
+
+    // the backoff doubles with every failure
+    assert_eq!(scheduler.back_off(2, now), (1, Duration::from_secs(120)));
+    assert_eq!(scheduler.back_off(2, now), (2, Duration::from_secs(240)));
+    assert_eq!(scheduler.failures(2), 2);
+
+    // a backed off table does not use the budget of the round
+    let scheduled = scheduler.schedule(
+        vec![
+            candidate(1, 10, 0),
+            candidate(2, 50, 100),
+            candidate(3, 0, 120),
+        ],
+        now,
+    );
+    assert_eq!(decisions(&scheduled), vec![
+        (2, Decision::Backoff),
+        (3, Decision::Compact),
+        (1, Decision::Compact),
+    ]);
+
+    // the table is compacted again once the backoff is over
+    let scheduled = scheduler.schedule(vec![candidate(2, 50, 100)], now + Duration::from_secs(241));
+    assert_eq!(decisions(&scheduled), vec![(2, Decision::Compact)]);
+    assert_eq!(scheduler.failures(2), 2);
+
+    // the backoff of a table which is no longer a candidate is forgotten
+    scheduler.schedule(vec![candidate(1, 10, 0)], now);
+    assert_eq!(scheduler.failures(2), 0);
+}
+
+#[test]
+fn test_backoff_is_capped() {
+    let mut scheduler = create_scheduler(2);
+    let now = Instant::now();
+    let mut backoff = Duration::ZERO;
+    for _ in 0..20 {
+        backoff = scheduler.back_off(1, now).1;
+    }
+    assert_eq!(scheduler.failures(1), 20);
+    assert_eq!(backoff, Duration::from_secs(24 * 60 * 60));
+}
@@ -23,6 +23,7 @@ mod api;
 mod auth;
 mod catalogs;
 mod clusters;
+mod compaction;
 mod configs;
 mod databases;
 mod frame;
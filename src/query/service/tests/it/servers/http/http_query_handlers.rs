@@ -14,9 +14,13 @@
 
 use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::Cursor;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_array::Array;
+use arrow_array::UInt64Array;
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use common_base::base::get_free_tcp_port;
@@ -25,17 +29,22 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::PasswordHashMethod;
+use common_storage::DataOperator;
 use common_users::CustomClaims;
 use common_users::EnsureUser;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::arrow_block::ARROW_METADATA_QUERY_RESPONSE;
+use databend_query::servers::http::v1::arrow_block::ARROW_STREAM_CONTENT_TYPE;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::sweep_all_expired_results;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
+use databend_query::servers::http::v1::PersistedResult;
 use databend_query::servers::http::v1::QueryResponse;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
@@ -480,6 +489,70 @@ async fn test_pagination() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_result() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select number, to_string(number) from numbers(10)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 1, "max_rows_per_page": 4}});
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT, ARROW_STREAM_CONTENT_TYPE.parse().unwrap());
+
+    let mut response = post_json_to_endpoint_raw(&ep, &json, headers).await?;
+    let mut numbers = vec![];
+    loop {
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            ARROW_STREAM_CONTENT_TYPE
+        );
+        let body = response.into_body().into_vec().await.unwrap();
+        let reader = StreamReader::try_new(Cursor::new(body), None).unwrap();
+        let result = serde_json::from_str::<QueryResponse>(
+            &reader.schema().metadata()[ARROW_METADATA_QUERY_RESPONSE],
+        )?;
+        assert!(result.error.is_none(), "{:?}", result);
+        assert!(result.data.is_empty(), "{:?}", result);
+        assert_eq!(result.schema.len(), 2, "{:?}", result);
+
+        let mut rows = 0;
+        for batch in reader {
+            let batch = batch.unwrap();
+            let column = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap();
+            numbers.extend(column.values().iter().copied());
+            rows += batch.num_rows();
+        }
+        assert!(rows <= 4, "{:?}", result);
+
+        let next_uri = result.next_uri.unwrap();
+        if next_uri == make_final_uri(&result.id) {
+            break;
+        }
+        response = get_uri(&ep, &next_uri).await;
+    }
+    assert_eq!(numbers, (0..10).collect::<Vec<u64>>());
+
+    // the result format can also be chosen in the request
+    let json = serde_json::json!({"sql": sql.to_string(), "result_format": "arrow"});
+    let response = post_json_to_endpoint_raw(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(
+        response.headers().get(header::CONTENT_TYPE).unwrap(),
+        ARROW_STREAM_CONTENT_TYPE
+    );
+
+    // errors are still reported in JSON
+    let json = serde_json::json!({"sql": "select * from t_not_exists", "result_format": "arrow"});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_http_session() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -543,6 +616,122 @@ async fn test_result_timeout() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_async_result() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select * from numbers(5)";
+    let json = serde_json::json!({"sql": sql.to_string(), "async": true, "pagination": {"max_rows_per_page": 2}, "session": { "settings": {"http_handler_result_timeout_secs": "1"}}});
+
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert!(result.data.is_empty(), "{:?}", result);
+    let query_id = result.id.clone();
+    assert_eq!(
+        result.next_uri,
+        Some(make_page_uri(&query_id, 0)),
+        "{:?}",
+        result
+    );
+
+    // the pages are produced in the background, an empty page is returned until then.
+    let mut rows = 0;
+    let mut next_uri = result.next_uri.clone().unwrap();
+    for _ in 0..100 {
+        let (status, result) = get_uri_checked(&ep, &next_uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result);
+        rows += result.data.len();
+        next_uri = result.next_uri.clone().unwrap();
+        if next_uri == make_final_uri(&query_id) {
+            break;
+        }
+        if result.data.is_empty() {
+            sleep(Duration::from_millis(100)).await;
+        }
+    }
+    assert_eq!(rows, 5);
+
+    // the result is still available after the query is removed from the memory.
+    sleep(Duration::from_secs(2)).await;
+    let uri = format!("/v1/query/{}/result", query_id);
+    let (status, result) = get_uri_checked(&ep, &uri).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+    assert_eq!(result.data.len(), 5, "{:?}", result);
+    assert_eq!(result.schema.len(), 1, "{:?}", result);
+
+    let (status, result) = get_uri_checked(&ep, &make_page_uri(&query_id, 2)).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data.len(), 1, "{:?}", result);
+    assert_eq!(
+        result.next_uri,
+        Some(make_final_uri(&query_id)),
+        "{:?}",
+        result
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_async_result_of_lost_node() -> Result<()> {
+    let config = ConfigBuilder::create().build();
+    let _guard = TestGlobalServices::setup(config.clone()).await?;
+    let tenant = config.query.tenant_id.clone();
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "select 1", "async": true});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let query_id = result.id.clone();
+
+    let store = PersistedResult::create(&tenant, &query_id);
+    let mut meta = None;
+    for _ in 0..100 {
+        meta = store.read_meta().await?;
+        if matches!(&meta, Some(m) if m.state != ExecuteStateKind::Running) {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    let mut meta = meta.unwrap();
+    assert_eq!(meta.state, ExecuteStateKind::Succeeded);
+    let response = get_uri(&ep, &make_final_uri(&query_id)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // pretend the query is still running on another node, which stopped the heartbeat.
+    meta.node_id = "lost-node".to_string();
+    meta.state = ExecuteStateKind::Running;
+    meta.heartbeat_at -= 3600;
+    store.write_meta(&meta).await?;
+    let (status, result) = get_uri_checked(&ep, &make_state_uri(&query_id)).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Failed, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+
+    // the kill request of a query on another node is left to that node.
+    meta.heartbeat_at += 3600;
+    store.write_meta(&meta).await?;
+    let uri = format!("/v1/query/{}/kill", query_id);
+    let response = get_uri(&ep, &uri).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // the expired results of every tenant are removed by the sweep without being read.
+    meta.expire_at = 0;
+    store.write_meta(&meta).await?;
+    let other_store = PersistedResult::create("other_tenant", &query_id);
+    other_store.write_meta(&meta).await?;
+    sweep_all_expired_results().await?;
+    let response = get_uri(&ep, &make_state_uri(&query_id)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let operator = DataOperator::instance().operator();
+    let other_meta = format!("_async_result/other_tenant/{}/meta.json", query_id);
+    assert!(!operator.is_exist(&other_meta).await?);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_system_tables() -> Result<()> {
     let config = ConfigBuilder::create().build();
@@ -828,6 +1017,15 @@ async fn post_json_to_endpoint(
     json: &serde_json::Value,
     headers: HeaderMap,
 ) -> Result<(StatusCode, QueryResponse)> {
+    let response = post_json_to_endpoint_raw(ep, json, headers).await?;
+    check_response(response).await
+}
+
+async fn post_json_to_endpoint_raw(
+    ep: &EndpointType,
+    json: &serde_json::Value,
+    headers: HeaderMap,
+) -> Result<Response> {
     let uri = "/v1/query";
     let content_type = "application/json";
     let body = serde_json::to_vec(&json)?;
@@ -845,7 +1043,7 @@ async fn post_json_to_endpoint(
         .call(req)
         .await
         .map_err(|e| ErrorCode::Internal(e.to_string()))?;
-    check_response(response).await
+    Ok(response)
 }
 
 #[tokio::test(flavor = "current_thread")]
@@ -1407,7 +1605,7 @@ async fn test_session_secondary_roles() -> Result<()> {
 
     let route = create_endpoint().await?;
 
-    // failed input: only ALL or NONE is allowed
+    // failed input: the secondary roles must be granted to the user
     let json = serde_json::json!({"sql":  "SELECT 1", "session": {"secondary_roles": vec!["role1".to_string()]}});
     let (_, result) = post_json_to_endpoint(&route, &json, HeaderMap::default()).await?;
     assert!(result.error.is_some());
@@ -1416,7 +1614,7 @@ async fn test_session_secondary_roles() -> Result<()> {
             .error
             .unwrap()
             .message
-            .contains("only ALL or NONE is allowed on setting secondary roles")
+            .contains("secondary role role1 is not granted to the current user")
     );
     assert_eq!(result.state, ExecuteStateKind::Failed);
 
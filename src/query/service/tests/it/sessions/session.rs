@@ -12,10 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use common_base::base::tokio;
+use common_base::runtime::MemStat;
 use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::WorkloadGroup;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
+use databend_query::sessions::UserResourcesManager;
+use databend_query::sessions::WorkloadGroupManager;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
 
@@ -68,3 +77,84 @@ async fn test_session_in_management_mode() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_resources() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut user = UserInfo::new("u1", "%", AuthInfo::None);
+    user.quota.max_cpu = 4;
+    let resources = UserResourcesManager::instance().get_resources(&user);
+    assert!(resources.mem_stat(None).is_none());
+    resources.check_memory()?;
+
+    // the running queries of the user take at most 4 threads, but at least one for each.
+    let slots1 = resources.acquire_threads(3);
+    assert_eq!(slots1.threads(), 3);
+    let slots2 = resources.acquire_threads(3);
+    assert_eq!(slots2.threads(), 1);
+    let slots3 = resources.acquire_threads(3);
+    assert_eq!(slots3.threads(), 1);
+
+    drop(slots1);
+    drop(slots2);
+    let slots4 = resources.acquire_threads(8);
+    assert_eq!(slots4.threads(), 3);
+
+    // the sessions of the same user share the resources, and see the altered quota.
+    user.quota.max_cpu = 0;
+    user.quota.max_memory_in_bytes = 1024 * 1024 * 1024;
+    let same = UserResourcesManager::instance().get_resources(&user);
+    assert!(Arc::ptr_eq(&resources, &same));
+    let mem_stat = same.mem_stat(None).unwrap();
+    assert!(Arc::ptr_eq(&mem_stat, &same.mem_stat(None).unwrap()));
+
+    // the memory of the user is accounted to the workload group of the user.
+    let group_mem_stat = MemStat::create_child("WorkloadGroup(g1)".to_string(), None);
+    let in_group = same.mem_stat(Some(group_mem_stat.clone())).unwrap();
+    assert!(!Arc::ptr_eq(&mem_stat, &in_group));
+    assert!(Arc::ptr_eq(
+        &in_group,
+        &same.mem_stat(Some(group_mem_stat)).unwrap()
+    ));
+    assert_eq!(same.acquire_threads(8).threads(), 8);
+
+    // the storage used by the users is cached by tenant.
+    let manager = UserResourcesManager::instance();
+    assert!(manager.get_storage_usage("tenant1").is_none());
+    manager.set_storage_usage("tenant1", HashMap::from([("u1".to_string(), 10)]));
+    let usage = manager.get_storage_usage("tenant1").unwrap();
+    assert_eq!(usage.get("u1"), Some(&10));
+    assert!(manager.get_storage_usage("tenant2").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_workload_group_match() -> Result<()> {
+    let etl = WorkloadGroup {
+        name: "etl".to_string(),
+        roles: vec!["r1".to_string()],
+        ..Default::default()
+    };
+    let adhoc = WorkloadGroup {
+        name: "adhoc".to_string(),
+        users: vec!["u1".to_string()],
+        roles: vec!["r2".to_string()],
+        ..Default::default()
+    };
+    let groups = vec![etl, adhoc];
+    let roles = vec!["r1".to_string(), "public".to_string()];
+
+    // the group of the user goes before the groups of the roles of the user.
+    let group = WorkloadGroupManager::match_group(&groups, "u1", &roles);
+    assert_eq!(group.map(|g| g.name.as_str()), Some("adhoc"));
+
+    let group = WorkloadGroupManager::match_group(&groups, "u2", &roles);
+    assert_eq!(group.map(|g| g.name.as_str()), Some("etl"));
+
+    let group = WorkloadGroupManager::match_group(&groups, "u2", &["public".to_string()]);
+    assert!(group.is_none());
+
+    Ok(())
+}
@@ -82,7 +82,6 @@ pub async fn test_snapshot_consistency() -> Result<()> {
             metadata,
             bind_context: _bind_context,
             rewrite_kind: _rewrite_kind,
-            formatted_ast: _formatted_ast,
             ignore_result: _ignore_result,
         } = query_plan
         {
@@ -341,6 +341,8 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector: None,
+        partition: None,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -356,6 +358,7 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         index_size: 0,
         col_stats: col_stats.clone(),
         cluster_stats: None,
+        partition: None,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
@@ -91,6 +91,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 6,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let mut latest_snapshot = TableSnapshot::new_empty_snapshot(TableSchema::default());
@@ -109,6 +110,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 9,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let removed_statistics = Statistics {
@@ -120,6 +122,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 5,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let merged_statistics = Statistics {
@@ -131,6 +134,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 8,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -163,6 +167,7 @@ fn test_resolvable_delete_conflict() {
         index_size: 12,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
     assert_eq!(actual, expected);
 }
@@ -194,6 +199,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 6,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let mut latest_snapshot = TableSnapshot::new_empty_snapshot(TableSchema::default());
@@ -212,6 +218,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 9,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let removed_statistics = Statistics {
@@ -223,6 +230,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 5,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let merged_statistics = Statistics {
@@ -234,6 +242,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 8,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -270,6 +279,7 @@ fn test_resolvable_replace_conflict() {
         index_size: 12,
         col_stats: HashMap::new(),
         cluster_stats: None,
+        partition: None,
     };
     assert_eq!(actual, expected);
 }
@@ -182,6 +182,7 @@ async fn test_fuse_table_optimize_alter_table() -> Result<()> {
         field,
         comment: "".to_string(),
         option: AddColumnOption::End,
+        codec: None,
     };
     let interpreter = AddTableColumnInterpreter::try_create(ctx.clone(), add_table_column_plan)?;
     interpreter.execute(ctx.clone()).await?;
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use chrono::Duration;
 use chrono::Utc;
 use common_base::base::tokio;
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_storages_fuse::io::MetaWriter;
@@ -349,3 +350,139 @@ async fn test_fuse_purge_older_version() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_purge_replaced_partition() -> Result<()> {
+    let fixture = TestFixture::new().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t(id int, p int) partition by (p)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t_src(id int, p int) partition by (p)"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t_src values(1, 1), (2, 1)"))
+        .await?;
+    fixture
+        .execute_command(&format!(
+            "alter table {db}.t replace partition (1) from {db}.t_src"
+        ))
+        .await?;
+
+    // the blocks of the partition are shared by the two tables.
+    let ctx = fixture.new_query_ctx().await?;
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let table = ctx
+        .get_table(&fixture.default_catalog_name(), &db, "t")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let shared = fuse_table
+        .get_block_locations(table_ctx, &snapshot.segments, false, false)
+        .await?
+        .block_location;
+    assert!(!shared.is_empty());
+    let operator = fuse_table.get_operator();
+
+    // the source table drops the partition, the blocks are kept for the marker.
+    fixture
+        .execute_command(&format!("alter table {db}.t_src drop partition (1)"))
+        .await?;
+    purge_table(&fixture, "t_src").await?;
+    for location in &shared {
+        assert!(operator.is_exist(location).await?);
+    }
+
+    // the marker is released once the partition is dropped from the table.
+    fixture
+        .execute_command(&format!("alter table {db}.t drop partition (1)"))
+        .await?;
+    purge_table(&fixture, "t_src").await?;
+    for location in &shared {
+        assert!(!operator.is_exist(location).await?);
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_purge_released_clone() -> Result<()> {
+    let fixture = TestFixture::new().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("create table {db}.t_src(id int)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t_src values(1), (2)"))
+        .await?;
+    fixture
+        .execute_command(&format!("create table {db}.t clone {db}.t_src"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let catalog = fixture.default_catalog_name();
+    let source = ctx.get_table(&catalog, &db, "t_src").await?;
+    let source = FuseTable::try_from_table(source.as_ref())?;
+    let snapshot = source.read_table_snapshot().await?.unwrap();
+    let shared = source
+        .get_block_locations(table_ctx, &snapshot.segments, false, false)
+        .await?
+        .block_location;
+    assert!(!shared.is_empty());
+    let operator = source.get_operator();
+    let table = ctx.get_table(&catalog, &db, "t").await?;
+    let marker = source
+        .meta_location_generator()
+        .gen_clone_marker_location(table.get_id());
+
+    // the clone still references the files of the source table, the marker is kept.
+    fixture
+        .execute_command(&format!("insert into {db}.t values(3)"))
+        .await?;
+    purge_table(&fixture, "t").await?;
+    assert_eq!(source.active_clone_markers().await?.len(), 1);
+
+    // the clone no longer references the files of the source table once the snapshots
+    // sharing them are purged, the marker is released but not removed.
+    fixture
+        .execute_command(&format!("truncate table {db}.t"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(4)"))
+        .await?;
+    purge_table(&fixture, "t").await?;
+    assert!(source.active_clone_markers().await?.is_empty());
+    assert!(operator.is_exist(&marker).await?);
+
+    fixture
+        .execute_command(&format!("truncate table {db}.t_src"))
+        .await?;
+    purge_table(&fixture, "t_src").await?;
+    for location in &shared {
+        assert!(!operator.is_exist(location).await?);
+    }
+    Ok(())
+}
+
+async fn purge_table(fixture: &TestFixture, table_name: &str) -> Result<()> {
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table(
+            &fixture.default_catalog_name(),
+            &fixture.default_db_name(),
+            table_name,
+        )
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot_files = fuse_table.list_snapshot_files().await?;
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    fuse_table
+        .do_purge(&table_ctx, snapshot_files, None, true, false)
+        .await?;
+    Ok(())
+}
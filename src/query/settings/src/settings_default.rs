@@ -112,6 +112,12 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("http_handler_async_result_retention_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(86400), // seconds
+                    desc: "Sets the time in seconds that the persisted results of an async http query are kept.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
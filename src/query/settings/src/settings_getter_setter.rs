@@ -256,6 +256,10 @@ impl Settings {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
 
+    pub fn get_http_handler_async_result_retention_secs(&self) -> Result<u64> {
+        self.try_get_u64("http_handler_async_result_retention_secs")
+    }
+
     pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
@@ -17,7 +17,6 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
-use common_ast::ast::format_statement;
 use common_ast::ast::ExplainKind;
 use common_ast::ast::Hint;
 use common_ast::ast::Identifier;
@@ -198,18 +197,12 @@ impl<'a> Binder {
                         Arc::new(s_expr),
                     );
                 }
-                let formatted_ast = if self.ctx.get_settings().get_enable_query_result_cache()? {
-                    Some(format_statement(stmt.clone())?)
-                } else {
-                    None
-                };
                 Plan::Query {
                     s_expr: Box::new(s_expr),
                     metadata: self.metadata.clone(),
                     bind_context: Box::new(bind_context),
                     rewrite_kind: None,
                     ignore_result: query.ignore_result,
-                    formatted_ast,
                 }
             }
 
@@ -536,6 +529,15 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
             Statement::CreateNetworkPolicy(stmt) => {
                 self.bind_create_network_policy(stmt).await?
             }
@@ -551,6 +553,33 @@ impl<'a> Binder {
             Statement::ShowNetworkPolicies => {
                 self.bind_show_network_policies().await?
             }
+            Statement::CreatePasswordPolicy(stmt) => {
+                self.bind_create_password_policy(stmt).await?
+            }
+            Statement::AlterPasswordPolicy(stmt) => {
+                self.bind_alter_password_policy(stmt).await?
+            }
+            Statement::DropPasswordPolicy(stmt) => {
+                self.bind_drop_password_policy(stmt).await?
+            }
+            Statement::DescPasswordPolicy(stmt) => {
+                self.bind_desc_password_policy(stmt).await?
+            }
+            Statement::ShowPasswordPolicies => {
+                self.bind_show_password_policies().await?
+            }
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
+            Statement::ShowWorkloadGroups => {
+                self.bind_show_workload_groups().await?
+            }
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
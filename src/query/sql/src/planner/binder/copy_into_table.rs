@@ -355,7 +355,6 @@ impl<'a> Binder {
             bind_context: Box::new(output_context),
             rewrite_kind: None,
             ignore_result: false,
-            formatted_ast: None,
         }));
         Ok(Plan::CopyIntoTable(Box::new(plan)))
     }
@@ -18,11 +18,13 @@ use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
 use common_ast::ast::GrantStmt;
 use common_ast::ast::RevokeStmt;
+use common_ast::ast::UserOptionItem;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_users::UserApiProvider;
 
 use crate::plans::AlterUserPlan;
@@ -158,13 +160,26 @@ impl Binder {
             user_options,
         } = stmt;
         let mut user_option = UserOption::default();
+        let mut quota = UserQuota::no_limit();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut quota);
+        }
+        let auth_info = AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?;
+        if let (AuthInfo::Password { .. }, Some(policy), Some(password)) = (
+            &auth_info,
+            user_option.password_policy(),
+            &auth_option.password,
+        ) {
+            UserApiProvider::instance()
+                .verify_password(&self.ctx.get_tenant(), policy, password, None)
+                .await?;
         }
         let plan = CreateUserPlan {
             user: user.clone(),
-            auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
+            auth_info,
             user_option,
+            quota,
             if_not_exists: *if_not_exists,
         };
         Ok(Plan::CreateUser(Box::new(plan)))
@@ -204,18 +219,42 @@ impl Binder {
         };
 
         let mut user_option = user_info.option.clone();
+        let mut quota = user_info.quota.clone();
         for option in user_options {
             option.apply(&mut user_option);
+            option.apply_quota(&mut quota);
+        }
+
+        // the new password must satisfy the password policy of the user after the change.
+        if let (Some(AuthInfo::Password { .. }), Some(policy), Some(auth_option)) =
+            (&new_auth_info, user_option.password_policy(), auth_option)
+        {
+            if let Some(password) = &auth_option.password {
+                UserApiProvider::instance()
+                    .verify_password(&self.ctx.get_tenant(), policy, password, Some(&user_info))
+                    .await?;
+            }
         }
+
+        let unlock = user_options
+            .iter()
+            .any(|option| matches!(option, UserOptionItem::Unlock));
         let new_user_option = if user_option == user_info.option {
             None
         } else {
             Some(user_option)
         };
+        let new_quota = if quota == user_info.quota {
+            None
+        } else {
+            Some(quota)
+        };
         let plan = AlterUserPlan {
             user: user_info.identity(),
             auth_info: new_auth_info,
             user_option: new_user_option,
+            quota: new_quota,
+            unlock,
         };
 
         Ok(Plan::AlterUser(Box::new(plan)))
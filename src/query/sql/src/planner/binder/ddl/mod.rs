@@ -20,10 +20,13 @@ mod data_mask;
 mod database;
 mod index;
 mod network_policy;
+mod password_policy;
 mod role;
+mod row_access_policy;
 mod share;
 mod stage;
 mod table;
 mod task;
 mod view;
 mod virtual_column;
+mod workload_group;
@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::Result;
+use common_meta_app::principal::DEFAULT_PASSWORD_HISTORY;
+use common_meta_app::principal::DEFAULT_PASSWORD_LOCKOUT_TIME_MINS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_AGE_DAYS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_LENGTH;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_RETRIES;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_AGE_DAYS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_CHARS;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_LENGTH;
+use common_meta_app::principal::DEFAULT_PASSWORD_MIN_SPECIAL_CHARS;
+
+use crate::binder::Binder;
+use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::Plan;
+use crate::plans::ShowPasswordPoliciesPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_password_policy(
+        &mut self,
+        stmt: &CreatePasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let CreatePasswordPolicyStmt {
+            if_not_exists,
+            name,
+            options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let mut plan = CreatePasswordPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            min_length: DEFAULT_PASSWORD_MIN_LENGTH,
+            max_length: DEFAULT_PASSWORD_MAX_LENGTH,
+            min_upper_case_chars: DEFAULT_PASSWORD_MIN_CHARS,
+            min_lower_case_chars: DEFAULT_PASSWORD_MIN_CHARS,
+            min_numeric_chars: DEFAULT_PASSWORD_MIN_CHARS,
+            min_special_chars: DEFAULT_PASSWORD_MIN_SPECIAL_CHARS,
+            min_age_days: DEFAULT_PASSWORD_MIN_AGE_DAYS,
+            max_age_days: DEFAULT_PASSWORD_MAX_AGE_DAYS,
+            max_retries: DEFAULT_PASSWORD_MAX_RETRIES,
+            lockout_time_mins: DEFAULT_PASSWORD_LOCKOUT_TIME_MINS,
+            history: DEFAULT_PASSWORD_HISTORY,
+            comment: "".to_string(),
+        };
+        for option in options {
+            match option {
+                PasswordPolicyOption::MinLength(v) => plan.min_length = *v,
+                PasswordPolicyOption::MaxLength(v) => plan.max_length = *v,
+                PasswordPolicyOption::MinUpperCaseChars(v) => plan.min_upper_case_chars = *v,
+                PasswordPolicyOption::MinLowerCaseChars(v) => plan.min_lower_case_chars = *v,
+                PasswordPolicyOption::MinNumericChars(v) => plan.min_numeric_chars = *v,
+                PasswordPolicyOption::MinSpecialChars(v) => plan.min_special_chars = *v,
+                PasswordPolicyOption::MinAgeDays(v) => plan.min_age_days = *v,
+                PasswordPolicyOption::MaxAgeDays(v) => plan.max_age_days = *v,
+                PasswordPolicyOption::MaxRetries(v) => plan.max_retries = *v,
+                PasswordPolicyOption::LockoutTimeMins(v) => plan.lockout_time_mins = *v,
+                PasswordPolicyOption::History(v) => plan.history = *v,
+                PasswordPolicyOption::Comment(v) => plan.comment = v.clone(),
+            }
+        }
+        Ok(Plan::CreatePasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_password_policy(
+        &mut self,
+        stmt: &AlterPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let AlterPasswordPolicyStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let mut plan = AlterPasswordPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            min_length: None,
+            max_length: None,
+            min_upper_case_chars: None,
+            min_lower_case_chars: None,
+            min_numeric_chars: None,
+            min_special_chars: None,
+            min_age_days: None,
+            max_age_days: None,
+            max_retries: None,
+            lockout_time_mins: None,
+            history: None,
+            comment: None,
+        };
+        for option in options {
+            match option {
+                PasswordPolicyOption::MinLength(v) => plan.min_length = Some(*v),
+                PasswordPolicyOption::MaxLength(v) => plan.max_length = Some(*v),
+                PasswordPolicyOption::MinUpperCaseChars(v) => plan.min_upper_case_chars = Some(*v),
+                PasswordPolicyOption::MinLowerCaseChars(v) => plan.min_lower_case_chars = Some(*v),
+                PasswordPolicyOption::MinNumericChars(v) => plan.min_numeric_chars = Some(*v),
+                PasswordPolicyOption::MinSpecialChars(v) => plan.min_special_chars = Some(*v),
+                PasswordPolicyOption::MinAgeDays(v) => plan.min_age_days = Some(*v),
+                PasswordPolicyOption::MaxAgeDays(v) => plan.max_age_days = Some(*v),
+                PasswordPolicyOption::MaxRetries(v) => plan.max_retries = Some(*v),
+                PasswordPolicyOption::LockoutTimeMins(v) => plan.lockout_time_mins = Some(*v),
+                PasswordPolicyOption::History(v) => plan.history = Some(*v),
+                PasswordPolicyOption::Comment(v) => plan.comment = Some(v.clone()),
+            }
+        }
+        Ok(Plan::AlterPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_password_policy(
+        &mut self,
+        stmt: &DropPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let DropPasswordPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropPasswordPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_password_policy(
+        &mut self,
+        stmt: &DescPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let DescPasswordPolicyStmt { name } = stmt;
+
+        let plan = DescPasswordPolicyPlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_password_policies(&mut self) -> Result<Plan> {
+        let plan = ShowPasswordPoliciesPlan {};
+        Ok(Plan::ShowPasswordPolicies(Box::new(plan)))
+    }
+}
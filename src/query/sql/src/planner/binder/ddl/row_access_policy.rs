@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        // a row is visible only if the policy returns true
+        let is_boolean = match return_type {
+            TypeName::Boolean => true,
+            TypeName::Nullable(inner) => matches!(inner.as_ref(), TypeName::Boolean),
+            _ => false,
+        };
+        if !is_boolean {
+            return Err(ErrorCode::BadArguments(format!(
+                "row access policy {} must return BOOLEAN, but got {}",
+                name, return_type
+            )));
+        }
+
+        let mut arg_names = HashSet::with_capacity(args.len());
+        for arg in args {
+            resolve_type_name(&arg.arg_type, true)?;
+            if !arg_names.insert(arg.arg_name.to_lowercase()) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "duplicate arg '{}' in row access policy {}",
+                    arg.arg_name, name
+                )));
+            }
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateRowAccessPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            args: args
+                .iter()
+                .map(|arg| (arg.arg_name.to_string(), arg.arg_type.to_string()))
+                .collect(),
+            body: body.to_string(),
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropRowAccessPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_row_access_policy(
+        &mut self,
+        stmt: &DescRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DescRowAccessPolicyStmt { name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DescRowAccessPolicyPlan {
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DescRowAccessPolicy(Box::new(plan)))
+    }
+}
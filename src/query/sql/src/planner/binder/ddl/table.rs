@@ -21,6 +21,7 @@ use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
 use common_ast::ast::AttachTableStmt;
+use common_ast::ast::ColumnCodec as AstColumnCodec;
 use common_ast::ast::ColumnDefinition;
 use common_ast::ast::ColumnExpr;
 use common_ast::ast::CompactTarget;
@@ -44,12 +45,14 @@ use common_ast::ast::ShowLimit;
 use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
 use common_ast::ast::Statement;
+use common_ast::ast::TableConstraint;
 use common_ast::ast::TableReference;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
 use common_ast::ast::UriLocation;
 use common_ast::ast::VacuumDropTableStmt;
 use common_ast::ast::VacuumTableStmt;
+use common_ast::parser::parse_comma_separated_exprs;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
@@ -59,10 +62,13 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
+use common_expression::type_check::check_cast;
 use common_expression::types::DataType;
 use common_expression::ComputedExpr;
+use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
@@ -74,8 +80,15 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use log::error;
+use storages_common_table_meta::table::is_internal_opt_key;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::ColumnCodec;
+use storages_common_table_meta::table::ColumnCompression;
+use storages_common_table_meta::table::ColumnEncoding;
+use storages_common_table_meta::table::UniqueConstraint;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
@@ -96,14 +109,21 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableTagPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
+use crate::plans::DropTableTagPlan;
 use crate::plans::ExistsTablePlan;
+use crate::plans::MergeTableBranchPlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
@@ -112,6 +132,7 @@ use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
+use crate::plans::ReplaceTablePartitionPlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
 use crate::plans::SetOptionsPlan;
@@ -399,6 +420,7 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            partition_by,
             as_query,
             transient,
             engine,
@@ -450,6 +472,82 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // `CREATE TABLE ... CLONE` shares the data files of the source table,
+        // the options of the source table are inherited unless specified.
+        let mut clone_cluster_key = None;
+        let clone_source = match source {
+            Some(CreateTableSource::Clone {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                travel_point,
+            }) => {
+                if as_query.is_some() || uri_location.is_some() || engine != Engine::Fuse {
+                    return Err(ErrorCode::BadArguments(
+                        "CREATE TABLE ... CLONE can only create a FUSE table without AS SELECT or external location",
+                    ));
+                }
+                if !partition_by.is_empty() {
+                    return Err(ErrorCode::BadArguments(
+                        "CREATE TABLE ... CLONE inherits the partition keys of the source table",
+                    ));
+                }
+                let (source_catalog, source_database, source_table) = self
+                    .normalize_object_identifier_triple(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                    );
+                if source_catalog != catalog {
+                    return Err(ErrorCode::BadArguments(
+                        "Can not clone table across catalogs",
+                    ));
+                }
+                let table_meta = self
+                    .ctx
+                    .get_table(&source_catalog, &source_database, &source_table)
+                    .await?
+                    .get_table_info()
+                    .meta
+                    .clone();
+                if table_meta.engine != Engine::Fuse.to_string()
+                    || table_meta.storage_params.is_some()
+                {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Can not clone table {source_database}.{source_table}, only FUSE table stored in the default storage can be cloned",
+                    )));
+                }
+                for (key, value) in table_meta.options.iter() {
+                    if !is_reserved_opt_key(key)
+                        && !is_internal_opt_key(key)
+                        && key != OPT_KEY_SNAPSHOT_LOCATION
+                    {
+                        options.entry(key.clone()).or_insert_with(|| value.clone());
+                    }
+                }
+                // the segments of a partitioned table are shared as they are
+                if let Some(partition_by) = table_meta.options.get(OPT_KEY_PARTITION_BY) {
+                    options.insert(OPT_KEY_PARTITION_BY.to_owned(), partition_by.clone());
+                }
+                clone_cluster_key = table_meta.default_cluster_key;
+
+                let point = match travel_point {
+                    Some(travel_point) => Some(
+                        self.resolve_data_travel_point(&mut BindContext::new(), travel_point)
+                            .await?,
+                    ),
+                    None => None,
+                };
+                Some(CloneTableSource {
+                    catalog: source_catalog,
+                    database: source_database,
+                    table: source_table,
+                    point,
+                })
+            }
+            _ => None,
+        };
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -501,6 +599,24 @@ impl Binder {
             ))?,
         };
 
+        if engine != Engine::Fuse {
+            if let Some(CreateTableSource::Columns(columns, _)) = &source {
+                if columns.iter().any(|column| !column.codecs.is_empty()) {
+                    return Err(ErrorCode::BadArguments(
+                        "Column CODEC is only supported by FUSE table",
+                    ));
+                }
+            }
+        }
+
+        // `PRIMARY KEY` and `UNIQUE` constraints are kept in the table options.
+        let schema = match &source {
+            Some(CreateTableSource::Columns(columns, constraints)) if !constraints.is_empty() => {
+                self.analyze_table_constraints(engine, columns, constraints, schema, &mut options)?
+            }
+            _ => schema,
+        };
+
         // for fuse engine, we will insert database_id, so if we check it in execute phase,
         // we can't distinct user key and our internal key.
         if options.contains_key(&OPT_KEY_DATABASE_ID.to_lowercase()) {
@@ -569,17 +685,42 @@ impl Binder {
             }
         }
 
+        if engine == Engine::Fuse {
+            ColumnCodec::check_storage_format_all(&schema, Self::is_native_format(&options))?;
+        }
+
         let cluster_key = {
             let keys = self
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
             if keys.is_empty() {
-                None
+                clone_cluster_key
             } else {
                 Some(format!("({})", keys.join(", ")))
             }
         };
 
+        if !partition_by.is_empty() {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::BadArguments(
+                    "PARTITION BY is only supported by FUSE table",
+                ));
+            }
+            // reclustering would mix the rows of different partitions
+            if cluster_key.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "PARTITION BY can not be used together with CLUSTER BY",
+                ));
+            }
+            let keys = self
+                .analyze_partition_keys(partition_by, schema.clone())
+                .await?
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+            options.insert(OPT_KEY_PARTITION_BY.to_owned(), keys.join(", "));
+        }
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -605,6 +746,7 @@ impl Binder {
             } else {
                 None
             },
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -674,6 +816,7 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            clone_source: None,
         })))
     }
 
@@ -741,19 +884,21 @@ impl Binder {
 
         let tenant = self.ctx.get_tenant();
 
-        let (catalog, database, table) = if let TableReference::Table {
+        let (catalog_ident, database_ident, table_ident) = if let TableReference::Table {
             catalog,
             database,
             table,
             ..
         } = table_reference
         {
-            self.normalize_object_identifier_triple(catalog, database, table)
+            (catalog, database, table)
         } else {
             return Err(ErrorCode::Internal(
                 "should not happen, parser should have report error already",
             ));
         };
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog_ident, database_ident, table_ident);
 
         match action {
             AlterTableAction::RenameTable { new_table } => {
@@ -793,12 +938,15 @@ impl Binder {
                 column,
                 option: ast_option,
             } => {
-                let schema = self
-                    .ctx
-                    .get_table(&catalog, &database, &table)
-                    .await?
-                    .schema();
-                let (field, comment) = self.analyze_add_column(column, schema).await?;
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let (field, comment) = self.analyze_add_column(column, tbl.schema()).await?;
+                let codec = Self::analyze_column_codec(&column.codecs, &field)?;
+                if let Some(codec) = &codec {
+                    codec.check_storage_format(
+                        field.name(),
+                        Self::is_native_format(tbl.options()),
+                    )?;
+                }
                 let option = match ast_option {
                     AstAddColumnOption::First => AddColumnOption::First,
                     AstAddColumnOption::After(ident) => AddColumnOption::After(
@@ -814,6 +962,7 @@ impl Binder {
                     field,
                     comment,
                     option,
+                    codec,
                 })))
             }
             AlterTableAction::ModifyColumn { action } => {
@@ -830,6 +979,17 @@ impl Binder {
                     ModifyColumnAction::ConvertStoredComputedColumn(column) => {
                         ModifyColumnActionInPlan::ConvertStoredComputedColumn(column.to_string())
                     }
+                    ModifyColumnAction::SetCodec(column, codecs) => {
+                        let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                        let schema = tbl.schema();
+                        let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                        let field = schema.field_with_name(&name).map_err(|_| {
+                            ErrorCode::UnknownColumn(format!("Column `{name}` does not exist"))
+                        })?;
+                        let codec = Self::analyze_column_codec(codecs, field)?.unwrap_or_default();
+                        codec.check_storage_format(&name, Self::is_native_format(tbl.options()))?;
+                        ModifyColumnActionInPlan::SetCodec(name, codec)
+                    }
                     ModifyColumnAction::SetDataType(column_def_vec) => {
                         let mut field_and_comment = Vec::with_capacity(column_def_vec.len());
                         let schema = self
@@ -861,11 +1021,13 @@ impl Binder {
                 })))
             }
             AlterTableAction::AlterTableClusterKey { cluster_by } => {
-                let schema = self
-                    .ctx
-                    .get_table(&catalog, &database, &table)
-                    .await?
-                    .schema();
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                if tbl.options().contains_key(OPT_KEY_PARTITION_BY) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Can not cluster the partitioned table {database}.{table}"
+                    )));
+                }
+                let schema = tbl.schema();
                 let cluster_keys = self.analyze_cluster_keys(cluster_by, schema).await?;
 
                 Ok(Plan::AlterTableClusterKey(Box::new(
@@ -941,6 +1103,138 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::CreateTag { tag, travel_point } => {
+                let point = match travel_point {
+                    Some(tp) => Some(self.resolve_data_travel_point(bind_context, tp).await?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableTag(Box::new(CreateTableTagPlan {
+                    catalog,
+                    database,
+                    table,
+                    tag: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                    point,
+                })))
+            }
+            AlterTableAction::DropTag { tag } => {
+                Ok(Plan::DropTableTag(Box::new(DropTableTagPlan {
+                    catalog,
+                    database,
+                    table,
+                    tag: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                })))
+            }
+            AlterTableAction::CreateBranch {
+                branch,
+                travel_point,
+            } => {
+                // A branch is a zero-copy clone of the table, in the same database.
+                let stmt = CreateTableStmt {
+                    if_not_exists: false,
+                    catalog: catalog_ident.clone(),
+                    database: database_ident.clone(),
+                    table: branch.clone(),
+                    source: Some(CreateTableSource::Clone {
+                        catalog: catalog_ident.clone(),
+                        database: database_ident.clone(),
+                        table: table_ident.clone(),
+                        travel_point: travel_point.clone(),
+                    }),
+                    engine: None,
+                    uri_location: None,
+                    cluster_by: vec![],
+                    partition_by: vec![],
+                    table_options: BTreeMap::new(),
+                    as_query: None,
+                    transient: false,
+                };
+                self.bind_create_table(&stmt).await
+            }
+            AlterTableAction::MergeBranch { branch } => {
+                Ok(Plan::MergeTableBranch(Box::new(MergeTableBranchPlan {
+                    catalog,
+                    database,
+                    table,
+                    branch: normalize_identifier(branch, &self.name_resolution_ctx).name,
+                })))
+            }
+            AlterTableAction::DropPartition { if_exists, values } => {
+                let values = self
+                    .analyze_partition_values(&catalog, &database, &table, values)
+                    .await?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    catalog,
+                    database,
+                    table,
+                    values,
+                    if_exists: *if_exists,
+                })))
+            }
+            AlterTableAction::TruncatePartition { values } => {
+                let values = self
+                    .analyze_partition_values(&catalog, &database, &table, values)
+                    .await?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    catalog,
+                    database,
+                    table,
+                    values,
+                    if_exists: true,
+                })))
+            }
+            AlterTableAction::ReplacePartition {
+                values,
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+            } => {
+                let (source_catalog, source_database, source_table) = self
+                    .normalize_object_identifier_triple(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                    );
+                if source_catalog != catalog {
+                    return Err(ErrorCode::BadArguments(
+                        "Can not replace partition across catalogs",
+                    ));
+                }
+                let values = self
+                    .analyze_partition_values(&catalog, &database, &table, values)
+                    .await?;
+                Ok(Plan::ReplaceTablePartition(Box::new(
+                    ReplaceTablePartitionPlan {
+                        catalog,
+                        database,
+                        table,
+                        values,
+                        source_database,
+                        source_table,
+                    },
+                )))
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => Ok(
+                Plan::AddTableRowAccessPolicy(Box::new(AddTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                    columns: columns
+                        .iter()
+                        .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                        .collect(),
+                })),
+            ),
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
         }
     }
 
@@ -1326,18 +1620,94 @@ impl Binder {
             fields
         };
 
-        let schema = TableSchemaRefExt::create(fields);
+        // Codecs are kept in the schema metadata, by column id.
+        let mut schema = TableSchema::new(fields);
+        for (index, column) in columns.iter().enumerate() {
+            let field = &schema.fields()[index];
+            if let Some(codec) = Self::analyze_column_codec(&column.codecs, field)? {
+                let column_id = field.column_id();
+                ColumnCodec::set(&mut schema, column_id, codec)?;
+            }
+        }
+        let schema = Arc::new(schema);
         Self::validate_create_table_schema(&schema)?;
         Ok((schema, fields_comments))
     }
 
+    /// Resolve the `CODEC(...)` of a column, which has at most one encoding and one compression.
+    fn is_native_format(options: &BTreeMap<String, String>) -> bool {
+        options
+            .get(OPT_KEY_STORAGE_FORMAT)
+            .is_some_and(|format| format.eq_ignore_ascii_case("native"))
+    }
+
+    fn analyze_column_codec(
+        codecs: &[AstColumnCodec],
+        field: &TableField,
+    ) -> Result<Option<ColumnCodec>> {
+        if codecs.is_empty() {
+            return Ok(None);
+        }
+        if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Virtual computed column `{}` can not have a codec",
+                field.name()
+            )));
+        }
+
+        let mut codec = ColumnCodec::default();
+        for item in codecs {
+            let (encoding, compression) = match item {
+                AstColumnCodec::Delta => (Some(ColumnEncoding::Delta), None),
+                AstColumnCodec::DoubleDelta => (Some(ColumnEncoding::DoubleDelta), None),
+                AstColumnCodec::Gorilla => (Some(ColumnEncoding::Gorilla), None),
+                AstColumnCodec::Dictionary => (Some(ColumnEncoding::Dictionary), None),
+                AstColumnCodec::None => (None, Some(ColumnCompression::None)),
+                AstColumnCodec::Lz4 => (None, Some(ColumnCompression::Lz4)),
+                AstColumnCodec::Snappy => (None, Some(ColumnCompression::Snappy)),
+                AstColumnCodec::Zstd(level) => {
+                    if let Some(level) = level {
+                        if !(1..=22).contains(level) {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Invalid ZSTD level {level} of column `{}`, expect 1 to 22",
+                                field.name()
+                            )));
+                        }
+                    }
+                    let level = level.map(|level| level as i32);
+                    (None, Some(ColumnCompression::Zstd(level)))
+                }
+            };
+            if encoding.is_some() {
+                if codec.encoding.is_some() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column `{}` can have only one encoding in CODEC",
+                        field.name()
+                    )));
+                }
+                codec.encoding = encoding;
+            }
+            if compression.is_some() {
+                if codec.compression.is_some() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column `{}` can have only one compression in CODEC",
+                        field.name()
+                    )));
+                }
+                codec.compression = compression;
+            }
+        }
+        codec.check(field.name(), field.data_type())?;
+        Ok(Some(codec))
+    }
+
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, _) => {
                 self.analyze_create_table_schema_by_columns(columns).await
             }
             CreateTableSource::Like {
@@ -1363,7 +1733,105 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                ..
+            } => {
+                // the schema of the cloned snapshot will be used while creating the table.
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                let table = self.ctx.get_table(&catalog, &database, &table).await?;
+                Ok((table.schema(), table.field_comments().clone()))
+            }
+        }
+    }
+
+    /// Resolve the `PRIMARY KEY` and `UNIQUE` constraints into table options.
+    ///
+    /// The primary key columns are NOT NULL, a column which is nullable by default
+    /// is changed to NOT NULL, while an explicitly nullable one is rejected.
+    fn analyze_table_constraints(
+        &self,
+        engine: Engine,
+        columns: &[ColumnDefinition],
+        constraints: &[TableConstraint],
+        schema: TableSchemaRef,
+        options: &mut BTreeMap<String, String>,
+    ) -> Result<TableSchemaRef> {
+        if engine != Engine::Fuse {
+            return Err(ErrorCode::BadArguments(
+                "PRIMARY KEY and UNIQUE constraints are only supported by FUSE table",
+            ));
+        }
+
+        let mut fields = schema.fields().clone();
+        let mut keys = Vec::with_capacity(constraints.len());
+        for constraint in constraints {
+            let (is_primary_key, key_columns) = match constraint {
+                TableConstraint::PrimaryKey { columns } => (true, columns),
+                TableConstraint::Unique { columns } => (false, columns),
+            };
+            if is_primary_key && keys.iter().any(|(is_pk, _)| *is_pk) {
+                return Err(ErrorCode::BadArguments(
+                    "Multiple PRIMARY KEY constraints are not allowed",
+                ));
+            }
+
+            let mut key_indices: Vec<usize> = Vec::with_capacity(key_columns.len());
+            for ident in key_columns {
+                let name = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                let index = schema.index_of(&name).map_err(|_| {
+                    ErrorCode::UnknownColumn(format!(
+                        "Column `{name}` of {constraint} does not exist"
+                    ))
+                })?;
+                if key_indices.contains(&index) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column `{name}` is duplicated in {constraint}"
+                    )));
+                }
+                let field = &fields[index];
+                if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Virtual computed column `{name}` can not be used in {constraint}"
+                    )));
+                }
+                if is_primary_key && field.data_type().is_nullable() {
+                    let explicit_null = columns.iter().any(|column| {
+                        normalize_identifier(&column.name, &self.name_resolution_ctx).name == name
+                            && column.nullable_constraint == Some(NullableConstraint::Null)
+                    });
+                    if explicit_null {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "PRIMARY KEY column `{name}` can not be NULL"
+                        )));
+                    }
+                    fields[index] = TableField::new(&name, field.data_type().remove_nullable())
+                        .with_default_expr(field.default_expr().cloned())
+                        .with_computed_expr(field.computed_expr().cloned());
+                }
+                key_indices.push(index);
+            }
+            keys.push((is_primary_key, key_indices));
         }
+
+        // Column ids are assigned by the final schema, in the same way as the original one,
+        // so the column codecs in the metadata stay valid.
+        let schema = Arc::new(TableSchema::new_from(fields, schema.metadata.clone()));
+        let constraints = keys
+            .into_iter()
+            .map(|(is_primary_key, key_indices)| UniqueConstraint {
+                is_primary_key,
+                column_ids: key_indices
+                    .into_iter()
+                    .map(|index| schema.fields()[index].column_id())
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+        UniqueConstraint::to_table_options(&constraints, options);
+        Ok(schema)
     }
 
     /// Validate the schema of the table to be created.
@@ -1471,6 +1939,151 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    /// Returns the normalized partition keys, and their data types.
+    #[async_backtrace::framed]
+    async fn analyze_partition_keys(
+        &mut self,
+        partition_by: &[Expr],
+        schema: TableSchemaRef,
+    ) -> Result<Vec<(String, DataType)>> {
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        scalar_binder.forbid_udf();
+
+        let mut partition_keys = Vec::with_capacity(partition_by.len());
+        for partition_by in partition_by.iter() {
+            let (partition_key, _) = scalar_binder.bind(partition_by).await?;
+            let used_columns = partition_key.used_columns();
+            if used_columns.is_empty() || !partition_key.evaluable() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Partition by expression `{:#}` is invalid",
+                    partition_by
+                )));
+            }
+            // the virtual computed columns are not stored in the blocks
+            if used_columns.iter().any(|index| {
+                matches!(
+                    schema.field(*index).computed_expr(),
+                    Some(ComputedExpr::Virtual(_))
+                )
+            }) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Partition by expression `{:#}` can not reference virtual computed columns",
+                    partition_by
+                )));
+            }
+
+            let expr = partition_key.as_expr()?;
+            if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Partition by expression `{:#}` is not deterministic",
+                    partition_by
+                )));
+            }
+
+            let data_type = expr.data_type();
+            if !Self::valid_cluster_key_type(data_type) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Unsupported data type '{}' for partition by expression `{:#}`",
+                    data_type, partition_by
+                )));
+            }
+
+            let mut partition_by = partition_by.clone();
+            walk_expr_mut(
+                &mut IdentifierNormalizer {
+                    ctx: &self.name_resolution_ctx,
+                },
+                &mut partition_by,
+            );
+            partition_keys.push((format!("{:#}", &partition_by), data_type.clone()));
+        }
+
+        Ok(partition_keys)
+    }
+
+    /// Returns the values of a partition of the table, casted to the types of the partition keys.
+    #[async_backtrace::framed]
+    async fn analyze_partition_values(
+        &mut self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        values: &[Expr],
+    ) -> Result<Vec<Scalar>> {
+        let tbl = self.ctx.get_table(catalog, database, table).await?;
+        let Some(partition_by) = tbl.options().get(OPT_KEY_PARTITION_BY) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table {database}.{table} is not partitioned"
+            )));
+        };
+        let tokens = tokenize_sql(partition_by)?;
+        let partition_by = parse_comma_separated_exprs(&tokens, Dialect::MySQL)?;
+        let keys = self
+            .analyze_partition_keys(&partition_by, tbl.schema())
+            .await?;
+        if keys.len() != values.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table {database}.{table} is partitioned by {} keys, but {} values are given",
+                keys.len(),
+                values.len()
+            )));
+        }
+
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let func_ctx = self.ctx.get_function_context()?;
+        let mut scalars = Vec::with_capacity(values.len());
+        for ((key, data_type), value) in keys.iter().zip(values) {
+            let (scalar, _) = scalar_binder.bind(value).await?;
+            let expr = check_cast(
+                None,
+                false,
+                scalar.as_expr()?,
+                data_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let (expr, _) = ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS);
+            match expr {
+                common_expression::Expr::Constant { scalar, .. } => scalars.push(scalar),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "The value `{:#}` of partition key `{}` is not a constant",
+                        value, key
+                    )));
+                }
+            }
+        }
+        Ok(scalars)
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
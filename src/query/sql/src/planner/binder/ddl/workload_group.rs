@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterWorkloadGroupPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+use crate::plans::ShowWorkloadGroupsPlan;
+
+/// The cpu weight of a workload group if not specified.
+const DEFAULT_CPU_WEIGHT: u64 = 100;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            if_not_exists,
+            name,
+            options,
+        } = stmt;
+
+        let mut plan = CreateWorkloadGroupPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            max_concurrency: 0,
+            memory_percentage: 0,
+            cpu_weight: DEFAULT_CPU_WEIGHT,
+            queue_timeout_secs: 0,
+            users: vec![],
+            roles: vec![],
+        };
+        for option in options {
+            check_workload_group_option(option)?;
+            match option {
+                WorkloadGroupOption::MaxConcurrency(v) => plan.max_concurrency = *v,
+                WorkloadGroupOption::MemoryPercentage(v) => plan.memory_percentage = *v,
+                WorkloadGroupOption::CpuWeight(v) => plan.cpu_weight = *v,
+                WorkloadGroupOption::QueueTimeout(v) => plan.queue_timeout_secs = *v,
+                WorkloadGroupOption::Users(v) => plan.users = v.clone(),
+                WorkloadGroupOption::Roles(v) => plan.roles = v.clone(),
+            }
+        }
+        Ok(Plan::CreateWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_workload_group(
+        &mut self,
+        stmt: &AlterWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterWorkloadGroupStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let mut plan = AlterWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            max_concurrency: None,
+            memory_percentage: None,
+            cpu_weight: None,
+            queue_timeout_secs: None,
+            users: None,
+            roles: None,
+        };
+        for option in options {
+            check_workload_group_option(option)?;
+            match option {
+                WorkloadGroupOption::MaxConcurrency(v) => plan.max_concurrency = Some(*v),
+                WorkloadGroupOption::MemoryPercentage(v) => plan.memory_percentage = Some(*v),
+                WorkloadGroupOption::CpuWeight(v) => plan.cpu_weight = Some(*v),
+                WorkloadGroupOption::QueueTimeout(v) => plan.queue_timeout_secs = Some(*v),
+                WorkloadGroupOption::Users(v) => plan.users = Some(v.clone()),
+                WorkloadGroupOption::Roles(v) => plan.roles = Some(v.clone()),
+            }
+        }
+        Ok(Plan::AlterWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        let plan = DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+        };
+        Ok(Plan::DropWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_workload_groups(&mut self) -> Result<Plan> {
+        let plan = ShowWorkloadGroupsPlan {};
+        Ok(Plan::ShowWorkloadGroups(Box::new(plan)))
+    }
+}
+
+fn check_workload_group_option(option: &WorkloadGroupOption) -> Result<()> {
+    match option {
+        WorkloadGroupOption::MemoryPercentage(v) if *v > 100 => Err(ErrorCode::SemanticError(
+            format!("MEMORY_PERCENTAGE must be between 0 and 100, got {v}"),
+        )),
+        WorkloadGroupOption::CpuWeight(0) => Err(ErrorCode::SemanticError(
+            "CPU_WEIGHT must be greater than 0",
+        )),
+        _ => Ok(()),
+    }
+}
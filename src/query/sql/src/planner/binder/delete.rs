@@ -29,6 +29,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Operator;
 use crate::plans::Plan;
 use crate::plans::RelOp;
@@ -57,6 +58,40 @@ impl<'a> Binder {
         })
     }
 
+    /// Only the rows visible by the row access policy of the table can be mutated, so the
+    /// policy is added to the selection.
+    pub(in crate::planner::binder) async fn process_row_access_policy(
+        &self,
+        bind_context: &BindContext,
+        table_expr: SExpr,
+        selection: Option<ScalarExpr>,
+        subquery_desc: &mut Vec<SubqueryDesc>,
+    ) -> Result<Option<ScalarExpr>> {
+        let table_index = match &*table_expr.plan {
+            Scan(scan) => scan.table_index,
+            _ => return Ok(selection),
+        };
+        let Some(predicate) = self
+            .bind_row_access_policy(bind_context, table_index)
+            .await?
+        else {
+            return Ok(selection);
+        };
+
+        // the subqueries of the policy are after the ones of the selection
+        self.subquery_desc(&predicate, table_expr, subquery_desc)
+            .await?;
+        Ok(Some(match selection {
+            Some(selection) => ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![selection, predicate],
+            }),
+            None => predicate,
+        }))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_delete(
         &mut self,
@@ -95,8 +130,11 @@ impl<'a> Binder {
             self.ctes_map.clone(),
         );
 
-        let (selection, subquery_desc) = self
-            .process_selection(selection, table_expr, &mut scalar_binder)
+        let (selection, mut subquery_desc) = self
+            .process_selection(selection, table_expr.clone(), &mut scalar_binder)
+            .await?;
+        let selection = self
+            .process_row_access_policy(&context, table_expr, selection, &mut subquery_desc)
             .await?;
 
         let plan = DeletePlan {
@@ -41,6 +41,7 @@ use crate::binder::InternalColumnBinding;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
 use crate::plans::MatchedEvaluator;
 use crate::plans::MergeInto;
 use crate::plans::Plan;
@@ -128,6 +129,9 @@ impl Binder {
         // bind source data
         let (source_expr, mut source_context) =
             self.bind_single_table(bind_context, &source_data).await?;
+        let source_expr = self
+            .bind_scan_filter(&mut source_context, source_expr)
+            .await?;
 
         // add all left source columns for read
         // todo: (JackTan25) do column prune after finish "split expr for target and source"
@@ -218,6 +222,17 @@ impl Binder {
         target_expr =
             SExpr::add_internal_column_index(&target_expr, table_index, column_binding.index);
 
+        // only the rows visible by the row access policy can be merged into
+        if let Some(predicate) = self
+            .bind_row_access_policy(&target_context, table_index)
+            .await?
+        {
+            let filter = Filter {
+                predicates: vec![predicate],
+            };
+            target_expr = SExpr::create_unary(Arc::new(filter.into()), Arc::new(target_expr));
+        }
+
         self.metadata
             .write()
             .set_table_row_id_index(table_index, column_binding.index);
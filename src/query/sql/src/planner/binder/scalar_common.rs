@@ -241,6 +241,7 @@ pub fn wrap_cast_scalar(
             | DataType::Timestamp
             | DataType::Date
             | DataType::Bitmap
+            | DataType::Geometry
             | DataType::Variant => wrap_cast(scalar, target_type),
             DataType::String => {
                 // parse string to JSON value
@@ -37,6 +37,7 @@ use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::UriLocation;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
@@ -62,9 +63,12 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_api::RowAccessPolicyApi;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
@@ -82,6 +86,7 @@ use common_users::UserApiProvider;
 use dashmap::DashMap;
 use log::info;
 use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -95,9 +100,14 @@ use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::CteScan;
 use crate::plans::EvalScalar;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
+use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::Statistics;
@@ -874,6 +884,198 @@ impl Binder {
         Ok((s_expr, bind_context))
     }
 
+    /// Filter the scan of a table with the row access policy and the TTL of the table.
+    ///
+    /// The filter is only added for queries. The target tables of mutations see the expired
+    /// rows, and the row access policy is added to the selection of the mutations instead.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_scan_filter(
+        &mut self,
+        bind_context: &mut BindContext,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let table_index = match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.table_index,
+            _ => return Ok(s_expr),
+        };
+
+        let mut predicates = vec![];
+        if let Some(predicate) = self
+            .bind_row_access_policy(bind_context, table_index)
+            .await?
+        {
+            predicates.push(predicate);
+        }
+        if let Some(predicate) = self.bind_ttl_predicate(bind_context, table_index).await? {
+            predicates.push(predicate);
+        }
+        if predicates.is_empty() {
+            return Ok(s_expr);
+        }
+
+        let filter = Filter { predicates };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// Bind the row access policy of a table to a predicate of the columns of the table,
+    /// the rows are visible only if the predicate is true.
+    ///
+    /// The columns of the table are bound to the args of the policy in order, and the body of
+    /// the policy can only see the args.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_row_access_policy(
+        &self,
+        bind_context: &BindContext,
+        table_index: IndexType,
+    ) -> Result<Option<ScalarExpr>> {
+        let table = self.metadata.read().table(table_index).table();
+        let Some(table_policy) = table.get_table_info().meta.row_access_policy.clone() else {
+            return Ok(None);
+        };
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy = meta_api
+            .get_row_access_policy(GetRowAccessPolicyReq {
+                name: RowAccessPolicyNameIdent {
+                    tenant: self.ctx.get_tenant(),
+                    name: table_policy.name.clone(),
+                },
+            })
+            .await?
+            .policy;
+        if policy.args.len() != table_policy.columns.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "row access policy {} has {} args, but is attached to {} columns of table {}",
+                table_policy.name,
+                policy.args.len(),
+                table_policy.columns.len(),
+                table.name()
+            )));
+        }
+
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for ((arg_name, _), column_name) in policy.args.iter().zip(table_policy.columns.iter()) {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|column| {
+                    column.table_index == Some(table_index) && &column.column_name == column_name
+                })
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "column {} of row access policy {} doesn't exist in table {}",
+                        column_name,
+                        table_policy.name,
+                        table.name()
+                    ))
+                })?;
+            let column = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            aliases.push((arg_name.clone(), column));
+        }
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let mut policy_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, data_type) = scalar_binder.bind(&ast).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::BadArguments(format!(
+                "row access policy {} must return BOOLEAN, but got {data_type}",
+                table_policy.name
+            )));
+        }
+        Ok(Some(predicate))
+    }
+
+    /// Hide the expired rows of a table with a TTL, by the predicate
+    /// `ttl IS NULL OR ttl >= now()`.
+    ///
+    /// The blocks whose rows are all expired are already pruned by the storage, the predicate
+    /// removes the expired rows of the partially expired blocks.
+    #[async_backtrace::framed]
+    async fn bind_ttl_predicate(
+        &mut self,
+        bind_context: &mut BindContext,
+        table_index: IndexType,
+    ) -> Result<Option<ScalarExpr>> {
+        let ttl = self
+            .metadata
+            .read()
+            .table(table_index)
+            .table()
+            .options()
+            .get(OPT_KEY_TTL)
+            .filter(|ttl| !ttl.is_empty())
+            .cloned();
+        let Some(ttl) = ttl else {
+            return Ok(None);
+        };
+
+        let tokens = tokenize_sql(&ttl)?;
+        let ast = parse_expr(&tokens, self.ctx.get_settings().get_sql_dialect()?)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (ttl, data_type) = scalar_binder.bind(&ast).await?;
+        let ttl = match data_type.remove_nullable() {
+            DataType::Timestamp => ttl,
+            DataType::Date => {
+                let target_type = if data_type.is_nullable() {
+                    DataType::Timestamp.wrap_nullable()
+                } else {
+                    DataType::Timestamp
+                };
+                ScalarExpr::CastExpr(CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(ttl),
+                    target_type: Box::new(target_type),
+                })
+            }
+            _ => {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid ttl option, expect a DATE or TIMESTAMP expression, but got {data_type}"
+                )));
+            }
+        };
+
+        let function = |func_name: &str, arguments: Vec<ScalarExpr>| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: func_name.to_string(),
+                params: vec![],
+                arguments,
+            })
+        };
+        let now = ScalarExpr::ConstantExpr(ConstantExpr {
+            span: None,
+            value: Scalar::Timestamp(Utc::now().timestamp_micros()),
+        });
+        let is_null = function("not", vec![function("is_not_null", vec![ttl.clone()])]);
+        let not_expired = function("gte", vec![ttl, now]);
+        Ok(Some(function("or", vec![is_null, not_expired])))
+    }
+
     #[async_backtrace::framed]
     pub async fn bind_table_reference(
         &mut self,
@@ -907,12 +1109,14 @@ impl Binder {
         // current_ref must be left table in its join
         let (mut result_expr, mut result_ctx) =
             self.bind_single_table(current_ctx, current_ref).await?;
+        result_expr = self.bind_scan_filter(&mut result_ctx, result_expr).await?;
 
         for join in join_stack.iter().rev() {
             match &*join.right {
                 TableReference::Join { .. } => {
-                    let (left_expr, left_ctx) =
+                    let (left_expr, mut left_ctx) =
                         self.bind_single_table(&mut result_ctx, &join.left).await?;
+                    let left_expr = self.bind_scan_filter(&mut left_ctx, left_expr).await?;
                     let (join_expr, ctx) = self
                         .bind_join(
                             current_ctx,
@@ -938,8 +1142,9 @@ impl Binder {
                         result_expr = expr;
                         result_ctx = ctx;
                     } else {
-                        let (right_expr, right_ctx) =
+                        let (right_expr, mut right_ctx) =
                             self.bind_single_table(&mut result_ctx, &join.right).await?;
+                        let right_expr = self.bind_scan_filter(&mut right_ctx, right_expr).await?;
                         let (join_expr, ctx) = self
                             .bind_join(
                                 current_ctx,
@@ -1209,6 +1414,9 @@ impl Binder {
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Tag(tag) => Ok(NavigationPoint::Tag(
+                normalize_identifier(tag, &self.name_resolution_ctx).name,
+            )),
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::new(
                     bind_context,
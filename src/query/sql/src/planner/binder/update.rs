@@ -124,8 +124,11 @@ impl Binder {
             update_columns.insert(index, scalar);
         }
 
-        let (selection, subquery_desc) = self
-            .process_selection(selection, table_expr, &mut scalar_binder)
+        let (selection, mut subquery_desc) = self
+            .process_selection(selection, table_expr.clone(), &mut scalar_binder)
+            .await?;
+        let selection = self
+            .process_row_access_policy(&context, table_expr, selection, &mut subquery_desc)
             .await?;
 
         let plan = UpdatePlan {
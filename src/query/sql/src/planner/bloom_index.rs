@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::str::FromStr;
 
-use common_ast::parser::parse_comma_separated_idents;
+use common_ast::parser::parse_bloom_index_columns;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_exception::ErrorCode;
@@ -32,14 +34,33 @@ use crate::planner::semantic::NameResolutionContext;
 
 #[derive(Clone)]
 pub enum BloomIndexColumns {
-    /// Default, all columns that support bloom index.
+    /// Default, all columns that support bloom index, except the array columns,
+    /// whose filters are built only if specified.
     All,
     /// Specify with column names.
-    Specify(Vec<String>),
+    Specify(Vec<BloomIndexColumn>),
     /// The column of bloom index is empty.
     None,
 }
 
+/// A column specified in the bloom index columns, e.g. `a` or `b NGRAM(3)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BloomIndexColumn {
+    pub name: String,
+    /// The size of the n-grams, if the column is indexed by the n-gram bloom filter
+    /// which is used by the `LIKE` predicates.
+    pub ngram: Option<usize>,
+}
+
+impl Display for BloomIndexColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.ngram {
+            Some(gram_size) => write!(f, "{} NGRAM({})", self.name, gram_size),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
 impl FromStr for BloomIndexColumns {
     type Err = ErrorCode;
 
@@ -48,44 +69,45 @@ impl FromStr for BloomIndexColumns {
             return Ok(BloomIndexColumns::None);
         }
 
+        Ok(BloomIndexColumns::Specify(Self::parse_columns(s)?))
+    }
+}
+
+impl BloomIndexColumns {
+    fn parse_columns(definition: &str) -> Result<Vec<BloomIndexColumn>> {
         let sql_dialect = Dialect::MySQL;
-        let tokens = tokenize_sql(s)?;
-        let idents = parse_comma_separated_idents(&tokens, sql_dialect)?;
+        let tokens = tokenize_sql(definition)?;
+        let columns = parse_bloom_index_columns(&tokens, sql_dialect)?;
 
         let settings = Settings::create("".to_string());
         let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
 
-        let mut cols = Vec::with_capacity(idents.len());
-        idents
+        Ok(columns
             .into_iter()
-            .for_each(|ident| cols.push(normalize_identifier(&ident, &name_resolution_ctx).name));
-
-        Ok(BloomIndexColumns::Specify(cols))
+            .map(|(ident, ngram)| BloomIndexColumn {
+                name: normalize_identifier(&ident, &name_resolution_ctx).name,
+                ngram: ngram.map(|v| v as usize),
+            })
+            .collect())
     }
-}
 
-impl BloomIndexColumns {
     /// Verify the definition based on schema.
-    pub fn verify_definition<F>(
+    pub fn verify_definition<F, G>(
         definition: &str,
         schema: TableSchemaRef,
         verify_type: F,
+        verify_ngram_type: G,
     ) -> Result<()>
     where
         F: Fn(&TableDataType) -> bool,
+        G: Fn(&TableDataType) -> bool,
     {
         if definition.trim().is_empty() {
             return Ok(());
         }
 
-        let settings = Settings::create("".to_string());
-        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-
-        let sql_dialect = Dialect::MySQL;
-        let tokens = tokenize_sql(definition)?;
-        let idents = parse_comma_separated_idents(&tokens, sql_dialect)?;
-        for ident in idents.iter() {
-            let name = &normalize_identifier(ident, &name_resolution_ctx).name;
+        for column in Self::parse_columns(definition)? {
+            let name = &column.name;
             let field = schema.field_with_name(name)?;
 
             if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
@@ -96,11 +118,26 @@ impl BloomIndexColumns {
             }
 
             let data_type = field.data_type();
-            if !verify_type(data_type) {
-                return Err(ErrorCode::TableOptionInvalid(format!(
-                    "Unsupported data type '{}' for bloom index",
-                    data_type
-                )));
+            match column.ngram {
+                Some(0) => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "The n-gram size of bloom index column '{}' must be greater than 0",
+                        name
+                    )));
+                }
+                Some(_) if !verify_ngram_type(data_type) => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "Unsupported data type '{}' for ngram bloom index",
+                        data_type
+                    )));
+                }
+                None if !verify_type(data_type) => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "Unsupported data type '{}' for bloom index",
+                        data_type
+                    )));
+                }
+                _ => (),
             }
         }
         Ok(())
@@ -120,14 +157,16 @@ impl BloomIndexColumns {
         match self {
             BloomIndexColumns::All => {
                 for (i, field) in source_schema.fields.into_iter().enumerate() {
-                    if verify_type(field.data_type()) {
+                    let is_array =
+                        matches!(field.data_type().remove_nullable(), TableDataType::Array(_));
+                    if !is_array && verify_type(field.data_type()) {
                         fields_map.insert(i, field);
                     }
                 }
             }
             BloomIndexColumns::Specify(cols) => {
-                for col in cols {
-                    let field_index = source_schema.index_of(col)?;
+                for col in cols.iter().filter(|col| col.ngram.is_none()) {
+                    let field_index = source_schema.index_of(&col.name)?;
                     let field = source_schema.fields[field_index].clone();
                     let data_type = field.data_type();
                     if !verify_type(data_type) {
@@ -143,4 +182,36 @@ impl BloomIndexColumns {
         }
         Ok(fields_map)
     }
+
+    /// Get the table fields with the n-gram sizes of the n-gram bloom index.
+    pub fn ngram_index_fields<F>(
+        &self,
+        schema: TableSchemaRef,
+        verify_type: F,
+    ) -> Result<Vec<(FieldIndex, TableField, usize)>>
+    where
+        F: Fn(&TableDataType) -> bool,
+    {
+        let BloomIndexColumns::Specify(cols) = self else {
+            return Ok(vec![]);
+        };
+
+        let source_schema = schema.remove_virtual_computed_fields();
+        let mut fields = vec![];
+        for col in cols {
+            let Some(gram_size) = col.ngram else {
+                continue;
+            };
+            let field_index = source_schema.index_of(&col.name)?;
+            let field = source_schema.fields[field_index].clone();
+            if !verify_type(field.data_type()) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "Unsupported data type for ngram bloom index: {:?}",
+                    field.data_type()
+                )));
+            }
+            fields.push((field_index, field, gram_size));
+        }
+        Ok(fields)
+    }
 }
@@ -540,7 +540,6 @@ impl Dataframe {
             bind_context: Box::new(self.bind_context),
             rewrite_kind: None,
             ignore_result: false,
-            formatted_ast: None,
         };
         let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
             enable_distributed_optimization,
@@ -81,6 +81,21 @@ impl Plan {
                 Ok(format!("{:?}", modify_table_column))
             }
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::CreateTableTag(create_table_tag) => Ok(format!("{:?}", create_table_tag)),
+            Plan::DropTableTag(drop_table_tag) => Ok(format!("{:?}", drop_table_tag)),
+            Plan::MergeTableBranch(merge_table_branch) => Ok(format!("{:?}", merge_table_branch)),
+            Plan::DropTablePartition(drop_table_partition) => {
+                Ok(format!("{:?}", drop_table_partition))
+            }
+            Plan::ReplaceTablePartition(replace_table_partition) => {
+                Ok(format!("{:?}", replace_table_partition))
+            }
+            Plan::AddTableRowAccessPolicy(add_table_row_access_policy) => {
+                Ok(format!("{:?}", add_table_row_access_policy))
+            }
+            Plan::DropTableRowAccessPolicy(drop_table_row_access_policy) => {
+                Ok(format!("{:?}", drop_table_row_access_policy))
+            }
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
             }
@@ -178,6 +193,9 @@ impl Plan {
             Plan::CreateDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DropDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescDatamaskPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescRowAccessPolicy(p) => Ok(format!("{:?}", p)),
 
             // network policy
             Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
@@ -185,6 +203,15 @@ impl Plan {
             Plan::DropNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::ShowNetworkPolicies(p) => Ok(format!("{:?}", p)),
+            Plan::CreatePasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::AlterPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::ShowPasswordPolicies(p) => Ok(format!("{:?}", p)),
+            Plan::CreateWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::AlterWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::DropWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::ShowWorkloadGroups(p) => Ok(format!("{:?}", p)),
 
             // task
             Plan::CreateTask(p) => Ok(format!("{:?}", p)),
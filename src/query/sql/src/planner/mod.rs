@@ -15,6 +15,7 @@
 mod bloom_index;
 mod format;
 mod metadata;
+mod plan_fingerprint;
 #[allow(clippy::module_inception)]
 mod planner;
 mod semantic;
@@ -34,10 +35,12 @@ pub use binder::ColumnBindingBuilder;
 pub use binder::ScalarBinder;
 pub use binder::SelectBuilder;
 pub use binder::Visibility;
+pub use bloom_index::BloomIndexColumn;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
 pub use metadata::*;
+pub use plan_fingerprint::plan_result_cache_key;
 pub use planner::PlanExtras;
 pub use planner::Planner;
 pub use plans::ScalarExpr;
@@ -69,14 +69,12 @@ pub fn optimize(
             bind_context,
             metadata,
             rewrite_kind,
-            formatted_ast,
             ignore_result,
         } => Ok(Plan::Query {
             s_expr: Box::new(optimize_query(ctx, opt_ctx, metadata.clone(), *s_expr)?),
             bind_context,
             metadata,
             rewrite_kind,
-            formatted_ast,
             ignore_result,
         }),
         Plan::Explain { kind, plan } => match kind {
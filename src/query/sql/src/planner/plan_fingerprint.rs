@@ -0,0 +1,303 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hash;
+
+use common_exception::Result;
+use common_storages_result_cache::ResultCacheKey;
+use common_storages_result_cache::ResultCacheKeyHasher;
+use common_storages_result_cache::ResultCacheTableVersion;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::optimizer::SExpr;
+use crate::plans::AggregateFunction;
+use crate::plans::BoundColumnRef;
+use crate::plans::Exchange;
+use crate::plans::LambdaFunc;
+use crate::plans::Limit;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Sort;
+use crate::plans::UDFServerCall;
+use crate::plans::VisitorMut;
+use crate::plans::WindowFunc;
+use crate::plans::WindowFuncType;
+use crate::ColumnBinding;
+use crate::MetadataRef;
+
+/// Generates the result cache key of an optimized query plan.
+///
+/// The fingerprint is computed on the plan rather than the SQL text, so the queries that differ
+/// in whitespace, literal spelling or aliases share the cached results. The outermost limit is
+/// kept out of the fingerprint, so that a cached result can answer the same query with a
+/// smaller limit.
+///
+/// Returns None if the result of the plan can not be cached.
+pub fn plan_result_cache_key(
+    s_expr: &SExpr,
+    metadata: &MetadataRef,
+    output_columns: &[ColumnBinding],
+) -> Result<Option<ResultCacheKey>> {
+    let mut hasher = ResultCacheKeyHasher::default();
+    let mut table_versions = vec![];
+    {
+        let metadata = metadata.read();
+        for entry in metadata.tables() {
+            let table = entry.table();
+            if !table.result_can_be_cached() {
+                return Ok(None);
+            }
+            let table_info = table.get_table_info();
+            entry.index().hash(&mut hasher);
+            entry.catalog().hash(&mut hasher);
+            table_info.ident.table_id.hash(&mut hasher);
+            table_versions.push(ResultCacheTableVersion {
+                table_id: table_info.ident.table_id,
+                seq: table_info.ident.seq,
+                snapshot_location: table.options().get(OPT_KEY_SNAPSHOT_LOCATION).cloned(),
+            });
+        }
+    }
+    table_versions.sort();
+    table_versions.dedup();
+
+    for column in output_columns {
+        column.index.hash(&mut hasher);
+        column.data_type.hash(&mut hasher);
+    }
+
+    let mut fingerprinter = PlanFingerprinter {
+        hasher,
+        limit: None,
+    };
+    fingerprinter.hash_s_expr(s_expr, true)?;
+
+    let (offset, limit) = fingerprinter.limit.unwrap_or((0, None));
+    Ok(Some(ResultCacheKey {
+        fingerprint: fingerprinter.hasher.finish_key(),
+        offset,
+        limit,
+        table_versions,
+    }))
+}
+
+struct PlanFingerprinter {
+    hasher: ResultCacheKeyHasher,
+    /// The offset and limit of the outermost limit of the plan.
+    limit: Option<(usize, Option<usize>)>,
+}
+
+impl PlanFingerprinter {
+    /// `top` is true while walking the chain of operators from the root that keep the rows
+    /// in order, in which the outermost limit is looked for.
+    fn hash_s_expr(&mut self, s_expr: &SExpr, top: bool) -> Result<()> {
+        let plan = s_expr.plan();
+        let top = match plan {
+            RelOperator::Limit(limit) if top => {
+                if self.skip_limit(limit) {
+                    return self.hash_s_expr(s_expr.child(0)?, true);
+                }
+                false
+            }
+            RelOperator::Sort(sort) if top && sort.after_exchange => {
+                if let Some(child) = self.hash_top_n(s_expr, sort)? {
+                    return self.hash_s_expr(child, false);
+                }
+                false
+            }
+            RelOperator::EvalScalar(_) | RelOperator::Exchange(_) => top,
+            _ => false,
+        };
+
+        let mut plan = plan.clone();
+        normalize_plan(&mut plan)?;
+        plan.hash(&mut self.hasher);
+        s_expr.arity().hash(&mut self.hasher);
+        for child in s_expr.children() {
+            self.hash_s_expr(child, top)?;
+        }
+        Ok(())
+    }
+
+    /// Hashes the top-n split by the distributed optimizer as the sort without limit, and
+    /// returns the input of the sort before the exchange:
+    ///
+    ///   Sort (after_exchange = true)         Sort
+    ///     Exchange                      =>     Exchange
+    ///       Sort (after_exchange = false)        Input
+    ///         Input
+    fn hash_top_n<'a>(&mut self, s_expr: &'a SExpr, sort: &Sort) -> Result<Option<&'a SExpr>> {
+        let exchange = s_expr.child(0)?;
+        if !matches!(exchange.plan(), RelOperator::Exchange(Exchange::Merge)) {
+            return Ok(None);
+        }
+        let before_exchange = exchange.child(0)?;
+        if !matches!(before_exchange.plan(), RelOperator::Sort(_)) {
+            return Ok(None);
+        }
+
+        let sort = Sort {
+            limit: None,
+            after_exchange: false,
+            ..sort.clone()
+        };
+        for plan in [RelOperator::Sort(sort), exchange.plan().clone()] {
+            plan.hash(&mut self.hasher);
+            1usize.hash(&mut self.hasher);
+        }
+        Ok(Some(before_exchange.child(0)?))
+    }
+
+    fn skip_limit(&mut self, limit: &Limit) -> bool {
+        match self.limit {
+            None => {
+                self.limit = Some((limit.offset, limit.limit));
+                true
+            }
+            // The copy of the outermost limit pushed below the merge exchange.
+            Some((offset, outer_limit)) => {
+                limit.before_exchange
+                    && limit.offset == 0
+                    && limit.limit == outer_limit.map(|v| v + offset)
+            }
+        }
+    }
+}
+
+/// Erases the names and the limits pushed down from the outermost limit, which do not
+/// change the result of the plan.
+fn normalize_plan(plan: &mut RelOperator) -> Result<()> {
+    let mut eraser = NameEraser;
+    match plan {
+        RelOperator::Scan(scan) => {
+            if let Some(predicates) = scan.push_down_predicates.as_mut() {
+                for predicate in predicates.iter_mut() {
+                    eraser.visit(predicate)?;
+                }
+            }
+        }
+        RelOperator::Join(join) => {
+            for condition in join
+                .left_conditions
+                .iter_mut()
+                .chain(join.right_conditions.iter_mut())
+                .chain(join.non_equi_conditions.iter_mut())
+            {
+                eraser.visit(condition)?;
+            }
+        }
+        RelOperator::Filter(filter) => {
+            for predicate in filter.predicates.iter_mut() {
+                eraser.visit(predicate)?;
+            }
+        }
+        RelOperator::EvalScalar(eval_scalar) => erase_items(&mut eval_scalar.items)?,
+        RelOperator::Aggregate(aggregate) => {
+            aggregate.limit = None;
+            erase_items(&mut aggregate.group_items)?;
+            erase_items(&mut aggregate.aggregate_functions)?;
+        }
+        RelOperator::Sort(sort) => sort.limit = None,
+        RelOperator::Exchange(Exchange::Hash(keys)) => {
+            for key in keys.iter_mut() {
+                eraser.visit(key)?;
+            }
+        }
+        RelOperator::Window(window) => {
+            if let WindowFuncType::Aggregate(func) = &mut window.function {
+                eraser.visit_aggregate_function(func)?;
+            }
+            erase_items(&mut window.arguments)?;
+            erase_items(&mut window.partition_by)?;
+            for order_by in window.order_by.iter_mut() {
+                eraser.visit(&mut order_by.order_by_item.scalar)?;
+            }
+        }
+        RelOperator::ProjectSet(project_set) => {
+            for srf in project_set.srfs.iter_mut() {
+                eraser.visit(&mut srf.scalar)?;
+            }
+        }
+        RelOperator::Lambda(lambda) => erase_items(&mut lambda.items)?,
+        RelOperator::Udf(udf) => erase_items(&mut udf.items)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+fn erase_items(items: &mut [ScalarItem]) -> Result<()> {
+    for item in items.iter_mut() {
+        NameEraser.visit(&mut item.scalar)?;
+    }
+    Ok(())
+}
+
+/// Erases the column, table and function names which come from the aliases in the query.
+struct NameEraser;
+
+impl<'a> VisitorMut<'a> for NameEraser {
+    fn visit_bound_column_ref(&mut self, col: &'a mut BoundColumnRef) -> Result<()> {
+        col.column.database_name = None;
+        col.column.table_name = None;
+        col.column.column_name = String::new();
+        Ok(())
+    }
+
+    fn visit_aggregate_function(&mut self, aggregate: &'a mut AggregateFunction) -> Result<()> {
+        aggregate.display_name = String::new();
+        for expr in &mut aggregate.args {
+            self.visit(expr)?;
+        }
+        Ok(())
+    }
+
+    fn visit_window_function(&mut self, window: &'a mut WindowFunc) -> Result<()> {
+        window.display_name = String::new();
+        for expr in &mut window.partition_by {
+            self.visit(expr)?;
+        }
+        for expr in &mut window.order_by {
+            self.visit(&mut expr.expr)?;
+        }
+        match &mut window.func {
+            WindowFuncType::Aggregate(func) => self.visit_aggregate_function(func)?,
+            WindowFuncType::NthValue(func) => self.visit(&mut func.arg)?,
+            WindowFuncType::LagLead(func) => {
+                self.visit(&mut func.arg)?;
+                if let Some(default) = func.default.as_mut() {
+                    self.visit(default)?
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn visit_lambda_function(&mut self, lambda: &'a mut LambdaFunc) -> Result<()> {
+        lambda.display_name = String::new();
+        for expr in &mut lambda.args {
+            self.visit(expr)?;
+        }
+        self.visit(&mut lambda.lambda_expr)?;
+        Ok(())
+    }
+
+    fn visit_udf_server_call(&mut self, udf: &'a mut UDFServerCall) -> Result<()> {
+        udf.display_name = String::new();
+        for expr in &mut udf.arguments {
+            self.visit(expr)?;
+        }
+        Ok(())
+    }
+}
@@ -18,12 +18,14 @@ mod connection;
 mod database;
 mod file_format;
 mod index;
+mod password_policy;
 mod stage;
 mod table;
 mod task;
 mod udf;
 mod view;
 mod virtual_column;
+mod workload_group;
 
 pub use account::*;
 pub use catalog::*;
@@ -31,9 +33,11 @@ pub use connection::*;
 pub use database::*;
 pub use file_format::*;
 pub use index::*;
+pub use password_policy::*;
 pub use stage::*;
 pub use table::*;
 pub use task::*;
 pub use udf::*;
 pub use view::*;
 pub use virtual_column::*;
+pub use workload_group::*;
@@ -23,12 +23,14 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
+use storages_common_table_meta::table::ColumnCodec;
 
 use crate::plans::Plan;
 
@@ -51,6 +53,7 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    pub clone_source: Option<CloneTableSource>,
 }
 
 impl CreateTablePlan {
@@ -59,6 +62,15 @@ impl CreateTablePlan {
     }
 }
 
+/// The source table of `CREATE TABLE ... CLONE`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub point: Option<NavigationPoint>,
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -231,6 +243,7 @@ pub struct AddTableColumnPlan {
     pub field: TableField,
     pub comment: String,
     pub option: AddColumnOption,
+    pub codec: Option<ColumnCodec>,
 }
 
 impl AddTableColumnPlan {
@@ -279,6 +292,121 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table create tag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTableTagPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+    pub point: Option<NavigationPoint>,
+}
+
+impl CreateTableTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop tag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableTagPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+}
+
+impl DropTableTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table merge branch, the branch is a table in the same database
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeTableBranchPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub branch: String,
+}
+
+impl MergeTableBranchPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop or truncate partition
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropTablePartitionPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    // values of the partition keys, casted to the types of the keys
+    pub values: Vec<Scalar>,
+    // do not report an error if the partition does not exist
+    pub if_exists: bool,
+}
+
+impl DropTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table replace partition, with the same partition of the source table
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplaceTablePartitionPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    // values of the partition keys, casted to the types of the keys
+    pub values: Vec<Scalar>,
+    pub source_database: String,
+    pub source_table: String,
+}
+
+impl ReplaceTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table add row access policy, the columns are bound to the args of the policy in order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+// Table drop row access policy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // ModifyColumnAction after name resolved, used in ModifyTableColumnPlan
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
@@ -290,6 +418,8 @@ pub enum ModifyColumnAction {
     SetDataType(Vec<(TableField, String)>),
     // column name
     ConvertStoredComputedColumn(String),
+    // column name, codec
+    SetCodec(String, ColumnCodec),
 }
 
 // Table modify column
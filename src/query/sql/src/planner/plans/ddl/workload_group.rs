@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateWorkloadGroupPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub max_concurrency: u64,
+    pub memory_percentage: u64,
+    pub cpu_weight: u64,
+    pub queue_timeout_secs: u64,
+    pub users: Vec<String>,
+    pub roles: Vec<String>,
+}
+
+impl CreateWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub max_concurrency: Option<u64>,
+    pub memory_percentage: Option<u64>,
+    pub cpu_weight: Option<u64>,
+    pub queue_timeout_secs: Option<u64>,
+    pub users: Option<Vec<String>>,
+    pub roles: Option<Vec<String>>,
+}
+
+impl AlterWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowWorkloadGroupsPlan {}
+
+impl ShowWorkloadGroupsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("Name", DataType::String),
+            DataField::new("Max Concurrency", DataType::Number(NumberDataType::UInt64)),
+            DataField::new(
+                "Memory Percentage",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+            DataField::new("Cpu Weight", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Queue Timeout", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("Users", DataType::String),
+            DataField::new("Roles", DataType::String),
+        ])
+    }
+}
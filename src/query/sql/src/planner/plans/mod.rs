@@ -43,6 +43,7 @@ mod project_set;
 mod recluster_table;
 mod replace;
 mod revert_table;
+pub mod row_access_policy;
 mod runtime_filter_source;
 mod scalar_expr;
 mod scan;
@@ -86,6 +87,7 @@ pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use runtime_filter_source::RuntimeFilterId;
 pub use runtime_filter_source::RuntimeFilterSource;
 pub use scalar_expr::*;
@@ -28,7 +28,9 @@ use super::SetSecondaryRolesPlan;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterNetworkPolicyPlan;
+use crate::plans::AlterPasswordPolicyPlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
@@ -36,6 +38,7 @@ use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
@@ -46,20 +49,26 @@ use crate::plans::CreateDatamaskPolicyPlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableTagPlan;
 use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -70,18 +79,24 @@ use crate::plans::DropDatamaskPolicyPlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
+use crate::plans::DropTableTagPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
@@ -90,6 +105,7 @@ use crate::plans::GrantShareObjectPlan;
 use crate::plans::Insert;
 use crate::plans::KillPlan;
 use crate::plans::MergeInto;
+use crate::plans::MergeTableBranchPlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
@@ -101,6 +117,7 @@ use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
+use crate::plans::ReplaceTablePartitionPlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
@@ -117,10 +134,12 @@ use crate::plans::ShowGrantTenantsOfSharePlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
 use crate::plans::ShowObjectGrantPrivilegesPlan;
+use crate::plans::ShowPasswordPoliciesPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::ShowSharesPlan;
 use crate::plans::ShowTasksPlan;
+use crate::plans::ShowWorkloadGroupsPlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::UndropDatabasePlan;
@@ -140,8 +159,6 @@ pub enum Plan {
         metadata: MetadataRef,
         bind_context: Box<BindContext>,
         rewrite_kind: Option<RewriteKind>,
-        // Use for generate query result cache key.
-        formatted_ast: Option<String>,
         ignore_result: bool,
     },
 
@@ -200,6 +217,13 @@ pub enum Plan {
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    CreateTableTag(Box<CreateTableTagPlan>),
+    DropTableTag(Box<DropTableTagPlan>),
+    MergeTableBranch(Box<MergeTableBranchPlan>),
+    DropTablePartition(Box<DropTablePartitionPlan>),
+    ReplaceTablePartition(Box<ReplaceTablePartitionPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
 
     // Insert
     Insert(Box<Insert>),
@@ -288,6 +312,11 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -295,6 +324,19 @@ pub enum Plan {
     DescNetworkPolicy(Box<DescNetworkPolicyPlan>),
     ShowNetworkPolicies(Box<ShowNetworkPoliciesPlan>),
 
+    // Password policy
+    CreatePasswordPolicy(Box<CreatePasswordPolicyPlan>),
+    AlterPasswordPolicy(Box<AlterPasswordPolicyPlan>),
+    DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
+    DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
+    ShowPasswordPolicies(Box<ShowPasswordPoliciesPlan>),
+
+    // Workload group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+    ShowWorkloadGroups(Box<ShowWorkloadGroupsPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -396,11 +438,23 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
             Plan::CreateNetworkPolicy(plan) => plan.schema(),
             Plan::AlterNetworkPolicy(plan) => plan.schema(),
             Plan::DropNetworkPolicy(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
+            Plan::CreatePasswordPolicy(plan) => plan.schema(),
+            Plan::AlterPasswordPolicy(plan) => plan.schema(),
+            Plan::DropPasswordPolicy(plan) => plan.schema(),
+            Plan::DescPasswordPolicy(plan) => plan.schema(),
+            Plan::ShowPasswordPolicies(plan) => plan.schema(),
+            Plan::CreateWorkloadGroup(plan) => plan.schema(),
+            Plan::AlterWorkloadGroup(plan) => plan.schema(),
+            Plan::DropWorkloadGroup(plan) => plan.schema(),
+            Plan::ShowWorkloadGroups(plan) => plan.schema(),
             Plan::CopyIntoTable(plan) => plan.schema(),
 
             Plan::CreateTask(plan) => plan.schema(),
@@ -442,8 +496,12 @@ impl Plan {
                 | Plan::VacuumTable(_)
                 | Plan::VacuumDropTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
+                | Plan::DescPasswordPolicy(_)
+                | Plan::ShowPasswordPolicies(_)
+                | Plan::ShowWorkloadGroups(_)
                 | Plan::CopyIntoTable(_)
                 | Plan::ShowTasks(_)
                 | Plan::DescribeTask(_)
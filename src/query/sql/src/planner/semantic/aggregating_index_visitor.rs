@@ -63,7 +63,9 @@ impl VisitorMut for AggregatingIndexRewriter {
                 window,
                 ..
             } if !*distinct
-                && args.len() == 1
+                // The second argument of `approx_top_k` is the constant k.
+                && (args.len() == 1
+                    || (args.len() == 2 && name.name.eq_ignore_ascii_case("approx_top_k")))
                 && SUPPORTED_AGGREGATING_INDEX_FUNCTIONS
                     .contains(&&*name.name.to_ascii_lowercase().to_lowercase())
                 && window.is_none() =>
@@ -39,5 +39,11 @@ pub use type_check::TypeChecker;
 pub use view_rewriter::ViewRewriter;
 pub use window_check::WindowChecker;
 
-pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 5] =
-    ["sum", "min", "max", "avg", "approx_count_distinct"];
+pub(crate) const SUPPORTED_AGGREGATING_INDEX_FUNCTIONS: [&str; 6] = [
+    "sum",
+    "min",
+    "max",
+    "avg",
+    "approx_count_distinct",
+    "approx_top_k",
+];
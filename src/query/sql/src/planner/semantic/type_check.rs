@@ -1717,6 +1717,20 @@ impl<'a> TypeChecker<'a> {
             }
             let delimiter = delimiter_value.unwrap();
             vec![delimiter.value]
+        } else if ["approx_top_k", "approx_top_k_state", "approx_top_k_merge"]
+            .iter()
+            .any(|name| func_name.eq_ignore_ascii_case(name))
+            && arguments.len() >= 2
+            && params.is_empty()
+        {
+            // Convert the k of `approx_top_k`, `approx_top_k_state` and `approx_top_k_merge` to params
+            let k_value = ConstantExpr::try_from(arguments[1].clone());
+            if !arg_types[1].is_integer() || k_value.is_err() {
+                return Err(ErrorCode::SemanticError(
+                    "The k of `approx_top_k` must be a constant integer",
+                ));
+            }
+            vec![k_value.unwrap().value]
         } else {
             params
         };
@@ -3799,6 +3813,7 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name_inner(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Geometry => TableDataType::Geometry,
     };
 
     Ok(data_type)
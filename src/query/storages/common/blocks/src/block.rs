@@ -14,6 +14,7 @@
 
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::io::parquet::write::can_encode;
 use common_arrow::arrow::io::parquet::write::transverse;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
@@ -26,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::TableSchema;
+use storages_common_table_meta::table::ColumnCodec;
 use storages_common_table_meta::table::TableCompression;
 
 /// Serialize data blocks to parquet format.
@@ -53,18 +55,40 @@ pub fn blocks_to_parquet(
         _ => col_encoding(data_type),
     };
 
+    // the codecs declared by `CODEC(...)`, by field index
+    let codecs = ColumnCodec::load_fields(schema.as_ref())?;
+
     let encodings: Vec<Vec<_>> = arrow_schema
         .fields
         .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
+        .zip(codecs.iter())
+        .map(|(f, codec)| {
+            transverse(&f.data_type, |data_type| {
+                match codec.as_ref().and_then(|c| c.parquet_encoding()) {
+                    Some(encoding) if can_encode(data_type, encoding) => encoding,
+                    _ => encoding_map(data_type),
+                }
+            })
+        })
         .collect::<Vec<_>>();
 
-    let row_groups = RowGroupIterator::try_new(
+    let mut row_groups = RowGroupIterator::try_new(
         batches.into_iter().map(Ok),
         &arrow_schema,
         row_group_write_options,
         encodings,
     )?;
+    if codecs.iter().any(Option::is_some) {
+        let default_compression = row_group_write_options.compression;
+        let compressions = codecs
+            .iter()
+            .map(|codec| match codec {
+                Some(codec) => codec.parquet_compression(default_compression),
+                None => default_compression,
+            })
+            .collect();
+        row_groups = row_groups.with_compressions(compressions)?;
+    }
 
     use common_arrow::parquet::write::WriteOptions as FileWriteOption;
     let options = FileWriteOption {
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -26,11 +27,13 @@ use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
 use common_expression::types::AnyType;
+use common_expression::types::ArrayType;
 use common_expression::types::DataType;
 use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
@@ -104,6 +107,28 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
     }
 }
 
+/// The arguments of the n-gram bloom filter of a string column.
+///
+/// The filter contains the n-grams(in bytes) of the strings, so that `LIKE` predicates can be
+/// checked against the literal parts of their patterns.
+#[derive(Clone, Debug)]
+pub struct NgramArgs {
+    /// The index of the column in the source block.
+    pub index: FieldIndex,
+    pub field: TableField,
+    pub gram_size: usize,
+}
+
+impl NgramArgs {
+    pub fn new(index: FieldIndex, field: TableField, gram_size: usize) -> Self {
+        Self {
+            index,
+            field,
+            gram_size,
+        }
+    }
+}
+
 /// BlockFilter represents multiple per-column filters(bloom filter or xor filter etc) for data block.
 ///
 /// By default we create a filter per column for a parquet data file. For columns whose data_type
@@ -138,6 +163,9 @@ pub struct BloomIndex {
 
     /// Approximate distinct count of columns generated by xor hash function.
     pub column_distinct_count: HashMap<FieldIndex, usize>,
+
+    /// The n-gram bloom filters of the string columns.
+    pub ngram_args: Vec<NgramArgs>,
 }
 
 /// FilterExprEvalResult represents the evaluation result of an expression by a filter.
@@ -160,6 +188,7 @@ impl BloomIndex {
         filter_schema: TableSchemaRef,
         filters: Vec<Arc<Xor8Filter>>,
         version: u64,
+        ngram_args: Vec<NgramArgs>,
     ) -> Result<Self> {
         Ok(Self {
             version,
@@ -167,6 +196,7 @@ impl BloomIndex {
             filter_schema,
             filters,
             column_distinct_count: HashMap::new(),
+            ngram_args,
         })
     }
 
@@ -178,6 +208,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -194,28 +225,66 @@ impl BloomIndex {
             let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
             let (column, data_type) = match field_type {
                 DataType::Map(box inner_ty) => {
-                    // Add bloom filter for the value of map type
-                    let val_type = match inner_ty {
-                        DataType::Tuple(kv_tys) => kv_tys[1].clone(),
+                    let (key_type, val_type) = match inner_ty {
+                        DataType::Tuple(kv_tys) => (kv_tys[0].clone(), kv_tys[1].clone()),
                         _ => unreachable!(),
                     };
+                    let map_columns = data_blocks_tobe_indexed
+                        .iter()
+                        .map(|block| {
+                            let value = &block.get_by_offset(index).value;
+                            let column = value.convert_to_full_column(field_type, block.num_rows());
+                            MapType::<AnyType, AnyType>::try_downcast_column(&column).unwrap()
+                        })
+                        .collect::<Vec<_>>();
+
+                    // Add bloom filter for the keys of map type
+                    if Xor8Filter::supported_type(&key_type) {
+                        let column = Column::concat_columns(
+                            map_columns.iter().map(|c| c.values.keys.clone()),
+                        )?;
+                        if column.len() > 0 && !Self::check_large_string(&column) {
+                            let filter = Self::build_filter(&func_ctx, &column, &key_type)?;
+                            let filter_name =
+                                Self::build_map_key_filter_column_name(version, &field)?;
+                            filter_fields
+                                .push(TableField::new(&filter_name, TableDataType::String));
+                            filters.push(Arc::new(filter));
+                        }
+                    }
+
+                    // Add bloom filter for the value of map type
                     if !Xor8Filter::supported_type(&val_type) {
                         continue;
                     }
+                    let column =
+                        Column::concat_columns(map_columns.into_iter().map(|c| c.values.values))?;
+
+                    if Self::check_large_string(&column) {
+                        continue;
+                    }
+
+                    (column, val_type)
+                }
+                DataType::Array(box inner_ty) => {
+                    // Add bloom filter for the elements of array type
+                    if !Xor8Filter::supported_type(inner_ty) {
+                        continue;
+                    }
                     let source_columns_iter = data_blocks_tobe_indexed.iter().map(|block| {
                         let value = &block.get_by_offset(index).value;
                         let column = value.convert_to_full_column(field_type, block.num_rows());
-                        let map_column =
-                            MapType::<AnyType, AnyType>::try_downcast_column(&column).unwrap();
-                        map_column.values.values
+                        let array_column =
+                            ArrayType::<AnyType>::try_downcast_column(&column).unwrap();
+                        array_column.values
                     });
                     let column = Column::concat_columns(source_columns_iter)?;
 
-                    if Self::check_large_string(&column) {
+                    if column.len() == 0 || Self::check_large_string(&column) {
                         continue;
                     }
 
-                    (column, val_type)
+                    (column, inner_ty.clone())
                 }
                 _ => {
                     if !Xor8Filter::supported_type(field_type) {
@@ -235,27 +304,12 @@ impl BloomIndex {
                 }
             };
 
-            let (column, validity) =
-                Self::calculate_nullable_column_digest(&func_ctx, &column, &data_type)?;
-
             // create filter per column
-            let mut filter_builder = Xor8Builder::create();
-            if validity.as_ref().map(|v| v.unset_bits()).unwrap_or(0) > 0 {
-                let validity = validity.unwrap();
-                let it = column.deref().iter().zip(validity.iter()).map(
-                    |(v, b)| {
-                        if !b { &0 } else { v }
-                    },
-                );
-                filter_builder.add_digests(it);
-            } else {
-                filter_builder.add_digests(column.deref());
-            }
-            let filter = filter_builder.build()?;
+            let filter = Self::build_filter(&func_ctx, &column, &data_type)?;
 
             if let Some(len) = filter.len() {
                 match field.data_type() {
-                    TableDataType::Map(_) => {}
+                    TableDataType::Map(_) | TableDataType::Array(_) => {}
                     _ => {
                         column_distinct_count.insert(index, len);
                     }
@@ -267,6 +321,45 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        for args in ngram_args {
+            let field_type = &data_blocks_tobe_indexed[0]
+                .get_by_offset(args.index)
+                .data_type;
+            if field_type.remove_nullable() != DataType::String || args.gram_size == 0 {
+                continue;
+            }
+
+            let column = Column::concat_columns(data_blocks_tobe_indexed.iter().map(|block| {
+                let value = &block.get_by_offset(args.index).value;
+                value
+                    .convert_to_full_column(field_type, block.num_rows())
+                    .remove_nullable()
+            }))?;
+            // same as the bloom filters, the n-grams of large strings are not indexed
+            if Self::check_large_string(&column) {
+                continue;
+            }
+
+            let mut grams = HashSet::new();
+            let column = StringType::try_downcast_column(&column).unwrap();
+            for value in column.iter() {
+                grams.extend(value.windows(args.gram_size));
+            }
+            // a filter without any keys can not be built
+            if grams.is_empty() {
+                continue;
+            }
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_keys(&grams.into_iter().collect::<Vec<_>>());
+            let filter = filter_builder.build()?;
+
+            let filter_name =
+                Self::build_ngram_filter_column_name(version, &args.field, args.gram_size)?;
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -279,9 +372,34 @@ impl BloomIndex {
             filter_schema,
             filters,
             column_distinct_count,
+            ngram_args: ngram_args.to_vec(),
         }))
     }
 
+    /// Build a filter of the digests of the column values.
+    fn build_filter(
+        func_ctx: &FunctionContext,
+        column: &Column,
+        data_type: &DataType,
+    ) -> Result<Xor8Filter> {
+        let (column, validity) =
+            Self::calculate_nullable_column_digest(func_ctx, column, data_type)?;
+
+        let mut filter_builder = Xor8Builder::create();
+        if validity.as_ref().map(|v| v.unset_bits()).unwrap_or(0) > 0 {
+            let validity = validity.unwrap();
+            let it = column
+                .deref()
+                .iter()
+                .zip(validity.iter())
+                .map(|(v, b)| if !b { &0 } else { v });
+            filter_builder.add_digests(it);
+        } else {
+            filter_builder.add_digests(column.deref());
+        }
+        Ok(filter_builder.build()?)
+    }
+
     pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
         let fields = self.filter_schema.fields();
         let mut filter_columns = Vec::with_capacity(fields.len());
@@ -307,11 +425,14 @@ impl BloomIndex {
     ) -> Result<FilterEvalResult> {
         visit_expr_column_eq_constant(
             &mut expr,
-            &mut |span, col_name, scalar, ty, return_type| {
-                let filter_column = &Self::build_filter_column_name(
-                    self.version,
-                    data_schema.field_with_name(col_name)?,
-                )?;
+            &mut |span, col_name, scalar, ty, return_type, target| {
+                let field = data_schema.field_with_name(col_name)?;
+                let filter_column = &match target {
+                    FilterTarget::Value => Self::build_filter_column_name(self.version, field)?,
+                    FilterTarget::MapKey => {
+                        Self::build_map_key_filter_column_name(self.version, field)?
+                    }
+                };
 
                 // If the column doesn't contain the constant, we rewrite the expression to `false`.
                 if self.find(filter_column, scalar, ty, scalar_map)? == FilterEvalResult::MustFalse
@@ -327,6 +448,37 @@ impl BloomIndex {
             },
         )?;
 
+        if !self.ngram_args.is_empty() {
+            visit_expr_column_like_constant(
+                &mut expr,
+                &mut |span, col_name, pattern, return_type| {
+                    let field = data_schema.field_with_name(col_name)?;
+                    for args in &self.ngram_args {
+                        if args.field.column_id() != field.column_id() {
+                            continue;
+                        }
+                        let filter_column = &Self::build_ngram_filter_column_name(
+                            self.version,
+                            field,
+                            args.gram_size,
+                        )?;
+
+                        // If the column doesn't contain the n-grams of the pattern, we rewrite the expression to `false`.
+                        if self.find_ngrams(filter_column, pattern, args.gram_size)?
+                            == FilterEvalResult::MustFalse
+                        {
+                            return Ok(Some(Expr::Constant {
+                                span,
+                                scalar: Scalar::Boolean(false),
+                                data_type: return_type.clone(),
+                            }));
+                        }
+                    }
+                    Ok(None)
+                },
+            )?;
+        }
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -412,7 +564,7 @@ impl BloomIndex {
         fields: Vec<TableField>,
     ) -> Result<Vec<(TableField, Scalar, DataType)>> {
         let mut cols = Vec::new();
-        visit_expr_column_eq_constant(&mut expr.clone(), &mut |_, col_name, scalar, ty, _| {
+        visit_expr_column_eq_constant(&mut expr.clone(), &mut |_, col_name, scalar, ty, _, _| {
             if let Some(v) = fields.iter().find(|f: &&TableField| f.name() == col_name) {
                 if Xor8Filter::supported_type(ty) && !scalar.is_null() {
                     cols.push((v.clone(), scalar.clone(), ty.clone()));
@@ -423,18 +575,61 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all the n-gram filters that match the pattern of `col LIKE <constant>` in the expression.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        ngram_args: &[NgramArgs],
+    ) -> Result<Vec<NgramArgs>> {
+        let mut cols = Vec::new();
+        visit_expr_column_like_constant(&mut expr.clone(), &mut |_, col_name, pattern, _| {
+            for args in ngram_args.iter().filter(|v| v.field.name() == col_name) {
+                if like_pattern_literals(pattern)
+                    .iter()
+                    .any(|literal| literal.len() >= args.gram_size)
+                {
+                    cols.push(args.clone());
+                }
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
+        Self::build_filter_column_name_with_prefix("Bloom", version, field)
+    }
+
+    /// The filter of the keys of a map column is stored with field name 'MapKey(column_name)'
+    pub fn build_map_key_filter_column_name(version: u64, field: &TableField) -> Result<String> {
+        Self::build_filter_column_name_with_prefix("MapKey", version, field)
+    }
+
+    /// The n-gram filter of a string column is stored with field name 'Ngram{n}(column_name)',
+    /// the filters of different n-gram sizes can not be mixed up.
+    pub fn build_ngram_filter_column_name(
+        version: u64,
+        field: &TableField,
+        gram_size: usize,
+    ) -> Result<String> {
+        Self::build_filter_column_name_with_prefix(&format!("Ngram{gram_size}"), version, field)
+    }
+
+    fn build_filter_column_name_with_prefix(
+        prefix: &str,
+        version: u64,
+        field: &TableField,
+    ) -> Result<String> {
         let index_version = BlockBloomFilterIndexVersion::try_from(version)?;
         match index_version {
             BlockBloomFilterIndexVersion::V0(_) => Err(ErrorCode::DeprecatedIndexFormat(
                 "bloom filter index version(v0) is deprecated",
             )),
             BlockBloomFilterIndexVersion::V2(_) | BlockBloomFilterIndexVersion::V3(_) => {
-                Ok(format!("Bloom({})", field.name()))
+                Ok(format!("{}({})", prefix, field.name()))
             }
-            BlockBloomFilterIndexVersion::V4(_) => Ok(format!("Bloom({})", field.column_id())),
+            BlockBloomFilterIndexVersion::V4(_) => Ok(format!("{}({})", prefix, field.column_id())),
         }
     }
 
@@ -472,20 +667,52 @@ impl BloomIndex {
         }
     }
 
+    /// Find the n-grams of the literals of the `LIKE` pattern in the filter.
+    fn find_ngrams(
+        &self,
+        filter_column: &str,
+        pattern: &[u8],
+        gram_size: usize,
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+        for literal in like_pattern_literals(pattern) {
+            if literal
+                .windows(gram_size)
+                .any(|gram| !filter.contains(&gram))
+            {
+                return Ok(FilterEvalResult::MustFalse);
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let data_type = DataType::from(data_type);
         Self::supported_data_type(&data_type)
     }
 
     pub fn supported_data_type(data_type: &DataType) -> bool {
-        let mut data_type = data_type;
-        if let DataType::Map(box inner_ty) = data_type {
-            data_type = match inner_ty {
-                DataType::Tuple(kv_tys) => &kv_tys[1],
-                _ => unreachable!(),
-            };
+        match data_type {
+            // The filters of map type are built for the keys and the values.
+            DataType::Map(box DataType::Tuple(kv_tys)) => {
+                kv_tys.iter().any(Xor8Filter::supported_type)
+            }
+            // The filter of array type is built for the elements, only if the column is
+            // specified in `bloom_index_columns`.
+            DataType::Array(box inner_ty) => Xor8Filter::supported_type(inner_ty),
+            _ => Xor8Filter::supported_type(data_type),
         }
-        Xor8Filter::supported_type(data_type)
+    }
+
+    /// The n-gram filter can only be built for string columns.
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        DataType::from(data_type).remove_nullable() == DataType::String
     }
 
     /// Checks if the average length of a string column exceeds 256 bytes.
@@ -501,12 +728,30 @@ impl BloomIndex {
     }
 }
 
+/// The filter of a column that a constant is looked up in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FilterTarget {
+    /// The values of the column, the values of a map column or the elements of an array column.
+    Value,
+    /// The keys of a map column.
+    MapKey,
+}
+
 fn visit_expr_column_eq_constant(
     expr: &mut Expr<String>,
-    visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
+    visitor: &mut impl FnMut(
+        Span,
+        &str,
+        &Scalar,
+        &DataType,
+        &DataType,
+        FilterTarget,
+    ) -> Result<Option<Expr<String>>>,
 ) -> Result<()> {
     // Find patterns like `Column = <constant>`, `<constant> = Column`,
-    // or `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`
+    // `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`,
+    // `ArrayColumn[<index>] = <constant>`, `<constant> = ArrayColumn[<index>]`
+    // or `contains(ArrayColumn, <constant>)`
     match expr {
         Expr::FunctionCall {
             span,
@@ -541,7 +786,14 @@ fn visit_expr_column_eq_constant(
             ] => {
                 debug_assert_eq!(scalar_type, column_type);
                 // If the visitor returns a new expression, then replace with the current expression.
-                if let Some(new_expr) = visitor(*span, id, scalar, column_type, return_type)? {
+                if let Some(new_expr) = visitor(
+                    *span,
+                    id,
+                    scalar,
+                    column_type,
+                    return_type,
+                    FilterTarget::Value,
+                )? {
                     *expr = new_expr;
                     return Ok(());
                 }
@@ -573,6 +825,41 @@ fn visit_expr_column_eq_constant(
             }
             _ => (),
         },
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == "contains" => {
+            if let [
+                Expr::ColumnRef {
+                    id,
+                    data_type: DataType::Array(box inner_ty),
+                    ..
+                },
+                Expr::Constant {
+                    scalar,
+                    data_type: scalar_type,
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                if inner_ty.remove_nullable() == scalar_type.remove_nullable() {
+                    if let Some(new_expr) = visitor(
+                        *span,
+                        id,
+                        scalar,
+                        inner_ty,
+                        return_type,
+                        FilterTarget::Value,
+                    )? {
+                        *expr = new_expr;
+                        return Ok(());
+                    }
+                }
+            }
+        }
         _ => (),
     }
 
@@ -598,17 +885,123 @@ fn visit_map_column(
     scalar: &Scalar,
     scalar_type: &DataType,
     return_type: &DataType,
-    visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
+    visitor: &mut impl FnMut(
+        Span,
+        &str,
+        &Scalar,
+        &DataType,
+        &DataType,
+        FilterTarget,
+    ) -> Result<Option<Expr<String>>>,
 ) -> Result<Option<Expr<String>>> {
     if let Expr::ColumnRef { id, data_type, .. } = &args[0] {
-        if let DataType::Map(box inner_ty) = data_type.remove_nullable() {
-            let val_type = match inner_ty {
-                DataType::Tuple(kv_tys) => kv_tys[1].clone(),
-                _ => unreachable!(),
-            };
-            debug_assert_eq!(&val_type.wrap_nullable(), scalar_type);
-            return visitor(span, id, scalar, &val_type, return_type);
+        match data_type.remove_nullable() {
+            DataType::Map(box inner_ty) => {
+                let (key_type, val_type) = match inner_ty {
+                    DataType::Tuple(kv_tys) => (kv_tys[0].clone(), kv_tys[1].clone()),
+                    _ => unreachable!(),
+                };
+                debug_assert_eq!(&val_type.wrap_nullable(), scalar_type);
+                if let Some(new_expr) = visitor(
+                    span,
+                    id,
+                    scalar,
+                    &val_type,
+                    return_type,
+                    FilterTarget::Value,
+                )? {
+                    return Ok(Some(new_expr));
+                }
+                // The value of a nonexistent key is NULL.
+                if let Expr::Constant { scalar: key, .. } = &args[1] {
+                    return visitor(span, id, key, &key_type, return_type, FilterTarget::MapKey);
+                }
+            }
+            DataType::Array(box inner_ty) => {
+                return visitor(
+                    span,
+                    id,
+                    scalar,
+                    &inner_ty,
+                    return_type,
+                    FilterTarget::Value,
+                );
+            }
+            _ => (),
         }
     }
     Ok(None)
 }
+
+fn visit_expr_column_like_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[u8], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `StringColumn LIKE <constant>`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "like" {
+            if let [
+                Expr::ColumnRef { id, data_type, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                if data_type.remove_nullable() == DataType::String {
+                    if let Some(new_expr) = visitor(*span, id, pattern, return_type)? {
+                        *expr = new_expr;
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_like_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_like_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Returns the literal parts of the `LIKE` pattern, which are separated by the wildcards `%` and `_`.
+///
+/// The pattern is matched in bytes, a literal must be a substring of the matched strings.
+fn like_pattern_literals(pattern: &[u8]) -> Vec<Vec<u8>> {
+    let mut literals = vec![];
+    let mut literal = vec![];
+    let mut iter = pattern.iter();
+    while let Some(c) = iter.next() {
+        match c {
+            b'%' | b'_' => {
+                if !literal.is_empty() {
+                    literals.push(std::mem::take(&mut literal));
+                }
+            }
+            // a trailing backslash matches itself
+            b'\\' => literal.push(*iter.next().unwrap_or(&b'\\')),
+            _ => literal.push(*c),
+        }
+    }
+    if !literal.is_empty() {
+        literals.push(literal);
+    }
+    literals
+}
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
+
 use common_exception::Result;
 use common_expression::types::decimal::Decimal128Type;
 use common_expression::types::decimal::Decimal256Type;
 use common_expression::types::decimal::DecimalDataType;
 use common_expression::types::decimal::DecimalDomain;
+use common_expression::types::geometry::GeometryDomain;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::SimpleDomain;
 use common_expression::types::string::StringDomain;
@@ -38,6 +41,7 @@ use common_expression::TableSchemaRef;
 use common_functions::BUILTIN_FUNCTIONS;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::table::ColumnEvolution;
 
 use crate::Index;
 
@@ -46,6 +50,7 @@ pub struct RangeIndex {
     expr: Expr<String>,
     func_ctx: FunctionContext,
     schema: TableSchemaRef,
+    column_evolution: ColumnEvolution,
 
     // Default stats for each column if no stats are available (e.g. for new-add columns)
     default_stats: StatisticsOfColumns,
@@ -58,10 +63,12 @@ impl RangeIndex {
         schema: TableSchemaRef,
         default_stats: StatisticsOfColumns,
     ) -> Result<Self> {
+        let column_evolution = ColumnEvolution::from_schema(&schema)?;
         Ok(Self {
             expr: expr.clone(),
             func_ctx,
             schema,
+            column_evolution,
             default_stats,
         })
     }
@@ -85,21 +92,24 @@ impl RangeIndex {
                 let column_ids = self.schema.leaf_columns_of(&name);
                 let stats = column_ids
                     .iter()
-                    .filter_map(|column_id| match stats.get(column_id) {
-                        None => {
-                            if column_is_default(column_id)
-                                && self.default_stats.contains_key(column_id)
-                            {
-                                Some(&self.default_stats[column_id])
-                            } else {
-                                None
+                    .filter_map(|column_id| {
+                        // stats of the lazily evolved columns are converted on the fly
+                        match self.column_evolution.column_statistics(stats, *column_id) {
+                            None => {
+                                if column_is_default(column_id)
+                                    && self.default_stats.contains_key(column_id)
+                                {
+                                    Some(Cow::Borrowed(&self.default_stats[column_id]))
+                                } else {
+                                    None
+                                }
                             }
+                            other => other,
                         }
-                        other => other,
                     })
-                    .collect();
+                    .collect::<Vec<_>>();
 
-                let domain = statistics_to_domain(stats, &ty);
+                let domain = statistics_to_domain(stats.iter().map(|s| s.as_ref()).collect(), &ty);
                 Ok((name, domain))
             })
             .collect::<Result<_>>()?;
@@ -171,6 +181,16 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                 Box::new(kv_domain[1].clone()),
             )))
         }
+        DataType::Geometry => {
+            // The min and max of geometries are the corners of their bounding box.
+            let stat = stats[0];
+            match (stat.min(), stat.max()) {
+                (Scalar::Geometry(min), Scalar::Geometry(max)) => Domain::Geometry(
+                    GeometryDomain::from_wkb(min).merge(&GeometryDomain::from_wkb(max)),
+                ),
+                _ => Domain::full(data_type),
+            }
+        }
         _ => {
             let stat = stats[0];
             with_number_mapped_type!(|NUM_TYPE| match data_type {
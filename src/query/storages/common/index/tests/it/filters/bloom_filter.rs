@@ -48,6 +48,7 @@ use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
 use storages_common_index::Index;
+use storages_common_index::NgramArgs;
 use storages_common_table_meta::meta::Versioned;
 
 #[test]
@@ -118,6 +119,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -219,14 +221,28 @@ fn test_bloom_filter() -> Result<()> {
         eval_map_index(
             &index,
             2,
-            schema,
-            map_ty,
+            schema.clone(),
+            map_ty.clone(),
             Scalar::Number(NumberScalar::UInt8(3)),
             DataType::Number(NumberDataType::UInt8),
             Scalar::String(b"x".to_vec()),
             DataType::String
         )
     );
+    // the key doesn't exist
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_map_index(
+            &index,
+            2,
+            schema,
+            map_ty,
+            Scalar::Number(NumberScalar::UInt8(4)),
+            DataType::Number(NumberDataType::UInt8),
+            Scalar::String(b"b".to_vec()),
+            DataType::String
+        )
+    );
 
     Ok(())
 }
@@ -251,6 +267,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -291,6 +308,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -309,6 +327,143 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_array_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "0",
+        TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt8))),
+    )]));
+    let array_ty = DataType::Array(Box::new(DataType::Number(NumberDataType::UInt8)));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![Column::Array(Box::new(
+        ArrayColumn::<AnyType> {
+            values: UInt8Type::from_data(vec![1, 2, 3]),
+            offsets: Buffer::<u64>::from(vec![0, 2, 3]),
+        },
+    ))])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let bloom_columns = BTreeMap::from([(0, schema.field(0).clone())]);
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        bloom_columns,
+        &[],
+    )?
+    .unwrap();
+
+    let eval_contains = |val: u8| {
+        let expr = check_function(
+            None,
+            "contains",
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: "0".to_string(),
+                    data_type: array_ty.clone(),
+                    display_name: "0".to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Number(NumberScalar::UInt8(val)),
+                    data_type: DataType::Number(NumberDataType::UInt8),
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+        let fields = schema.fields.clone();
+        eval_expr(&index, expr, fields, schema.clone())
+    };
+
+    assert_eq!(FilterEvalResult::Uncertain, eval_contains(2));
+    assert_eq!(FilterEvalResult::Uncertain, eval_contains(3));
+    assert_eq!(FilterEvalResult::MustFalse, eval_contains(4));
+
+    Ok(())
+}
+
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "0",
+        TableDataType::String,
+    )]));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![StringType::from_data(
+        vec!["GET /index.html error_code=503", "GET /about.html ok"],
+    )])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let ngram_args = vec![NgramArgs::new(0, schema.field(0).clone(), 3)];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        &ngram_args,
+    )?
+    .unwrap();
+
+    let like_expr = |pattern: &str| {
+        check_function(
+            None,
+            "like",
+            &[],
+            &[
+                Expr::ColumnRef {
+                    span: None,
+                    id: "0".to_string(),
+                    data_type: DataType::String,
+                    display_name: "0".to_string(),
+                },
+                Expr::Constant {
+                    span: None,
+                    scalar: Scalar::String(pattern.as_bytes().to_vec()),
+                    data_type: DataType::String,
+                },
+            ],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap()
+    };
+    let eval_like = |pattern: &str| eval_expr(&index, like_expr(pattern), vec![], schema.clone());
+
+    assert_eq!(FilterEvalResult::Uncertain, eval_like("%error_code=503%"));
+    assert_eq!(FilterEvalResult::Uncertain, eval_like("GET %.html%"));
+    assert_eq!(FilterEvalResult::Uncertain, eval_like("%about_html%"));
+    assert_eq!(FilterEvalResult::MustFalse, eval_like("%error_code=500%"));
+    assert_eq!(FilterEvalResult::MustFalse, eval_like("POST %"));
+
+    // the literals are shorter than the n-grams
+    assert!(BloomIndex::find_ngram_columns(&like_expr("%xy%"), &ngram_args)?.is_empty());
+    assert_eq!(FilterEvalResult::Uncertain, eval_like("%xy%"));
+
+    Ok(())
+}
+
+fn eval_expr(
+    index: &BloomIndex,
+    expr: Expr<String>,
+    fields: Vec<TableField>,
+    schema: Arc<TableSchema>,
+) -> FilterEvalResult {
+    let point_query_cols = BloomIndex::find_eq_columns(&expr, fields).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, scalar, ty) in point_query_cols.iter() {
+        if !scalar_map.contains_key(scalar) {
+            let digest = BloomIndex::calculate_scalar_digest(&func_ctx, scalar, ty).unwrap();
+            scalar_map.insert(scalar.clone(), digest);
+        }
+    }
+
+    index.apply(expr, &scalar_map, schema).unwrap()
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
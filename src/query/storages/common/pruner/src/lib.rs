@@ -18,6 +18,7 @@ mod block_meta;
 mod internal_column_pruner;
 mod limiter_pruner;
 mod page_pruner;
+mod partition_pruner;
 mod range_pruner;
 mod topn_pruner;
 
@@ -28,6 +29,7 @@ pub use limiter_pruner::LimiterPruner;
 pub use limiter_pruner::LimiterPrunerCreator;
 pub use page_pruner::PagePruner;
 pub use page_pruner::PagePrunerCreator;
+pub use partition_pruner::PartitionPruner;
 pub use range_pruner::RangePruner;
 pub use range_pruner::RangePrunerCreator;
 pub use topn_pruner::TopNPrunner;
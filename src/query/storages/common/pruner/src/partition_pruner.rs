@@ -0,0 +1,190 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+
+/// Prunes the segments and blocks of a partitioned table by their partition values,
+/// see `PARTITION BY`.
+///
+/// Every sub expression of the filter which is one of the partition keys is replaced by
+/// the value of the key, the segment or block is pruned if the filter is folded to false.
+pub struct PartitionPruner {
+    func_ctx: FunctionContext,
+    expr: Expr<String>,
+    partition_keys: Vec<Expr<String>>,
+}
+
+impl PartitionPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        expr: Option<&Expr<String>>,
+        partition_keys: Vec<Expr<String>>,
+    ) -> Option<Arc<Self>> {
+        let expr = expr?;
+        if !partition_keys.iter().any(|key| contains_key(expr, key)) {
+            return None;
+        }
+        Some(Arc::new(PartitionPruner {
+            func_ctx,
+            expr: expr.clone(),
+            partition_keys,
+        }))
+    }
+
+    /// Returns false if no row of the `partition` can match the filter.
+    pub fn should_keep(&self, partition: Option<&[Scalar]>) -> bool {
+        let Some(partition) = partition else {
+            return true;
+        };
+        if partition.len() != self.partition_keys.len() {
+            return true;
+        }
+
+        let expr = self.replace_keys(&self.expr, partition);
+        let (folded_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        !matches!(folded_expr, Expr::Constant {
+            scalar: Scalar::Boolean(false) | Scalar::Null,
+            ..
+        })
+    }
+
+    fn replace_keys(&self, expr: &Expr<String>, partition: &[Scalar]) -> Expr<String> {
+        if let Some((key, value)) = self
+            .partition_keys
+            .iter()
+            .zip(partition)
+            .find(|(key, _)| same_expr(expr, key))
+        {
+            return Expr::Constant {
+                span: None,
+                scalar: value.clone(),
+                data_type: key.data_type().clone(),
+            };
+        }
+
+        match expr {
+            Expr::Constant { .. } | Expr::ColumnRef { .. } => expr.clone(),
+            Expr::Cast {
+                span,
+                is_try,
+                expr,
+                dest_type,
+            } => Expr::Cast {
+                span: *span,
+                is_try: *is_try,
+                expr: Box::new(self.replace_keys(expr, partition)),
+                dest_type: dest_type.clone(),
+            },
+            Expr::FunctionCall {
+                span,
+                id,
+                function,
+                generics,
+                args,
+                return_type,
+            } => Expr::FunctionCall {
+                span: *span,
+                id: id.clone(),
+                function: function.clone(),
+                generics: generics.clone(),
+                args: args
+                    .iter()
+                    .map(|arg| self.replace_keys(arg, partition))
+                    .collect(),
+                return_type: return_type.clone(),
+            },
+        }
+    }
+}
+
+fn contains_key(expr: &Expr<String>, key: &Expr<String>) -> bool {
+    if same_expr(expr, key) {
+        return true;
+    }
+    match expr {
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => false,
+        Expr::Cast { expr, .. } => contains_key(expr, key),
+        Expr::FunctionCall { args, .. } => args.iter().any(|arg| contains_key(arg, key)),
+    }
+}
+
+// Unlike `PartialEq`, the display names of the columns are ignored.
+fn same_expr(lhs: &Expr<String>, rhs: &Expr<String>) -> bool {
+    match (lhs, rhs) {
+        (
+            Expr::Constant {
+                scalar: l_scalar,
+                data_type: l_type,
+                ..
+            },
+            Expr::Constant {
+                scalar: r_scalar,
+                data_type: r_type,
+                ..
+            },
+        ) => l_scalar == r_scalar && l_type == r_type,
+        (
+            Expr::ColumnRef {
+                id: l_id,
+                data_type: l_type,
+                ..
+            },
+            Expr::ColumnRef {
+                id: r_id,
+                data_type: r_type,
+                ..
+            },
+        ) => l_id == r_id && l_type == r_type,
+        (
+            Expr::Cast {
+                is_try: l_try,
+                expr: l_expr,
+                dest_type: l_type,
+                ..
+            },
+            Expr::Cast {
+                is_try: r_try,
+                expr: r_expr,
+                dest_type: r_type,
+                ..
+            },
+        ) => l_try == r_try && l_type == r_type && same_expr(l_expr, r_expr),
+        (
+            Expr::FunctionCall {
+                id: l_id,
+                generics: l_generics,
+                args: l_args,
+                ..
+            },
+            Expr::FunctionCall {
+                id: r_id,
+                generics: r_generics,
+                args: r_args,
+                ..
+            },
+        ) => {
+            l_id == r_id
+                && l_generics == r_generics
+                && l_args.len() == r_args.len()
+                && l_args.iter().zip(r_args).all(|(l, r)| same_expr(l, r))
+        }
+        _ => false,
+    }
+}
@@ -20,6 +20,7 @@ use common_expression::RemoteExpr;
 use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::table::ColumnEvolution;
 
 use crate::BlockMetaIndex;
 
@@ -84,16 +85,19 @@ impl TopNPrunner {
             return Ok(metas);
         }
 
+        let column_evolution = ColumnEvolution::from_schema(&self.schema)?;
         let mut id_stats = metas
             .iter()
             .map(|(id, meta)| {
-                let stat = meta.col_stats.get(&sort_column_id).ok_or_else(|| {
-                    ErrorCode::UnknownException(format!(
-                        "Unable to get the colStats by ColumnId: {}",
-                        sort_column_id
-                    ))
-                })?;
-                Ok((id.clone(), stat.clone(), meta.clone()))
+                let stat = column_evolution
+                    .column_statistics(&meta.col_stats, sort_column_id)
+                    .ok_or_else(|| {
+                        ErrorCode::UnknownException(format!(
+                            "Unable to get the colStats by ColumnId: {}",
+                            sort_column_id
+                        ))
+                    })?;
+                Ok((id.clone(), stat.into_owned(), meta.clone()))
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use roaring::RoaringBitmap;
+
+/// Offsets of the deleted rows of a block, kept inline in the [BlockMeta](crate::meta::BlockMeta).
+///
+/// The offsets are stored as a serialized roaring bitmap (portable format),
+/// so a handful of deleted rows only costs a few bytes in the segment.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVector {
+    /// number of deleted rows, i.e. the cardinality of the bitmap
+    pub deleted_rows: u64,
+    /// serialized [RoaringBitmap]
+    pub bitmap: Vec<u8>,
+}
+
+impl DeletionVector {
+    /// Create a deletion vector which marks the rows at `offsets` of the block.
+    pub fn from_offsets(offsets: impl IntoIterator<Item = usize>) -> Result<Self> {
+        let bitmap = offsets.into_iter().map(|v| v as u32).collect();
+        Self::from_bitmap(&bitmap)
+    }
+
+    /// Returns a new deletion vector which marks the rows of both `self` and `other`.
+    pub fn union(&self, other: &DeletionVector) -> Result<Self> {
+        let bitmap = self.to_bitmap()? | other.to_bitmap()?;
+        Self::from_bitmap(&bitmap)
+    }
+
+    fn from_bitmap(bitmap: &RoaringBitmap) -> Result<Self> {
+        let mut bytes = Vec::with_capacity(bitmap.serialized_size());
+        bitmap.serialize_into(&mut bytes)?;
+        Ok(Self {
+            deleted_rows: bitmap.len(),
+            bitmap: bytes,
+        })
+    }
+
+    fn to_bitmap(&self) -> Result<RoaringBitmap> {
+        RoaringBitmap::deserialize_from(self.bitmap.as_slice())
+            .map_err(|e| ErrorCode::StorageOther(format!("invalid deletion vector of block: {e}")))
+    }
+
+    /// Build the filter of the rows within `rows` (offsets in the block),
+    /// in which the deleted rows are unset.
+    pub fn to_filter(&self, rows: Range<usize>) -> Result<Bitmap> {
+        let bitmap = self.to_bitmap()?;
+        let mut filter = MutableBitmap::from_len_set(rows.len());
+        for offset in bitmap.iter().map(|v| v as usize) {
+            if offset >= rows.end {
+                break;
+            }
+            if offset >= rows.start {
+                filter.set(offset - rows.start, false);
+            }
+        }
+        Ok(filter.into())
+    }
+}
@@ -16,6 +16,7 @@
 
 mod compression;
 mod current;
+mod deletion_vector;
 mod format;
 mod statistics;
 mod utils;
@@ -30,6 +31,7 @@ mod versions;
 pub use compression::Compression;
 // table meta types of current version
 pub use current::*;
+pub use deletion_vector::DeletionVector;
 pub(crate) use format::load_json;
 pub(crate) use format::MetaCompression;
 pub(crate) use format::MetaEncoding;
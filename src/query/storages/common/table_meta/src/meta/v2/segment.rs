@@ -22,6 +22,7 @@ use common_arrow::native::ColumnMeta as NativeColumnMeta;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use common_expression::TableField;
 use enum_as_inner::EnumAsInner;
 use serde::Deserialize;
@@ -32,6 +33,7 @@ use crate::meta::v1;
 use crate::meta::ClusterStatistics;
 use crate::meta::ColumnStatistics;
 use crate::meta::Compression;
+use crate::meta::DeletionVector;
 use crate::meta::FormatVersion;
 use crate::meta::Location;
 use crate::meta::Statistics;
@@ -80,6 +82,16 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// rows of this block that have been deleted but not yet materialized,
+    /// only set if the table is in `merge_on_read` delete mode
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVector>,
+
+    /// values of the partition keys of all the rows of this block,
+    /// only set if the table is partitioned, see `PARTITION BY`
+    #[serde(default)]
+    pub partition: Option<Vec<Scalar>>,
 }
 
 impl BlockMeta {
@@ -109,6 +121,8 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector: None,
+            partition: None,
         }
     }
 
@@ -116,6 +130,14 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Number of rows that are still visible, i.e. not marked by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        match &self.deletion_vector {
+            Some(dv) => self.row_count.saturating_sub(dv.deleted_rows),
+            None => self.row_count,
+        }
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -254,6 +276,8 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector: None,
+            partition: None,
         }
     }
 
@@ -285,6 +309,8 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector: None,
+            partition: None,
         }
     }
 }
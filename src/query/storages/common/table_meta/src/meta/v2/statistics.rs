@@ -53,6 +53,10 @@ pub struct Statistics {
 
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub cluster_stats: Option<ClusterStatistics>,
+
+    /// values of the partition keys, if all the blocks belong to the same partition
+    #[serde(default)]
+    pub partition: Option<Vec<Scalar>>,
 }
 
 // conversions from old meta data
@@ -169,6 +173,7 @@ impl Statistics {
             index_size: v0.index_size,
             col_stats,
             cluster_stats: None,
+            partition: None,
         }
     }
 }
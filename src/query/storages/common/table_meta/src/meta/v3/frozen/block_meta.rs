@@ -62,6 +62,8 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             bloom_filter_index_size: value.bloom_filter_index_size,
             compression: value.compression.into(),
             create_on: None,
+            deletion_vector: None,
+            partition: None,
         }
     }
 }
@@ -60,6 +60,7 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Geometry,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -137,6 +138,7 @@ mod converters {
                     fields_type: fields_type.into_iter().map(|x| x.into()).collect(),
                 },
                 TableDataType::Variant => Self::Variant,
+                TableDataType::Geometry => Self::Geometry,
             }
         }
     }
@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_arrow::native;
+use common_arrow::parquet::compression::CompressionOptions;
+use common_arrow::parquet::compression::ZstdLevel;
+use common_arrow::parquet::encoding::Encoding;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnId;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Key of the table schema metadata, which records the codecs declared for the columns.
+pub const SCHEMA_META_KEY_COLUMN_CODECS: &str = "column_codecs";
+
+/// The encoding of a column declared by `CODEC(...)`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnEncoding {
+    Delta,
+    DoubleDelta,
+    Gorilla,
+    Dictionary,
+}
+
+/// The compression of a column declared by `CODEC(...)`, which overrides the table compression.
+///
+/// The level of ZSTD is only supported by the parquet storage format, see [`ColumnCodec::check_storage_format`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnCompression {
+    None,
+    Lz4,
+    Snappy,
+    Zstd(Option<i32>),
+}
+
+/// The codec of a column, i.e. the encoding and the compression used to write its data.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColumnCodec {
+    pub encoding: Option<ColumnEncoding>,
+    pub compression: Option<ColumnCompression>,
+}
+
+impl ColumnCodec {
+    pub fn is_empty(&self) -> bool {
+        self.encoding.is_none() && self.compression.is_none()
+    }
+
+    /// Checks that the encoding of the codec is applicable to `data_type`.
+    pub fn check(&self, column: &str, data_type: &TableDataType) -> Result<()> {
+        let Some(encoding) = self.encoding else {
+            return Ok(());
+        };
+        let supported = match (encoding, data_type.remove_nullable()) {
+            (ColumnEncoding::Delta | ColumnEncoding::DoubleDelta, TableDataType::Number(num)) => {
+                !num.is_float()
+            }
+            (
+                ColumnEncoding::Delta | ColumnEncoding::DoubleDelta,
+                TableDataType::Date | TableDataType::Timestamp,
+            ) => true,
+            (
+                ColumnEncoding::Gorilla,
+                TableDataType::Number(NumberDataType::Float32 | NumberDataType::Float64),
+            ) => true,
+            (
+                ColumnEncoding::Dictionary,
+                TableDataType::Number(_)
+                | TableDataType::String
+                | TableDataType::Date
+                | TableDataType::Timestamp,
+            ) => true,
+            _ => false,
+        };
+        if !supported {
+            return Err(ErrorCode::BadArguments(format!(
+                "codec {} is not applicable to column '{}' of type {}",
+                self,
+                column,
+                data_type.remove_nullable()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the codec can be honored by the storage format of the table.
+    ///
+    /// The native writer always compresses ZSTD with the default level, and parquet has
+    /// neither a gorilla encoding nor dictionary pages for the arrays we write.
+    pub fn check_storage_format(&self, column: &str, is_native: bool) -> Result<()> {
+        let unsupported = if is_native {
+            matches!(self.compression, Some(ColumnCompression::Zstd(Some(_))))
+        } else {
+            matches!(
+                self.encoding,
+                Some(ColumnEncoding::Gorilla | ColumnEncoding::Dictionary)
+            )
+        };
+        if unsupported {
+            return Err(ErrorCode::BadArguments(format!(
+                "codec {} of column '{}' is not supported by the {} storage format",
+                self,
+                column,
+                if is_native { "native" } else { "parquet" }
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks the codecs declared in `schema` against the storage format of the table.
+    pub fn check_storage_format_all(schema: &TableSchema, is_native: bool) -> Result<()> {
+        let codecs = Self::load_fields(schema)?;
+        for (field, codec) in schema.fields().iter().zip(codecs) {
+            if let Some(codec) = codec {
+                codec.check_storage_format(field.name(), is_native)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the native write options of the column, based on the table level `options`.
+    ///
+    /// The declared encoding is the only extended encoding the writer may choose, it is
+    /// used whenever it compresses the data at all.
+    pub fn to_native_options(
+        &self,
+        options: &native::write::WriteOptions,
+    ) -> native::write::WriteOptions {
+        let mut options = options.clone();
+        if let Some(compression) = self.compression {
+            options.default_compression = match compression {
+                ColumnCompression::None => native::CommonCompression::None,
+                ColumnCompression::Lz4 => native::CommonCompression::Lz4,
+                ColumnCompression::Snappy => native::CommonCompression::Snappy,
+                ColumnCompression::Zstd(_) => native::CommonCompression::Zstd,
+            };
+        }
+        if let Some(encoding) = self.encoding {
+            let allowed = match encoding {
+                ColumnEncoding::Delta => native::Compression::DeltaBitpacking,
+                ColumnEncoding::DoubleDelta => native::Compression::DoubleDelta,
+                ColumnEncoding::Gorilla => native::Compression::Patas,
+                ColumnEncoding::Dictionary => native::Compression::Dict,
+            };
+            options.default_compress_ratio = Some(0.0);
+            options.forbidden_compressions = [
+                native::Compression::Rle,
+                native::Compression::Dict,
+                native::Compression::OneValue,
+                native::Compression::Freq,
+                native::Compression::Bitpacking,
+                native::Compression::DeltaBitpacking,
+                native::Compression::Patas,
+                native::Compression::DoubleDelta,
+            ]
+            .into_iter()
+            .filter(|c| *c != allowed)
+            .collect();
+        }
+        options
+    }
+
+    /// Returns the parquet compression of the column, `default` if not declared.
+    pub fn parquet_compression(&self, default: CompressionOptions) -> CompressionOptions {
+        match self.compression {
+            None => default,
+            Some(ColumnCompression::None) => CompressionOptions::Uncompressed,
+            Some(ColumnCompression::Lz4) => CompressionOptions::Lz4Raw,
+            Some(ColumnCompression::Snappy) => CompressionOptions::Snappy,
+            Some(ColumnCompression::Zstd(level)) => {
+                CompressionOptions::Zstd(level.and_then(|l| ZstdLevel::try_new(l).ok()))
+            }
+        }
+    }
+
+    /// Returns the parquet encoding of the column, if the declared encoding has one.
+    ///
+    /// Parquet has no double delta encoding, but `DELTA_BINARY_PACKED` subtracts the minimum
+    /// delta of each miniblock, which packs the values increasing by a constant step the same.
+    /// Gorilla and dictionary encodings are rejected for parquet tables when the codec
+    /// is declared, see [`ColumnCodec::check_storage_format`].
+    pub fn parquet_encoding(&self) -> Option<Encoding> {
+        match self.encoding {
+            Some(ColumnEncoding::Delta | ColumnEncoding::DoubleDelta) => {
+                Some(Encoding::DeltaBinaryPacked)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the codecs declared in `schema`, by column id.
+    pub fn load_all(schema: &TableSchema) -> Result<BTreeMap<ColumnId, ColumnCodec>> {
+        match schema.metadata.get(SCHEMA_META_KEY_COLUMN_CODECS) {
+            None => Ok(BTreeMap::new()),
+            Some(value) => serde_json::from_str(value)
+                .map_err(|e| ErrorCode::Internal(format!("invalid column codecs '{value}': {e}"))),
+        }
+    }
+
+    /// Returns the codec of each field of `schema`, by field index.
+    pub fn load_fields(schema: &TableSchema) -> Result<Vec<Option<ColumnCodec>>> {
+        let codecs = Self::load_all(schema)?;
+        Ok(schema
+            .fields()
+            .iter()
+            .map(|f| codecs.get(&f.column_id()).copied())
+            .collect())
+    }
+
+    /// Sets (or removes if `codec` is empty) the codec of `column_id` in `schema`.
+    pub fn set(schema: &mut TableSchema, column_id: ColumnId, codec: ColumnCodec) -> Result<()> {
+        let mut codecs = Self::load_all(schema)?;
+        if codec.is_empty() {
+            codecs.remove(&column_id);
+        } else {
+            codecs.insert(column_id, codec);
+        }
+        Self::store_all(schema, &codecs)
+    }
+
+    /// Moves the codecs of `from` to the fields at the same positions of `to`.
+    ///
+    /// Used when a schema is rebuilt and the column ids are reassigned.
+    pub fn remap(from: &TableSchema, to: &mut TableSchema) -> Result<()> {
+        let codecs = Self::load_fields(from)?;
+        let codecs = to
+            .fields()
+            .iter()
+            .zip(codecs)
+            .filter_map(|(f, codec)| codec.map(|c| (f.column_id(), c)))
+            .collect();
+        Self::store_all(to, &codecs)
+    }
+
+    fn store_all(schema: &mut TableSchema, codecs: &BTreeMap<ColumnId, ColumnCodec>) -> Result<()> {
+        if codecs.is_empty() {
+            schema.metadata.remove(SCHEMA_META_KEY_COLUMN_CODECS);
+            return Ok(());
+        }
+        let value = serde_json::to_string(codecs)
+            .map_err(|e| ErrorCode::Internal(format!("failed to encode the column codecs: {e}")))?;
+        schema
+            .metadata
+            .insert(SCHEMA_META_KEY_COLUMN_CODECS.to_string(), value);
+        Ok(())
+    }
+}
+
+impl Display for ColumnCodec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut items = vec![];
+        match self.encoding {
+            Some(ColumnEncoding::Delta) => items.push("DELTA".to_string()),
+            Some(ColumnEncoding::DoubleDelta) => items.push("DOUBLEDELTA".to_string()),
+            Some(ColumnEncoding::Gorilla) => items.push("GORILLA".to_string()),
+            Some(ColumnEncoding::Dictionary) => items.push("DICTIONARY".to_string()),
+            None => {}
+        }
+        match self.compression {
+            Some(ColumnCompression::None) => items.push("NONE".to_string()),
+            Some(ColumnCompression::Lz4) => items.push("LZ4".to_string()),
+            Some(ColumnCompression::Snappy) => items.push("SNAPPY".to_string()),
+            Some(ColumnCompression::Zstd(None)) => items.push("ZSTD".to_string()),
+            Some(ColumnCompression::Zstd(Some(level))) => items.push(format!("ZSTD({level})")),
+            None => {}
+        }
+        write!(f, "{}", items.join(", "))
+    }
+}
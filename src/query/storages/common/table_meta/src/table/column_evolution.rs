@@ -0,0 +1,274 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalDataType;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnId;
+use common_expression::FieldIndex;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ColumnCodec;
+use crate::meta::ColumnStatistics;
+use crate::meta::StatisticsOfColumns;
+
+/// Key of the table schema metadata, which records the columns whose data type
+/// has been changed without rewriting the existing blocks.
+pub const SCHEMA_META_KEY_EVOLVED_COLUMNS: &str = "evolved_columns";
+
+/// The column a lazily evolved column was evolved from.
+///
+/// Blocks written before the evolution keep the data of the column under `column_id`,
+/// in the type of `data_type`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EvolvedColumn {
+    pub column_id: ColumnId,
+    pub data_type: TableDataType,
+}
+
+/// Column id mapping of the lazily evolved columns of a table schema.
+///
+/// A compatible type change (e.g. INT32 to INT64) assigns a new column id to the field and
+/// records the previous column id and type, instead of rewriting the table. Readers look up the
+/// previous column ids for blocks which do not contain the new one, and cast the data (and
+/// the statistics) read to the current type.
+#[derive(Clone, Debug, Default)]
+pub struct ColumnEvolution {
+    // current column id -> the column it was evolved from
+    sources: BTreeMap<ColumnId, EvolvedColumn>,
+    // current column id -> current type, of the evolved fields of the schema
+    targets: HashMap<ColumnId, TableDataType>,
+}
+
+impl ColumnEvolution {
+    pub fn from_schema(schema: &TableSchema) -> Result<Self> {
+        let sources = Self::load_sources(schema)?;
+        let targets = schema
+            .fields()
+            .iter()
+            .filter(|f| sources.contains_key(&f.column_id()))
+            .map(|f| (f.column_id(), f.data_type().clone()))
+            .collect();
+        Ok(Self { sources, targets })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Returns the columns `column_id` was evolved from, the latest one first.
+    pub fn sources_of(&self, column_id: ColumnId) -> Vec<&EvolvedColumn> {
+        let mut sources = vec![];
+        let mut current = column_id;
+        while let Some(source) = self.sources.get(&current) {
+            sources.push(source);
+            current = source.column_id;
+        }
+        sources
+    }
+
+    /// Whether `column_id` is a column that some column was evolved from.
+    pub fn is_source(&self, column_id: ColumnId) -> bool {
+        self.sources.values().any(|s| s.column_id == column_id)
+    }
+
+    /// Returns the statistics of `column_id` in the current type.
+    ///
+    /// If there are no statistics of `column_id`, the statistics of the column it was evolved
+    /// from are converted on the fly.
+    pub fn column_statistics<'a>(
+        &self,
+        stats: &'a StatisticsOfColumns,
+        column_id: ColumnId,
+    ) -> Option<Cow<'a, ColumnStatistics>> {
+        if let Some(stat) = stats.get(&column_id) {
+            return Some(Cow::Borrowed(stat));
+        }
+        let data_type = self.targets.get(&column_id)?;
+        self.sources_of(column_id)
+            .into_iter()
+            .find_map(|source| stats.get(&source.column_id))
+            .and_then(|stat| {
+                Some(Cow::Owned(ColumnStatistics {
+                    min: widen_scalar(stat.min(), data_type)?,
+                    max: widen_scalar(stat.max(), data_type)?,
+                    null_count: stat.null_count,
+                    in_memory_size: stat.in_memory_size,
+                    distinct_of_values: stat.distinct_of_values,
+                }))
+            })
+    }
+
+    /// Change the type of the field at `index` to `data_type`, without rewriting existing data.
+    ///
+    /// The field is assigned a new column id, and the previous column id and type are recorded
+    /// in the schema metadata. The caller should make sure the change [is_lazy_evolvable].
+    pub fn evolve(
+        schema: &mut TableSchema,
+        index: FieldIndex,
+        data_type: TableDataType,
+    ) -> Result<()> {
+        let mut sources = Self::load_sources(schema)?;
+        let field = &mut schema.fields[index];
+        let previous_column_id = field.column_id();
+        let column_id = schema.next_column_id;
+        sources.insert(column_id, EvolvedColumn {
+            column_id: previous_column_id,
+            data_type: field.data_type().clone(),
+        });
+        field.column_id = column_id;
+        field.data_type = data_type;
+        schema.next_column_id += 1;
+
+        // the codec declared for the column follows it to the new column id
+        if let Some(codec) = ColumnCodec::load_all(schema)?
+            .get(&previous_column_id)
+            .copied()
+        {
+            ColumnCodec::set(schema, previous_column_id, ColumnCodec::default())?;
+            ColumnCodec::set(schema, column_id, codec)?;
+        }
+
+        let value = serde_json::to_string(&sources).map_err(|e| {
+            ErrorCode::Internal(format!("failed to encode the evolved columns: {e}"))
+        })?;
+        schema
+            .metadata
+            .insert(SCHEMA_META_KEY_EVOLVED_COLUMNS.to_string(), value);
+        Ok(())
+    }
+
+    fn load_sources(schema: &TableSchema) -> Result<BTreeMap<ColumnId, EvolvedColumn>> {
+        match schema.metadata.get(SCHEMA_META_KEY_EVOLVED_COLUMNS) {
+            None => Ok(BTreeMap::new()),
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::Internal(format!("invalid evolved columns '{value}': {e}"))
+            }),
+        }
+    }
+}
+
+/// Whether the data of type `from` can be read as type `to` losslessly, so that the type
+/// change needs not rewrite the existing data.
+///
+/// Only non-nested types are supported: widening of numbers (e.g. INT32 to INT64, FLOAT to
+/// DOUBLE), increasing the precision of decimals (with the same scale), and making a column
+/// nullable.
+pub fn is_lazy_evolvable(from: &TableDataType, to: &TableDataType) -> bool {
+    match (from, to) {
+        (TableDataType::Nullable(from), TableDataType::Nullable(to)) => {
+            from != to && is_lazy_evolvable_inner(from, to)
+        }
+        (from, TableDataType::Nullable(to)) => {
+            from == to.as_ref() || is_lazy_evolvable_inner(from, to)
+        }
+        (from, to) => is_lazy_evolvable_inner(from, to),
+    }
+}
+
+fn is_lazy_evolvable_inner(from: &TableDataType, to: &TableDataType) -> bool {
+    match (from, to) {
+        (TableDataType::Number(from), TableDataType::Number(to)) => {
+            from != to && from.can_lossless_cast_to(*to)
+        }
+        (
+            TableDataType::Decimal(DecimalDataType::Decimal128(from)),
+            TableDataType::Decimal(DecimalDataType::Decimal128(to)),
+        )
+        | (
+            TableDataType::Decimal(DecimalDataType::Decimal256(from)),
+            TableDataType::Decimal(DecimalDataType::Decimal256(to)),
+        ) => from.scale == to.scale && from.precision < to.precision,
+        _ => false,
+    }
+}
+
+/// Converts `scalar` of a column to the type `to` the column was lazily evolved to.
+///
+/// Returns None if the conversion is not a widening one, or the value does not fit `to`.
+pub fn widen_scalar(scalar: &Scalar, to: &TableDataType) -> Option<Scalar> {
+    match (scalar, to.remove_nullable()) {
+        (Scalar::Null, _) => Some(Scalar::Null),
+        (Scalar::Number(num), TableDataType::Number(to)) => {
+            widen_number(num, to).map(Scalar::Number)
+        }
+        (
+            Scalar::Decimal(DecimalScalar::Decimal128(v, _)),
+            TableDataType::Decimal(DecimalDataType::Decimal128(size)),
+        ) => Some(Scalar::Decimal(DecimalScalar::Decimal128(*v, size))),
+        (
+            Scalar::Decimal(DecimalScalar::Decimal256(v, _)),
+            TableDataType::Decimal(DecimalDataType::Decimal256(size)),
+        ) => Some(Scalar::Decimal(DecimalScalar::Decimal256(*v, size))),
+        _ => None,
+    }
+}
+
+/// Converts the number `num` to the number type `to`, see [widen_scalar].
+pub fn widen_number(num: &NumberScalar, to: NumberDataType) -> Option<NumberScalar> {
+    if to.is_float() {
+        let v = match num {
+            NumberScalar::UInt8(v) => *v as f64,
+            NumberScalar::UInt16(v) => *v as f64,
+            NumberScalar::UInt32(v) => *v as f64,
+            NumberScalar::UInt64(v) => *v as f64,
+            NumberScalar::Int8(v) => *v as f64,
+            NumberScalar::Int16(v) => *v as f64,
+            NumberScalar::Int32(v) => *v as f64,
+            NumberScalar::Int64(v) => *v as f64,
+            NumberScalar::Float32(v) => v.0 as f64,
+            NumberScalar::Float64(v) => v.0,
+        };
+        return match to {
+            NumberDataType::Float32 => Some(NumberScalar::Float32(F32::from(v as f32))),
+            _ => Some(NumberScalar::Float64(F64::from(v))),
+        };
+    }
+
+    let v = match num {
+        NumberScalar::UInt8(v) => *v as i128,
+        NumberScalar::UInt16(v) => *v as i128,
+        NumberScalar::UInt32(v) => *v as i128,
+        NumberScalar::UInt64(v) => *v as i128,
+        NumberScalar::Int8(v) => *v as i128,
+        NumberScalar::Int16(v) => *v as i128,
+        NumberScalar::Int32(v) => *v as i128,
+        NumberScalar::Int64(v) => *v as i128,
+        NumberScalar::Float32(_) | NumberScalar::Float64(_) => return None,
+    };
+    Some(match to {
+        NumberDataType::UInt8 => NumberScalar::UInt8(v.try_into().ok()?),
+        NumberDataType::UInt16 => NumberScalar::UInt16(v.try_into().ok()?),
+        NumberDataType::UInt32 => NumberScalar::UInt32(v.try_into().ok()?),
+        NumberDataType::UInt64 => NumberScalar::UInt64(v.try_into().ok()?),
+        NumberDataType::Int8 => NumberScalar::Int8(v.try_into().ok()?),
+        NumberDataType::Int16 => NumberScalar::Int16(v.try_into().ok()?),
+        NumberDataType::Int32 => NumberScalar::Int32(v.try_into().ok()?),
+        NumberDataType::Int64 => NumberScalar::Int64(v.try_into().ok()?),
+        NumberDataType::Float32 | NumberDataType::Float64 => return None,
+    })
+}
@@ -12,10 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_codec;
+mod column_evolution;
 mod table_compression;
+mod table_constraints;
 mod table_keys;
 mod table_prefix;
 
+pub use column_codec::ColumnCodec;
+pub use column_codec::ColumnCompression;
+pub use column_codec::ColumnEncoding;
+pub use column_codec::SCHEMA_META_KEY_COLUMN_CODECS;
+pub use column_evolution::is_lazy_evolvable;
+pub use column_evolution::widen_number;
+pub use column_evolution::widen_scalar;
+pub use column_evolution::ColumnEvolution;
+pub use column_evolution::EvolvedColumn;
+pub use column_evolution::SCHEMA_META_KEY_EVOLVED_COLUMNS;
 pub use table_compression::TableCompression;
+pub use table_constraints::UniqueConstraint;
 pub use table_keys::*;
 pub use table_prefix::*;
@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::TableSchema;
+
+use crate::table::OPT_KEY_PRIMARY_KEY;
+use crate::table::OPT_KEY_UNIQUE_KEYS;
+
+/// A `PRIMARY KEY` or `UNIQUE` constraint of a table.
+///
+/// The key columns are referred by column id, so that the constraint keeps valid
+/// after the key columns are renamed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UniqueConstraint {
+    pub is_primary_key: bool,
+    pub column_ids: Vec<ColumnId>,
+}
+
+impl UniqueConstraint {
+    /// Loads the constraints of a table from its options, the primary key comes first.
+    pub fn from_table_options(options: &BTreeMap<String, String>) -> Result<Vec<Self>> {
+        let mut constraints = vec![];
+        if let Some(value) = options.get(OPT_KEY_PRIMARY_KEY) {
+            constraints.push(Self {
+                is_primary_key: true,
+                column_ids: decode_column_ids(value)?,
+            });
+        }
+        if let Some(value) = options.get(OPT_KEY_UNIQUE_KEYS) {
+            for key in value.split(';').filter(|key| !key.is_empty()) {
+                constraints.push(Self {
+                    is_primary_key: false,
+                    column_ids: decode_column_ids(key)?,
+                });
+            }
+        }
+        Ok(constraints)
+    }
+
+    /// Stores the constraints into the table options, replacing the existing ones.
+    pub fn to_table_options(constraints: &[Self], options: &mut BTreeMap<String, String>) {
+        options.remove(OPT_KEY_PRIMARY_KEY);
+        options.remove(OPT_KEY_UNIQUE_KEYS);
+        let mut unique_keys = vec![];
+        for constraint in constraints {
+            let ids = encode_column_ids(&constraint.column_ids);
+            if constraint.is_primary_key {
+                options.insert(OPT_KEY_PRIMARY_KEY.to_string(), ids);
+            } else {
+                unique_keys.push(ids);
+            }
+        }
+        if !unique_keys.is_empty() {
+            options.insert(OPT_KEY_UNIQUE_KEYS.to_string(), unique_keys.join(";"));
+        }
+    }
+
+    /// Names of the key columns in the given schema.
+    pub fn column_names(&self, schema: &TableSchema) -> Result<Vec<String>> {
+        self.column_ids
+            .iter()
+            .map(|id| {
+                schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.column_id() == *id)
+                    .map(|f| f.name().clone())
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "column id {id} of table constraint not found in schema"
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+fn encode_column_ids(ids: &[ColumnId]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn decode_column_ids(value: &str) -> Result<Vec<ColumnId>> {
+    value
+        .split(',')
+        .map(|id| {
+            id.trim().parse::<ColumnId>().map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid column id '{id}' in table constraint"
+                ))
+            })
+        })
+        .collect()
+}
@@ -23,12 +23,30 @@ pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+/// Expression of the expiration time of a row, e.g. `ts + INTERVAL 90 DAY`.
+///
+/// Rows whose expiration time has passed are hidden from queries, and the blocks whose rows
+/// are all expired are dropped by `OPTIMIZE TABLE ... PURGE`.
+pub const OPT_KEY_TTL: &str = "ttl";
+/// Column ids of the primary key, e.g. `1,2`.
+pub const OPT_KEY_PRIMARY_KEY: &str = "primary_key";
+/// Column ids of the unique keys, separated by `;`, e.g. `3;4,5`.
+pub const OPT_KEY_UNIQUE_KEYS: &str = "unique_keys";
+/// Partition keys of the table, separated by `, `, e.g. `to_yyyymm(ts), region`, see `PARTITION BY`.
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+/// Name of the user who created the table, the storage of the table is charged to the
+/// `max_storage_in_bytes` quota of the user.
+pub const OPT_KEY_OWNER_USER: &str = "owner_user";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
 
+/// Location of the marker which a cloned table left in the storage of its source table,
+/// the data files shared with the cloned table are protected from being purged by the marker.
+pub const OPT_KEY_CLONE_MARKER_LOCATION: &str = "clone_marker_location";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -46,6 +64,11 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONE_MARKER_LOCATION);
+    r.insert(OPT_KEY_PRIMARY_KEY);
+    r.insert(OPT_KEY_UNIQUE_KEYS);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_OWNER_USER);
     r
 });
 
@@ -54,6 +77,11 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CLONE_MARKER_LOCATION);
+    r.insert(OPT_KEY_PRIMARY_KEY);
+    r.insert(OPT_KEY_UNIQUE_KEYS);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_OWNER_USER);
     r
 });
 
@@ -0,0 +1,284 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_expression::types::decimal::DecimalDataType;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::NumberDataType;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::table::is_lazy_evolvable;
+use storages_common_table_meta::table::widen_number;
+use storages_common_table_meta::table::widen_scalar;
+use storages_common_table_meta::table::ColumnEvolution;
+
+fn number(ty: NumberDataType) -> TableDataType {
+    TableDataType::Number(ty)
+}
+
+fn nullable(ty: TableDataType) -> TableDataType {
+    TableDataType::Nullable(Box::new(ty))
+}
+
+fn decimal128(precision: u8, scale: u8) -> TableDataType {
+    TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+        precision,
+        scale,
+    }))
+}
+
+fn decimal256(precision: u8, scale: u8) -> TableDataType {
+    TableDataType::Decimal(DecimalDataType::Decimal256(DecimalSize {
+        precision,
+        scale,
+    }))
+}
+
+#[test]
+fn test_is_lazy_evolvable() {
+    use NumberDataType::*;
+
+    let cases = vec![
+        // widening numbers
+        (number(Int32), number(Int64), true),
+        (number(UInt8), number(UInt16), true),
+        (number(UInt32), number(Int64), true),
+        (number(Int16), number(Float32), true),
+        (number(Float32), number(Float64), true),
+        // narrowing or lossy numbers
+        (number(Int64), number(Int32), false),
+        (number(UInt32), number(Int32), false),
+        (number(Int8), number(UInt64), false),
+        (number(Int32), number(Float32), false),
+        (number(Float64), number(Int64), false),
+        (number(Int32), number(Int32), false),
+        // nullability
+        (number(Int32), nullable(number(Int32)), true),
+        (number(Int32), nullable(number(Int64)), true),
+        (nullable(number(Int32)), nullable(number(Int64)), true),
+        (nullable(number(Int32)), nullable(number(Int32)), false),
+        (nullable(number(Int32)), number(Int64), false),
+        // decimals
+        (decimal128(5, 2), decimal128(10, 2), true),
+        (decimal256(40, 2), decimal256(50, 2), true),
+        (decimal128(10, 2), decimal128(5, 2), false),
+        (decimal128(5, 2), decimal128(10, 3), false),
+        (decimal128(5, 2), decimal256(40, 2), false),
+        // other types
+        (TableDataType::String, TableDataType::String, false),
+        (number(Int32), TableDataType::String, false),
+        (
+            TableDataType::Array(Box::new(number(Int32))),
+            TableDataType::Array(Box::new(number(Int64))),
+            false,
+        ),
+    ];
+
+    for (from, to, expected) in cases {
+        assert_eq!(
+            is_lazy_evolvable(&from, &to),
+            expected,
+            "{:?} -> {:?}",
+            from,
+            to
+        );
+    }
+}
+
+#[test]
+fn test_widen_number() {
+    use NumberDataType::*;
+
+    let cases = vec![
+        (NumberScalar::Int8(-1), Int64, Some(NumberScalar::Int64(-1))),
+        (
+            NumberScalar::UInt32(u32::MAX),
+            Int64,
+            Some(NumberScalar::Int64(u32::MAX as i64)),
+        ),
+        (
+            NumberScalar::UInt64(u64::MAX),
+            UInt64,
+            Some(NumberScalar::UInt64(u64::MAX)),
+        ),
+        (
+            NumberScalar::Int32(3),
+            Float64,
+            Some(NumberScalar::Float64(F64::from(3.0))),
+        ),
+        (
+            NumberScalar::Int16(-3),
+            Float32,
+            Some(NumberScalar::Float32(F32::from(-3.0))),
+        ),
+        (
+            NumberScalar::Float32(F32::from(1.5)),
+            Float64,
+            Some(NumberScalar::Float64(F64::from(1.5))),
+        ),
+        // the value does not fit the target type
+        (NumberScalar::UInt8(200), Int8, None),
+        (NumberScalar::Int32(-1), UInt32, None),
+        // floats are never narrowed to integers
+        (NumberScalar::Float64(F64::from(1.0)), Int64, None),
+    ];
+
+    for (num, to, expected) in cases {
+        assert_eq!(widen_number(&num, to), expected, "{:?} -> {:?}", num, to);
+    }
+}
+
+#[test]
+fn test_widen_scalar() {
+    let size = DecimalSize {
+        precision: 10,
+        scale: 2,
+    };
+    let cases = vec![
+        (
+            Scalar::Number(NumberScalar::Int32(7)),
+            number(NumberDataType::Int64),
+            Some(Scalar::Number(NumberScalar::Int64(7))),
+        ),
+        (
+            Scalar::Number(NumberScalar::Int32(7)),
+            nullable(number(NumberDataType::Int64)),
+            Some(Scalar::Number(NumberScalar::Int64(7))),
+        ),
+        (
+            Scalar::Null,
+            nullable(number(NumberDataType::Int64)),
+            Some(Scalar::Null),
+        ),
+        (
+            Scalar::Decimal(DecimalScalar::Decimal128(125, DecimalSize {
+                precision: 5,
+                scale: 2,
+            })),
+            decimal128(10, 2),
+            Some(Scalar::Decimal(DecimalScalar::Decimal128(125, size))),
+        ),
+        (
+            Scalar::Decimal(DecimalScalar::Decimal128(125, DecimalSize {
+                precision: 5,
+                scale: 2,
+            })),
+            decimal256(40, 2),
+            None,
+        ),
+        (Scalar::String(b"a".to_vec()), TableDataType::String, None),
+    ];
+
+    for (scalar, to, expected) in cases {
+        assert_eq!(
+            widen_scalar(&scalar, &to),
+            expected,
+            "{:?} -> {:?}",
+            scalar,
+            to
+        );
+    }
+}
+
+#[test]
+fn test_column_evolution_sources() -> Result<()> {
+    let mut schema = TableSchema::new(vec![
+        TableField::new("a", number(NumberDataType::Int16)),
+        TableField::new("b", TableDataType::String),
+    ]);
+    assert!(ColumnEvolution::from_schema(&schema)?.is_empty());
+
+    // a: INT16 (0) -> INT32 (2) -> INT64 (3)
+    ColumnEvolution::evolve(&mut schema, 0, number(NumberDataType::Int32))?;
+    ColumnEvolution::evolve(&mut schema, 0, number(NumberDataType::Int64))?;
+    assert_eq!(schema.fields()[0].column_id(), 3);
+    assert_eq!(schema.fields()[1].column_id(), 1);
+    assert_eq!(schema.next_column_id, 4);
+
+    let evolution = ColumnEvolution::from_schema(&schema)?;
+    assert!(!evolution.is_empty());
+
+    let sources = evolution.sources_of(3);
+    assert_eq!(sources.len(), 2);
+    assert_eq!(sources[0].column_id, 2);
+    assert_eq!(sources[0].data_type, number(NumberDataType::Int32));
+    assert_eq!(sources[1].column_id, 0);
+    assert_eq!(sources[1].data_type, number(NumberDataType::Int16));
+
+    let sources = evolution.sources_of(2);
+    assert_eq!(sources.len(), 1);
+    assert_eq!(sources[0].column_id, 0);
+
+    assert!(evolution.sources_of(0).is_empty());
+    assert!(evolution.sources_of(1).is_empty());
+
+    assert!(evolution.is_source(0));
+    assert!(evolution.is_source(2));
+    assert!(!evolution.is_source(1));
+    assert!(!evolution.is_source(3));
+
+    Ok(())
+}
+
+#[test]
+fn test_column_evolution_statistics() -> Result<()> {
+    let mut schema = TableSchema::new(vec![TableField::new("a", number(NumberDataType::Int32))]);
+    ColumnEvolution::evolve(&mut schema, 0, number(NumberDataType::Int64))?;
+    let evolution = ColumnEvolution::from_schema(&schema)?;
+
+    let old_stat = ColumnStatistics::new(
+        Scalar::Number(NumberScalar::Int32(-5)),
+        Scalar::Number(NumberScalar::Int32(10)),
+        1,
+        100,
+        Some(3),
+    );
+
+    // the blocks written before the evolution keep the statistics of the source column
+    let stats = HashMap::from([(0, old_stat.clone())]);
+    let stat = evolution.column_statistics(&stats, 1).unwrap();
+    assert_eq!(stat.min(), &Scalar::Number(NumberScalar::Int64(-5)));
+    assert_eq!(stat.max(), &Scalar::Number(NumberScalar::Int64(10)));
+    assert_eq!(stat.null_count, 1);
+    assert_eq!(stat.in_memory_size, 100);
+    assert_eq!(stat.distinct_of_values, Some(3));
+
+    // the statistics of the current column are preferred
+    let new_stat = ColumnStatistics::new(
+        Scalar::Number(NumberScalar::Int64(20)),
+        Scalar::Number(NumberScalar::Int64(30)),
+        0,
+        80,
+        None,
+    );
+    let stats = HashMap::from([(0, old_stat), (1, new_stat.clone())]);
+    assert_eq!(
+        evolution.column_statistics(&stats, 1).unwrap().as_ref(),
+        &new_stat
+    );
+
+    // no statistics of the column or its sources
+    assert!(evolution.column_statistics(&HashMap::new(), 1).is_none());
+
+    Ok(())
+}
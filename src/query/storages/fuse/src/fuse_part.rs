@@ -22,6 +22,7 @@ use std::sync::Arc;
 
 use chrono::DateTime;
 use chrono::Utc;
+use common_arrow::arrow::bitmap::Bitmap;
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
@@ -31,6 +32,7 @@ use common_expression::Scalar;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::DeletionVector;
 use storages_common_table_meta::meta::Location;
 
 /// Fuse table partition information.
@@ -45,6 +47,7 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    pub deletion_vector: Option<DeletionVector>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -67,6 +70,7 @@ impl PartInfo for FusePartInfo {
 }
 
 impl FusePartInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         location: String,
         rows_count: u64,
@@ -75,6 +79,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector: Option<DeletionVector>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -84,6 +89,7 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            deletion_vector,
         }))
     }
 
@@ -111,6 +117,15 @@ impl FusePartInfo {
             .map(|meta| meta.page_size)
             .unwrap_or(self.nums_rows)
     }
+
+    /// Returns the bitmap of the rows within `rows` (offsets in the block) which are not
+    /// marked as deleted, or `None` if the block has no deletion vector.
+    pub fn live_rows(&self, rows: Range<usize>) -> Result<Option<Bitmap>> {
+        self.deletion_vector
+            .as_ref()
+            .map(|dv| dv.to_filter(rows))
+            .transpose()
+    }
 }
 
 /// Fuse table lazy partition information.
@@ -32,6 +32,7 @@ use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
+use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
@@ -50,6 +51,8 @@ use log::error;
 use log::warn;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::BloomIndex;
+use storages_common_index::NgramArgs;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::SnapshotId;
 use storages_common_table_meta::meta::Statistics as FuseStatistics;
@@ -75,6 +78,7 @@ use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
 use crate::table_functions::unwrap_tuple;
+use crate::FuseDeleteMode;
 use crate::FuseStorageFormat;
 use crate::NavigationPoint;
 use crate::Table;
@@ -84,6 +88,7 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_DELETE_MODE;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -380,6 +385,14 @@ impl FuseTable {
         self.table_info.meta.options.contains_key("TRANSIENT")
     }
 
+    pub fn delete_mode(&self) -> FuseDeleteMode {
+        self.table_info
+            .options()
+            .get(FUSE_OPT_KEY_DELETE_MODE)
+            .and_then(|s| s.parse::<FuseDeleteMode>().ok())
+            .unwrap_or_default()
+    }
+
     pub fn cluster_key_str(&self) -> Option<&String> {
         self.cluster_key_meta.as_ref().map(|(_, key)| key)
     }
@@ -392,6 +405,16 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    /// The n-gram bloom filters of the columns in `source_schema`, see `bloom_index_columns`.
+    pub fn ngram_args(&self, source_schema: TableSchemaRef) -> Result<Vec<NgramArgs>> {
+        Ok(self
+            .bloom_index_cols
+            .ngram_index_fields(source_schema, BloomIndex::supported_ngram_type)?
+            .into_iter()
+            .map(|(index, field, gram_size)| NgramArgs::new(index, field, gram_size))
+            .collect())
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -718,6 +741,7 @@ impl Table for FuseTable {
             NavigationPoint::TimePoint(time_point) => Ok(self
                 .navigate_to_time_point(snapshot_location, *time_point)
                 .await?),
+            NavigationPoint::Tag(tag) => Ok(self.navigate_to_tag(tag).await?),
         }
     }
 
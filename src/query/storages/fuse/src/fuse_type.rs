@@ -67,3 +67,29 @@ impl FromStr for FuseStorageFormat {
         }
     }
 }
+
+/// How DELETE removes rows from the blocks of a fuse table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FuseDeleteMode {
+    /// Rewrite every block that contains deleted rows.
+    #[default]
+    CopyOnWrite,
+    /// Mark deleted rows in the deletion vector of the block, readers filter them out,
+    /// and compaction materializes them later.
+    MergeOnRead,
+}
+
+impl FromStr for FuseDeleteMode {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "" | "copy_on_write" => Ok(FuseDeleteMode::CopyOnWrite),
+            "merge_on_read" => Ok(FuseDeleteMode::MergeOnRead),
+            other => Err(ErrorCode::TableOptionInvalid(format!(
+                "unknown fuse delete_mode {}, expecting copy_on_write or merge_on_read",
+                other
+            ))),
+        }
+    }
+}
@@ -30,7 +30,9 @@ use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_CLONE_MARKER_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_TAG_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -143,6 +145,37 @@ impl TableMetaLocationGenerator {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
 
+    pub fn gen_clone_marker_location(&self, clone_table_id: u64) -> String {
+        format!(
+            "{}/{}/{}",
+            &self.prefix, FUSE_TBL_CLONE_MARKER_PREFIX, clone_table_id
+        )
+    }
+
+    /// The marker of the partition replaced into table `table_id`, the data files of the
+    /// partition are shared with that table.
+    pub fn gen_partition_marker_location(&self, table_id: u64, id: &Uuid) -> String {
+        format!(
+            "{}/{}/{}_{}",
+            &self.prefix,
+            FUSE_TBL_CLONE_MARKER_PREFIX,
+            table_id,
+            id.simple()
+        )
+    }
+
+    pub fn clone_marker_prefix(&self) -> String {
+        format!("{}/{}/", &self.prefix, FUSE_TBL_CLONE_MARKER_PREFIX)
+    }
+
+    pub fn gen_tag_location(&self, tag: &str) -> String {
+        format!("{}/{}/{}", &self.prefix, FUSE_TBL_TAG_PREFIX, tag)
+    }
+
+    pub fn tag_prefix(&self) -> String {
+        format!("{}/{}/", &self.prefix, FUSE_TBL_TAG_PREFIX)
+    }
+
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
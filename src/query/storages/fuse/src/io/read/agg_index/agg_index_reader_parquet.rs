@@ -51,6 +51,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -100,6 +101,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 Some((part, res))
             }
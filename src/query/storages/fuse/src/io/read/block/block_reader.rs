@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Field;
@@ -29,11 +30,14 @@ use common_expression::DataSchema;
 use common_expression::FieldIndex;
 use common_expression::Scalar;
 use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_sql::field_default_value;
 use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
+use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::table::ColumnEvolution;
 
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
@@ -46,10 +50,20 @@ pub struct BlockReader {
     pub(crate) project_column_nodes: Vec<ColumnNode>,
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
+    // column id of projected column -> the columns it was evolved from, the latest one first
+    pub(crate) evolved_column_nodes: HashMap<ColumnId, Vec<EvolvedColumnNode>>,
     pub query_internal_columns: bool,
     pub put_cache: bool,
 }
 
+/// A column that a projected column was evolved from, which may still be kept by blocks
+/// written before the type of the column was changed. See [ColumnEvolution].
+#[derive(Clone)]
+pub(crate) struct EvolvedColumnNode {
+    pub(crate) column_node: ColumnNode,
+    pub(crate) parquet_schema_descriptor: SchemaDescriptor,
+}
+
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
     if paths.is_empty() {
         return Err(ErrorCode::BadArguments(
@@ -131,6 +145,10 @@ impl BlockReader {
             .map(|c| (*c).clone())
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
+        let evolved_column_nodes = Self::build_evolved_column_nodes(
+            &ColumnEvolution::from_schema(&schema)?,
+            &project_column_nodes,
+        )?;
 
         Ok(Arc::new(BlockReader {
             ctx,
@@ -141,6 +159,7 @@ impl BlockReader {
             project_column_nodes,
             parquet_schema_descriptor,
             default_vals,
+            evolved_column_nodes,
             query_internal_columns,
             put_cache,
         }))
@@ -168,6 +187,71 @@ impl BlockReader {
         indices
     }
 
+    /// Returns the id of the column which keeps the data of the projected column `column_id`
+    /// in the block, it may be a column that `column_id` was evolved from.
+    pub(crate) fn stored_column_id(
+        &self,
+        column_metas: &HashMap<ColumnId, ColumnMeta>,
+        column_id: ColumnId,
+    ) -> ColumnId {
+        if column_metas.contains_key(&column_id) {
+            return column_id;
+        }
+        self.evolved_column_nodes
+            .get(&column_id)
+            .and_then(|nodes| {
+                nodes
+                    .iter()
+                    .map(|node| node.column_node.leaf_column_ids[0])
+                    .find(|id| column_metas.contains_key(id))
+            })
+            .unwrap_or(column_id)
+    }
+
+    fn build_evolved_column_nodes(
+        column_evolution: &ColumnEvolution,
+        columns: &[ColumnNode],
+    ) -> Result<HashMap<ColumnId, Vec<EvolvedColumnNode>>> {
+        let mut evolved_column_nodes = HashMap::new();
+        if column_evolution.is_empty() {
+            return Ok(evolved_column_nodes);
+        }
+        for column in columns {
+            // only non-nested columns can be evolved lazily
+            if column.has_children() || column.leaf_column_ids.len() != 1 {
+                continue;
+            }
+            let column_id = column.leaf_column_ids[0];
+            let sources = column_evolution.sources_of(column_id);
+            if sources.is_empty() {
+                continue;
+            }
+
+            let mut nodes = Vec::with_capacity(sources.len());
+            for source in sources {
+                let field = TableField::new_from_column_id(
+                    &column.field.name,
+                    source.data_type.clone(),
+                    source.column_id,
+                );
+                let schema = TableSchema::new_from_column_ids(
+                    vec![field],
+                    BTreeMap::new(),
+                    source.column_id + 1,
+                );
+                let arrow_schema = schema.to_arrow();
+                let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
+                let mut column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&schema));
+                nodes.push(EvolvedColumnNode {
+                    column_node: column_nodes.column_nodes.remove(0),
+                    parquet_schema_descriptor,
+                });
+            }
+            evolved_column_nodes.insert(column_id, nodes);
+        }
+        Ok(evolved_column_nodes)
+    }
+
     pub fn query_internal_columns(&self) -> bool {
         self.query_internal_columns
     }
@@ -155,6 +155,7 @@ impl BlockReader {
                     continue;
                 }
             }
+            let column_id = &self.stored_column_id(columns_meta, *column_id);
 
             if let Some(column_meta) = columns_meta.get(column_id) {
                 let (offset, len) = column_meta.offset_length();
@@ -111,6 +111,7 @@ impl BlockReader {
                     continue;
                 }
             }
+            let column_id = &self.stored_column_id(&part.columns_meta, *column_id);
             let block_path = &part.location;
 
             if let Some(column_meta) = part.columns_meta.get(column_id) {
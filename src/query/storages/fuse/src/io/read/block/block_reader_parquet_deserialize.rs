@@ -17,6 +17,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
 use common_arrow::arrow::io::parquet::read::nested_column_iter_to_arrays;
@@ -111,7 +112,13 @@ impl BlockReader {
             parquet_schema_descriptor: &None::<SchemaDescriptor>,
         };
         for column_node in &self.project_column_nodes {
-            match self.deserialize_field(&field_deserialization_ctx, column_node)? {
+            let deserialized = match self
+                .deserialize_field(&field_deserialization_ctx, column_node)?
+            {
+                None => self.deserialize_evolved_field(&field_deserialization_ctx, column_node)?,
+                deserialized => deserialized,
+            };
+            match deserialized {
                 None => {
                     need_to_fill_default_val = true;
                     need_default_vals.push(true);
@@ -327,6 +334,53 @@ impl BlockReader {
         }
     }
 
+    /// Deserialize the data of `column` which is kept by a column that `column` was evolved
+    /// from, i.e. the block is written before the type of the column was changed, the data
+    /// is cast to the current type of the column.
+    fn deserialize_evolved_field<'a>(
+        &self,
+        deserialization_context: &FieldDeserializationContext<'a>,
+        column: &ColumnNode,
+    ) -> Result<Option<DeserializedArray<'a>>> {
+        let Some(evolved_column_nodes) = column
+            .leaf_column_ids
+            .first()
+            .and_then(|column_id| self.evolved_column_nodes.get(column_id))
+        else {
+            return Ok(None);
+        };
+        let Some(evolved) = evolved_column_nodes.iter().find(|evolved| {
+            deserialization_context
+                .column_metas
+                .contains_key(&evolved.column_node.leaf_column_ids[0])
+        }) else {
+            return Ok(None);
+        };
+
+        let parquet_schema_descriptor = Some(evolved.parquet_schema_descriptor.clone());
+        let evolved_context = FieldDeserializationContext {
+            column_metas: deserialization_context.column_metas,
+            column_chunks: deserialization_context.column_chunks,
+            num_rows: deserialization_context.num_rows,
+            compression: deserialization_context.compression,
+            uncompressed_buffer: deserialization_context.uncompressed_buffer,
+            parquet_schema_descriptor: &parquet_schema_descriptor,
+        };
+        let array = match self.deserialize_field(&evolved_context, &evolved.column_node)? {
+            None => return Ok(None),
+            Some(DeserializedArray::Cached(sized_column)) => sized_column.0.to_boxed(),
+            Some(DeserializedArray::Deserialized((_, array, _))) => array,
+            Some(DeserializedArray::NoNeedToCache(array)) => array,
+        };
+        let array = cast::cast(
+            array.as_ref(),
+            column.field.data_type(),
+            cast::CastOptions::default(),
+        )?;
+        // the array cache is keyed by column id, the cast array should not be cached
+        Ok(Some(DeserializedArray::NoNeedToCache(array)))
+    }
+
     fn to_parquet_compression(meta_compression: &Compression) -> Result<ParquetCompression> {
         match meta_compression {
             Compression::Lz4 => {
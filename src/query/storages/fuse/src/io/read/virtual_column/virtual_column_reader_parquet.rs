@@ -98,6 +98,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result =
@@ -139,6 +140,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result = BlockReader::merge_io_read(
@@ -31,10 +31,12 @@ use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::NgramArgs;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::table::ColumnCodec;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::io::write::WriteSettings;
@@ -42,6 +44,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
 use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::PartitionGenerator;
 use crate::FuseStorageFormat;
 
 // TODO rename this, it is serialization, or pass in a writer(if not rename)
@@ -68,16 +71,20 @@ pub fn serialize_block(
                 default_compress_ratio = Some(3.72f64);
             }
 
-            let mut writer = NativeWriter::new(
-                buf,
-                arrow_schema,
-                common_arrow::native::write::WriteOptions {
-                    default_compression: write_settings.table_compression.into(),
-                    max_page_size: Some(write_settings.max_page_size),
-                    default_compress_ratio,
-                    forbidden_compressions: vec![],
-                },
-            );
+            let options = common_arrow::native::write::WriteOptions {
+                default_compression: write_settings.table_compression.into(),
+                max_page_size: Some(write_settings.max_page_size),
+                default_compress_ratio,
+                // double delta is only used for the columns declared with it, see `CODEC`.
+                forbidden_compressions: vec![common_arrow::native::Compression::DoubleDelta],
+            };
+            let codecs = ColumnCodec::load_fields(schema)?;
+            let mut writer = NativeWriter::new(buf, arrow_schema, options.clone());
+            for (idx, codec) in codecs.iter().enumerate() {
+                if let Some(codec) = codec {
+                    writer.set_column_options(idx, codec.to_native_options(&options));
+                }
+            }
 
             let batch = ArrowChunk::try_from(block)?;
 
@@ -118,6 +125,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_args: &[NgramArgs],
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -125,6 +133,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            ngram_args,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -165,6 +174,8 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_args: Vec<NgramArgs>,
+    pub partition_gen: PartitionGenerator,
 }
 
 impl BlockBuilder {
@@ -180,11 +191,13 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            &self.ngram_args,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let partition = self.partition_gen.block_partition(&data_block)?;
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -213,6 +226,8 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            deletion_vector: None,
+            partition,
         };
 
         let serialized = BlockSerialization {
@@ -42,6 +42,7 @@ pub use fuse_column::FuseTableColumnStatisticsProvider;
 pub use fuse_part::FuseLazyPartInfo;
 pub use fuse_part::FusePartInfo;
 pub use fuse_table::FuseTable;
+pub use fuse_type::FuseDeleteMode;
 pub use fuse_type::FuseStorageFormat;
 pub use fuse_type::FuseTableType;
 pub use io::MergeIOReadResult;
@@ -28,6 +28,7 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::create_dummy_items;
+use common_pipeline_transforms::processors::AccumulatingTransformer;
 use common_pipeline_transforms::processors::BlockCompactor;
 use common_pipeline_transforms::processors::BlockCompactorForCopy;
 use common_pipeline_transforms::processors::TransformCompact;
@@ -35,6 +36,7 @@ use common_pipeline_transforms::processors::TransformSortPartial;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 
+use crate::operations::common::TransformPartitionBlock;
 use crate::operations::common::TransformSerializeBlock;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -71,6 +73,17 @@ impl FuseTable {
             }
         }
 
+        let partition_gen = self.get_partition_gen(ctx.clone())?;
+        if partition_gen.is_partitioned() {
+            pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                    input,
+                    output,
+                    TransformPartitionBlock::create(partition_gen.clone(), block_thresholds),
+                )))
+            })?;
+        }
+
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, None)?;
         pipeline.add_transform(|input, output| {
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::warn;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_CLONE_MARKER_LOCATION;
+use uuid::Uuid;
+
+use super::clone::parse_clone_marker;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Fast-forward this table to the current snapshot of `branch`.
+    ///
+    /// `branch` must be a clone of this table (see [FuseTable::do_clone_from]), and this
+    /// table must not have been modified since the branch was created, or since the
+    /// last merge of the branch.
+    ///
+    /// The new snapshot of this table references the segments of the branch, no data is
+    /// copied. A clone marker is left in the storage of the branch, so that the merged
+    /// files will not be purged by the branch.
+    #[async_backtrace::framed]
+    pub async fn merge_branch(&self, ctx: &dyn TableContext, branch: &FuseTable) -> Result<()> {
+        let base_marker = self
+            .meta_location_generator
+            .gen_clone_marker_location(branch.get_id());
+        let is_branch = branch
+            .table_info
+            .options()
+            .get(OPT_KEY_CLONE_MARKER_LOCATION)
+            .is_some_and(|markers| markers.split(',').any(|marker| marker == base_marker));
+        if !is_branch {
+            return Err(ErrorCode::TableCannotFastForward(format!(
+                "table {} is not a branch of table {}",
+                branch.table_info.desc, self.table_info.desc
+            )));
+        }
+
+        // the snapshot which the branch is based on
+        let (base_location, _) = parse_clone_marker(self.operator.read(&base_marker).await?)?;
+        if self.snapshot_loc().await?.as_ref() != Some(&base_location) {
+            return Err(ErrorCode::TableCannotFastForward(format!(
+                "table {} has been modified since the branch {} was created or merged",
+                self.table_info.desc, branch.table_info.desc
+            )));
+        }
+
+        let (Some(branch_location), Some(branch_snapshot)) = (
+            branch.snapshot_loc().await?,
+            branch.read_table_snapshot().await?,
+        ) else {
+            return Ok(());
+        };
+        if branch_snapshot.schema != *self.schema() {
+            return Err(ErrorCode::TableCannotFastForward(format!(
+                "the schema of branch {} is different from the schema of table {}",
+                branch.table_info.desc, self.table_info.desc
+            )));
+        }
+        let Some(prev) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        // 1. leave a marker in the storage of the branch before the commit, the files of the
+        // branch are shared once the snapshot is committed. The marker of the previous merge of
+        // the branch is restored if the commit fails.
+        let branch_marker = branch
+            .meta_location_generator
+            .gen_clone_marker_location(self.get_id());
+        let prev_branch_location = match self.operator.read(&branch_marker).await {
+            Ok(location) => Some(location),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        self.operator.write(&branch_marker, branch_location).await?;
+
+        // 2. commit the snapshot which references the segments of the branch
+        let snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev.timestamp,
+            Some((prev.snapshot_id, prev.format_version)),
+            branch_snapshot.schema.clone(),
+            branch_snapshot.summary.clone(),
+            branch_snapshot.segments.clone(),
+            self.cluster_key_meta.clone(),
+            None,
+        );
+        let snapshot_location = self
+            .meta_location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
+
+        let mut table_info = self.table_info.clone();
+        let markers = match table_info.meta.options.get(OPT_KEY_CLONE_MARKER_LOCATION) {
+            Some(markers) if markers.split(',').any(|marker| marker == branch_marker) => {
+                markers.clone()
+            }
+            Some(markers) => format!("{},{}", markers, branch_marker),
+            None => branch_marker.clone(),
+        };
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_CLONE_MARKER_LOCATION.to_owned(), markers);
+
+        if let Err(e) = FuseTable::commit_to_meta_server(
+            ctx,
+            &table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+        {
+            let restored = match prev_branch_location {
+                Some(location) => self.operator.write(&branch_marker, location).await,
+                None => self.operator.delete(&branch_marker).await,
+            };
+            if let Err(err) = restored {
+                warn!("failed to restore branch marker {}: {}", branch_marker, err);
+            }
+            return Err(e);
+        }
+
+        // 3. the branch is based on the merged snapshot now
+        self.operator.write(&base_marker, snapshot_location).await?;
+        Ok(())
+    }
+}
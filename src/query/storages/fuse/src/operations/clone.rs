@@ -0,0 +1,307 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::info;
+use log::warn;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_CLONE_MARKER_LOCATION;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_TBL_CLONE_MARKER_PREFIX;
+
+impl FuseTable {
+    /// Make this (newly created) table a zero-copy clone of the current snapshot of `source`.
+    ///
+    /// A new snapshot which references the segments of the source snapshot is committed,
+    /// no data is copied. A marker, which keeps the location of the source snapshot, is left
+    /// in the storage of the source table, so that the shared files will not be purged
+    /// by the source table. See [FuseTable::get_protected_locations].
+    #[async_backtrace::framed]
+    pub async fn do_clone_from(&self, ctx: &dyn TableContext, source: &FuseTable) -> Result<()> {
+        let source_snapshot_location = match source.snapshot_loc().await? {
+            Some(location) => location,
+            // nothing to share, the source table is empty
+            None => return Ok(()),
+        };
+
+        // 1. leave a marker in the storage of the source table, before the source snapshot
+        // is read, so that a purge of the source table running after this point keeps it.
+        let marker_location = source
+            .meta_location_generator
+            .gen_clone_marker_location(self.get_id());
+        self.operator
+            .write(&marker_location, source_snapshot_location.clone())
+            .await?;
+
+        let res = self
+            .commit_clone(ctx, source, &source_snapshot_location, &marker_location)
+            .await;
+        if res.is_err() {
+            if let Err(e) = self.operator.delete(&marker_location).await {
+                warn!("failed to remove clone marker {}: {}", marker_location, e);
+            }
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn commit_clone(
+        &self,
+        ctx: &dyn TableContext,
+        source: &FuseTable,
+        source_snapshot_location: &str,
+        marker_location: &str,
+    ) -> Result<()> {
+        // 2. re-validate the source snapshot, it may have been purged before the marker
+        // was written.
+        if !source.operator.is_exist(source_snapshot_location).await? {
+            return Err(ErrorCode::StorageNotFound(format!(
+                "snapshot {} of table {} has been purged, please retry",
+                source_snapshot_location, source.table_info.desc
+            )));
+        }
+        let source_snapshot = match source.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        // 3. commit the snapshot which shares the segments of the source snapshot
+        let snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            source_snapshot.schema.clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            self.cluster_key_meta.clone(),
+            // the table statistics are not shared, they can be re-generated by `ANALYZE TABLE`
+            None,
+        );
+        let mut table_info = self.table_info.clone();
+        table_info.meta.options.insert(
+            OPT_KEY_CLONE_MARKER_LOCATION.to_owned(),
+            marker_location.to_string(),
+        );
+
+        FuseTable::commit_to_meta_server(
+            ctx,
+            &table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
+    /// Returns the files of this table that must not be purged.
+    ///
+    /// That is, the snapshots recorded by the active clone markers and the tags, and the
+    /// segments, blocks and bloom indexes referenced by them.
+    #[async_backtrace::framed]
+    pub async fn get_protected_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<HashSet<String>> {
+        let mut shared = HashSet::new();
+        let mut snapshot_locations = self.active_clone_markers().await?;
+        for tag in SnapshotsIO::list_files(
+            self.get_operator(),
+            &self.meta_location_generator.tag_prefix(),
+            None,
+        )
+        .await?
+        {
+            let location = String::from_utf8(self.operator.read(&tag).await?)?;
+            snapshot_locations.push((tag, location));
+        }
+        if snapshot_locations.is_empty() {
+            return Ok(shared);
+        }
+
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        for (marker, snapshot_location) in snapshot_locations {
+            let params = LoadParams {
+                location: snapshot_location.clone(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(&snapshot_location),
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&params).await {
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    warn!(
+                        "snapshot {} of marker {} not found. table: {}, ident {}",
+                        snapshot_location, marker, self.table_info.desc, self.table_info.ident,
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+
+            let locations = self
+                .get_block_locations(ctx.clone(), &snapshot.segments, false, true)
+                .await?;
+            shared.extend(locations.block_location);
+            shared.extend(locations.bloom_location);
+            shared.extend(snapshot.segments.iter().map(|(loc, _)| loc.clone()));
+            shared.extend(snapshot.table_statistics_location.clone());
+            shared.insert(snapshot_location);
+        }
+        Ok(shared)
+    }
+
+    /// Returns the clone markers left in the storage of this table which have not been
+    /// released, with the snapshot locations recorded by them.
+    #[async_backtrace::framed]
+    pub async fn active_clone_markers(&self) -> Result<Vec<(String, String)>> {
+        let markers = SnapshotsIO::list_files(
+            self.get_operator(),
+            &self.meta_location_generator.clone_marker_prefix(),
+            None,
+        )
+        .await?;
+
+        let mut active = Vec::with_capacity(markers.len());
+        for marker in markers {
+            let content = match self.operator.read(&marker).await {
+                Ok(content) => content,
+                // concurrent drop of the cloned table
+                Err(e) if e.kind() == opendal::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let (location, is_active) = parse_clone_marker(content)?;
+            if is_active {
+                active.push((marker, location));
+            }
+        }
+        Ok(active)
+    }
+
+    /// Releases the clone markers left by this table in the storage of its source tables,
+    /// once the snapshots of this table no longer reference any file of the source tables.
+    ///
+    /// It is called at the end of the purge of this table, the retained snapshots are the
+    /// only ones left. A released marker keeps the location of the source snapshot, which
+    /// is still needed by [FuseTable::merge_branch], but it no longer protects the files of
+    /// the source snapshot from the purge of the source table.
+    ///
+    /// The markers written after `retained_since`, the timestamp of the oldest retained
+    /// snapshot, are skipped: they may belong to a mutation which has not been committed yet.
+    #[async_backtrace::framed]
+    pub async fn release_clone_markers(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        retained_since: DateTime<Utc>,
+    ) -> Result<()> {
+        let Some(markers) = self.table_info.options().get(OPT_KEY_CLONE_MARKER_LOCATION) else {
+            return Ok(());
+        };
+        let pattern = format!("/{}/", FUSE_TBL_CLONE_MARKER_PREFIX);
+        let markers = markers
+            .split(',')
+            .filter_map(|marker| {
+                marker
+                    .split_once(&pattern)
+                    .map(|(source_prefix, _)| (marker, format!("{source_prefix}/")))
+            })
+            .collect::<Vec<_>>();
+        if markers.is_empty() {
+            return Ok(());
+        }
+
+        // the files referenced by the retained snapshots of this table
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let mut segments = HashSet::new();
+        for snapshot_location in self.list_snapshot_files().await? {
+            let params = LoadParams {
+                location: snapshot_location.clone(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(&snapshot_location),
+                put_cache: false,
+            };
+            match reader.read(&params).await {
+                // concurrent gc: someone else has already collected this snapshot, ignore it
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => continue,
+                Err(e) => return Err(e),
+                Ok(snapshot) => segments.extend(snapshot.segments.iter().cloned()),
+            }
+        }
+        let segments = segments.into_iter().collect::<Vec<_>>();
+        let blocks = self
+            .get_block_locations(ctx.clone(), &segments, false, true)
+            .await?
+            .block_location;
+
+        for (marker, source_prefix) in markers {
+            let is_referenced = segments
+                .iter()
+                .map(|(location, _)| location)
+                .chain(blocks.iter())
+                .any(|location| location.starts_with(&source_prefix));
+            if is_referenced {
+                continue;
+            }
+
+            let last_modified = match self.operator.stat(marker).await {
+                Ok(meta) => meta.last_modified(),
+                Err(e) if e.kind() == opendal::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            if !last_modified.is_some_and(|modified| modified < retained_since) {
+                continue;
+            }
+            let (location, is_active) = parse_clone_marker(self.operator.read(marker).await?)?;
+            if is_active {
+                info!(
+                    "release clone marker {}, table {} no longer references snapshot {}",
+                    marker, self.table_info.desc, location
+                );
+                self.operator
+                    .write(marker, format!("{RELEASED_CLONE_MARKER_PREFIX}{location}"))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The content of a released clone marker, followed by the location of the source snapshot.
+/// See [FuseTable::release_clone_markers].
+const RELEASED_CLONE_MARKER_PREFIX: &str = "released:";
+
+/// Parses the content of a clone marker, returns the location of the source snapshot and
+/// whether the files of the source snapshot are still protected by the marker.
+pub fn parse_clone_marker(content: Vec<u8>) -> Result<(String, bool)> {
+    let content = String::from_utf8(content)?;
+    Ok(match content.strip_prefix(RELEASED_CLONE_MARKER_PREFIX) {
+        Some(location) => (location.to_string(), false),
+        None => (content, true),
+    })
+}
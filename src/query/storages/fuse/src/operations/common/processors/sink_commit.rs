@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
@@ -95,6 +96,8 @@ pub struct CommitSink<F: SnapshotGenerator> {
     need_lock: bool,
     start_time: Instant,
     prev_snapshot_id: Option<SnapshotId>,
+    // segments that the appended rows have been checked against for the unique constraints
+    unique_checked_segments: HashSet<String>,
 }
 
 impl<F> CommitSink<F>
@@ -129,6 +132,7 @@ where F: SnapshotGenerator + Send + 'static
             need_lock,
             start_time: Instant::now(),
             prev_snapshot_id,
+            unique_checked_segments: HashSet::new(),
         })))
     }
 
@@ -141,6 +145,43 @@ where F: SnapshotGenerator + Send + 'static
         FuseTable::is_error_recoverable(e, self.transient)
     }
 
+    /// Check the appended rows against the unique constraints of the table.
+    ///
+    /// On retries, only the segments committed since the last check are checked again.
+    /// If the constraints are violated, the operation is aborted.
+    async fn check_unique_constraints(
+        &mut self,
+        fuse_table: &FuseTable,
+        previous: &Option<Arc<TableSnapshot>>,
+    ) -> Result<()> {
+        let Some(base_segments) = self.snapshot_gen.unique_check_segments(previous) else {
+            return Ok(());
+        };
+        let base_segments = base_segments
+            .iter()
+            .filter(|(path, _)| !self.unique_checked_segments.contains(path))
+            .cloned()
+            .collect::<Vec<_>>();
+        let appended_segments = self
+            .abort_operation
+            .segments
+            .iter()
+            .map(|path| (path.clone(), SegmentInfo::VERSION))
+            .collect::<Vec<_>>();
+        if let Err(e) = fuse_table
+            .check_unique_constraints(self.ctx.clone(), &appended_segments, &base_segments)
+            .await
+        {
+            metrics_inc_commit_aborts();
+            let op = self.abort_operation.clone();
+            op.abort(self.ctx.clone(), self.dal.clone()).await?;
+            return Err(e);
+        }
+        self.unique_checked_segments
+            .extend(base_segments.into_iter().map(|(path, _)| path));
+        Ok(())
+    }
+
     fn read_meta(&mut self) -> Result<Event> {
         self.start_time = Instant::now();
         {
@@ -279,6 +320,8 @@ where F: SnapshotGenerator + Send + 'static
                     self.snapshot_gen
                         .fill_default_values(schema, &previous)
                         .await?;
+                    self.check_unique_constraints(&fuse_table, &previous)
+                        .await?;
 
                     self.state = State::GenerateSnapshot {
                         previous,
@@ -426,6 +469,8 @@ where F: SnapshotGenerator + Send + 'static
                 self.table = self.table.refresh(self.ctx.as_ref()).await?;
                 let fuse_table = FuseTable::try_from_table(self.table.as_ref())?.to_owned();
                 let previous = fuse_table.read_table_snapshot().await?;
+                self.check_unique_constraints(&fuse_table, &previous)
+                    .await?;
                 let cluster_key_meta = fuse_table.cluster_key_meta.clone();
                 self.state = State::GenerateSnapshot {
                     previous,
@@ -146,7 +146,11 @@ impl TableMutationAggregator {
     pub fn accumulate_log_entry(&mut self, log_entry: MutationLogEntry) {
         match log_entry {
             MutationLogEntry::ReplacedBlock { index, block_meta } => {
-                self.abort_operation.add_block(&block_meta);
+                // a block carrying a deletion vector still refers to the data of the original
+                // block (merge-on-read deletion), which must be kept if the mutation aborts.
+                if block_meta.deletion_vector.is_none() {
+                    self.abort_operation.add_block(&block_meta);
+                }
                 match self.mutations.entry(index.segment_idx) {
                     Entry::Occupied(mut v) => {
                         v.get_mut().push_replaced(index.block_idx, block_meta);
@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+use common_expression::BlockThresholds;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_pipeline_transforms::processors::AccumulatingTransform;
+
+use crate::statistics::PartitionGenerator;
+
+/// Splits the appended blocks of a partitioned table by partition.
+///
+/// The rows of each partition are buffered until they are large enough to make a block,
+/// so that none of the written blocks spans several partitions.
+pub struct TransformPartitionBlock {
+    partition_gen: PartitionGenerator,
+    thresholds: BlockThresholds,
+    buffers: BTreeMap<Vec<Scalar>, Vec<DataBlock>>,
+}
+
+impl TransformPartitionBlock {
+    pub fn create(partition_gen: PartitionGenerator, thresholds: BlockThresholds) -> Self {
+        TransformPartitionBlock {
+            partition_gen,
+            thresholds,
+            buffers: BTreeMap::new(),
+        }
+    }
+}
+
+impl AccumulatingTransform for TransformPartitionBlock {
+    const NAME: &'static str = "TransformPartitionBlock";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let mut blocks = vec![];
+        for (partition, block) in self.partition_gen.split(data)? {
+            let buffer = self.buffers.entry(partition.clone()).or_default();
+            buffer.push(block);
+
+            let (num_rows, data_size) = buffer.iter().fold((0, 0), |acc, block| {
+                (acc.0 + block.num_rows(), acc.1 + block.memory_size())
+            });
+            if self.thresholds.check_large_enough(num_rows, data_size) {
+                let buffer = self.buffers.remove(&partition).unwrap();
+                blocks.push(DataBlock::concat(&buffer)?);
+            }
+        }
+        Ok(blocks)
+    }
+
+    fn on_finish(&mut self, _output: bool) -> Result<Vec<DataBlock>> {
+        std::mem::take(&mut self.buffers)
+            .into_values()
+            .map(|buffer| DataBlock::concat(&buffer))
+            .collect()
+    }
+}
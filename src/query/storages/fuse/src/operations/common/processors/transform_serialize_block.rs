@@ -78,6 +78,8 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_args = table.ngram_args(source_schema.clone())?;
+        let partition_gen = table.get_partition_gen(ctx.clone())?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -85,6 +87,8 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_args,
+            partition_gen,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -200,6 +204,15 @@ impl Processor for TransformSerializeBlock {
                     self.output.push_data(Ok(data_block));
                     Ok(Event::NeedConsume)
                 }
+                SerializeDataMeta::MarkDeletedRows(mark_deleted_rows) => {
+                    // rows deleted in merge-on-read mode, only the block meta is replaced
+                    let data_block = Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                        index: mark_deleted_rows.index,
+                        block_meta: mark_deleted_rows.block_meta,
+                    });
+                    self.output.push_data(Ok(data_block));
+                    Ok(Event::NeedConsume)
+                }
             }
         } else if input_data.is_empty() {
             // do nothing
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -22,6 +23,7 @@ use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockThresholds;
 use common_expression::DataBlock;
+use common_expression::Scalar;
 use common_pipeline_core::processors::Event;
 use common_pipeline_core::processors::InputPort;
 use common_pipeline_core::processors::OutputPort;
@@ -47,7 +49,7 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
 enum State {
     None,
-    GenerateSegment,
+    GenerateSegment(StatisticsAccumulator),
     SerializedSegment {
         data: Vec<u8>,
         location: String,
@@ -64,7 +66,8 @@ pub struct TransformSerializeSegment {
     ctx: Arc<dyn TableContext>,
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
-    accumulator: StatisticsAccumulator,
+    // the blocks of different partitions are written into different segments
+    accumulators: BTreeMap<Option<Vec<Scalar>>, StatisticsAccumulator>,
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -92,7 +95,7 @@ impl TransformSerializeSegment {
             data_accessor: table.get_operator(),
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
-            accumulator: Default::default(),
+            accumulators: BTreeMap::new(),
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
@@ -126,7 +129,7 @@ impl Processor for TransformSerializeSegment {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment(_) | State::PreCommitSegment { .. }
         ) {
             return Ok(Event::Sync);
         }
@@ -149,9 +152,11 @@ impl Processor for TransformSerializeSegment {
         }
 
         if self.input.is_finished() {
-            if self.accumulator.summary_row_count != 0 {
-                self.state = State::GenerateSegment;
-                return Ok(Event::Sync);
+            while let Some((_, acc)) = self.accumulators.pop_first() {
+                if acc.summary_row_count != 0 {
+                    self.state = State::GenerateSegment(acc);
+                    return Ok(Event::Sync);
+                }
             }
             self.output.finish();
             self.state = State::Finished;
@@ -170,9 +175,12 @@ impl Processor for TransformSerializeSegment {
                 .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?
                 .clone();
 
-            self.accumulator.add_with_block_meta(block_meta);
-            if self.accumulator.summary_block_count >= self.block_per_seg {
-                self.state = State::GenerateSegment;
+            let partition = block_meta.partition.clone();
+            let acc = self.accumulators.entry(partition.clone()).or_default();
+            acc.add_with_block_meta(block_meta);
+            if acc.summary_block_count >= self.block_per_seg {
+                let acc = self.accumulators.remove(&partition).unwrap();
+                self.state = State::GenerateSegment(acc);
                 return Ok(Event::Sync);
             }
         }
@@ -183,8 +191,7 @@ impl Processor for TransformSerializeSegment {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::GenerateSegment => {
-                let acc = std::mem::take(&mut self.accumulator);
+            State::GenerateSegment(acc) => {
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
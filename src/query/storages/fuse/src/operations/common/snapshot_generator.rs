@@ -32,6 +32,7 @@ use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::ColumnEvolution;
 use uuid::Uuid;
 
 use crate::statistics::merge_statistics;
@@ -50,6 +51,15 @@ pub trait SnapshotGenerator {
         Ok(())
     }
 
+    /// The segments of `previous` that the appended rows should be checked against for the
+    /// unique constraints of the table, or None if the mutation appends nothing.
+    fn unique_check_segments<'a>(
+        &self,
+        _previous: &'a Option<Arc<TableSnapshot>>,
+    ) -> Option<&'a [Location]> {
+        None
+    }
+
     fn generate_new_snapshot(
         &self,
         schema: TableSchema,
@@ -358,6 +368,16 @@ impl SnapshotGenerator for AppendGenerator {
         Ok(())
     }
 
+    fn unique_check_segments<'a>(
+        &self,
+        previous: &'a Option<Arc<TableSnapshot>>,
+    ) -> Option<&'a [Location]> {
+        match previous {
+            Some(snapshot) if !self.overwrite => Some(snapshot.segments.as_slice()),
+            _ => Some(&[]),
+        }
+    }
+
     fn generate_new_snapshot(
         &self,
         schema: TableSchema,
@@ -385,10 +405,21 @@ impl SnapshotGenerator for AppendGenerator {
             if !self.overwrite {
                 let mut summary = snapshot.summary.clone();
                 if self.check_fill_default(&summary)? {
+                    let column_evolution =
+                        ColumnEvolution::from_schema(self.conflict_resolve_ctx()?.1)?;
                     self.leaf_default_values
                         .iter()
                         .for_each(|(col_id, default_value)| {
                             if !summary.col_stats.contains_key(col_id) {
+                                // the stats of a lazily evolved column are converted from
+                                // the stats of the column it was evolved from
+                                if let Some(col_stat) =
+                                    column_evolution.column_statistics(&summary.col_stats, *col_id)
+                                {
+                                    let col_stat = col_stat.into_owned();
+                                    summary.col_stats.insert(*col_id, col_stat);
+                                    return;
+                                }
                                 let (null_count, distinct_of_values) = if default_value.is_null() {
                                     (summary.row_count, Some(0))
                                 } else {
@@ -48,7 +48,38 @@ pub struct CompactOptions {
     pub limit: Option<usize>,
 }
 
+/// The fragmentation of a table, derived from the summary of its current snapshot.
+#[derive(Clone, Debug, Default)]
+pub struct TableFragmentation {
+    pub segment_count: u64,
+    pub block_count: u64,
+    /// The number of segments that would be saved by merging the segments.
+    pub excess_segments: u64,
+    /// The number of blocks that are smaller than the block thresholds.
+    pub undersized_blocks: u64,
+}
+
 impl FuseTable {
+    #[async_backtrace::framed]
+    pub async fn fragmentation(&self) -> Result<Option<TableFragmentation>> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(None);
+        };
+
+        let block_per_seg = self
+            .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
+            .max(1) as u64;
+        let segment_count = snapshot.segments.len() as u64;
+        let block_count = snapshot.summary.block_count;
+        Ok(Some(TableFragmentation {
+            segment_count,
+            block_count,
+            excess_segments: segment_count
+                .saturating_sub((block_count + block_per_seg - 1) / block_per_seg),
+            undersized_blocks: block_count.saturating_sub(snapshot.summary.perfect_block_count),
+        }))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn do_compact_segments(
         &self,
@@ -226,6 +226,7 @@ impl FuseTable {
                     remain_reader.clone(),
                     ops.clone(),
                     self.storage_format,
+                    self.delete_mode(),
                     query_row_id_col,
                 )
             },
@@ -266,11 +267,12 @@ impl FuseTable {
             ..PushDownInfo::default()
         });
 
-        let mut pruner = FusePruner::create(
+        let mut pruner = FusePruner::create_with_partitions(
             &ctx,
             self.operator.clone(),
             self.table_info.schema(),
             &push_down,
+            self.partition_keys(ctx.clone())?,
             self.bloom_index_cols(),
         )?;
 
@@ -350,6 +352,7 @@ impl FuseTable {
                             cluster_stats,
                             inner_part,
                             whole_block_mutation,
+                            block_meta,
                         })));
                     part_info_ptr
                 })
@@ -80,6 +80,13 @@ impl FuseTable {
         let mut dry_run_purge_files = vec![];
         let mut purged_snapshot_count = 0;
 
+        // Files shared with the source or the clones of this table, and the files
+        // referenced by the tags must be kept.
+        let protected = ProtectedLocations {
+            prefix: format!("{}/", location_gen.prefix()),
+            shared: self.get_protected_locations(ctx).await?,
+        };
+
         let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
         let table_agg_index_ids = catalog
             .list_index_ids_by_table_id(ListIndexesByIdReq {
@@ -170,6 +177,7 @@ impl FuseTable {
                         ts_to_be_purged,
                         snapshots_to_be_purged,
                         &table_agg_index_ids,
+                        &protected,
                     )
                     .await?;
 
@@ -185,6 +193,7 @@ impl FuseTable {
                         ts_to_be_purged,
                         snapshots_to_be_purged,
                         &table_agg_index_ids,
+                        &protected,
                     )
                     .await?;
 
@@ -225,6 +234,7 @@ impl FuseTable {
                     ts_to_be_purged,
                     snapshots_to_be_purged,
                     &table_agg_index_ids,
+                    &protected,
                 )
                 .await?;
             } else {
@@ -236,6 +246,7 @@ impl FuseTable {
                     ts_to_be_purged,
                     snapshots_to_be_purged,
                     &table_agg_index_ids,
+                    &protected,
                 )
                 .await?;
             }
@@ -246,6 +257,7 @@ impl FuseTable {
         }
 
         // 3. purge root snapshots.
+        let retained_since = root_snapshot_info.snapshot_lite.timestamp.unwrap();
         if !keep_last_snapshot {
             self.purge_root_snapshot(
                 ctx,
@@ -254,9 +266,18 @@ impl FuseTable {
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
                 &table_agg_index_ids,
+                &protected,
             )
             .await?;
         }
+
+        // 4. release the clone markers no longer referenced by the retained snapshots.
+        if let Err(e) = self.release_clone_markers(ctx, retained_since).await {
+            warn!(
+                "failed to release clone markers. table: {}, ident {}: {}",
+                self.table_info.desc, self.table_info.ident, e
+            );
+        }
         Ok(None)
     }
 
@@ -320,6 +341,7 @@ impl FuseTable {
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
+        protected: &ProtectedLocations,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -331,7 +353,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || protected.contains(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -345,16 +369,23 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || protected.contains(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| !protected.contains(&loc.0))
+                    .map(|loc| loc.0.clone()),
+            );
         }
-        purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
-        purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.extend(protected.retain(ts_to_be_purged));
+        purge_files.extend(protected.retain(snapshots_to_be_purged));
 
         Ok(())
     }
@@ -369,6 +400,7 @@ impl FuseTable {
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
+        protected: &ProtectedLocations,
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -383,7 +415,9 @@ impl FuseTable {
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || protected.contains(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -398,18 +432,20 @@ impl FuseTable {
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || protected.contains(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
-            let segment_locations_to_be_purged = HashSet::from_iter(
+            let segment_locations_to_be_purged = protected.retain(HashSet::from_iter(
                 chunk
                     .iter()
                     .map(|loc| loc.0.clone())
                     .collect::<Vec<String>>(),
-            );
+            ));
 
             // Refresh status.
             {
@@ -434,10 +470,16 @@ impl FuseTable {
             .await?;
         }
 
-        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
-            .await
+        self.purge_ts_snapshots(
+            ctx,
+            counter,
+            protected.retain(ts_to_be_purged),
+            protected.retain(snapshots_to_be_purged),
+        )
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -446,18 +488,20 @@ impl FuseTable {
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
         table_agg_index_ids: &[u64],
+        protected: &ProtectedLocations,
     ) -> Result<()> {
-        let segment_locations_to_be_purged = HashSet::from_iter(
+        let segment_locations_to_be_purged = protected.retain(HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
-        );
+        ));
 
+        let blocks_to_be_purged = protected.retain(root_location_tuple.block_location);
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
-            agg_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(|loc| {
+            agg_indexes_to_be_purged.extend(blocks_to_be_purged.iter().map(|loc| {
                 TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                     loc, *index_id,
                 )
@@ -467,9 +511,9 @@ impl FuseTable {
         self.purge_block_segments(
             ctx,
             counter,
-            root_location_tuple.block_location,
+            blocks_to_be_purged,
             agg_indexes_to_be_purged,
-            root_location_tuple.bloom_location,
+            protected.retain(root_location_tuple.bloom_location),
             segment_locations_to_be_purged,
         )
         .await?;
@@ -481,8 +525,8 @@ impl FuseTable {
         self.purge_ts_snapshots(
             ctx,
             counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
+            protected.retain(ts_to_be_purged),
+            protected.retain(HashSet::from([root_snapshot_location])),
         )
         .await
     }
@@ -696,6 +740,25 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     }
 }
 
+/// Files which are no longer referenced by the table, but can not be purged by it.
+struct ProtectedLocations {
+    /// Storage prefix of the table, files out of it belong to the source table of a clone.
+    prefix: String,
+    /// Files shared with the tables cloned from this table.
+    shared: HashSet<String>,
+}
+
+impl ProtectedLocations {
+    fn contains(&self, location: &str) -> bool {
+        !location.starts_with(&self.prefix) || self.shared.contains(location)
+    }
+
+    fn retain(&self, mut locations: HashSet<String>) -> HashSet<String> {
+        locations.retain(|loc| !self.contains(loc));
+        locations
+    }
+}
+
 struct PurgeCounter {
     start: Instant,
     blocks: usize,
@@ -295,10 +295,18 @@ impl AggregationContext {
         )
         .await?;
         let origin_num_rows = origin_data_block.num_rows();
+        // rows already deleted by the deletion vector are dropped along with the rewrite
+        let live_rows = block_meta
+            .deletion_vector
+            .as_ref()
+            .map(|dv| dv.to_filter(0..origin_num_rows))
+            .transpose()?;
         // apply delete
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row)
+                || live_rows.as_ref().is_some_and(|live| !live.get_bit(row))
+            {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
@@ -15,6 +15,8 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod branch;
+mod clone;
 mod commit;
 pub mod common;
 mod compact;
@@ -24,6 +26,7 @@ mod merge;
 mod merge_into;
 mod mutation;
 mod navigate;
+mod partition;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -31,12 +34,16 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod tag;
 mod truncate;
+mod ttl;
+mod unique_constraint;
 mod update;
 pub mod util;
 pub use agg_index_sink::AggIndexSink;
 pub use common::*;
 pub use compact::CompactOptions;
+pub use compact::TableFragmentation;
 pub use delete::MutationBlockPruningContext;
 pub use merge_into::*;
 pub use mutation::*;
@@ -126,6 +126,12 @@ impl BlockCompactMutator {
                         default_cluster_key,
                     )
                 });
+            } else if segment_infos
+                .iter()
+                .any(|(_, v)| v.summary.partition.is_some())
+            {
+                // keep the segments of the same partition together.
+                segment_infos.sort_by(|a, b| a.1.summary.partition.cmp(&b.1.summary.partition));
             }
 
             // Check the segment to be compacted.
@@ -331,7 +337,9 @@ impl SegmentCompactChecker {
 
         if segments.len() == 1 {
             let summary = &segments[0].1.summary;
-            if (summary.block_count == 1 || summary.perfect_block_count == summary.block_count)
+            // a single block with deleted rows still needs to be compacted to materialize them.
+            let single_block = summary.block_count == 1 && !has_deleted_rows(&segments[0].1);
+            if (single_block || summary.perfect_block_count == summary.block_count)
                 && (self.cluster_key_id.is_none()
                     || self.cluster_key_id
                         == summary.cluster_stats.as_ref().map(|v| v.cluster_key_id))
@@ -352,6 +360,18 @@ impl SegmentCompactChecker {
         idx: SegmentIndex,
         segment: Arc<CompactSegmentInfo>,
     ) -> Vec<Vec<(SegmentIndex, Arc<CompactSegmentInfo>)>> {
+        // The segments of different partitions cannot be compacted together.
+        if self
+            .segments
+            .last()
+            .is_some_and(|(_, v)| v.summary.partition != segment.summary.partition)
+        {
+            self.total_block_count = 0;
+            let mut segments_vec = vec![std::mem::take(&mut self.segments)];
+            segments_vec.extend(self.add(idx, segment));
+            return segments_vec;
+        }
+
         self.total_block_count += segment.summary.block_count;
         if self.total_block_count < self.block_threshold {
             self.segments.push((idx, segment));
@@ -374,6 +394,12 @@ impl SegmentCompactChecker {
     }
 }
 
+fn has_deleted_rows(segment: &CompactSegmentInfo) -> bool {
+    segment
+        .block_metas()
+        .is_ok_and(|blocks| blocks.iter().any(|b| b.deletion_vector.is_some()))
+}
+
 struct CompactTaskBuilder {
     column_ids: HashSet<ColumnId>,
     cluster_key_id: Option<u32>,
@@ -457,6 +483,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        if block.deletion_vector.is_some() {
+            // Rewrite the block to materialize the deleted rows.
+            return true;
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
@@ -518,10 +549,23 @@ impl CompactTaskBuilder {
             blocks.sort_by(|a, b| {
                 sort_by_cluster_stats(&a.cluster_stats, &b.cluster_stats, default_cluster_key)
             });
+        } else if blocks.iter().any(|v| v.partition.is_some()) {
+            // keep the blocks of the same partition together.
+            blocks.sort_by(|a, b| a.partition.cmp(&b.partition));
         }
 
         let mut tasks = VecDeque::new();
         for block in blocks.iter() {
+            // The blocks of different partitions cannot be compacted together.
+            if self
+                .blocks
+                .last()
+                .is_some_and(|v| v.partition != block.partition)
+            {
+                let blocks = self.take_blocks();
+                latest_flag = self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                block_idx += 1;
+            }
             let (unchanged, need_take) = self.add(block, self.thresholds);
             if need_take {
                 let blocks = self.take_blocks();
@@ -541,10 +585,28 @@ impl CompactTaskBuilder {
                 // The clustering table cannot compact different level blocks.
                 self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, tail);
             } else {
+                // The blocks of different partitions cannot be compacted together.
+                let same_partition = |blocks: &[Arc<BlockMeta>]| {
+                    blocks
+                        .last()
+                        .map_or(true, |v| v.partition == tail[0].partition)
+                };
                 let mut blocks = if latest_flag {
-                    unchanged_blocks.pop().map_or(vec![], |(_, v)| vec![v])
+                    match unchanged_blocks.pop() {
+                        Some((idx, v)) if !same_partition(std::slice::from_ref(&v)) => {
+                            unchanged_blocks.push((idx, v));
+                            vec![]
+                        }
+                        v => v.map_or(vec![], |(_, v)| vec![v]),
+                    }
                 } else {
-                    tasks.pop_back().map_or(vec![], |(_, v)| v)
+                    match tasks.pop_back() {
+                        Some((idx, v)) if !same_partition(&v) => {
+                            tasks.push_back((idx, v));
+                            vec![]
+                        }
+                        v => v.map_or(vec![], |(_, v)| v),
+                    }
                 };
 
                 let (total_rows, total_size) =
@@ -558,7 +620,9 @@ impl CompactTaskBuilder {
                     self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
                 } else {
                     // blocks > 2N
-                    self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                    if !blocks.is_empty() {
+                        self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                    }
                     self.build_task(&mut tasks, &mut unchanged_blocks, block_idx + 1, tail);
                 }
             }
@@ -134,11 +134,18 @@ impl Processor for CompactSource {
                     .into_iter()
                     .zip(metas.into_iter())
                     .map(|(data, meta)| {
-                        self.block_reader.deserialize_chunks_with_meta(
+                        let block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
                             data,
-                        )
+                        )?;
+                        // Materialize the rows deleted by the deletion vector.
+                        match &meta.deletion_vector {
+                            Some(dv) => {
+                                block.filter_with_bitmap(&dv.to_filter(0..block.num_rows())?)
+                            }
+                            None => Ok(block),
+                        }
                     })
                     .collect::<Result<Vec<_>>>()?;
 
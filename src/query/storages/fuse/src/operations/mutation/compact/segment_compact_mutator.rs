@@ -239,6 +239,14 @@ impl<'a> SegmentCompactor<'a> {
                 });
             }
 
+            // gather the segments of the same partition, see `PARTITION BY`
+            if segment_infos
+                .iter()
+                .any(|(segment, _)| segment.summary.partition.is_some())
+            {
+                segment_infos.sort_by(|a, b| a.0.summary.partition.cmp(&b.0.summary.partition));
+            }
+
             for (segment, location) in segment_infos.into_iter() {
                 if is_end {
                     self.compacted_state
@@ -302,6 +310,17 @@ impl<'a> SegmentCompactor<'a> {
             return Ok(());
         }
 
+        // a segment never spans several partitions
+        if self
+            .fragmented_segments
+            .first()
+            .is_some_and(|(fragment, _)| {
+                fragment.summary.partition != segment_info.summary.partition
+            })
+        {
+            self.compact_fragments().await?;
+        }
+
         let s = self.accumulated_num_blocks + num_blocks_current_segment;
 
         if s < self.threshold {
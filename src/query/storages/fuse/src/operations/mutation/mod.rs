@@ -29,6 +29,7 @@ pub use compact::SegmentCompactMutator;
 pub use compact::SegmentCompactionState;
 pub use compact::SegmentCompactor;
 pub use mutation_meta::ClusterStatsGenType;
+pub use mutation_meta::MarkDeletedRows;
 pub use mutation_meta::SerializeDataMeta;
 pub use mutation_part::DeletedSegmentInfo;
 pub use mutation_part::Mutation;
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::operations::common::BlockMetaIndex;
@@ -25,6 +28,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    MarkDeletedRows(MarkDeletedRows),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -55,3 +59,18 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// Rows of a block that are deleted in `merge_on_read` mode,
+/// only the deletion vector of the block is updated, the block data is untouched.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct MarkDeletedRows {
+    pub index: BlockMetaIndex,
+    /// the meta of the block, carrying the updated deletion vector.
+    pub block_meta: Arc<BlockMeta>,
+}
+
+impl MarkDeletedRows {
+    pub fn create(index: BlockMetaIndex, block_meta: Arc<BlockMeta>) -> Self {
+        MarkDeletedRows { index, block_meta }
+    }
+}
@@ -16,12 +16,14 @@ use std::any::Any;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::sync::Arc;
 
 use common_catalog::plan::PartInfo;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::Statistics;
 
@@ -87,6 +89,8 @@ pub struct MutationPartInfo {
     pub cluster_stats: Option<ClusterStatistics>,
     pub inner_part: PartInfoPtr,
     pub whole_block_mutation: bool,
+    /// meta of the block being mutated, used to update its deletion vector.
+    pub block_meta: Arc<BlockMeta>,
 }
 
 impl MutationPartInfo {
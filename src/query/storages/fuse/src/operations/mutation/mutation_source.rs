@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::ProgressValues;
 use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnMeta;
@@ -38,7 +39,10 @@ use common_pipeline_core::processors::OutputPort;
 use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_sql::evaluator::BlockOperator;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DeletionVector;
 
+use super::mutation_meta::MarkDeletedRows;
 use super::mutation_meta::SerializeBlock;
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
@@ -47,6 +51,8 @@ use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::read::fuse_source::live_offsets;
+use crate::FuseDeleteMode;
 use crate::FuseStorageFormat;
 use crate::MergeIOReadResult;
 
@@ -84,10 +90,12 @@ pub struct MutationSource {
     remain_reader: Arc<Option<BlockReader>>,
     operators: Vec<BlockOperator>,
     storage_format: FuseStorageFormat,
+    delete_mode: FuseDeleteMode,
     action: MutationAction,
     query_row_id_col: bool,
 
     index: BlockMetaIndex,
+    block_meta: Option<Arc<BlockMeta>>,
     stats_type: ClusterStatsGenType,
 }
 
@@ -102,6 +110,7 @@ impl MutationSource {
         remain_reader: Arc<Option<BlockReader>>,
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
+        delete_mode: FuseDeleteMode,
         query_row_id_col: bool,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
@@ -113,12 +122,53 @@ impl MutationSource {
             remain_reader,
             operators,
             storage_format,
+            delete_mode,
             action,
             query_row_id_col,
             index: BlockMetaIndex::default(),
+            block_meta: None,
             stats_type: ClusterStatsGenType::Generally,
         })))
     }
+
+    /// Mark the rows selected by `predicate` in the deletion vector of the block,
+    /// instead of rewriting the block.
+    ///
+    /// `live_offsets` maps the rows of `predicate` to their offsets in the block,
+    /// if some rows of the block have already been deleted.
+    fn mark_deleted_rows(
+        &self,
+        predicate: &Bitmap,
+        live_offsets: Option<&[usize]>,
+    ) -> Result<State> {
+        let block_meta = self
+            .block_meta
+            .as_ref()
+            .ok_or_else(|| ErrorCode::Internal("It's a bug. Need block meta"))?;
+
+        let deleted = DeletionVector::from_offsets(
+            predicate
+                .iter()
+                .enumerate()
+                .filter(|(_, selected)| *selected)
+                .map(|(i, _)| live_offsets.map_or(i, |offsets| offsets[i])),
+        )?;
+        let deletion_vector = match &block_meta.deletion_vector {
+            Some(origin) => origin.union(&deleted)?,
+            None => deleted,
+        };
+
+        let mut new_block_meta = block_meta.as_ref().clone();
+        new_block_meta.deletion_vector = Some(deletion_vector);
+        let meta = Box::new(SerializeDataMeta::MarkDeletedRows(MarkDeletedRows::create(
+            self.index.clone(),
+            Arc::new(new_block_meta),
+        )));
+        Ok(State::Output(
+            self.ctx.get_partition(),
+            DataBlock::empty_with_meta(meta),
+        ))
+    }
 }
 
 #[async_trait::async_trait]
@@ -179,12 +229,20 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+
+                // Rows marked by the deletion vector are invisible to the mutation,
+                // and they are dropped if the block is rewritten.
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                let live_rows = fuse_part.live_rows(0..data_block.num_rows())?;
+                if let Some(bitmap) = &live_rows {
+                    data_block = data_block.filter_with_bitmap(bitmap)?;
+                }
+                let live_offsets = live_offsets(live_rows);
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
                     if self.query_row_id_col {
                         // Add internal column to data block
-                        let fuse_part = FusePartInfo::from_part(&part)?;
                         let block_meta = fuse_part.block_meta_index().unwrap();
                         let internal_column_meta = InternalColumnMeta {
                             segment_idx: block_meta.segment_idx,
@@ -192,7 +250,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: live_offsets.clone(),
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -252,6 +310,14 @@ impl Processor for MutationSource {
                                     );
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
+                                    if self.delete_mode == FuseDeleteMode::MergeOnRead {
+                                        // only mark the deleted rows, the block is untouched.
+                                        self.state = self.mark_deleted_rows(
+                                            &predicate_col,
+                                            live_offsets.as_deref(),
+                                        )?;
+                                        return Ok(());
+                                    }
                                     let filter = predicate_col.not();
                                     data_block = data_block.filter_with_bitmap(&filter)?;
                                     if self.remain_reader.is_none() {
@@ -304,11 +370,15 @@ impl Processor for MutationSource {
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let chunks = merged_io_read_result.columns_chunks()?;
-                    let remain_block = remain_reader.deserialize_chunks_with_part_info(
-                        part,
+                    let mut remain_block = remain_reader.deserialize_chunks_with_part_info(
+                        part.clone(),
                         chunks,
                         &self.storage_format,
                     )?;
+                    let fuse_part = FusePartInfo::from_part(&part)?;
+                    if let Some(bitmap) = fuse_part.live_rows(0..remain_block.num_rows())? {
+                        remain_block = remain_block.filter_with_bitmap(&bitmap)?;
+                    }
 
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
@@ -367,6 +437,7 @@ impl Processor for MutationSource {
                             segment_idx: part.index.segment_idx,
                             block_idx: part.index.block_idx,
                         };
+                        self.block_meta = Some(part.block_meta.clone());
                         if matches!(self.action, MutationAction::Deletion) {
                             self.stats_type =
                                 ClusterStatsGenType::WithOrigin(part.cluster_stats.clone());
@@ -380,7 +451,7 @@ impl Processor for MutationSource {
                         {
                             // whole block deletion.
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: part.block_meta.live_row_count() as usize,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -0,0 +1,371 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_sql::parse_exprs;
+use log::warn;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::Statistics;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_CLONE_MARKER_LOCATION;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use uuid::Uuid;
+
+use crate::io::SegmentsIO;
+use crate::operations::common::AbortOperation;
+use crate::statistics::reducers::merge_statistics_mut;
+use crate::statistics::PartitionGenerator;
+use crate::FuseTable;
+use crate::FUSE_TBL_CLONE_MARKER_PREFIX;
+
+impl FuseTable {
+    pub fn partition_by(&self) -> Option<&String> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_PARTITION_BY)
+            .filter(|partition_by| !partition_by.is_empty())
+    }
+
+    /// Returns the generator of the partitions of the blocks written into this table.
+    pub fn get_partition_gen(&self, ctx: Arc<dyn TableContext>) -> Result<PartitionGenerator> {
+        let Some(partition_by) = self.partition_by() else {
+            return Ok(PartitionGenerator::default());
+        };
+
+        let schema = self.schema();
+        let source_schema = schema.remove_virtual_computed_fields();
+        let func_ctx = ctx.get_function_context()?;
+        let exprs = parse_exprs(ctx, Arc::new(self.clone()), partition_by)?;
+
+        // the written blocks do not contain the virtual computed columns.
+        let mut projected = Vec::with_capacity(exprs.len());
+        for expr in exprs {
+            for index in expr.column_refs().into_keys() {
+                let name = schema.field(index).name();
+                if source_schema.index_of(name).is_err() {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "invalid partition key '{partition_by}', virtual computed column {name} can not be referenced"
+                    )));
+                }
+            }
+            projected.push(expr.project_column_ref(|index| {
+                source_schema.index_of(schema.field(*index).name()).unwrap()
+            }));
+        }
+        Ok(PartitionGenerator::new(projected, func_ctx))
+    }
+
+    /// Returns the partition keys of this table, used to prune the segments and blocks by
+    /// their partition values.
+    pub fn partition_keys(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<RemoteExpr<String>>> {
+        let Some(partition_by) = self.partition_by() else {
+            return Ok(vec![]);
+        };
+        let schema = self.schema();
+        let exprs = parse_exprs(ctx, Arc::new(self.clone()), partition_by)?;
+        Ok(exprs
+            .iter()
+            .map(|expr| {
+                expr.project_column_ref(|index| schema.field(*index).name().to_string())
+                    .as_remote_expr()
+            })
+            .collect())
+    }
+
+    /// Removes the partition `values` from this table, returns false if the partition does
+    /// not exist.
+    ///
+    /// Only the snapshot is rewritten, the segments of the partition are left to be purged.
+    /// The markers left in the source tables of the partition, if it was replaced, are
+    /// released once the new snapshot no longer shares any block with them.
+    #[async_backtrace::framed]
+    pub async fn drop_partition(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        values: &[Scalar],
+    ) -> Result<bool> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(false);
+        };
+
+        let mut found = false;
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut summary = Statistics::default();
+        for (location, segment_summary) in self.read_segment_summaries(ctx, &snapshot).await? {
+            if segment_summary.partition.as_deref() == Some(values) {
+                found = true;
+            } else {
+                merge_statistics_mut(&mut summary, &segment_summary, self.cluster_key_id());
+                segments.push(location);
+            }
+        }
+        if !found {
+            return Ok(false);
+        }
+
+        let released_markers = self.released_partition_markers(ctx, &segments).await?;
+        let mut table = self.clone();
+        if !released_markers.is_empty() {
+            let markers = self
+                .remaining_markers(&released_markers)
+                .collect::<Vec<_>>()
+                .join(",");
+            let options = &mut table.table_info.meta.options;
+            if markers.is_empty() {
+                options.remove(OPT_KEY_CLONE_MARKER_LOCATION);
+            } else {
+                options.insert(OPT_KEY_CLONE_MARKER_LOCATION.to_owned(), markers);
+            }
+        }
+
+        table
+            .commit_mutation(
+                ctx,
+                snapshot,
+                &segments,
+                summary,
+                AbortOperation::default(),
+                None,
+            )
+            .await?;
+
+        // the files of the released markers are not shared by the new snapshot
+        self.remove_partition_markers(released_markers).await;
+        Ok(true)
+    }
+
+    /// Replaces the partition `values` of this table with the same partition of `source`.
+    ///
+    /// The new snapshot of this table references the segments of `source`, no data is
+    /// copied. Like [FuseTable::merge_branch], a marker is left in the storage of `source`,
+    /// so that the shared files will not be purged by `source`. The markers of the tables
+    /// which no longer share any block with the new snapshot are removed.
+    #[async_backtrace::framed]
+    pub async fn replace_partition(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        values: &[Scalar],
+        source: &FuseTable,
+    ) -> Result<()> {
+        if source.schema().fields() != self.schema().fields()
+            || source.partition_by() != self.partition_by()
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "table {} must have the same columns and partition keys as table {}",
+                source.table_info.desc, self.table_info.desc
+            )));
+        }
+
+        let (Some(source_location), Some(source_snapshot)) = (
+            source.snapshot_loc().await?,
+            source.read_table_snapshot().await?,
+        ) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "the partition does not exist in table {}",
+                source.table_info.desc
+            )));
+        };
+        let mut replaced_segments = vec![];
+        let mut replaced_summary = Statistics::default();
+        for (location, segment_summary) in
+            source.read_segment_summaries(ctx, &source_snapshot).await?
+        {
+            if segment_summary.partition.as_deref() == Some(values) {
+                merge_statistics_mut(
+                    &mut replaced_summary,
+                    &segment_summary,
+                    self.cluster_key_id(),
+                );
+                replaced_segments.push(location);
+            }
+        }
+        if replaced_segments.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "the partition does not exist in table {}",
+                source.table_info.desc
+            )));
+        }
+
+        let prev = self.read_table_snapshot().await?;
+        let mut segments = vec![];
+        let mut summary = Statistics::default();
+        if let Some(prev) = &prev {
+            for (location, segment_summary) in self.read_segment_summaries(ctx, prev).await? {
+                if segment_summary.partition.as_deref() != Some(values) {
+                    merge_statistics_mut(&mut summary, &segment_summary, self.cluster_key_id());
+                    segments.push(location);
+                }
+            }
+        }
+        merge_statistics_mut(&mut summary, &replaced_summary, self.cluster_key_id());
+        segments.extend(replaced_segments);
+
+        let released_markers = self.released_partition_markers(ctx, &segments).await?;
+
+        // 1. leave a marker in the storage of the source table, the files of the partition are shared now
+        let marker = source
+            .meta_location_generator
+            .gen_partition_marker_location(self.get_id(), &Uuid::new_v4());
+        self.operator.write(&marker, source_location).await?;
+
+        // 2. commit the snapshot which references the segments of the source table
+        let snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev.as_ref().and_then(|prev| prev.timestamp),
+            prev.as_ref()
+                .map(|prev| (prev.snapshot_id, prev.format_version)),
+            self.schema().as_ref().clone(),
+            summary,
+            segments,
+            self.cluster_key_meta.clone(),
+            None,
+        );
+
+        let mut table_info = self.table_info.clone();
+        let markers = self
+            .remaining_markers(&released_markers)
+            .chain(std::iter::once(marker.as_str()))
+            .collect::<Vec<_>>()
+            .join(",");
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_CLONE_MARKER_LOCATION.to_owned(), markers);
+
+        if let Err(e) = FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+        {
+            if let Err(err) = self.operator.delete(&marker).await {
+                warn!("failed to remove partition marker {}: {}", marker, err);
+            }
+            return Err(e);
+        }
+
+        // 3. the files of the released markers are not shared by the new snapshot
+        self.remove_partition_markers(released_markers).await;
+        Ok(())
+    }
+
+    /// Returns the clone markers of this table, except the `released` ones.
+    fn remaining_markers<'a>(
+        &'a self,
+        released: &'a [String],
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        self.table_info
+            .options()
+            .get(OPT_KEY_CLONE_MARKER_LOCATION)
+            .into_iter()
+            .flat_map(|markers| markers.split(','))
+            .filter(|m| !released.iter().any(|released| released == m))
+    }
+
+    async fn remove_partition_markers(&self, markers: Vec<String>) {
+        for marker in markers {
+            if let Err(err) = self.operator.delete(&marker).await {
+                warn!("failed to remove partition marker {}: {}", marker, err);
+            }
+        }
+    }
+
+    /// Returns the partition markers left by this table, whose source tables do not share
+    /// any block with `segments` any longer.
+    ///
+    /// Only the blocks of `segments` are checked, the markers are not needed by the older
+    /// snapshots of this table once they are purged.
+    async fn released_partition_markers(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        segments: &[Location],
+    ) -> Result<Vec<String>> {
+        let Some(markers) = self.table_info.options().get(OPT_KEY_CLONE_MARKER_LOCATION) else {
+            return Ok(vec![]);
+        };
+        // the partition markers are `{source_prefix}/_cl/{table_id}_{uuid}`
+        let pattern = format!("/{}/{}_", FUSE_TBL_CLONE_MARKER_PREFIX, self.get_id());
+        let partition_markers = markers
+            .split(',')
+            .filter_map(|marker| {
+                marker
+                    .split_once(&pattern)
+                    .map(|(source_prefix, _)| (marker, format!("{source_prefix}/")))
+            })
+            .collect::<Vec<_>>();
+        if partition_markers.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let mut block_locations = Vec::new();
+        for segment_info in segments_io
+            .read_segments::<Arc<CompactSegmentInfo>>(segments, false)
+            .await?
+        {
+            for block in segment_info?.block_metas()? {
+                block_locations.push(block.location.0.clone());
+            }
+        }
+
+        Ok(partition_markers
+            .into_iter()
+            .filter(|(_, source_prefix)| {
+                !block_locations
+                    .iter()
+                    .any(|location| location.starts_with(source_prefix))
+            })
+            .map(|(marker, _)| marker.to_owned())
+            .collect())
+    }
+
+    /// Returns the segments of `snapshot` with their summaries. Each segment of a partitioned
+    /// table belongs to a single partition.
+    async fn read_segment_summaries(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot: &TableSnapshot,
+    ) -> Result<Vec<(Location, Statistics)>> {
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segment_infos = segments_io
+            .read_segments::<Arc<CompactSegmentInfo>>(&snapshot.segments, false)
+            .await?;
+
+        let mut summaries = Vec::with_capacity(segment_infos.len());
+        for (location, segment_info) in snapshot.segments.iter().zip(segment_infos) {
+            let summary = segment_info?.summary.clone();
+            if summary.partition.is_none() && summary.row_count > 0 {
+                return Err(ErrorCode::StorageOther(format!(
+                    "segment {} of table {} does not belong to a single partition",
+                    location.0, self.table_info.desc
+                )));
+            }
+            summaries.push((location.clone(), summary));
+        }
+        Ok(summaries)
+    }
+}
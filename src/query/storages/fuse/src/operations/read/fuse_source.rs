@@ -15,6 +15,7 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::InternalColumnMeta;
 use common_catalog::plan::PartInfoPtr;
@@ -305,3 +306,8 @@ pub(crate) fn fill_internal_column_meta(
     let meta: Option<BlockMetaInfoPtr> = Some(Box::new(internal_column_meta));
     data_block.add_meta(meta)
 }
+
+/// Offsets of the rows which are not marked as deleted by the deletion vector of the block.
+pub(crate) fn live_offsets(live_rows: Option<Bitmap>) -> Option<Vec<usize>> {
+    live_rows.map(|bitmap| (0..bitmap.len()).filter(|i| bitmap.get_bit(*i)).collect())
+}
@@ -19,6 +19,7 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::native::read::ArrayIter;
 use common_arrow::parquet::metadata::ColumnDescriptor;
@@ -59,6 +60,7 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 
 use super::fuse_source::fill_internal_column_meta;
+use super::fuse_source::live_offsets;
 use super::native_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
@@ -411,7 +413,10 @@ impl NativeDeserializeDataTransform {
                         let default_val = self.block_reader.default_vals[*index].clone();
                         let value = Value::Scalar(default_val);
                         let col = value.convert_to_full_column(&data_type, num_rows);
-                        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+                        let mut bitmap = match part.live_rows(0..num_rows)? {
+                            Some(live_rows) => live_rows.make_mut(),
+                            None => MutableBitmap::from_len_set(num_rows),
+                        };
                         sorter.push_column(&col, &mut bitmap);
                     }
                 }
@@ -439,7 +444,10 @@ impl NativeDeserializeDataTransform {
         let part = self.parts.pop_front().unwrap();
         let fuse_part = FusePartInfo::from_part(&part)?;
 
-        let num_rows = fuse_part.nums_rows;
+        let live_rows = fuse_part.live_rows(0..fuse_part.nums_rows)?;
+        let num_rows = live_rows.as_ref().map_or(fuse_part.nums_rows, |bitmap| {
+            bitmap.len() - bitmap.unset_bits()
+        });
         let mut data_block = self.block_reader.build_default_values_block(num_rows)?;
         if let Some(ref virtual_columns) = &self.virtual_columns {
             for virtual_column in virtual_columns {
@@ -454,7 +462,7 @@ impl NativeDeserializeDataTransform {
         let data_block = if !self.block_reader.query_internal_columns() {
             data_block
         } else {
-            fill_internal_column_meta(data_block, fuse_part, None)?
+            fill_internal_column_meta(data_block, fuse_part, live_offsets(live_rows))?
         };
         let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
         self.add_block(data_block)?;
@@ -473,12 +481,15 @@ impl NativeDeserializeDataTransform {
         let part = self.parts.pop_front().unwrap();
         let fuse_part = FusePartInfo::from_part(&part)?;
 
-        let num_rows = fuse_part.nums_rows;
+        let live_rows = fuse_part.live_rows(0..fuse_part.nums_rows)?;
+        let num_rows = live_rows.as_ref().map_or(fuse_part.nums_rows, |bitmap| {
+            bitmap.len() - bitmap.unset_bits()
+        });
         let data_block = DataBlock::new(vec![], num_rows);
         let data_block = if !self.block_reader.query_internal_columns() {
             data_block
         } else {
-            fill_internal_column_meta(data_block, fuse_part, None)?
+            fill_internal_column_meta(data_block, fuse_part, live_offsets(live_rows))?
         };
 
         self.add_block(data_block)?;
@@ -740,6 +751,16 @@ impl Processor for NativeDeserializeDataTransform {
 
                             let mut bitmap =
                                 FilterHelpers::filter_to_bitmap(filter, prewhere_block.num_rows());
+                            // The deleted rows must not enter the top-k heap, or the pages
+                            // holding live rows would be skipped.
+                            let live_rows = FusePartInfo::from_part(&self.parts[0])?.live_rows(
+                                self.offset_in_part
+                                    ..self.offset_in_part + prewhere_block.num_rows(),
+                            )?;
+                            if let Some(live_rows) = live_rows {
+                                let filtered: Bitmap = bitmap.into();
+                                bitmap = (&filtered & &live_rows).make_mut();
+                            }
                             sorter.push_column(top_k_column, &mut bitmap);
                             Value::Column(bitmap.into())
                         } else {
@@ -790,6 +811,19 @@ impl Processor for NativeDeserializeDataTransform {
             self.add_virtual_columns(arrays, &self.src_schema, &self.virtual_columns, &mut block)?;
 
             let origin_num_rows = block.num_rows();
+
+            // Rows marked by the deletion vector of the block are removed along with the filter.
+            let live_rows = FusePartInfo::from_part(&self.parts[0])?
+                .live_rows(self.offset_in_part..self.offset_in_part + origin_num_rows)?;
+            let filter = match live_rows {
+                None => filter,
+                Some(live_rows) => Some(match filter {
+                    Some(Value::Column(bitmap)) => Value::Column(&bitmap & &live_rows),
+                    Some(Value::Scalar(false)) => Value::Scalar(false),
+                    _ => Value::Column(live_rows),
+                }),
+            };
+
             let block = if let Some(filter) = &filter {
                 block.filter_boolean_value(filter)?
             } else {
@@ -33,6 +33,7 @@ use common_storage::ColumnNodes;
 use itertools::Itertools;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::ColumnEvolution;
 
 use super::fuse_rows_fetcher::RowsFetcher;
 use crate::io::BlockReader;
@@ -188,6 +189,7 @@ impl<const BLOCKING_IO: bool> NativeRowsFetcher<BLOCKING_IO> {
 
         let arrow_schema = self.schema.to_arrow();
         let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&self.schema));
+        let column_evolution = ColumnEvolution::from_schema(&self.schema)?;
 
         for row_id in row_ids {
             let (prefix, _) = split_row_id(*row_id);
@@ -216,6 +218,7 @@ impl<const BLOCKING_IO: bool> NativeRowsFetcher<BLOCKING_IO> {
                 block_meta,
                 &None,
                 &column_nodes,
+                &column_evolution,
                 None,
                 &self.projection,
             );
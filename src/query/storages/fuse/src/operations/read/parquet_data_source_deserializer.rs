@@ -35,6 +35,7 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_core::processors::ProcessorPtr;
 
 use super::fuse_source::fill_internal_column_meta;
+use super::fuse_source::live_offsets;
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
@@ -224,12 +225,20 @@ impl Processor for DeserializeDataTransform {
                     };
                     self.scan_progress.incr(&progress_values);
 
+                    // Remove the rows marked by the deletion vector of the block.
+                    let live_rows = part.live_rows(0..part.nums_rows)?;
+                    let data_block = match &live_rows {
+                        Some(bitmap) => data_block.filter_with_bitmap(bitmap)?,
+                        None => data_block,
+                    };
+
                     let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
 
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        let data_block = fill_internal_column_meta(data_block, part, None)?;
+                        let offsets = live_offsets(live_rows);
+                        let data_block = fill_internal_column_meta(data_block, part, offsets)?;
                         self.output_data = Some(data_block);
                     } else {
                         self.output_data = Some(data_block);
@@ -101,8 +101,14 @@ impl SyncSource for ReadParquetDataSource<true> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index can't be used once rows of the block are deleted.
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| fuse_part.deletion_vector.is_none())
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -122,7 +128,6 @@ impl SyncSource for ReadParquetDataSource<true> {
 
                 // If virtual column file exists, read the data from the virtual columns directly.
                 let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -206,7 +211,12 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async_backtrace::location!().frame(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index can't be used once rows of the block are deleted.
+                        if let Some(index_reader) = index_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| part.deletion_vector.is_none())
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
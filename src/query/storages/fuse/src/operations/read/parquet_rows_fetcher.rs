@@ -31,6 +31,7 @@ use common_storage::ColumnNodes;
 use itertools::Itertools;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::ColumnEvolution;
 
 use super::fuse_rows_fetcher::RowsFetcher;
 use crate::io::BlockReader;
@@ -181,6 +182,7 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
 
         let arrow_schema = self.schema.to_arrow();
         let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&self.schema));
+        let column_evolution = ColumnEvolution::from_schema(&self.schema)?;
 
         for row_id in row_ids {
             let (prefix, _) = split_row_id(*row_id);
@@ -208,6 +210,7 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
                 block_meta,
                 &None,
                 &column_nodes,
+                &column_evolution,
                 None,
                 &self.projection,
             );
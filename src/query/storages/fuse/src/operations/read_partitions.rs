@@ -44,6 +44,7 @@ use storages_common_index::RangeIndex;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::table::ColumnEvolution;
 
 use crate::fuse_part::FusePartInfo;
 use crate::pruning::FusePruner;
@@ -142,16 +143,19 @@ impl FuseTable {
 
         type CacheItem = (PartStatistics, Partitions);
 
-        let derterministic_cache_key =
-            push_downs
-                .as_ref()
-                .filter(|p| p.is_deterministic)
-                .map(|push_downs| {
-                    format!(
-                        "{:x}",
-                        Sha256::digest(format!("{:?}_{:?}", segments_location, push_downs))
-                    )
-                });
+        // the blocks whose rows are all expired are pruned by the ttl of the table, which
+        // depends on the current time, so the pruning result can not be cached.
+        let ttl_push_downs = self.ttl_pruning_push_downs(ctx.clone(), &push_downs)?;
+
+        let derterministic_cache_key = push_downs
+            .as_ref()
+            .filter(|p| p.is_deterministic && ttl_push_downs.is_none())
+            .map(|push_downs| {
+                format!(
+                    "{:x}",
+                    Sha256::digest(format!("{:?}_{:?}", segments_location, push_downs))
+                )
+            });
 
         if let Some(cache_key) = derterministic_cache_key.as_ref() {
             if let Some(cache) = CacheItem::cache() {
@@ -166,12 +170,14 @@ impl FuseTable {
             }
         }
 
+        let pruning_push_downs = ttl_push_downs.or_else(|| push_downs.clone());
         let mut pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
-            FusePruner::create(
+            FusePruner::create_with_partitions(
                 &ctx,
                 dal.clone(),
                 table_info.schema(),
-                &push_downs,
+                &pruning_push_downs,
+                self.partition_keys(ctx.clone())?,
                 self.bloom_index_cols(),
             )?
         } else {
@@ -181,7 +187,7 @@ impl FuseTable {
                 &ctx,
                 dal.clone(),
                 table_info.schema(),
-                &push_downs,
+                &pruning_push_downs,
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
@@ -307,13 +313,30 @@ impl FuseTable {
             }
         }
 
+        // the block readers will fail on a malformed schema, no need to report it here
+        let column_evolution = schema
+            .and_then(|schema| ColumnEvolution::from_schema(schema).ok())
+            .unwrap_or_default();
         let (mut statistics, mut partitions) = match &push_downs {
-            None => Self::all_columns_partitions(schema, &block_metas, top_k.clone(), limit),
+            None => Self::all_columns_partitions(
+                schema,
+                &column_evolution,
+                &block_metas,
+                top_k.clone(),
+                limit,
+            ),
             Some(extras) => match &extras.projection {
-                None => Self::all_columns_partitions(schema, &block_metas, top_k.clone(), limit),
+                None => Self::all_columns_partitions(
+                    schema,
+                    &column_evolution,
+                    &block_metas,
+                    top_k.clone(),
+                    limit,
+                ),
                 Some(projection) => Self::projection_partitions(
                     &block_metas,
                     column_nodes,
+                    &column_evolution,
                     projection,
                     top_k.clone(),
                     limit,
@@ -337,6 +360,7 @@ impl FuseTable {
 
     fn all_columns_partitions(
         schema: Option<&TableSchemaRef>,
+        column_evolution: &ColumnEvolution,
         block_metas: &[(Option<BlockMetaIndex>, Arc<BlockMeta>)],
         top_k: Option<(TopK, Scalar)>,
         limit: usize,
@@ -350,9 +374,10 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
+                column_evolution,
                 block_meta_index,
                 &top_k,
                 block_meta,
@@ -377,6 +402,7 @@ impl FuseTable {
     fn projection_partitions(
         block_metas: &[(Option<BlockMetaIndex>, Arc<BlockMeta>)],
         column_nodes: &ColumnNodes,
+        column_evolution: &ColumnEvolution,
         projection: &Projection,
         top_k: Option<(TopK, Scalar)>,
         limit: usize,
@@ -396,11 +422,12 @@ impl FuseTable {
                 block_meta,
                 block_meta_index,
                 column_nodes,
+                column_evolution,
                 top_k.clone(),
                 projection,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -428,6 +455,7 @@ impl FuseTable {
 
     fn all_columns_part(
         schema: Option<&TableSchemaRef>,
+        column_evolution: &ColumnEvolution,
         block_meta_index: &Option<BlockMetaIndex>,
         top_k: &Option<(TopK, Scalar)>,
         meta: &BlockMeta,
@@ -437,7 +465,7 @@ impl FuseTable {
         for column_id in meta.col_metas.keys() {
             // ignore all deleted field
             if let Some(schema) = schema {
-                if schema.is_column_deleted(*column_id) {
+                if schema.is_column_deleted(*column_id) && !column_evolution.is_source(*column_id) {
                     continue;
                 }
             }
@@ -467,6 +495,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 
@@ -474,6 +503,7 @@ impl FuseTable {
         meta: &BlockMeta,
         block_meta_index: &Option<BlockMetaIndex>,
         column_nodes: &ColumnNodes,
+        column_evolution: &ColumnEvolution,
         top_k: Option<(TopK, Scalar)>,
         projection: &Projection,
     ) -> PartInfoPtr {
@@ -485,6 +515,14 @@ impl FuseTable {
                 // ignore column this block dose not exist
                 if let Some(column_meta) = meta.col_metas.get(column_id) {
                     columns_meta.insert(*column_id, column_meta.clone());
+                    continue;
+                }
+                // the block may be written before the type of the column was changed
+                for source in column_evolution.sources_of(*column_id) {
+                    if let Some(column_meta) = meta.col_metas.get(&source.column_id) {
+                        columns_meta.insert(source.column_id, column_meta.clone());
+                        break;
+                    }
                 }
             }
         }
@@ -510,6 +548,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 }
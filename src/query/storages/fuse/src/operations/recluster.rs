@@ -356,6 +356,7 @@ impl FuseTable {
             push_down,
             None,
             vec![],
+            vec![],
             BloomIndexColumns::None,
             max_concurrency,
         )?;
@@ -19,6 +19,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::FieldIndex;
+use common_expression::TableDataType;
 use common_pipeline_core::processors::ProcessorPtr;
 use common_pipeline_core::PipeItem;
 use common_pipeline_core::Pipeline;
@@ -239,7 +240,14 @@ impl FuseTable {
             .iter()
             .enumerate()
             .filter_map(|(idx, key)| {
-                if !BloomIndex::supported_type(&key.table_field.data_type) {
+                // the filters of map and array columns are built for the nested values,
+                // which can not be used to check the conflicts of the whole values.
+                if !BloomIndex::supported_type(&key.table_field.data_type)
+                    || matches!(
+                        key.table_field.data_type.remove_nullable(),
+                        TableDataType::Map(_) | TableDataType::Array(_)
+                    )
+                {
                     None
                 } else {
                     let maybe_col_stats =
@@ -72,6 +72,7 @@ pub fn row_hash_of_columns(
             ScalarRef::Timestamp(v) => sip.write_i64(v),
             ScalarRef::String(v) => sip.write(v),
             ScalarRef::Bitmap(v) => sip.write(v),
+            ScalarRef::Geometry(v) => sip.write(v),
             ScalarRef::Decimal(v) => match v {
                 DecimalScalar::Decimal128(i, DecimalSize { precision, scale }) => {
                     sip.write_i128(i);
@@ -47,6 +47,7 @@ use storages_common_table_meta::meta::BlockSlotDescription;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::table::ColumnEvolution;
 
 use crate::io::read::bloom::block_filter_reader::BloomBlockFilterReader;
 use crate::io::write_data;
@@ -79,6 +80,8 @@ struct AggregationContext {
     bloom_filter_column_indexes: Vec<FieldIndex>,
     // table fields excludes `on_conflict_fields`
     remain_column_field_ids: Vec<FieldIndex>,
+    // the lazily evolved columns of the table
+    column_evolution: ColumnEvolution,
     // reader that reads the ON CONFLICT key fields
     key_column_reader: Arc<BlockReader>,
     // reader that reads the `remain_column_field_ids`
@@ -114,6 +117,7 @@ impl MergeIntoOperationAggregator {
         io_request_semaphore: Arc<Semaphore>,
     ) -> Result<Self> {
         let deletion_accumulator = DeletionAccumulator::default();
+        let column_evolution = ColumnEvolution::from_schema(&table_schema)?;
         let segment_reader =
             MetaReaders::segment_info_reader(data_accessor.clone(), table_schema.clone());
 
@@ -174,6 +178,7 @@ impl MergeIntoOperationAggregator {
                 on_conflict_fields,
                 bloom_filter_column_indexes,
                 remain_column_field_ids,
+                column_evolution,
                 key_column_reader,
                 remain_column_reader,
                 data_accessor,
@@ -353,7 +358,97 @@ impl MergeIntoOperationAggregator {
     }
 }
 
+// check the accumulated deletions against the existing rows, without mutating anything
+impl MergeIntoOperationAggregator {
+    /// Returns true if any live row of the accumulated blocks has one of the deletion keys.
+    #[async_backtrace::framed]
+    pub async fn has_conflict(&mut self) -> Result<bool> {
+        let aggregation_ctx = &self.aggregation_ctx;
+        for (segment_idx, block_deletion) in self.deletion_accumulator.deletions.drain() {
+            let (path, ver) = aggregation_ctx
+                .segment_locations
+                .get(&segment_idx)
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "unexpected, segment (idx {}) not found, during checking conflicts",
+                        segment_idx
+                    ))
+                })?;
+
+            let load_param = LoadParams {
+                location: path.clone(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: true,
+            };
+
+            let compact_segment_info = aggregation_ctx.segment_reader.read(&load_param).await?;
+            let segment_info: SegmentInfo = compact_segment_info.try_into()?;
+
+            for (block_index, keys) in block_deletion {
+                let block_meta = &segment_info.blocks[block_index];
+                if aggregation_ctx.block_has_keys(block_meta, &keys).await? {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+}
+
 impl AggregationContext {
+    #[async_backtrace::framed]
+    async fn block_has_keys(
+        &self,
+        block_meta: &BlockMeta,
+        key_hashes: &(ahash::HashSet<UniqueKeyDigest>, Vec<Vec<u64>>),
+    ) -> Result<bool> {
+        let (key_hashes, bloom_hashes) = key_hashes;
+        if block_meta.live_row_count() == 0 {
+            return Ok(false);
+        }
+
+        if self
+            .apply_bloom_pruning(block_meta, bloom_hashes, &self.bloom_filter_column_indexes)
+            .await
+        {
+            return Ok(false);
+        }
+
+        let key_columns_data = read_block(
+            self.write_settings.storage_format,
+            &self.key_column_reader,
+            block_meta,
+            &self.read_settings,
+        )
+        .await?;
+
+        let num_rows = key_columns_data.num_rows();
+        let columns = key_columns_data
+            .columns()
+            .iter()
+            .map(|entry| &entry.value)
+            .collect::<Vec<_>>();
+
+        let live_rows = block_meta
+            .deletion_vector
+            .as_ref()
+            .map(|dv| dv.to_filter(0..num_rows))
+            .transpose()?;
+
+        for row in 0..num_rows {
+            if live_rows.as_ref().is_some_and(|live| !live.get_bit(row)) {
+                continue;
+            }
+            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+                if key_hashes.contains(&hash) {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
     #[async_backtrace::framed]
     async fn apply_deletion_to_data_block(
         &self,
@@ -411,9 +506,18 @@ impl AggregationContext {
                 .value);
         }
 
+        // rows already deleted by the deletion vector are dropped along with the rewrite
+        let live_rows = block_meta
+            .deletion_vector
+            .as_ref()
+            .map(|dv| dv.to_filter(0..num_rows))
+            .transpose()?;
+
         let mut bitmap = MutableBitmap::new();
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if live_rows.as_ref().is_some_and(|live| !live.get_bit(row)) {
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -423,7 +527,7 @@ impl AggregationContext {
             }
         }
 
-        let delete_nums = bitmap.unset_bits();
+        let delete_nums = bitmap.unset_bits() - (num_rows - block_meta.live_row_count() as usize);
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -446,12 +550,11 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == block_meta.live_row_count() as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -544,7 +647,21 @@ impl AggregationContext {
         column_stats: &HashMap<ColumnId, ColumnStatistics>,
         columns_min_max: &[(Scalar, Scalar)],
     ) -> bool {
-        Self::check_overlap(&self.on_conflict_fields, column_stats, columns_min_max)
+        if self.column_evolution.is_empty() {
+            return Self::check_overlap(&self.on_conflict_fields, column_stats, columns_min_max);
+        }
+        // the stats of the lazily evolved columns are converted on the fly
+        let column_stats = self
+            .on_conflict_fields
+            .iter()
+            .filter_map(|field| {
+                let column_id = field.table_field.column_id();
+                self.column_evolution
+                    .column_statistics(column_stats, column_id)
+                    .map(|stat| (column_id, stat.into_owned()))
+            })
+            .collect();
+        Self::check_overlap(&self.on_conflict_fields, &column_stats, columns_min_max)
     }
 
     // if any item of `column_min_max` does NOT overlap with the corresponding item of `column_stats`
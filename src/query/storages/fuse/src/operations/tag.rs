@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Tag the snapshot at `snapshot_location` as `tag`.
+    ///
+    /// The tag is kept as a file under `_tag/` which records the location of the snapshot.
+    /// The tagged snapshot, and the files referenced by it, will not be purged while the
+    /// tag exists. See [FuseTable::get_protected_locations].
+    #[async_backtrace::framed]
+    pub async fn create_tag(&self, tag: &str, snapshot_location: String) -> Result<()> {
+        let location = self.meta_location_generator.gen_tag_location(tag);
+        if self.operator.is_exist(&location).await? {
+            return Err(ErrorCode::TableTagAlreadyExists(format!(
+                "tag {} of table {} already exists",
+                tag, self.table_info.desc
+            )));
+        }
+        self.operator.write(&location, snapshot_location).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn drop_tag(&self, tag: &str) -> Result<()> {
+        let location = self.meta_location_generator.gen_tag_location(tag);
+        if !self.operator.is_exist(&location).await? {
+            return Err(self.unknown_tag(tag));
+        }
+        self.operator.delete(&location).await?;
+        Ok(())
+    }
+
+    /// Returns the location of the snapshot recorded by the tag `tag`.
+    #[async_backtrace::framed]
+    pub async fn read_tag(&self, tag: &str) -> Result<String> {
+        let location = self.meta_location_generator.gen_tag_location(tag);
+        match self.operator.read(&location).await {
+            Ok(data) => Ok(String::from_utf8(data)?),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => Err(self.unknown_tag(tag)),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub(crate) async fn read_tagged_snapshot(
+        &self,
+        tag: &str,
+        location: &str,
+    ) -> Result<Arc<TableSnapshot>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: location.to_owned(),
+            len_hint: None,
+            ver: TableMetaLocationGenerator::snapshot_version(location),
+            put_cache: true,
+        };
+        reader.read(&params).await.map_err(|e| {
+            if e.code() == ErrorCode::STORAGE_NOT_FOUND {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "snapshot {} of tag {} not found",
+                    location, tag
+                ))
+            } else {
+                e
+            }
+        })
+    }
+
+    fn unknown_tag(&self, tag: &str) -> ErrorCode {
+        ErrorCode::UnknownTableTag(format!(
+            "tag {} of table {} does not exist",
+            tag, self.table_info.desc
+        ))
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::plan::Filters;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::query_kind::QueryKind;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::type_check::check_function;
+use common_expression::types::DataType;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_sql::parse_exprs;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::operations::mutation::Mutation;
+use crate::operations::MutationBlockPruningContext;
+use crate::pruning::create_segment_location_vector;
+use crate::FuseTable;
+
+impl FuseTable {
+    pub fn ttl(&self) -> Option<&String> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_TTL)
+            .filter(|ttl| !ttl.is_empty())
+    }
+
+    /// Returns the filters of the expired rows, and the columns referenced by the TTL.
+    ///
+    /// A row is expired if its expiration time is earlier than now. Rows whose expiration
+    /// time is NULL never expire.
+    pub fn ttl_expired_filters(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<(Filters, Vec<FieldIndex>)>> {
+        let Some(ttl) = self.ttl() else {
+            return Ok(None);
+        };
+
+        let mut exprs = parse_exprs(ctx, Arc::new(self.clone()), ttl)?;
+        if exprs.len() != 1 {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid ttl option '{ttl}', expect a single expression"
+            )));
+        }
+        let expr = exprs.remove(0);
+        let col_indices = expr.column_refs().into_keys().collect();
+
+        let expr = match expr.data_type().remove_nullable() {
+            DataType::Timestamp => expr,
+            DataType::Date => {
+                let dest_type = if expr.data_type().is_nullable() {
+                    DataType::Timestamp.wrap_nullable()
+                } else {
+                    DataType::Timestamp
+                };
+                check_cast(None, false, expr, &dest_type, &BUILTIN_FUNCTIONS)?
+            }
+            other => {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid ttl option '{ttl}', expect a DATE or TIMESTAMP expression, but got {other}"
+                )));
+            }
+        };
+
+        let now = Expr::Constant {
+            span: None,
+            scalar: Scalar::Timestamp(Utc::now().timestamp_micros()),
+            data_type: DataType::Timestamp,
+        };
+        let is_null = check_function(
+            None,
+            "not",
+            &[],
+            &[check_function(
+                None,
+                "is_not_null",
+                &[],
+                &[expr.clone()],
+                &BUILTIN_FUNCTIONS,
+            )?],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let not_expired = check_function(None, "gte", &[], &[expr, now], &BUILTIN_FUNCTIONS)?;
+        let alive = check_function(None, "or", &[], &[is_null, not_expired], &BUILTIN_FUNCTIONS)?;
+        let expired = check_function(None, "not", &[], &[alive.clone()], &BUILTIN_FUNCTIONS)?;
+
+        let schema = self.schema();
+        let to_remote = |expr: &Expr| {
+            expr.project_column_ref(|index| schema.field(*index).name().to_string())
+                .as_remote_expr()
+        };
+        Ok(Some((
+            Filters {
+                filter: to_remote(&expired),
+                inverted_filter: to_remote(&alive),
+            },
+            col_indices,
+        )))
+    }
+
+    /// Returns the push downs to prune the blocks of a read, with the filter of the alive rows
+    /// added to the pushed down filters, so that the blocks whose rows are all expired are
+    /// pruned by the statistics of the columns referenced by the TTL.
+    ///
+    /// Returns None if the table has no TTL, or the read is for a mutation, whose target table
+    /// sees all the rows.
+    pub fn ttl_pruning_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Option<PushDownInfo>> {
+        if ctx.get_query_kind() == QueryKind::Update {
+            return Ok(None);
+        }
+        let Some((ttl_filters, _)) = self.ttl_expired_filters(ctx)? else {
+            return Ok(None);
+        };
+
+        let mut push_downs = push_downs.clone().unwrap_or_default();
+        let filters = match push_downs.filters.take() {
+            None => ttl_filters,
+            Some(filters) => {
+                let combine =
+                    |func_name: &str, left: &RemoteExpr<String>, right: &RemoteExpr<String>| {
+                        check_function(
+                            None,
+                            func_name,
+                            &[],
+                            &[
+                                left.as_expr(&BUILTIN_FUNCTIONS),
+                                right.as_expr(&BUILTIN_FUNCTIONS),
+                            ],
+                            &BUILTIN_FUNCTIONS,
+                        )
+                        .map(|expr| expr.as_remote_expr())
+                    };
+                Filters {
+                    filter: combine("and", &filters.filter, &ttl_filters.inverted_filter)?,
+                    inverted_filter: combine("or", &filters.inverted_filter, &ttl_filters.filter)?,
+                }
+            }
+        };
+        push_downs.filters = Some(filters);
+        Ok(Some(push_downs))
+    }
+
+    /// Prune the blocks whose rows are all expired, by the column statistics of the blocks.
+    ///
+    /// The partially expired blocks are left untouched, the returned partitions only contain
+    /// the blocks (and segments) to be removed as a whole, without reading their data.
+    #[async_backtrace::framed]
+    pub async fn expired_blocks_pruning(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot: &TableSnapshot,
+        filters: Filters,
+        col_indices: Vec<FieldIndex>,
+    ) -> Result<Partitions> {
+        let prune_ctx = MutationBlockPruningContext {
+            segment_locations: create_segment_location_vector(snapshot.segments.clone(), None),
+            block_count: Some(snapshot.summary.block_count as usize),
+        };
+        let (parts, _) = self
+            .do_mutation_block_pruning(
+                ctx,
+                Some(filters),
+                Projection::Columns(col_indices),
+                prune_ctx,
+                true,
+                true,
+            )
+            .await?;
+
+        let partitions = parts
+            .partitions
+            .into_iter()
+            .filter(|part| match Mutation::from_part(part) {
+                Ok(Mutation::MutationPartInfo(part)) => part.whole_block_mutation,
+                Ok(Mutation::MutationDeletedSegment(_)) => true,
+                Err(_) => false,
+            })
+            .collect();
+        Ok(Partitions::create_nolazy(
+            PartitionsShuffleKind::Mod,
+            partitions,
+        ))
+    }
+}
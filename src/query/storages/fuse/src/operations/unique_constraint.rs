@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use ahash::HashSet;
+use ahash::HashSetExt;
+use common_base::base::tokio::sync::Semaphore;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_sql::executor::physical_plans::OnConflictField;
+use storages_common_cache::LoadParams;
+use storages_common_index::BloomIndex;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::table::UniqueConstraint;
+
+use crate::io::BlockBuilder;
+use crate::io::BlockReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::operations::read_block;
+use crate::operations::replace_into::meta::DeletionByColumn;
+use crate::operations::replace_into::meta::MergeIntoOperation;
+use crate::operations::replace_into::mutator::row_hash_of_columns;
+use crate::operations::replace_into::mutator::MergeIntoOperationAggregator;
+use crate::operations::replace_into::mutator::ReplaceIntoMutator;
+use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::PartitionGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    pub fn unique_constraints(&self) -> Result<Vec<UniqueConstraint>> {
+        UniqueConstraint::from_table_options(self.table_info.options())
+    }
+
+    /// Check the appended segments against the PRIMARY KEY and UNIQUE constraints of the table.
+    ///
+    /// The keys of the appended rows must be distinct, and must not exist in the live rows of
+    /// `base_segments`. Rows having NULL in any key column are not checked.
+    #[async_backtrace::framed]
+    pub async fn check_unique_constraints(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        appended_segments: &[Location],
+        base_segments: &[Location],
+    ) -> Result<()> {
+        let constraints = self.unique_constraints()?;
+        if constraints.is_empty() || appended_segments.is_empty() {
+            return Ok(());
+        }
+
+        let schema = self.table_info.schema();
+        let read_settings = ReadSettings::from_ctx(&ctx)?;
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.operator.clone(), schema.clone());
+        let mut appended_blocks = vec![];
+        for (path, ver) in appended_segments {
+            let load_param = LoadParams {
+                location: path.clone(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: true,
+            };
+            let compact_segment_info = segment_reader.read(&load_param).await?;
+            let segment_info = SegmentInfo::try_from(compact_segment_info.as_ref())?;
+            appended_blocks.extend(segment_info.blocks);
+        }
+        if appended_blocks.is_empty() {
+            return Ok(());
+        }
+
+        let func_ctx = ctx.get_function_context()?;
+        let settings = ctx.get_settings();
+        let max_threads = settings.get_max_threads()? as usize;
+        let max_bloom_columns = settings.get_replace_into_bloom_pruning_max_column_number()?;
+
+        for constraint in constraints {
+            let mut on_conflict_fields = Vec::with_capacity(constraint.column_ids.len());
+            for column_id in &constraint.column_ids {
+                let (field_index, table_field) = schema
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .find(|(_, f)| f.column_id() == *column_id)
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "column id {column_id} of table constraint not found in schema"
+                        ))
+                    })?;
+                on_conflict_fields.push(OnConflictField {
+                    table_field: table_field.clone(),
+                    field_index,
+                });
+            }
+            let violation = || {
+                let keys = on_conflict_fields
+                    .iter()
+                    .map(|f| format!("`{}`", f.table_field.name()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let kind = if constraint.is_primary_key {
+                    "PRIMARY KEY"
+                } else {
+                    "UNIQUE"
+                };
+                ErrorCode::UniqueConstraintViolation(format!(
+                    "duplicate key violates the {kind} ({keys}) constraint of table {}",
+                    self.table_info.desc
+                ))
+            };
+
+            let key_reader = BlockReader::create(
+                ctx.clone(),
+                self.operator.clone(),
+                schema.clone(),
+                Projection::Columns(on_conflict_fields.iter().map(|f| f.field_index).collect()),
+                false,
+                false,
+            )?;
+            // the existing blocks are pruned by the bloom filters of the key columns, as
+            // replace into does
+            let bloom_filter_column_indexes = self
+                .choose_bloom_filter_columns(&on_conflict_fields, max_bloom_columns)
+                .await?;
+            let mut aggregator = if base_segments.is_empty() {
+                None
+            } else {
+                Some(MergeIntoOperationAggregator::try_create(
+                    ctx.clone(),
+                    on_conflict_fields.clone(),
+                    bloom_filter_column_indexes.clone(),
+                    base_segments.iter().cloned().enumerate().collect(),
+                    None,
+                    self.operator.clone(),
+                    schema.clone(),
+                    self.get_write_settings(),
+                    read_settings,
+                    self.unique_check_block_builder(ctx.clone())?,
+                    Arc::new(Semaphore::new(max_threads)),
+                )?)
+            };
+
+            // the keys are checked block by block, only the digests of the appended keys are
+            // kept to check that the appended keys are distinct
+            let mut appended_key_hashes = HashSet::new();
+            for block_meta in &appended_blocks {
+                let key_block =
+                    read_block(self.storage_format, &key_reader, block_meta, &read_settings)
+                        .await?;
+                let num_rows = key_block.num_rows();
+                let key_columns = key_block
+                    .columns()
+                    .iter()
+                    .map(|entry| &entry.value)
+                    .collect::<Vec<_>>();
+                let bloom_columns = bloom_filter_column_indexes
+                    .iter()
+                    .map(|idx| {
+                        let data_type: DataType =
+                            (&on_conflict_fields[*idx].table_field.data_type).into();
+                        let column = key_columns[*idx].convert_to_full_column(&data_type, num_rows);
+                        BloomIndex::calculate_nullable_column_digest(&func_ctx, &column, &data_type)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                let mut key_hashes = HashSet::with_capacity(num_rows);
+                let mut bloom_hashes = vec![Vec::with_capacity(num_rows); bloom_columns.len()];
+                for row in 0..num_rows {
+                    let Some(hash) = row_hash_of_columns(&key_columns, row)? else {
+                        continue;
+                    };
+                    if !appended_key_hashes.insert(hash) {
+                        return Err(violation());
+                    }
+                    key_hashes.insert(hash);
+                    for ((hashes, validity), row_hashes) in
+                        bloom_columns.iter().zip(bloom_hashes.iter_mut())
+                    {
+                        let is_valid = validity.as_ref().map_or(true, |v| v.get_bit(row));
+                        row_hashes.push(if is_valid { hashes[row] } else { 0 });
+                    }
+                }
+
+                // keys of the existing rows
+                let Some(aggregator) = aggregator.as_mut() else {
+                    continue;
+                };
+                if key_hashes.is_empty() {
+                    continue;
+                }
+                let columns_min_max = ReplaceIntoMutator::columns_min_max(&key_columns, num_rows)?;
+                aggregator
+                    .accumulate(MergeIntoOperation::Delete(vec![DeletionByColumn {
+                        columns_min_max,
+                        key_hashes,
+                        bloom_hashes,
+                    }]))
+                    .await?;
+                if aggregator.has_conflict().await? {
+                    return Err(violation());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // the aggregator only reads blocks when checking conflicts, nothing is written by the builder
+    fn unique_check_block_builder(&self, ctx: Arc<dyn TableContext>) -> Result<BlockBuilder> {
+        let source_schema = Arc::new(self.table_info.schema().remove_virtual_computed_fields());
+        let bloom_columns_map = self
+            .bloom_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_args = self.ngram_args(source_schema.clone())?;
+        Ok(BlockBuilder {
+            ctx,
+            meta_locations: self.meta_location_generator().clone(),
+            source_schema,
+            write_settings: self.get_write_settings(),
+            cluster_stats_gen: ClusterStatsGenerator::default(),
+            bloom_columns_map,
+            ngram_args,
+            partition_gen: PartitionGenerator::default(),
+        })
+    }
+}
@@ -41,6 +41,7 @@ use crate::operations::common::TransformSerializeBlock;
 use crate::operations::mutation::MutationAction;
 use crate::operations::mutation::MutationSource;
 use crate::pruning::create_segment_location_vector;
+use crate::FuseDeleteMode;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -305,6 +306,8 @@ impl FuseTable {
                         remain_reader.clone(),
                         ops.clone(),
                         self.storage_format,
+                        // update always rewrites the touched blocks.
+                        FuseDeleteMode::CopyOnWrite,
                         true,
                     )
                 },
@@ -87,6 +87,7 @@ impl BlockPruner {
         } else {
             segment_block_metas.iter().enumerate().collect()
         };
+        let blocks = self.partition_pruning(blocks);
 
         let mut blocks = blocks.into_iter();
         let pruning_tasks = std::iter::from_fn(|| {
@@ -111,7 +112,7 @@ impl BlockPruner {
                 }
 
                 let block_meta = block_meta.clone();
-                let row_count = block_meta.row_count;
+                let row_count = block_meta.live_row_count();
                 if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas)) {
                     // Perf.
                     {
@@ -220,6 +221,20 @@ impl BlockPruner {
         Ok(result)
     }
 
+    // Prunes the blocks of a partitioned table by their partition values.
+    fn partition_pruning<'a>(
+        &self,
+        blocks: Vec<(usize, &'a Arc<BlockMeta>)>,
+    ) -> Vec<(usize, &'a Arc<BlockMeta>)> {
+        match &self.pruning_ctx.partition_pruner {
+            Some(partition_pruner) => blocks
+                .into_iter()
+                .filter(|(_, block)| partition_pruner.should_keep(block.partition.as_deref()))
+                .collect(),
+            None => blocks,
+        }
+    }
+
     fn block_pruning_sync(
         &self,
         segment_location: SegmentLocation,
@@ -245,6 +260,7 @@ impl BlockPruner {
         } else {
             segment_block_metas.iter().enumerate().collect::<Vec<_>>()
         };
+        let blocks = self.partition_pruning(blocks);
         let mut result = Vec::with_capacity(blocks.len());
         let block_num = segment_info.summary.block_count as usize;
         for (block_idx, block_meta) in blocks {
@@ -260,7 +276,7 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
+            let row_count = block_meta.live_row_count();
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
                 && limit_pruner.within_limit(row_count)
             {
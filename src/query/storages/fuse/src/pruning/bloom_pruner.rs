@@ -22,6 +22,7 @@ use common_expression::ColumnId;
 use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_expression::Scalar;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_sql::BloomIndexColumns;
@@ -29,6 +30,7 @@ use log::warn;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
 use storages_common_index::FilterEvalResult;
+use storages_common_index::NgramArgs;
 use storages_common_table_meta::meta::Location;
 
 use crate::io::BloomBlockFilterReader;
@@ -50,6 +52,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// n-gram indices that should be loaded from filter block
+    ngram_args: Vec<NgramArgs>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -77,7 +82,14 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_args = bloom_index_cols
+                .ngram_index_fields(schema.clone(), BloomIndex::supported_ngram_type)?
+                .into_iter()
+                .map(|(index, field, gram_size)| NgramArgs::new(index, field, gram_size))
+                .collect::<Vec<_>>();
+            let ngram_args = BloomIndex::find_ngram_columns(expr, &ngram_args)?;
+
+            if !point_query_cols.is_empty() || !ngram_args.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -92,6 +104,7 @@ impl BloomPrunerCreator {
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_args,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -119,10 +132,28 @@ impl BloomPrunerCreator {
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
+                    if matches!(field.data_type(), TableDataType::Map(_)) {
+                        acc.push(BloomIndex::build_map_key_filter_column_name(
+                            version, field,
+                        )?);
+                    }
                 }
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        let index_columns = self
+            .ngram_args
+            .iter()
+            .try_fold(index_columns, |mut acc, args| {
+                if column_ids_of_indexed_block.contains(&args.field.column_id()) {
+                    acc.push(BloomIndex::build_ngram_filter_column_name(
+                        version,
+                        &args.field,
+                        args.gram_size,
+                    )?);
+                }
+                Ok::<_, ErrorCode>(acc)
+            })?;
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
@@ -134,6 +165,7 @@ impl BloomPrunerCreator {
                 filter.filter_schema,
                 filter.filters,
                 version,
+                self.ngram_args.clone(),
             )?
             .apply(
                 self.filter_expression.clone(),
@@ -36,6 +36,7 @@ use storages_common_pruner::Limiter;
 use storages_common_pruner::LimiterPrunerCreator;
 use storages_common_pruner::PagePruner;
 use storages_common_pruner::PagePrunerCreator;
+use storages_common_pruner::PartitionPruner;
 use storages_common_pruner::RangePruner;
 use storages_common_pruner::RangePrunerCreator;
 use storages_common_pruner::TopNPrunner;
@@ -63,6 +64,7 @@ pub struct PruningContext {
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
+    pub partition_pruner: Option<Arc<PartitionPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
 }
@@ -76,6 +78,7 @@ impl PruningContext {
         push_down: &Option<PushDownInfo>,
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
+        partition_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
         max_concurrency: usize,
     ) -> Result<Arc<PruningContext>> {
@@ -143,7 +146,16 @@ impl PruningContext {
         // Internal column pruner, if there are predicates using internal columns,
         // we can use them to prune segments and blocks.
         let internal_column_pruner =
-            InternalColumnPruner::try_create(func_ctx, filter_expr.as_ref());
+            InternalColumnPruner::try_create(func_ctx.clone(), filter_expr.as_ref());
+
+        // Partition pruner, if the filter references the partition keys of a partitioned
+        // table, we can use the partition values to prune segments and blocks.
+        let partition_keys = partition_keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+            .collect();
+        let partition_pruner =
+            PartitionPruner::try_create(func_ctx, filter_expr.as_ref(), partition_keys);
 
         // Constraint the degree of parallelism
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -166,6 +178,7 @@ impl PruningContext {
             bloom_pruner,
             page_pruner,
             internal_column_pruner,
+            partition_pruner,
             pruning_stats,
         });
         Ok(pruning_ctx)
@@ -210,6 +223,50 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+    ) -> Result<Self> {
+        Self::try_create(
+            ctx,
+            dal,
+            table_schema,
+            push_down,
+            cluster_key_meta,
+            cluster_keys,
+            vec![],
+            bloom_index_cols,
+        )
+    }
+
+    // Create fuse pruner of a partitioned table, which prunes by the partition values.
+    pub fn create_with_partitions(
+        ctx: &Arc<dyn TableContext>,
+        dal: Operator,
+        table_schema: TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+        partition_keys: Vec<RemoteExpr<String>>,
+        bloom_index_cols: BloomIndexColumns,
+    ) -> Result<Self> {
+        Self::try_create(
+            ctx,
+            dal,
+            table_schema,
+            push_down,
+            None,
+            vec![],
+            partition_keys,
+            bloom_index_cols,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        dal: Operator,
+        table_schema: TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+        cluster_key_meta: Option<ClusterKey>,
+        cluster_keys: Vec<RemoteExpr<String>>,
+        partition_keys: Vec<RemoteExpr<String>>,
+        bloom_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         let max_concurrency = {
             let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -231,6 +288,7 @@ impl FusePruner {
             push_down,
             cluster_key_meta,
             cluster_keys,
+            partition_keys,
             bloom_index_cols,
             max_concurrency,
         )?;
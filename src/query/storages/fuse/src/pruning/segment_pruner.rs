@@ -71,7 +71,14 @@ impl SegmentPruner {
                 pruning_stats.set_segments_range_pruning_before(1);
             }
 
-            if range_pruner.should_keep(&info.summary.col_stats, None) {
+            let keep_partition = self
+                .pruning_ctx
+                .partition_pruner
+                .as_ref()
+                .map_or(true, |pruner| {
+                    pruner.should_keep(info.summary.partition.as_deref())
+                });
+            if keep_partition && range_pruner.should_keep(&info.summary.col_stats, None) {
                 // Perf.
                 {
                     metrics_inc_segments_range_pruning_after(1);
@@ -15,14 +15,18 @@
 use std::collections::HashMap;
 
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::types::NumberType;
 use common_expression::types::ValueType;
 use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
 use common_functions::aggregates::eval_aggr;
+use common_io::geometry::rect_to_wkb_points;
+use common_io::geometry::wkbs_bounding_rect;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
@@ -50,8 +54,9 @@ pub fn gen_columns_statistics(
     let leaves = get_traverse_columns_dfs(&data_block)?;
     let leaf_column_ids = schema.to_leaf_column_ids();
     for ((col_idx, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
+        let is_geometry = data_type.remove_nullable() == DataType::Geometry;
         // Ignore the range index does not supported type.
-        if !RangeIndex::supported_type(data_type) {
+        if !is_geometry && !RangeIndex::supported_type(data_type) {
             continue;
         }
 
@@ -59,30 +64,50 @@ pub fn gen_columns_statistics(
         let mut min = Scalar::Null;
         let mut max = Scalar::Null;
 
-        let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
-        let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
+        if is_geometry {
+            // The min and max of geometries are the corners of their bounding box.
+            let mut geometries = col
+                .iter()
+                .filter_map(|v| match v {
+                    ScalarRef::Geometry(wkb) => Some(wkb),
+                    _ => None,
+                })
+                .peekable();
+            if geometries.peek().is_some() {
+                // Empty geometries have no bounding box, skip the statistics of them.
+                let Some(rect) = wkbs_bounding_rect(geometries) else {
+                    continue;
+                };
+                let (lower, upper) = rect_to_wkb_points(&rect)?;
+                min = Scalar::Geometry(lower);
+                max = Scalar::Geometry(upper);
+            }
+        } else {
+            let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
 
-        if mins.len() > 0 {
-            min = if let Some(v) = mins.index(0) {
-                if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
-                    v
+            if mins.len() > 0 {
+                min = if let Some(v) = mins.index(0) {
+                    if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
-        }
 
-        if maxs.len() > 0 {
-            max = if let Some(v) = maxs.index(0) {
-                if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
-                    v
+            if maxs.len() > 0 {
+                max = if let Some(v) = maxs.index(0) {
+                    if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
         }
 
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+use common_expression::types::AnyType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+
+/// Evaluates the partition keys of a partitioned table, see `PARTITION BY`.
+///
+/// The column references of the keys are the indexes of the columns in the written blocks,
+/// i.e. the table schema without the virtual computed columns.
+#[derive(Clone, Default)]
+pub struct PartitionGenerator {
+    exprs: Vec<Expr>,
+    func_ctx: FunctionContext,
+}
+
+impl PartitionGenerator {
+    pub fn new(exprs: Vec<Expr>, func_ctx: FunctionContext) -> Self {
+        Self { exprs, func_ctx }
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        !self.exprs.is_empty()
+    }
+
+    /// Returns the partition of `block`, if all the rows of the block belong to the same partition.
+    pub fn block_partition(&self, block: &DataBlock) -> Result<Option<Vec<Scalar>>> {
+        if !self.is_partitioned() || block.num_rows() == 0 {
+            return Ok(None);
+        }
+        let mut partition = Vec::with_capacity(self.exprs.len());
+        for value in self.eval(block)? {
+            match value {
+                Value::Scalar(scalar) => partition.push(scalar),
+                Value::Column(column) => {
+                    let first = column.index(0).unwrap();
+                    if (1..column.len()).any(|row| column.index(row).unwrap() != first) {
+                        return Ok(None);
+                    }
+                    partition.push(first.to_owned());
+                }
+            }
+        }
+        Ok(Some(partition))
+    }
+
+    /// Splits `block` by partition, the partitions are ordered by their values.
+    pub fn split(&self, block: DataBlock) -> Result<Vec<(Vec<Scalar>, DataBlock)>> {
+        let num_rows = block.num_rows();
+        let columns = self
+            .eval(&block)?
+            .into_iter()
+            .zip(&self.exprs)
+            .map(|(value, expr)| value.convert_to_full_column(expr.data_type(), num_rows))
+            .collect::<Vec<Column>>();
+
+        let mut partitions: BTreeMap<Vec<Scalar>, Vec<u32>> = BTreeMap::new();
+        for row in 0..num_rows {
+            let partition = columns
+                .iter()
+                .map(|column| column.index(row).unwrap().to_owned())
+                .collect();
+            partitions.entry(partition).or_default().push(row as u32);
+        }
+
+        if partitions.len() == 1 {
+            let partition = partitions.into_keys().next().unwrap();
+            return Ok(vec![(partition, block)]);
+        }
+        partitions
+            .into_iter()
+            .map(|(partition, rows)| Ok((partition, block.take(&rows, &mut None)?)))
+            .collect()
+    }
+
+    fn eval(&self, block: &DataBlock) -> Result<Vec<Value<AnyType>>> {
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        self.exprs.iter().map(|expr| evaluator.run(expr)).collect()
+    }
+}
@@ -18,6 +18,8 @@ use std::collections::HashMap;
 use common_expression::BlockThresholds;
 use common_expression::ColumnId;
 use common_expression::Scalar;
+use common_io::geometry::rect_to_wkb_points;
+use common_io::geometry::wkbs_bounding_rect;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnStatistics;
@@ -62,17 +64,22 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            let min = min_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .min_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+            let (min, max) = if min_stats.iter().any(|s| matches!(s, Scalar::Geometry(_))) {
+                reduce_geometry_min_max(&min_stats, &max_stats)
+            } else {
+                let min = min_stats
+                    .into_iter()
+                    .filter(|s| !s.is_null())
+                    .min_by(|x, y| x.cmp(y))
+                    .unwrap_or(Scalar::Null);
 
-            let max = max_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .max_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+                let max = max_stats
+                    .into_iter()
+                    .filter(|s| !s.is_null())
+                    .max_by(|x, y| x.cmp(y))
+                    .unwrap_or(Scalar::Null);
+                (min, max)
+            };
 
             acc.insert(
                 *id,
@@ -82,6 +89,22 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+// The min and max of geometries are the corners of their bounding box,
+// so the reduced bounding box is the one that covers all the corners.
+fn reduce_geometry_min_max(min_stats: &[Scalar], max_stats: &[Scalar]) -> (Scalar, Scalar) {
+    let corners = min_stats
+        .iter()
+        .chain(max_stats.iter())
+        .filter_map(|s| match s {
+            Scalar::Geometry(wkb) => Some(wkb.as_slice()),
+            _ => None,
+        });
+    match wkbs_bounding_rect(corners).and_then(|rect| rect_to_wkb_points(&rect).ok()) {
+        Some((min, max)) => (Scalar::Geometry(min), Scalar::Geometry(max)),
+        None => (Scalar::Null, Scalar::Null),
+    }
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
@@ -147,12 +170,16 @@ pub fn merge_statistics_mut(
     if l.row_count == 0 {
         l.col_stats = r.col_stats.clone();
         l.cluster_stats = r.cluster_stats.clone();
+        l.partition = r.partition.clone();
     } else {
         l.col_stats = reduce_block_statistics(&[&l.col_stats, &r.col_stats]);
         l.cluster_stats = reduce_cluster_statistics(
             &[&l.cluster_stats, &r.cluster_stats],
             default_cluster_key_id,
         );
+        if l.partition != r.partition {
+            l.partition = None;
+        }
     }
 
     l.row_count += r.row_count;
@@ -206,13 +233,15 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        row_count += b.live_row_count();
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
         index_size += b.bloom_filter_index_size;
-        if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
-            || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0)
+        // blocks with deleted rows are never perfect, so that compaction can materialize them.
+        if b.deletion_vector.is_none()
+            && (thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
+                || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0))
         {
             perfect_block_count += 1;
         }
@@ -222,6 +251,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     let merged_col_stats = reduce_block_statistics(&col_stats);
     let merged_cluster_stats = reduce_cluster_statistics(&cluster_stats, default_cluster_key_id);
+    let partition = reduce_block_partitions(block_metas);
 
     Statistics {
         row_count,
@@ -232,5 +262,15 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         index_size,
         col_stats: merged_col_stats,
         cluster_stats: merged_cluster_stats,
+        partition,
     }
 }
+
+/// Returns the partition of the blocks, if all of them belong to the same partition.
+pub fn reduce_block_partitions<T: Borrow<BlockMeta>>(block_metas: &[T]) -> Option<Vec<Scalar>> {
+    let (first, rest) = block_metas.split_first()?;
+    let partition = first.borrow().partition.as_ref()?;
+    rest.iter()
+        .all(|b| b.borrow().partition.as_ref() == Some(partition))
+        .then(|| partition.clone())
+}
@@ -45,6 +45,7 @@ use common_expression::TableSchemaRefExt;
 use common_expression::Value;
 use common_functions::BUILTIN_FUNCTIONS;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::table::ColumnCodec;
 
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
@@ -96,6 +97,7 @@ impl<'a> FuseEncoding<'a> {
 
             let schema = table.schema();
             let fields = schema.fields();
+            let codecs = ColumnCodec::load_fields(&schema)?;
             for chunk in snapshot.segments.chunks(chunk_size) {
                 let segments = segments_io
                     .read_segments::<SegmentInfo>(chunk, false)
@@ -103,7 +105,7 @@ impl<'a> FuseEncoding<'a> {
                 for segment in segments {
                     let segment = segment?;
                     for block in segment.blocks.iter() {
-                        for field in fields {
+                        for (field, codec) in fields.iter().zip(codecs.iter()) {
                             if field.is_nested() {
                                 continue;
                             }
@@ -131,7 +133,12 @@ impl<'a> FuseEncoding<'a> {
                             let page_metas = column_meta.as_native().unwrap().pages.clone();
                             let reader = NativeReader::new(pages, page_metas, vec![]);
                             let this_column_info = stat_simple(reader, arrow_field.clone())?;
-                            columns_info.push((field.data_type.sql_name(), this_column_info));
+                            let codec = codec.map(|codec| codec.to_string());
+                            columns_info.push((
+                                field.data_type.sql_name(),
+                                codec,
+                                this_column_info,
+                            ));
                         }
                     }
                 }
@@ -159,7 +166,10 @@ impl<'a> FuseEncoding<'a> {
     }
 
     #[async_backtrace::framed]
-    async fn to_block(&self, info: &Vec<(&str, Vec<(String, ColumnInfo)>)>) -> Result<DataBlock> {
+    async fn to_block(
+        &self,
+        info: &Vec<(&str, Vec<(String, Option<String>, ColumnInfo)>)>,
+    ) -> Result<DataBlock> {
         let mut validity_size = Vec::new();
         let mut compressed_size = Vec::new();
         let mut uncompressed_size = Vec::new();
@@ -168,9 +178,10 @@ impl<'a> FuseEncoding<'a> {
         let mut table_name = StringColumnBuilder::with_capacity(0, 0);
         let mut column_name = StringColumnBuilder::with_capacity(0, 0);
         let mut column_type = StringColumnBuilder::with_capacity(0, 0);
+        let mut column_codec = NullableColumnBuilder::<StringType>::with_capacity(0, &[]);
         let mut all_num_rows = 0;
         for (table, columns_info) in info {
-            for (type_str, column_info) in columns_info {
+            for (type_str, codec, column_info) in columns_info {
                 let pages_info = &column_info.pages;
                 let num_row = pages_info.len();
                 all_num_rows += num_row;
@@ -200,6 +211,11 @@ impl<'a> FuseEncoding<'a> {
                     } else {
                         l2.push_null();
                     }
+                    if let Some(codec) = codec {
+                        column_codec.push(codec.as_bytes());
+                    } else {
+                        column_codec.push_null();
+                    }
                 }
 
                 table_name.append_column(&tmp_table_name.build());
@@ -239,6 +255,10 @@ impl<'a> FuseEncoding<'a> {
                     DataType::Nullable(Box::new(DataType::String)),
                     Value::Column(Column::Nullable(Box::new(l2.build().upcast()))),
                 ),
+                BlockEntry::new(
+                    DataType::Nullable(Box::new(DataType::String)),
+                    Value::Column(Column::Nullable(Box::new(column_codec.build().upcast()))),
+                ),
             ],
             all_num_rows,
         ))
@@ -266,6 +286,10 @@ impl<'a> FuseEncoding<'a> {
                 "level_two",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new(
+                "codec",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ])
     }
 }
@@ -279,6 +303,7 @@ fn encoding_to_string(page_body: &PageBody) -> String {
         PageBody::Patas => "Patas".to_string(),
         PageBody::Bitpack => "Bitpack".to_string(),
         PageBody::DeltaBitpack => "DeltaBitpack".to_string(),
+        PageBody::DoubleDelta => "DoubleDelta".to_string(),
         PageBody::Common(c) => format!("Common({:?})", c),
     }
 }
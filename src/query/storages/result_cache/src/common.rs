@@ -12,15 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::hash::Hasher;
+use std::ops::Range;
+
 use sha2::Digest;
 use sha2::Sha256;
 
 const RESULT_CACHE_PREFIX: &str = "_result_cache";
-
-#[inline(always)]
-pub fn gen_result_cache_key(raw: &str) -> String {
-    format!("{:x}", Sha256::digest(raw))
-}
+const RESULT_CACHE_TABLE_PREFIX: &str = "_result_cache_table";
 
 #[inline(always)]
 pub fn gen_result_cache_meta_key(tenant: &str, key: &str) -> String {
@@ -32,6 +31,17 @@ pub fn gen_result_cache_prefix(tenant: &str) -> String {
     format!("{RESULT_CACHE_PREFIX}/{tenant}/")
 }
 
+#[inline(always)]
+pub(crate) fn gen_result_cache_table_prefix(table_id: u64) -> String {
+    format!("{RESULT_CACHE_TABLE_PREFIX}/{table_id}/")
+}
+
+/// Records that the cached result of `meta_key` depends on the table.
+#[inline(always)]
+pub(crate) fn gen_result_cache_table_key(table_id: u64, meta_key: &str) -> String {
+    format!("{}{meta_key}", gen_result_cache_table_prefix(table_id))
+}
+
 #[inline(always)]
 pub(crate) fn gen_result_cache_dir(key: &str) -> String {
     format!("{RESULT_CACHE_PREFIX}/{key}")
@@ -55,4 +65,85 @@ pub struct ResultCacheValue {
     pub partitions_shas: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
+    /// The offset of the query, the cached rows start at it in the result without limit.
+    #[serde(default)]
+    pub offset: usize,
+    /// The limit of the query, None if the cached rows run to the end of the result.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// The versions of the tables in the query when the result was cached.
+    #[serde(default)]
+    pub table_versions: Vec<ResultCacheTableVersion>,
+}
+
+impl ResultCacheValue {
+    /// Returns the range of the cached rows that answers the same plan with `offset` and
+    /// `limit`, or None if the cached rows do not cover it.
+    pub fn covered_range(&self, offset: usize, limit: Option<usize>) -> Option<Range<usize>> {
+        if offset < self.offset {
+            return None;
+        }
+        let cached_end = self.offset + self.num_rows;
+        // Fewer rows than the limit means there are no more rows after the cached ones.
+        let complete = self.limit.map_or(true, |limit| self.num_rows < limit);
+        let end = limit.map_or(usize::MAX, |limit| offset.saturating_add(limit));
+        if end > cached_end && !complete {
+            return None;
+        }
+        let start = (offset - self.offset).min(self.num_rows);
+        let end = end.min(cached_end) - self.offset;
+        Some(start..end.max(start))
+    }
+}
+
+/// Identifies the cached results of a query plan.
+#[derive(Clone, Debug)]
+pub struct ResultCacheKey {
+    /// The fingerprint of the plan without its outermost limit, the results of the plan
+    /// with different offsets and limits share it.
+    pub fingerprint: String,
+    pub offset: usize,
+    pub limit: Option<usize>,
+    /// The versions of the tables in the plan, sorted by the table id.
+    pub table_versions: Vec<ResultCacheTableVersion>,
+}
+
+impl ResultCacheKey {
+    /// Each offset and limit of the plan is cached under its own key.
+    pub fn limit_key(&self) -> String {
+        match self.limit {
+            Some(limit) => format!("{}/{}_{}", self.fingerprint, self.offset, limit),
+            None => format!("{}/{}_all", self.fingerprint, self.offset),
+        }
+    }
+}
+
+/// A table changes its version on each commit, and its snapshot location on time travel.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ResultCacheTableVersion {
+    pub table_id: u64,
+    pub seq: u64,
+    pub snapshot_location: Option<String>,
+}
+
+/// Feeds the `Hash` of a plan into sha256, so that the same plan has the same key on all
+/// the query nodes.
+#[derive(Default)]
+pub struct ResultCacheKeyHasher(Sha256);
+
+impl ResultCacheKeyHasher {
+    pub fn finish_key(self) -> String {
+        format!("{:x}", self.0.finalize())
+    }
+}
+
+impl Hasher for ResultCacheKeyHasher {
+    fn finish(&self) -> u64 {
+        let digest = self.0.clone().finalize();
+        u64::from_le_bytes(digest[..8].try_into().unwrap())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
 }
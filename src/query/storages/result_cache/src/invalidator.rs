@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio::time::sleep;
+use common_base::base::tokio::time::timeout;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_base::GLOBAL_TASK;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::deserialize_struct;
+use common_meta_app::schema::TableId;
+use common_meta_app::schema::TableMeta;
+use common_meta_kvapi::kvapi::prefix_to_range;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::Key;
+use common_meta_store::MetaStore;
+use common_meta_types::protobuf::watch_request::FilterType;
+use common_meta_types::protobuf::WatchRequest;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKV;
+use futures_util::StreamExt;
+use log::info;
+use log::warn;
+
+use crate::common::gen_result_cache_table_key;
+use crate::common::gen_result_cache_table_prefix;
+use crate::common::ResultCacheTableVersion;
+
+const WATCH_RETRY_INTERVAL_SECS: u64 = 5;
+/// The watcher is run by the query node holding the lease, the table ids are global
+/// so a single watcher serves all the tenants.
+const LEASE_KEY: &str = "_result_cache_invalidator";
+const LEASE_TTL_SECS: u64 = 30;
+const LEASE_RENEW_INTERVAL_SECS: u64 = 10;
+
+/// Removes the cached results of a table as soon as the table is dropped, by watching the
+/// table metas in the meta service.
+///
+/// The results cached on the older versions of a changed table are kept until they expire,
+/// they are only read by the sessions with `query_result_cache_allow_inconsistent`, since
+/// the table versions are checked on reading, and are overwritten by the next result of
+/// the same query.
+pub struct ResultCacheInvalidator;
+
+impl ResultCacheInvalidator {
+    pub fn init(meta_store: Arc<MetaStore>, node_id: String) -> Result<()> {
+        // The embedded meta store does not support watching, the results of the dropped
+        // tables are left to expire.
+        if meta_store.is_local() {
+            return Ok(());
+        }
+
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            loop {
+                match Self::acquire_lease(&meta_store, &node_id).await {
+                    Ok(true) => {
+                        if let Err(e) = Self::watch_tables(&meta_store, &node_id).await {
+                            warn!("result cache: fail to watch the table changes, {}", e);
+                        }
+                    }
+                    Ok(false) => {}
+                    Err(e) => warn!("result cache: cannot acquire the invalidator lease, {}", e),
+                }
+                sleep(Duration::from_secs(WATCH_RETRY_INTERVAL_SECS)).await;
+            }
+        });
+        Ok(())
+    }
+
+    /// Acquires or renews the lease of running the watcher, returns false if it is held
+    /// by another node.
+    #[async_backtrace::framed]
+    async fn acquire_lease(meta_store: &MetaStore, node_id: &str) -> Result<bool> {
+        let seq = match meta_store.get_kv(LEASE_KEY).await? {
+            Some(holder) if holder.data != node_id.as_bytes() => return Ok(false),
+            Some(holder) => MatchSeq::Exact(holder.seq),
+            None => MatchSeq::Exact(0),
+        };
+
+        let expire_at = (SystemTime::now() + Duration::from_secs(LEASE_TTL_SECS))
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        let reply = meta_store
+            .upsert_kv(UpsertKV::new(
+                LEASE_KEY,
+                seq,
+                Operation::Update(node_id.as_bytes().to_vec()),
+                Some(KVMeta {
+                    expire_at: Some(expire_at.as_secs()),
+                }),
+            ))
+            .await?;
+        Ok(reply.is_changed())
+    }
+
+    /// Records that the cached result of `meta_key` depends on the versions of the tables,
+    /// the records expire along with the cached result.
+    #[async_backtrace::framed]
+    pub async fn add_table_deps(
+        meta_store: &MetaStore,
+        meta_key: &str,
+        table_versions: &[ResultCacheTableVersion],
+        expire_at: u64,
+    ) -> Result<()> {
+        for version in table_versions {
+            meta_store
+                .upsert_kv(UpsertKV {
+                    key: gen_result_cache_table_key(version.table_id, meta_key),
+                    seq: MatchSeq::GE(0),
+                    value: Operation::Update(serde_json::to_vec(&version.seq)?),
+                    value_meta: Some(KVMeta {
+                        expire_at: Some(expire_at),
+                    }),
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Removes the cached results that depend on the table.
+    #[async_backtrace::framed]
+    pub async fn invalidate_table(meta_store: &MetaStore, table_id: u64) -> Result<()> {
+        let prefix = gen_result_cache_table_prefix(table_id);
+        for (dep_key, _) in meta_store.prefix_list_kv(&prefix).await? {
+            let meta_key = &dep_key[prefix.len()..];
+            for key in [meta_key.to_string(), dep_key.clone()] {
+                meta_store
+                    .upsert_kv(UpsertKV {
+                        key,
+                        seq: MatchSeq::GE(0),
+                        value: Operation::Delete,
+                        value_meta: None,
+                    })
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Watches the table metas until the stream ends or the lease is lost.
+    #[async_backtrace::framed]
+    async fn watch_tables(meta_store: &MetaStore, node_id: &str) -> Result<()> {
+        let prefix = format!("{}/", TableId::PREFIX);
+        let (key, key_end) =
+            prefix_to_range(&prefix).map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        let req = WatchRequest {
+            key,
+            key_end: Some(key_end),
+            filter_type: FilterType::All.into(),
+        };
+        let mut watch_stream = meta_store.watch(req).await?;
+        info!("result cache: start watching the table changes");
+
+        let renew_interval = Duration::from_secs(LEASE_RENEW_INTERVAL_SECS);
+        let mut renewed_at = Instant::now();
+        loop {
+            if renewed_at.elapsed() >= renew_interval {
+                if !Self::acquire_lease(meta_store, node_id).await? {
+                    info!("result cache: the invalidator lease is taken by another node");
+                    break;
+                }
+                renewed_at = Instant::now();
+            }
+            let wait = renew_interval.saturating_sub(renewed_at.elapsed());
+            let resp = match timeout(wait, watch_stream.next()).await {
+                Ok(Some(resp)) => resp,
+                Ok(None) => break,
+                Err(_) => continue,
+            };
+            let event = match resp?.event {
+                Some(event) => event,
+                None => continue,
+            };
+            let table_id = match TableId::from_str_key(&event.key) {
+                Ok(table_id) => table_id.table_id,
+                Err(_) => continue,
+            };
+            // The table meta is kept after the table is dropped, until it is vacuumed.
+            let dropped = match &event.current {
+                Some(v) => deserialize_struct::<TableMeta>(&v.data)
+                    .map(|meta| meta.drop_on.is_some())
+                    .unwrap_or(false),
+                None => true,
+            };
+            if !dropped {
+                continue;
+            }
+            if let Err(e) = Self::invalidate_table(meta_store, table_id).await {
+                warn!(
+                    "result cache: fail to invalidate the results of table {}, {}",
+                    table_id, e
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -16,14 +16,18 @@
 #![feature(impl_trait_in_assoc_type)]
 
 mod common;
+mod invalidator;
 mod meta_manager;
 mod read;
 mod table_function;
 mod write;
 
-pub use common::gen_result_cache_key;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
+pub use common::ResultCacheKey;
+pub use common::ResultCacheKeyHasher;
+pub use common::ResultCacheTableVersion;
+pub use invalidator::ResultCacheInvalidator;
 pub use meta_manager::ResultCacheMetaManager;
 pub use read::ResultCacheReader;
 pub use table_function::ResultScan;
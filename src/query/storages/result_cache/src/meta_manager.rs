@@ -84,6 +84,19 @@ impl ResultCacheMetaManager {
         Ok(r)
     }
 
+    #[async_backtrace::framed]
+    pub async fn list_entries(&self, prefix: &str) -> Result<Vec<(String, ResultCacheValue)>> {
+        let result = self.inner.prefix_list_kv(prefix).await?;
+
+        let mut r = Vec::with_capacity(result.len());
+        for (key, val) in result {
+            let u = serde_json::from_slice::<ResultCacheValue>(&val.data)?;
+            r.push((key, u));
+        }
+
+        Ok(r)
+    }
+
     pub fn get_ttl(&self) -> u64 {
         self.ttl
     }
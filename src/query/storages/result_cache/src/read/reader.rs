@@ -28,38 +28,41 @@ use common_storage::DataOperator;
 use opendal::Operator;
 
 use crate::common::gen_result_cache_meta_key;
+use crate::common::ResultCacheKey;
 use crate::common::ResultCacheValue;
 use crate::meta_manager::ResultCacheMetaManager;
 
 pub struct ResultCacheReader {
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
+    /// The prefix of the meta keys of all the offsets and limits of the plan.
+    meta_key_prefix: String,
 
     operator: Operator,
-    /// To ensure the cache is valid.
-    partitions_shas: Vec<String>,
+    key: ResultCacheKey,
 
     /// If true, the cache will be used even if it is inconsistent.
-    /// In another word, `partitions_sha` will not be checked.
+    /// In another word, the table versions will not be checked.
     tolerate_inconsistent: bool,
 }
 
 impl ResultCacheReader {
     pub fn create(
         ctx: Arc<dyn TableContext>,
-        key: &str,
+        key: ResultCacheKey,
         kv_store: Arc<MetaStore>,
         tolerate_inconsistent: bool,
     ) -> Self {
         let tenant = ctx.get_tenant();
-        let meta_key = gen_result_cache_meta_key(&tenant, key);
-        let partitions_shas = ctx.get_partitions_shas();
+        let meta_key = gen_result_cache_meta_key(&tenant, &key.limit_key());
+        let meta_key_prefix = format!("{}/", gen_result_cache_meta_key(&tenant, &key.fingerprint));
 
         Self {
             meta_mgr: ResultCacheMetaManager::create(kv_store, 0),
             meta_key,
-            partitions_shas,
+            meta_key_prefix,
             operator: DataOperator::instance().operator(),
+            key,
             tolerate_inconsistent,
         }
     }
@@ -68,42 +71,50 @@ impl ResultCacheReader {
         self.meta_key.clone()
     }
 
+    /// Finds a valid cached result of the plan that covers the offset and limit of the query,
+    /// the one of the same offset and limit is preferred.
     #[async_backtrace::framed]
-    pub async fn check_cache(&self) -> Result<Option<ResultCacheValue>> {
-        if let Some(v) = self.meta_mgr.get(self.meta_key.clone()).await? {
-            if self.tolerate_inconsistent || v.partitions_shas == self.partitions_shas {
-                return Ok(Some(v));
+    pub async fn check_cache(&self) -> Result<Option<(String, ResultCacheValue)>> {
+        let mut found = None;
+        for (meta_key, value) in self.meta_mgr.list_entries(&self.meta_key_prefix).await? {
+            if !self.tolerate_inconsistent && value.table_versions != self.key.table_versions {
+                // The cache is invalid (due to data update or other reasons).
+                continue;
+            }
+            if meta_key == self.meta_key {
+                return Ok(Some((meta_key, value)));
+            }
+            if found.is_none()
+                && value
+                    .covered_range(self.key.offset, self.key.limit)
+                    .is_some()
+            {
+                found = Some((meta_key, value));
             }
         }
-        Ok(None)
+        Ok(found)
     }
 
+    /// Returns the meta key of the cached result being read along with the rows of the query.
     #[async_backtrace::framed]
-    pub async fn try_read_cached_result(&self) -> Result<Option<Vec<DataBlock>>> {
-        self.try_read_cached_result_with_meta_key(self.meta_key.clone())
-            .await
-    }
+    pub async fn try_read_cached_result(&self) -> Result<Option<(String, Vec<DataBlock>)>> {
+        let (meta_key, value) = match self.check_cache().await? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        let range = value
+            .covered_range(self.key.offset, self.key.limit)
+            .unwrap_or(0..value.num_rows);
+        if range.is_empty() {
+            return Ok(Some((meta_key, vec![DataBlock::empty()])));
+        }
 
-    #[async_backtrace::framed]
-    pub async fn try_read_cached_result_with_meta_key(
-        &self,
-        meta_key: String,
-    ) -> Result<Option<Vec<DataBlock>>> {
-        match self.meta_mgr.get(meta_key).await? {
-            Some(value) => {
-                if self.tolerate_inconsistent || value.partitions_shas == self.partitions_shas {
-                    if value.num_rows == 0 {
-                        Ok(Some(vec![DataBlock::empty()]))
-                    } else {
-                        Ok(Some(self.read_result_from_cache(&value.location).await?))
-                    }
-                } else {
-                    // The cache is invalid (due to data update or other reasons).
-                    Ok(None)
-                }
-            }
-            None => Ok(None),
+        let blocks = self.read_result_from_cache(&value.location).await?;
+        if range.len() == value.num_rows {
+            return Ok(Some((meta_key, blocks)));
         }
+        let block = DataBlock::concat(&blocks)?;
+        Ok(Some((meta_key, vec![block.slice(range)])))
     }
 
     #[async_backtrace::framed]
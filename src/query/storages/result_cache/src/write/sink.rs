@@ -30,14 +30,18 @@ use common_storage::DataOperator;
 use super::writer::ResultCacheWriter;
 use crate::common::gen_result_cache_dir;
 use crate::common::gen_result_cache_meta_key;
+use crate::common::ResultCacheKey;
 use crate::common::ResultCacheValue;
+use crate::invalidator::ResultCacheInvalidator;
 use crate::meta_manager::ResultCacheMetaManager;
 
 pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
     partitions_shas: Vec<String>,
+    key: ResultCacheKey,
 
+    kv_store: Arc<MetaStore>,
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
     cache_writer: ResultCacheWriter,
@@ -82,10 +86,22 @@ impl AsyncMpscSink for WriteResultCacheSink {
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
             location,
+            offset: self.key.offset,
+            limit: self.key.limit,
+            table_versions: self.key.table_versions.clone(),
         };
         self.meta_mgr
             .set(self.meta_key.clone(), value, MatchSeq::GE(0), expire_at)
             .await?;
+
+        // 3. Record the tables the result depends on, so that it can be removed on changes.
+        ResultCacheInvalidator::add_table_deps(
+            &self.kv_store,
+            &self.meta_key,
+            &self.key.table_versions,
+            expire_at,
+        )
+        .await?;
         self.ctx
             .set_query_id_result_cache(self.ctx.get_id(), self.meta_key.clone());
         Ok(())
@@ -95,7 +111,7 @@ impl AsyncMpscSink for WriteResultCacheSink {
 impl WriteResultCacheSink {
     pub fn try_create(
         ctx: Arc<dyn TableContext>,
-        key: &str,
+        key: ResultCacheKey,
         schema: TableSchemaRef,
         inputs: Vec<Arc<InputPort>>,
         kv_store: Arc<MetaStore>,
@@ -107,8 +123,8 @@ impl WriteResultCacheSink {
         let sql = ctx.get_query_str();
         let partitions_shas = ctx.get_partitions_shas();
 
-        let meta_key = gen_result_cache_meta_key(&tenant, key);
-        let location = gen_result_cache_dir(key);
+        let meta_key = gen_result_cache_meta_key(&tenant, &key.limit_key());
+        let location = gen_result_cache_dir(&key.limit_key());
 
         let operator = DataOperator::instance().operator();
         let cache_writer = ResultCacheWriter::create(schema, location, operator, max_bytes);
@@ -119,7 +135,9 @@ impl WriteResultCacheSink {
                 ctx,
                 sql,
                 partitions_shas,
-                meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
+                key,
+                meta_mgr: ResultCacheMetaManager::create(kv_store.clone(), ttl),
+                kv_store,
                 meta_key,
                 cache_writer,
             },
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use serde::Serialize;
+
+use crate::query_log_table::datetime_str;
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// An audit event of a login, a DDL, a privilege change or an access denial.
+///
+/// Each event carries the hash of the previous event of the node, so a removed or modified
+/// event breaks the chain of the audit trail.
+#[derive(Clone, Serialize)]
+pub struct AuditLogElement {
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+    // `login`, `ddl`, `privilege`, `access_denied` or `gap`
+    pub event_type: String,
+
+    // Who and from where.
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub node_id: String,
+    pub user: String,
+    pub client_address: String,
+
+    // What.
+    pub query_id: String,
+    // the plan or the auth method, such as `CreateTable` or `password`
+    pub action: String,
+    pub object: String,
+    pub query_text: String,
+
+    // `allowed` or `denied`
+    pub result: String,
+    pub error_code: i32,
+    pub error_message: String,
+
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl SystemLogElement for AuditLogElement {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("event_type", TableDataType::String),
+            TableField::new("tenant_id", TableDataType::String),
+            TableField::new("cluster_id", TableDataType::String),
+            TableField::new("node_id", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("action", TableDataType::String),
+            TableField::new("object", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("result", TableDataType::String),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+            TableField::new("prev_hash", TableDataType::String),
+            TableField::new("hash", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        for value in [
+            &self.event_type,
+            &self.tenant_id,
+            &self.cluster_id,
+            &self.node_id,
+            &self.user,
+            &self.client_address,
+            &self.query_id,
+            &self.action,
+            &self.object,
+            &self.query_text,
+            &self.result,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        for value in [&self.error_message, &self.prev_hash, &self.hash] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditLogElement>;
+pub type AuditLogTable = SystemLogTable<AuditLogElement>;
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A decision made by the compaction scheduler on a fragmented table.
+#[derive(Clone)]
+pub struct CompactionHistoryLogElement {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub database: String,
+    pub table: String,
+    pub segment_count: u64,
+    pub block_count: u64,
+    pub excess_segments: u64,
+    pub undersized_blocks: u64,
+    // `compact`, `backoff` or `deferred`
+    pub decision: String,
+    pub compact_segment: bool,
+    pub compact_block: bool,
+    // `success`, `conflict`, `failed` or `skipped`
+    pub status: String,
+    // the number of consecutive failed attempts
+    pub failures: u64,
+    pub message: String,
+}
+
+impl SystemLogElement for CompactionHistoryLogElement {
+    const TABLE_NAME: &'static str = "compaction_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new(
+                "segment_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("block_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "excess_segments",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "undersized_blocks",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("decision", TableDataType::String),
+            TableField::new("compact_segment", TableDataType::Boolean),
+            TableField::new("compact_block", TableDataType::Boolean),
+            TableField::new("status", TableDataType::String),
+            TableField::new("failures", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.end_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.database.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.table.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.segment_count)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.block_count)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.excess_segments)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.undersized_blocks)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.decision.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.compact_segment).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.compact_block).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.status.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.failures)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.message.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type CompactionHistoryQueue = SystemLogQueue<CompactionHistoryLogElement>;
+pub type CompactionHistoryTable = SystemLogTable<CompactionHistoryLogElement>;
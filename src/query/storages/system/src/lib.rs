@@ -18,6 +18,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -27,6 +28,7 @@ mod catalogs_table;
 mod clustering_history_table;
 mod clusters_table;
 mod columns_table;
+mod compaction_history_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
@@ -58,6 +60,9 @@ mod tracing_table;
 mod users_table;
 mod util;
 
+pub use audit_log_table::AuditLogElement;
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -69,6 +74,9 @@ pub use clustering_history_table::ClusteringHistoryQueue;
 pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
+pub use compaction_history_table::CompactionHistoryLogElement;
+pub use compaction_history_table::CompactionHistoryQueue;
+pub use compaction_history_table::CompactionHistoryTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
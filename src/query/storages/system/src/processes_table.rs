@@ -71,6 +71,7 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
+        let mut processes_workload_group = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
@@ -118,6 +119,12 @@ impl SyncSystemTable for ProcessesTable {
                     .unwrap_or("".to_owned())
                     .into_bytes(),
             );
+            processes_workload_group.push(
+                process_info
+                    .workload_group
+                    .clone()
+                    .map(|group| group.into_bytes()),
+            );
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -137,6 +144,7 @@ impl SyncSystemTable for ProcessesTable {
             UInt32Type::from_opt_data(processes_mysql_connection_id),
             UInt64Type::from_data(processes_time),
             StringType::from_data(processes_status),
+            StringType::from_opt_data(processes_workload_group),
         ]))
     }
 }
@@ -178,6 +186,10 @@ impl ProcessesTable {
             ),
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("status", TableDataType::String),
+            TableField::new(
+                "workload_group",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use common_base::base::mask_string;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use jwt_simple::algorithms::ECDSAP256PublicKeyLike;
@@ -22,30 +27,102 @@ use jwt_simple::prelude::JWTClaims;
 use jwt_simple::token::Token;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
+use super::introspection::TokenIntrospector;
 use super::jwk;
 
+const DEFAULT_INTROSPECTION_CACHE_TTL_SECS: u64 = 60;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct JwtConfig {
+    /// The audiences allowed for the tokens of an issuer, in the form of `issuer=audience`.
+    /// Once any issuer rule is set, the tokens of the issuers not listed are rejected.
+    pub issuer_audiences: Vec<String>,
+    /// The claims the tokens of an issuer must have, in the form of `issuer=claim`.
+    pub issuer_required_claims: Vec<String>,
+    /// The claim which lists the groups of the user, such as `groups`.
+    pub group_claim: String,
+    /// The roles granted to the members of the groups, in the form of `group:role`.
+    pub group_role_mapping: Vec<String>,
+    /// The OAuth2 token introspection endpoint (RFC 7662) to validate the opaque access tokens,
+    /// introspection is disabled if empty.
+    pub introspection_url: String,
+    /// The client to authenticate to the introspection endpoint with.
+    pub client_id: String,
+    pub client_secret: String,
+    /// How long an active token is trusted without introspecting it again.
+    pub introspection_cache_ttl_secs: u64,
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        JwtConfig {
+            issuer_audiences: vec![],
+            issuer_required_claims: vec![],
+            group_claim: "".to_string(),
+            group_role_mapping: vec![],
+            introspection_url: "".to_string(),
+            client_id: "".to_string(),
+            client_secret: "".to_string(),
+            introspection_cache_ttl_secs: DEFAULT_INTROSPECTION_CACHE_TTL_SECS,
+        }
+    }
+}
+
+impl fmt::Debug for JwtConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtConfig")
+            .field("issuer_audiences", &self.issuer_audiences)
+            .field("issuer_required_claims", &self.issuer_required_claims)
+            .field("group_claim", &self.group_claim)
+            .field("group_role_mapping", &self.group_role_mapping)
+            .field("introspection_url", &self.introspection_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &mask_string(&self.client_secret, 3))
+            .field(
+                "introspection_cache_ttl_secs",
+                &self.introspection_cache_ttl_secs,
+            )
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum PubKey {
     RSA256(RS256PublicKey),
     ES256(ES256PublicKey),
 }
 
+/// The rules of the tokens of an issuer.
+#[derive(Debug, Default)]
+struct IssuerRules {
+    audiences: HashSet<String>,
+    required_claims: Vec<String>,
+}
+
 pub struct JwtAuthenticator {
-    // Todo(youngsofun): verify settings, like issuer
     key_stores: Vec<jwk::JwkKeyStore>,
+    introspector: Option<TokenIntrospector>,
+    issuer_rules: HashMap<String, IssuerRules>,
+    group_claim: String,
+    group_roles: Vec<(String, String)>,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct EnsureUser {
     pub roles: Option<Vec<String>>,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct CustomClaims {
     pub tenant_id: Option<String>,
     pub role: Option<String>,
     pub ensure_user: Option<EnsureUser>,
+    /// The other claims, such as the groups of the user.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 impl CustomClaims {
@@ -54,6 +131,7 @@ impl CustomClaims {
             tenant_id: None,
             role: None,
             ensure_user: None,
+            extra: Map::new(),
         }
     }
 
@@ -75,19 +153,95 @@ impl CustomClaims {
         self.role = Some(role.to_string());
         self
     }
+
+    pub fn with_claim(mut self, name: &str, value: Value) -> Self {
+        self.extra.insert(name.to_string(), value);
+        self
+    }
+}
+
+fn split_issuer_rule(rule: &str) -> Result<(String, String)> {
+    match rule.rsplit_once('=') {
+        Some((issuer, value)) if !issuer.trim().is_empty() && !value.trim().is_empty() => {
+            Ok((issuer.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(ErrorCode::InvalidConfig(format!(
+            "invalid jwt issuer rule {}, expect `issuer=value`",
+            rule
+        ))),
+    }
 }
 
 impl JwtAuthenticator {
-    pub fn create(jwt_key_file: String, jwt_key_files: Vec<String>) -> Option<Self> {
-        if jwt_key_file.is_empty() && jwt_key_files.is_empty() {
-            return None;
+    /// Returns None if neither the jwt key files nor the token introspection is configured.
+    pub fn create(
+        jwt_key_file: String,
+        jwt_key_files: Vec<String>,
+        config: &JwtConfig,
+    ) -> Result<Option<Self>> {
+        if jwt_key_file.is_empty()
+            && jwt_key_files.is_empty()
+            && config.introspection_url.is_empty()
+        {
+            return Ok(None);
         }
         // init a vec of key store
-        let mut key_stores = vec![jwk::JwkKeyStore::new(jwt_key_file)];
-        for u in jwt_key_files {
-            key_stores.push(jwk::JwkKeyStore::new(u))
+        let mut key_stores = vec![];
+        for u in [jwt_key_file].into_iter().chain(jwt_key_files) {
+            if !u.is_empty() {
+                key_stores.push(jwk::JwkKeyStore::new(u))
+            }
         }
-        Some(JwtAuthenticator { key_stores })
+
+        let mut issuer_rules: HashMap<String, IssuerRules> = HashMap::new();
+        for rule in &config.issuer_audiences {
+            let (issuer, audience) = split_issuer_rule(rule)?;
+            issuer_rules
+                .entry(issuer)
+                .or_default()
+                .audiences
+                .insert(audience);
+        }
+        for rule in &config.issuer_required_claims {
+            let (issuer, claim) = split_issuer_rule(rule)?;
+            issuer_rules
+                .entry(issuer)
+                .or_default()
+                .required_claims
+                .push(claim);
+        }
+
+        let mut group_roles = Vec::with_capacity(config.group_role_mapping.len());
+        for mapping in &config.group_role_mapping {
+            match mapping.rsplit_once(':') {
+                Some((group, role)) if !group.trim().is_empty() && !role.trim().is_empty() => {
+                    group_roles.push((group.trim().to_string(), role.trim().to_string()));
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "invalid jwt group role mapping {}, expect `group:role`",
+                        mapping
+                    )));
+                }
+            }
+        }
+        if !group_roles.is_empty() && config.group_claim.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "jwt group role mapping requires the group claim",
+            ));
+        }
+
+        let introspector = match config.introspection_url.is_empty() {
+            true => None,
+            false => Some(TokenIntrospector::create(config)),
+        };
+        Ok(Some(JwtAuthenticator {
+            key_stores,
+            introspector,
+            issuer_rules,
+            group_claim: config.group_claim.clone(),
+            group_roles,
+        }))
     }
 
     // parse jwt claims from single source, if custom claim is not matching on desired, claim parsed would be empty
@@ -113,7 +267,7 @@ impl JwtAuthenticator {
         }
     }
     #[async_backtrace::framed]
-    pub async fn parse_jwt_claims(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
+    async fn parse_signed_jwt_claims(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
         let mut combined_code = ErrorCode::AuthenticateFailure(
             "could not decode token from all available jwt key stores. ",
         );
@@ -133,4 +287,105 @@ impl JwtAuthenticator {
         }
         Err(combined_code)
     }
+
+    /// Validates a token and returns its claims. The signed jwt are verified with the jwt key
+    /// stores, and the other tokens are introspected by the OAuth2 authorization server.
+    #[async_backtrace::framed]
+    pub async fn parse_jwt_claims(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
+        let is_signed_jwt = Token::decode_metadata(token).is_ok();
+        let claims = match &self.introspector {
+            Some(introspector) if !is_signed_jwt || self.key_stores.is_empty() => {
+                introspector.introspect(token).await?
+            }
+            _ => self.parse_signed_jwt_claims(token).await?,
+        };
+        self.check_issuer_rules(&claims)?;
+        Ok(claims)
+    }
+
+    // Once any issuer rule is configured, only the tokens of the listed issuers are accepted.
+    fn check_issuer_rules(&self, claims: &JWTClaims<CustomClaims>) -> Result<()> {
+        if self.issuer_rules.is_empty() {
+            return Ok(());
+        }
+        let Some(issuer) = &claims.issuer else {
+            return Err(ErrorCode::AuthenticateFailure("missing issuer in jwt"));
+        };
+        let Some(rules) = self.issuer_rules.get(issuer) else {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "jwt issuer `{}` is not allowed",
+                issuer
+            )));
+        };
+
+        if !rules.audiences.is_empty()
+            && !claims
+                .audiences
+                .as_ref()
+                .map_or(false, |audiences| audiences.contains(&rules.audiences))
+        {
+            return Err(ErrorCode::AuthenticateFailure(
+                "jwt audience is not allowed for the issuer",
+            ));
+        }
+        for claim in &rules.required_claims {
+            let present = match claim.as_str() {
+                "iss" => claims.issuer.is_some(),
+                "sub" => claims.subject.is_some(),
+                "aud" => claims.audiences.is_some(),
+                "exp" => claims.expires_at.is_some(),
+                "nbf" => claims.invalid_before.is_some(),
+                "iat" => claims.issued_at.is_some(),
+                "jti" => claims.jwt_id.is_some(),
+                "nonce" => claims.nonce.is_some(),
+                "tenant_id" => claims.custom.tenant_id.is_some(),
+                "role" => claims.custom.role.is_some(),
+                "ensure_user" => claims.custom.ensure_user.is_some(),
+                name => claims
+                    .custom
+                    .extra
+                    .get(name)
+                    .map_or(false, |v| !v.is_null()),
+            };
+            if !present {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "missing required claim `{}` in jwt",
+                    claim
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The roles of the groups in the group claim by the group role mapping, in the order of
+    /// the mapping.
+    pub fn map_roles(&self, claims: &JWTClaims<CustomClaims>) -> Vec<String> {
+        let groups = match claims.custom.extra.get(&self.group_claim) {
+            Some(Value::Array(groups)) => groups
+                .iter()
+                .filter_map(|group| group.as_str())
+                .collect::<Vec<_>>(),
+            Some(Value::String(group)) => vec![group.as_str()],
+            _ => vec![],
+        };
+        let mut roles: Vec<String> = vec![];
+        for (group, role) in &self.group_roles {
+            if groups.contains(&group.as_str()) && !roles.contains(role) {
+                roles.push(role.clone());
+            }
+        }
+        roles
+    }
+
+    /// All the roles in the group role mapping, which are granted and revoked by the logins.
+    pub fn mapped_roles(&self) -> Vec<String> {
+        let mut roles = self
+            .group_roles
+            .iter()
+            .map(|(_, role)| role.clone())
+            .collect::<Vec<_>>();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
 }
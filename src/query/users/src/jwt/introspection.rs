@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::num::NonZeroUsize;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use jwt_simple::prelude::Clock;
+use jwt_simple::prelude::JWTClaims;
+use lru::LruCache;
+use parking_lot::Mutex;
+use serde_json::Value;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::CustomClaims;
+use super::JwtConfig;
+
+const INTROSPECTION_CACHE_CAPACITY: usize = 10000;
+
+/// Validates the opaque access tokens by the OAuth2 token introspection endpoint (RFC 7662).
+///
+/// The active tokens are cached until the cache ttl or their expiration, whichever comes
+/// first, so that a login does not always cost a round trip to the authorization server.
+/// The cache keeps the most recently used tokens, keyed by their sha256 so that the tokens
+/// themselves are not kept in memory.
+pub struct TokenIntrospector {
+    url: String,
+    client_id: String,
+    client_secret: String,
+    cache_ttl: Duration,
+    client: reqwest::Client,
+    cache: Mutex<LruCache<[u8; 32], (JWTClaims<CustomClaims>, Instant)>>,
+}
+
+impl TokenIntrospector {
+    pub fn create(config: &JwtConfig) -> Self {
+        TokenIntrospector {
+            url: config.introspection_url.clone(),
+            client_id: config.client_id.clone(),
+            client_secret: config.client_secret.clone(),
+            cache_ttl: Duration::from_secs(config.introspection_cache_ttl_secs),
+            client: reqwest::Client::new(),
+            cache: Mutex::new(LruCache::new(
+                NonZeroUsize::new(INTROSPECTION_CACHE_CAPACITY).unwrap(),
+            )),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn introspect(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
+        let key: [u8; 32] = Sha256::digest(token.as_bytes()).into();
+        if let Some(claims) = self.get_cached(&key) {
+            return Ok(claims);
+        }
+
+        let mut request = self
+            .client
+            .post(&self.url)
+            .form(&[("token", token), ("token_type_hint", "access_token")]);
+        if !self.client_id.is_empty() {
+            request = request.basic_auth(&self.client_id, Some(&self.client_secret));
+        }
+        let response = request.send().await.map_err(|e| {
+            ErrorCode::AuthenticateFailure(format!("could not introspect token: {}", e))
+        })?;
+        if !response.status().is_success() {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "could not introspect token: status {} from {}",
+                response.status(),
+                self.url
+            )));
+        }
+        let body = response.text().await.map_err(|e| {
+            ErrorCode::AuthenticateFailure(format!("could not introspect token: {}", e))
+        })?;
+        let mut claims = serde_json::from_str::<JWTClaims<CustomClaims>>(&body).map_err(|e| {
+            ErrorCode::AuthenticateFailure(format!(
+                "failed to parse token introspection response: {}",
+                e
+            ))
+        })?;
+
+        if claims.custom.extra.get("active") != Some(&Value::Bool(true)) {
+            return Err(ErrorCode::AuthenticateFailure("token is not active"));
+        }
+        // `sub` is optional in the introspection response, fall back to `username`.
+        if claims.subject.is_none() {
+            claims.subject = claims
+                .custom
+                .extra
+                .get("username")
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string());
+        }
+
+        let mut ttl = self.cache_ttl;
+        if let Some(expires_at) = claims.expires_at {
+            let now = Clock::now_since_epoch();
+            if expires_at <= now {
+                return Err(ErrorCode::AuthenticateFailure("token has expired"));
+            }
+            ttl = ttl.min(Duration::from_secs((expires_at - now).as_secs()));
+        }
+        if !ttl.is_zero() {
+            let mut cache = self.cache.lock();
+            cache.put(key, (claims.clone(), Instant::now() + ttl));
+        }
+        Ok(claims)
+    }
+
+    fn get_cached(&self, key: &[u8; 32]) -> Option<JWTClaims<CustomClaims>> {
+        let mut cache = self.cache.lock();
+        match cache.get(key) {
+            Some((claims, deadline)) if *deadline > Instant::now() => Some(claims.clone()),
+            Some(_) => {
+                cache.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+}
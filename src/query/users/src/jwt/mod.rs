@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod authenticator;
+mod introspection;
 mod jwk;
 
 pub use authenticator::CustomClaims;
 pub use authenticator::EnsureUser;
 pub use authenticator::JwtAuthenticator;
+pub use authenticator::JwtConfig;
 pub use authenticator::PubKey;
@@ -0,0 +1,359 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::time::Duration;
+
+use common_base::base::mask_string;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use ldap3::ldap_escape;
+use ldap3::parse_filter;
+use ldap3::Ldap;
+use ldap3::LdapConnAsync;
+use ldap3::LdapConnSettings;
+use ldap3::LdapError;
+use ldap3::Scope;
+use ldap3::SearchEntry;
+use ldap3::SearchOptions;
+
+pub const DEFAULT_LDAP_USER_FILTER: &str = "(uid={username})";
+pub const DEFAULT_LDAP_GROUP_ATTRIBUTE: &str = "memberOf";
+
+/// The max number of groups read by the group filter.
+const MAX_GROUPS: i32 = 1000;
+
+/// The timeout of connecting to the server and of each request.
+const LDAP_TIMEOUT: Duration = Duration::from_secs(10);
+
+const RESULT_SIZE_LIMIT_EXCEEDED: u32 = 4;
+const RESULT_INVALID_CREDENTIALS: u32 = 49;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct LdapConfig {
+    /// `ldap://host[:port]` or `ldaps://host[:port]`, LDAP authentication is disabled if empty.
+    pub url: String,
+    /// Upgrades the connection of an `ldap://` url by StartTLS.
+    pub start_tls: bool,
+    /// Allows an `ldap://` url without StartTLS, which sends the passwords in cleartext.
+    pub allow_insecure: bool,
+    /// The account to search the users with, the search is anonymous if empty.
+    pub bind_dn: String,
+    pub bind_password: String,
+    pub base_dn: String,
+    /// The filter to find the entry of a user, `{username}` is replaced with the login name.
+    pub user_filter: String,
+    /// The attribute of a user entry which lists the groups of the user, such as `memberOf`.
+    pub group_attribute: String,
+    /// The filter to find the groups of a user, `{dn}` is replaced with the dn of the user
+    /// and `{username}` with the login name, such as `(&(objectClass=groupOfNames)(member={dn}))`.
+    pub group_filter: String,
+    /// The roles granted to the members of the groups, in the form of `group:role`. The group is
+    /// either the dn of the group or the value of its first RDN, such as
+    /// `cn=analysts,dc=example,dc=org` or `analysts`.
+    pub group_role_mapping: Vec<String>,
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        LdapConfig {
+            url: "".to_string(),
+            start_tls: false,
+            allow_insecure: false,
+            bind_dn: "".to_string(),
+            bind_password: "".to_string(),
+            base_dn: "".to_string(),
+            user_filter: DEFAULT_LDAP_USER_FILTER.to_string(),
+            group_attribute: DEFAULT_LDAP_GROUP_ATTRIBUTE.to_string(),
+            group_filter: "".to_string(),
+            group_role_mapping: vec![],
+        }
+    }
+}
+
+impl fmt::Debug for LdapConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LdapConfig")
+            .field("url", &self.url)
+            .field("start_tls", &self.start_tls)
+            .field("allow_insecure", &self.allow_insecure)
+            .field("bind_dn", &self.bind_dn)
+            .field("bind_password", &mask_string(&self.bind_password, 3))
+            .field("base_dn", &self.base_dn)
+            .field("user_filter", &self.user_filter)
+            .field("group_attribute", &self.group_attribute)
+            .field("group_filter", &self.group_filter)
+            .field("group_role_mapping", &self.group_role_mapping)
+            .finish()
+    }
+}
+
+/// A user authenticated by the LDAP server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LdapUser {
+    pub dn: String,
+    /// The dn of the groups of the user.
+    pub groups: Vec<String>,
+}
+
+pub struct LdapAuthenticator {
+    config: LdapConfig,
+    /// The lower case group and the role.
+    group_roles: Vec<(String, String)>,
+}
+
+impl LdapAuthenticator {
+    /// Returns None if LDAP authentication is not configured.
+    pub fn create(config: &LdapConfig) -> Result<Option<Self>> {
+        if config.url.is_empty() {
+            return Ok(None);
+        }
+        check_url(config)?;
+        // check the filters when the server starts, not on the first login.
+        check_filter(&config.user_filter.replace("{username}", "test"))?;
+        if !config.group_filter.is_empty() {
+            check_filter(
+                &config
+                    .group_filter
+                    .replace("{username}", "test")
+                    .replace("{dn}", "test"),
+            )?;
+        }
+
+        let mut group_roles = Vec::with_capacity(config.group_role_mapping.len());
+        for mapping in &config.group_role_mapping {
+            match mapping.rsplit_once(':') {
+                Some((group, role)) if !group.trim().is_empty() && !role.trim().is_empty() => {
+                    group_roles.push((group.trim().to_lowercase(), role.trim().to_string()));
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "invalid ldap group role mapping {}, expect `group:role`",
+                        mapping
+                    )));
+                }
+            }
+        }
+        Ok(Some(LdapAuthenticator {
+            config: config.clone(),
+            group_roles,
+        }))
+    }
+
+    /// Checks the password of a user by binding as the user, and returns the user with its
+    /// groups.
+    ///
+    /// Returns None if the user is not found or the password is wrong, which is counted as a
+    /// failed login of the user.
+    #[async_backtrace::framed]
+    pub async fn authenticate(&self, username: &str, password: &str) -> Result<Option<LdapUser>> {
+        // a simple bind without a password is an anonymous bind, which always succeeds.
+        if password.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure("password required"));
+        }
+
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(LDAP_TIMEOUT)
+            .set_starttls(self.config.start_tls);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &self.config.url)
+            .await
+            .map_err(|e| ldap_error(e, "connect"))?;
+        ldap3::drive!(conn);
+
+        let res = self.authenticate_with(&mut ldap, username, password).await;
+        let _ = ldap.unbind().await;
+        res
+    }
+
+    async fn authenticate_with(
+        &self,
+        ldap: &mut Ldap,
+        username: &str,
+        password: &str,
+    ) -> Result<Option<LdapUser>> {
+        ldap.with_timeout(LDAP_TIMEOUT)
+            .simple_bind(&self.config.bind_dn, &self.config.bind_password)
+            .await
+            .and_then(|res| res.success())
+            .map_err(|e| ldap_error(e, "bind ldap search account"))?;
+
+        let username_value = ldap_escape(username);
+        let filter = self
+            .config
+            .user_filter
+            .replace("{username}", &username_value);
+        let group_attribute = self.config.group_attribute.as_str();
+        // `1.1` is no attributes.
+        let attribute = match group_attribute.is_empty() {
+            true => "1.1",
+            false => group_attribute,
+        };
+        let mut entries = search(ldap, &self.config.base_dn, &filter, attribute, 2).await?;
+        let entry = match entries.len() {
+            0 => return Ok(None),
+            1 => entries.remove(0),
+            _ => {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "ldap user filter {} matches more than one entry",
+                    filter
+                )));
+            }
+        };
+
+        // the attribute names are case insensitive.
+        let mut groups = entry
+            .attrs
+            .iter()
+            .filter(|(name, _)| {
+                !group_attribute.is_empty() && name.eq_ignore_ascii_case(group_attribute)
+            })
+            .flat_map(|(_, values)| values.iter().cloned())
+            .collect::<Vec<_>>();
+        if !self.config.group_filter.is_empty() {
+            let filter = self
+                .config
+                .group_filter
+                .replace("{username}", &username_value)
+                .replace("{dn}", &ldap_escape(entry.dn.as_str()));
+            let entries = search(ldap, &self.config.base_dn, &filter, "1.1", MAX_GROUPS).await?;
+            groups.extend(entries.into_iter().map(|group| group.dn));
+        }
+        groups.sort();
+        groups.dedup();
+
+        match ldap
+            .with_timeout(LDAP_TIMEOUT)
+            .simple_bind(&entry.dn, password)
+            .await
+        {
+            Ok(res) if res.rc == RESULT_INVALID_CREDENTIALS => return Ok(None),
+            res => {
+                res.and_then(|res| res.success())
+                    .map_err(|e| ldap_error(e, "bind"))?;
+            }
+        }
+        Ok(Some(LdapUser {
+            dn: entry.dn,
+            groups,
+        }))
+    }
+
+    /// The roles of the members of `groups` by the group role mapping.
+    pub fn map_roles(&self, groups: &[String]) -> Vec<String> {
+        let names = groups
+            .iter()
+            .flat_map(|group| {
+                let group = group.to_lowercase();
+                let rdn_value = group
+                    .split(',')
+                    .next()
+                    .and_then(|rdn| rdn.split_once('='))
+                    .map(|(_, value)| value.trim().to_string());
+                [Some(group), rdn_value]
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        let mut roles = self
+            .group_roles
+            .iter()
+            .filter(|(group, _)| names.contains(group))
+            .map(|(_, role)| role.clone())
+            .collect::<Vec<_>>();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+
+    /// All the roles in the group role mapping, which are granted and revoked by the logins.
+    pub fn mapped_roles(&self) -> Vec<String> {
+        let mut roles = self
+            .group_roles
+            .iter()
+            .map(|(_, role)| role.clone())
+            .collect::<Vec<_>>();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+}
+
+/// Searches the subtree of `base_dn`, returns at most `size_limit` entries.
+async fn search(
+    ldap: &mut Ldap,
+    base_dn: &str,
+    filter: &str,
+    attribute: &str,
+    size_limit: i32,
+) -> Result<Vec<SearchEntry>> {
+    let res = ldap
+        .with_timeout(LDAP_TIMEOUT)
+        .with_search_options(SearchOptions::new().sizelimit(size_limit))
+        .search(base_dn, Scope::Subtree, filter, vec![attribute])
+        .await
+        .map_err(|e| ldap_error(e, "search"))?;
+    if res.1.rc != 0 && res.1.rc != RESULT_SIZE_LIMIT_EXCEEDED {
+        return Err(ldap_error(LdapError::from(res.1), "search"));
+    }
+    Ok(res.0.into_iter().map(SearchEntry::construct).collect())
+}
+
+/// The passwords are sent in cleartext on a plain `ldap://` connection, which must be upgraded
+/// by StartTLS unless it is explicitly allowed.
+fn check_url(config: &LdapConfig) -> Result<()> {
+    let bad_url = || ErrorCode::InvalidConfig(format!("invalid ldap url: {}", config.url));
+    let (tls, address) = if let Some(address) = config.url.strip_prefix("ldaps://") {
+        (true, address)
+    } else if let Some(address) = config.url.strip_prefix("ldap://") {
+        (false, address)
+    } else {
+        return Err(bad_url());
+    };
+    let address = address.trim_end_matches('/');
+    let host = match address.rsplit_once(':') {
+        Some((host, port)) => {
+            port.parse::<u16>().map_err(|_| bad_url())?;
+            host
+        }
+        None => address,
+    };
+    if host.is_empty() {
+        return Err(bad_url());
+    }
+
+    if tls && config.start_tls {
+        return Err(ErrorCode::InvalidConfig(format!(
+            "ldap url {} is already over TLS, StartTLS can not be used with it",
+            config.url
+        )));
+    }
+    if !tls && !config.start_tls && !config.allow_insecure {
+        return Err(ErrorCode::InvalidConfig(format!(
+            "ldap url {} sends the passwords in cleartext, use ldaps:// or ldap_start_tls, \
+            or set ldap_allow_insecure to allow it",
+            config.url
+        )));
+    }
+    Ok(())
+}
+
+fn check_filter(filter: &str) -> Result<()> {
+    // the outer parentheses are optional.
+    parse_filter(filter.trim())
+        .map(|_| ())
+        .map_err(|_| ErrorCode::InvalidConfig(format!("invalid ldap filter {}", filter)))
+}
+
+fn ldap_error(e: LdapError, op: &str) -> ErrorCode {
+    ErrorCode::AuthenticateFailure(format!("ldap {} failed: {}", op, e))
+}
@@ -19,7 +19,9 @@
 extern crate core;
 
 mod jwt;
+mod ldap;
 mod network_policy;
+mod password_policy;
 mod role_mgr;
 mod user;
 mod user_api;
@@ -28,6 +30,7 @@ mod user_setting;
 mod user_stage;
 mod user_udf;
 mod visibility_checker;
+mod workload_group;
 
 pub mod connection;
 pub mod file_format;
@@ -36,6 +39,9 @@ pub mod role_cache_mgr;
 pub mod role_util;
 
 pub use jwt::*;
+pub use ldap::*;
+pub use password_policy::check_password_complexity;
+pub use password_policy::check_password_policy;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
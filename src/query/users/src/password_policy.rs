@@ -0,0 +1,348 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::PasswordPolicyApi;
+use common_management::UserApi;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_LENGTH;
+use common_meta_app::principal::MAX_PASSWORD_HISTORY;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new password policy.
+    #[async_backtrace::framed]
+    pub async fn add_password_policy(
+        &self,
+        tenant: &str,
+        password_policy: PasswordPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        check_password_policy(&password_policy)?;
+        let client = self.get_password_policy_api_client(tenant)?;
+        match client.add_password_policy(password_policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PASSWORD_POLICY_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while add password policy)"))
+                }
+            }
+        }
+    }
+
+    // Update password policy with the function `f`.
+    #[async_backtrace::framed]
+    pub async fn update_password_policy<F>(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+        f: F,
+    ) -> Result<Option<u64>>
+    where
+        F: FnOnce(&mut PasswordPolicy) + Send,
+    {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let seq_password_policy = match client.get_password_policy(name, MatchSeq::GE(0)).await {
+            Ok(seq_password_policy) => seq_password_policy,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter password policy)"));
+                }
+            }
+        };
+
+        let seq = seq_password_policy.seq;
+        let mut password_policy = seq_password_policy.data;
+        f(&mut password_policy);
+        check_password_policy(&password_policy)?;
+        password_policy.update_on = Some(Utc::now());
+
+        match client
+            .update_password_policy(password_policy, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter password policy).")),
+        }
+    }
+
+    // Drop a password policy by name.
+    #[async_backtrace::framed]
+    pub async fn drop_password_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        for user_info in user_infos {
+            if user_info
+                .option
+                .password_policy()
+                .is_some_and(|p| p == name)
+            {
+                return Err(ErrorCode::PasswordPolicyIsUsedByUser(format!(
+                    "password policy `{}` is used by user",
+                    name,
+                )));
+            }
+        }
+
+        let client = self.get_password_policy_api_client(tenant)?;
+        match client.drop_password_policy(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop password policy)"))
+                }
+            }
+        }
+    }
+
+    // Get a password policy by name.
+    #[async_backtrace::framed]
+    pub async fn get_password_policy(&self, tenant: &str, name: &str) -> Result<PasswordPolicy> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let password_policy = client
+            .get_password_policy(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(password_policy)
+    }
+
+    // Get all password policies by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_password_policies(&self, tenant: &str) -> Result<Vec<PasswordPolicy>> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let password_policies = client
+            .get_password_policies()
+            .await
+            .map_err(|e| e.add_message_back(" (while get password policies)."))?;
+        Ok(password_policies)
+    }
+
+    // Check the new password of a user against the password policy of the user.
+    //
+    // `user_info` is the user before the change, None if the user is being created.
+    #[async_backtrace::framed]
+    pub async fn verify_password(
+        &self,
+        tenant: &str,
+        policy_name: &str,
+        password: &str,
+        user_info: Option<&UserInfo>,
+    ) -> Result<()> {
+        let policy = self.get_password_policy(tenant, policy_name).await?;
+        check_password_complexity(&policy, password)?;
+
+        let Some(user_info) = user_info else {
+            return Ok(());
+        };
+        if policy.min_age_days > 0 {
+            if let Some(update_on) = user_info.password_update_on {
+                if Utc::now() < update_on + Duration::days(policy.min_age_days as i64) {
+                    return Err(ErrorCode::InvalidPassword(format!(
+                        "password of user {} can not be changed within {} days since the last change",
+                        user_info.name, policy.min_age_days
+                    )));
+                }
+            }
+        }
+
+        // the current password and the latest `history` passwords can not be reused.
+        let history = std::iter::once(&user_info.auth_info)
+            .chain(user_info.history_auth_infos.iter())
+            .take(policy.history as usize + 1);
+        for auth_info in history {
+            if let AuthInfo::Password {
+                hash_value,
+                hash_method,
+            } = auth_info
+            {
+                if *hash_value == hash_method.hash(password.as_bytes()) {
+                    return Err(ErrorCode::InvalidPassword(format!(
+                        "password of user {} can not reuse the latest {} passwords",
+                        user_info.name, policy.history
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Check the user is not locked out and the password is not expired before login.
+    //
+    // The password of a user without `password_update_on` never expires, it is set when the
+    // password is changed or a password policy is attached to the user.
+    #[async_backtrace::framed]
+    pub async fn check_login_password(&self, tenant: &str, user_info: &UserInfo) -> Result<()> {
+        if let Some(lockout_time) = user_info.lockout_time {
+            if Utc::now() < lockout_time {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "user {} is locked until {} after too many failed logins",
+                    user_info.name, lockout_time
+                )));
+            }
+        }
+
+        let Some(name) = user_info.option.password_policy() else {
+            return Ok(());
+        };
+        let policy = self.get_password_policy(tenant, name).await?;
+        if policy.max_age_days > 0 {
+            if let Some(update_on) = user_info.password_update_on {
+                if Utc::now() >= update_on + Duration::days(policy.max_age_days as i64) {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "password of user {} is expired, please ask the administrator to reset it",
+                        user_info.name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Record a failed login of the user, the user is locked out after `max_retries` failed
+    // logins in a row.
+    #[async_backtrace::framed]
+    pub async fn update_user_login_fail(&self, tenant: &str, user_info: &UserInfo) -> Result<()> {
+        let Some(name) = user_info.option.password_policy() else {
+            return Ok(());
+        };
+        let policy = self.get_password_policy(tenant, name).await?;
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(
+                user_info.identity(),
+                MatchSeq::GE(1),
+                |ui: &mut UserInfo| {
+                    ui.update_login_fail(policy.max_retries, policy.lockout_time_mins)
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    // Clear the failed logins of the user, after a successful login or `ALTER USER ... UNLOCK`.
+    #[async_backtrace::framed]
+    pub async fn clear_user_login_fail(&self, tenant: &str, user: UserIdentity) -> Result<()> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Ok(());
+        }
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| {
+                ui.clear_login_fail()
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+/// Checks the limits of a password policy are consistent.
+pub fn check_password_policy(policy: &PasswordPolicy) -> Result<()> {
+    if policy.min_length == 0 || policy.min_length > policy.max_length {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "password policy {}: PASSWORD_MIN_LENGTH must be between 1 and PASSWORD_MAX_LENGTH {}",
+            policy.name, policy.max_length
+        )));
+    }
+    if policy.max_length > DEFAULT_PASSWORD_MAX_LENGTH {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "password policy {}: PASSWORD_MAX_LENGTH can not be greater than {}",
+            policy.name, DEFAULT_PASSWORD_MAX_LENGTH
+        )));
+    }
+    let min_chars = policy.min_upper_case_chars
+        + policy.min_lower_case_chars
+        + policy.min_numeric_chars
+        + policy.min_special_chars;
+    if min_chars > policy.max_length {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "password policy {}: the sum of the minimum characters {} can not be greater than PASSWORD_MAX_LENGTH {}",
+            policy.name, min_chars, policy.max_length
+        )));
+    }
+    if policy.min_age_days > policy.max_age_days && policy.max_age_days > 0 {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "password policy {}: PASSWORD_MIN_AGE_DAYS can not be greater than PASSWORD_MAX_AGE_DAYS {}",
+            policy.name, policy.max_age_days
+        )));
+    }
+    if policy.history > MAX_PASSWORD_HISTORY {
+        return Err(ErrorCode::IllegalPasswordPolicy(format!(
+            "password policy {}: PASSWORD_HISTORY can not be greater than {}",
+            policy.name, MAX_PASSWORD_HISTORY
+        )));
+    }
+    Ok(())
+}
+
+/// Checks the length and the characters of a password by the password policy.
+pub fn check_password_complexity(policy: &PasswordPolicy, password: &str) -> Result<()> {
+    let length = password.chars().count() as u64;
+    if length < policy.min_length || (policy.max_length > 0 && length > policy.max_length) {
+        return Err(ErrorCode::InvalidPassword(format!(
+            "password length must be between {} and {}, got {}",
+            policy.min_length, policy.max_length, length
+        )));
+    }
+
+    let count = |f: fn(&char) -> bool| password.chars().filter(f).count() as u64;
+    let checks = [
+        (
+            count(char::is_ascii_uppercase),
+            policy.min_upper_case_chars,
+            "upper case",
+        ),
+        (
+            count(char::is_ascii_lowercase),
+            policy.min_lower_case_chars,
+            "lower case",
+        ),
+        (
+            count(char::is_ascii_digit),
+            policy.min_numeric_chars,
+            "numeric",
+        ),
+        (
+            count(|c| !c.is_ascii_alphanumeric()),
+            policy.min_special_chars,
+            "special",
+        ),
+    ];
+    for (got, want, kind) in checks {
+        if got < want {
+            return Err(ErrorCode::InvalidPassword(format!(
+                "password must contain at least {} {} characters, got {}",
+                want, kind, got
+            )));
+        }
+    }
+    Ok(())
+}
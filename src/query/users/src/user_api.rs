@@ -24,6 +24,8 @@ use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
 use common_management::NetworkPolicyApi;
 use common_management::NetworkPolicyMgr;
+use common_management::PasswordPolicyApi;
+use common_management::PasswordPolicyMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
@@ -36,6 +38,8 @@ use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
 use common_management::UserMgr;
+use common_management::WorkloadGroupApi;
+use common_management::WorkloadGroupMgr;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::tenant::TenantQuota;
 use common_meta_kvapi::kvapi;
@@ -139,6 +143,26 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_password_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl PasswordPolicyApi>> {
+        Ok(Arc::new(PasswordPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_workload_group_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl WorkloadGroupApi>> {
+        Ok(Arc::new(WorkloadGroupMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -24,6 +24,7 @@ use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserQuota;
 use common_meta_types::MatchSeq;
 
 use crate::role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
@@ -140,6 +141,14 @@ impl UserApiProvider {
                 )));
             }
         }
+        if let Some(name) = user_info.option.password_policy() {
+            if self.get_password_policy(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownPasswordPolicy(format!(
+                    "password policy `{}` is not exist",
+                    name
+                )));
+            }
+        }
         if self.get_configured_user(&user_info.name).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
                 "Same name with configured user `{}`",
@@ -250,6 +259,35 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role from user)"))
     }
 
+    // Grant and revoke the roles of a user at once, such as the roles mapped from the LDAP groups.
+    #[async_backtrace::framed]
+    pub async fn update_user_roles(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        grant_roles: Vec<String>,
+        revoke_roles: Vec<String>,
+    ) -> Result<Option<u64>> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Err(ErrorCode::UserAlreadyExists(format!(
+                "Cannot update roles of configured user `{}`",
+                user.username
+            )));
+        }
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| {
+                for role in &revoke_roles {
+                    ui.grants.revoke_role(role);
+                }
+                for role in grant_roles {
+                    ui.grants.grant_role(role);
+                }
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while update roles of user)"))
+    }
+
     // Drop a user by name and hostname.
     #[async_backtrace::framed]
     pub async fn drop_user(&self, tenant: &str, user: UserIdentity, if_exists: bool) -> Result<()> {
@@ -291,6 +329,14 @@ impl UserApiProvider {
                     )));
                 }
             }
+            if let Some(name) = user_option.password_policy() {
+                if self.get_password_policy(tenant, name).await.is_err() {
+                    return Err(ErrorCode::UnknownPasswordPolicy(format!(
+                        "password policy `{}` is not exist",
+                        name
+                    )));
+                }
+            }
         }
         if self.get_configured_user(&user.username).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
@@ -324,4 +370,29 @@ impl UserApiProvider {
         self.update_user(tenant, user, None, Some(user_info.option))
             .await
     }
+
+    // Update an user's quota
+    #[async_backtrace::framed]
+    pub async fn update_user_quota(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        quota: UserQuota,
+    ) -> Result<Option<u64>> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Err(ErrorCode::UserAlreadyExists(format!(
+                "Configured user `{}` cannot be updated",
+                user.username
+            )));
+        }
+        let client = self.get_user_api_client(tenant)?;
+        let update_user = client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| ui.quota = quota)
+            .await;
+
+        match update_user {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while alter user).")),
+        }
+    }
 }
@@ -16,8 +16,12 @@ use base64::engine::general_purpose;
 use base64::prelude::*;
 use common_base::base::tokio;
 use common_exception::Result;
+use common_users::CustomClaims;
 use common_users::JwtAuthenticator;
+use common_users::JwtConfig;
 use jwt_simple::prelude::*;
+use wiremock::matchers::basic_auth;
+use wiremock::matchers::body_string_contains;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
 use wiremock::Mock;
@@ -53,7 +57,7 @@ async fn test_parse_non_custom_claim() -> Result<()> {
         .mount(&server)
         .await;
     let first_url = format!("http://{}{}", server.address(), json_path);
-    let auth = JwtAuthenticator::create(first_url, vec![]).unwrap();
+    let auth = JwtAuthenticator::create(first_url, vec![], &JwtConfig::default())?.unwrap();
     let user_name = "test-user2";
     let my_additional_data = MyAdditionalData {
         user_is_admin: false,
@@ -67,3 +71,161 @@ async fn test_parse_non_custom_claim() -> Result<()> {
     assert_eq!(res.custom.role, None);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_claims_with_issuer_rules() -> Result<()> {
+    let (pair1, pbkey1) = get_jwks_file_rs256("test_kid");
+    let template1 = ResponseTemplate::new(200).set_body_raw(pbkey1, "application/json");
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template1)
+        .expect(1..)
+        .mount(&server)
+        .await;
+    let first_url = format!("http://{}{}", server.address(), json_path);
+    let config = JwtConfig {
+        issuer_audiences: vec!["idp=databend".to_string(), "idp=warehouse".to_string()],
+        issuer_required_claims: vec!["idp=groups".to_string()],
+        group_claim: "groups".to_string(),
+        group_role_mapping: vec![
+            "analysts:reader".to_string(),
+            "admins:writer".to_string(),
+            "admins:reader".to_string(),
+        ],
+        ..Default::default()
+    };
+    let auth = JwtAuthenticator::create(first_url, vec![], &config)?.unwrap();
+    assert_eq!(auth.mapped_roles(), vec!["reader", "writer"]);
+
+    let sign = |issuer: &str, audience: &str, groups: Option<serde_json::Value>| {
+        let mut custom = CustomClaims::new();
+        if let Some(groups) = groups {
+            custom = custom.with_claim("groups", groups);
+        }
+        let claims = Claims::with_custom_claims(custom, Duration::from_hours(2))
+            .with_subject("test-user")
+            .with_issuer(issuer)
+            .with_audience(audience);
+        pair1.sign(claims)
+    };
+
+    // the groups are mapped in the order of the mapping.
+    let token = sign(
+        "idp",
+        "warehouse",
+        Some(serde_json::json!(["admins", "others"])),
+    )?;
+    let claims = auth.parse_jwt_claims(&token).await?;
+    assert_eq!(auth.map_roles(&claims), vec!["writer", "reader"]);
+
+    let token = sign("idp", "databend", Some(serde_json::json!("analysts")))?;
+    let claims = auth.parse_jwt_claims(&token).await?;
+    assert_eq!(auth.map_roles(&claims), vec!["reader"]);
+
+    // the audience is not allowed for the issuer.
+    let token = sign("idp", "other", Some(serde_json::json!(["admins"])))?;
+    let err = auth.parse_jwt_claims(&token).await.unwrap_err();
+    assert!(err.message().contains("audience"), "{}", err.message());
+
+    // the required claim is missing.
+    let token = sign("idp", "databend", None)?;
+    let err = auth.parse_jwt_claims(&token).await.unwrap_err();
+    assert!(err.message().contains("groups"), "{}", err.message());
+
+    // the tokens of the other issuers are rejected.
+    let token = sign("other-idp", "other", None)?;
+    let err = auth.parse_jwt_claims(&token).await.unwrap_err();
+    assert!(err.message().contains("other-idp"), "{}", err.message());
+
+    // so are the tokens without issuer.
+    let claims = Claims::with_custom_claims(CustomClaims::new(), Duration::from_hours(2))
+        .with_subject("test-user")
+        .with_audience("databend");
+    let token = pair1.sign(claims)?;
+    let err = auth.parse_jwt_claims(&token).await.unwrap_err();
+    assert!(err.message().contains("issuer"), "{}", err.message());
+    Ok(())
+}
+
+#[test]
+fn test_invalid_jwt_config() -> Result<()> {
+    let config = JwtConfig {
+        issuer_audiences: vec!["databend".to_string()],
+        ..Default::default()
+    };
+    assert!(
+        JwtAuthenticator::create("http://localhost/jwks.json".to_string(), vec![], &config)
+            .is_err()
+    );
+
+    let config = JwtConfig {
+        group_role_mapping: vec!["analysts:reader".to_string()],
+        ..Default::default()
+    };
+    assert!(
+        JwtAuthenticator::create("http://localhost/jwks.json".to_string(), vec![], &config)
+            .is_err()
+    );
+
+    assert!(JwtAuthenticator::create("".to_string(), vec![], &JwtConfig::default())?.is_none());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_introspect_opaque_token() -> Result<()> {
+    let server = MockServer::start().await;
+    let introspect_path = "/oauth2/introspect";
+    let expires_at = Clock::now_since_epoch().as_secs() + 3600;
+    Mock::given(method("POST"))
+        .and(path(introspect_path))
+        .and(basic_auth("databend", "secret"))
+        .and(body_string_contains("token=active-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "active": true,
+            "username": "test-user",
+            "iss": "idp",
+            "aud": "databend",
+            "exp": expires_at,
+            "groups": ["analysts"],
+        })))
+        // the result of an active token is cached.
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(introspect_path))
+        .and(body_string_contains("token=revoked-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "active": false,
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let config = JwtConfig {
+        issuer_audiences: vec!["idp=databend".to_string()],
+        group_claim: "groups".to_string(),
+        group_role_mapping: vec!["analysts:reader".to_string()],
+        introspection_url: format!("http://{}{}", server.address(), introspect_path),
+        client_id: "databend".to_string(),
+        client_secret: "secret".to_string(),
+        ..Default::default()
+    };
+    let auth = JwtAuthenticator::create("".to_string(), vec![], &config)?.unwrap();
+
+    for _ in 0..2 {
+        let claims = auth.parse_jwt_claims("active-token").await?;
+        assert_eq!(claims.subject.as_deref(), Some("test-user"));
+        assert_eq!(auth.map_roles(&claims), vec!["reader"]);
+    }
+
+    // the inactive tokens are not cached.
+    for _ in 0..2 {
+        let err = auth.parse_jwt_claims("revoked-token").await.unwrap_err();
+        assert!(err.message().contains("not active"), "{}", err.message());
+    }
+    server.verify().await;
+    Ok(())
+}
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_users::LdapAuthenticator;
+use common_users::LdapConfig;
+
+fn ldap_config(group_role_mapping: &[&str]) -> LdapConfig {
+    LdapConfig {
+        url: "ldap://127.0.0.1:389".to_string(),
+        allow_insecure: true,
+        base_dn: "dc=example,dc=org".to_string(),
+        group_role_mapping: group_role_mapping.iter().map(|s| s.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_ldap_authenticator_create() -> Result<()> {
+    // not configured
+    assert!(LdapAuthenticator::create(&LdapConfig::default())?.is_none());
+    assert!(LdapAuthenticator::create(&ldap_config(&[]))?.is_some());
+
+    let mut config = ldap_config(&[]);
+    config.url = "ldaps://ldap.example.org".to_string();
+    assert!(LdapAuthenticator::create(&config)?.is_some());
+
+    for url in [
+        "http://ldap.example.org",
+        "ldap://",
+        "ldap://ldap.example.org:x",
+    ] {
+        let mut config = ldap_config(&[]);
+        config.url = url.to_string();
+        let err = LdapAuthenticator::create(&config).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::INVALID_CONFIG, "{}", url);
+    }
+
+    // the passwords are not sent in cleartext unless it is allowed.
+    let mut config = ldap_config(&[]);
+    config.allow_insecure = false;
+    let err = LdapAuthenticator::create(&config).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+    config.start_tls = true;
+    assert!(LdapAuthenticator::create(&config)?.is_some());
+    config.url = "ldaps://ldap.example.org".to_string();
+    let err = LdapAuthenticator::create(&config).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+
+    for filter in ["(uid={username}", "(=x)", "(uid={username}))"] {
+        let mut config = ldap_config(&[]);
+        config.user_filter = filter.to_string();
+        let err = LdapAuthenticator::create(&config).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::INVALID_CONFIG, "{}", filter);
+    }
+
+    let mut config = ldap_config(&[]);
+    config.user_filter = "(&(objectClass=user)(|(sAMAccountName={username})(mail=*)))".to_string();
+    config.group_filter = "(&(objectClass=groupOfNames)(member={dn}))".to_string();
+    assert!(LdapAuthenticator::create(&config)?.is_some());
+
+    let err = LdapAuthenticator::create(&ldap_config(&["analysts"]))
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+    Ok(())
+}
+
+#[test]
+fn test_ldap_map_roles() -> Result<()> {
+    let auth = LdapAuthenticator::create(&ldap_config(&[
+        "analysts:analyst",
+        "cn=admins,ou=groups,dc=example,dc=org:admin",
+        "Analysts:reader",
+    ]))?
+    .unwrap();
+
+    assert_eq!(auth.mapped_roles(), vec!["admin", "analyst", "reader"]);
+
+    // by the value of the first RDN, case insensitive
+    let roles = auth.map_roles(&["CN=Analysts,OU=Groups,DC=example,DC=org".to_string()]);
+    assert_eq!(roles, vec!["analyst", "reader"]);
+
+    // by the dn
+    let roles = auth.map_roles(&["cn=admins,ou=groups,dc=example,dc=org".to_string()]);
+    assert_eq!(roles, vec!["admin"]);
+
+    // the dn of another admins group
+    let roles = auth.map_roles(&["cn=admins,ou=others,dc=example,dc=org".to_string()]);
+    assert!(roles.is_empty());
+
+    let roles = auth.map_roles(&[]);
+    assert!(roles.is_empty());
+    Ok(())
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod jwt;
+mod ldap;
+mod password_policy;
 mod role_cache_mgr;
 mod role_mgr;
 mod role_util;
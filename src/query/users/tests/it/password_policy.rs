@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::DEFAULT_PASSWORD_MAX_LENGTH;
+use common_meta_app::principal::MAX_PASSWORD_HISTORY;
+use common_users::check_password_complexity;
+use common_users::check_password_policy;
+
+fn policy() -> PasswordPolicy {
+    PasswordPolicy {
+        name: "p1".to_string(),
+        min_length: 8,
+        max_length: 16,
+        min_upper_case_chars: 1,
+        min_lower_case_chars: 1,
+        min_numeric_chars: 1,
+        min_special_chars: 1,
+        min_age_days: 0,
+        max_age_days: 90,
+        max_retries: 5,
+        lockout_time_mins: 15,
+        history: 0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_check_password_policy() -> Result<()> {
+    check_password_policy(&policy())?;
+
+    let cases: Vec<(&str, fn(&mut PasswordPolicy))> = vec![
+        ("zero min length", |p| p.min_length = 0),
+        ("min length above max length", |p| p.min_length = 17),
+        ("max length above the limit", |p| {
+            p.max_length = DEFAULT_PASSWORD_MAX_LENGTH + 1
+        }),
+        ("too many minimum characters", |p| {
+            p.min_upper_case_chars = 10;
+            p.min_lower_case_chars = 10;
+        }),
+        ("min age above max age", |p| p.min_age_days = 91),
+        ("history above the limit", |p| {
+            p.history = MAX_PASSWORD_HISTORY + 1
+        }),
+    ];
+    for (name, f) in cases {
+        let mut policy = policy();
+        f(&mut policy);
+        let err = check_password_policy(&policy).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::ILLEGAL_PASSWORD_POLICY, "{name}");
+    }
+
+    // a min age is allowed if the password never expires.
+    let mut policy = policy();
+    policy.min_age_days = 100;
+    policy.max_age_days = 0;
+    check_password_policy(&policy)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_check_password_complexity() -> Result<()> {
+    let policy = policy();
+    check_password_complexity(&policy, "Abcdef1!")?;
+    check_password_complexity(&policy, "Abcdefghijklm1!_")?;
+
+    let cases = [
+        ("Abcde1!", "length"),
+        ("Abcdefghijklmn1!_", "length"),
+        ("abcdef1!", "upper case"),
+        ("ABCDEF1!", "lower case"),
+        ("Abcdefg!", "numeric"),
+        ("Abcdefg1", "special"),
+    ];
+    for (password, kind) in cases {
+        let err = check_password_complexity(&policy, password).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::INVALID_PASSWORD, "{password}");
+        assert!(
+            err.message().contains(kind),
+            "{password}: {}",
+            err.message()
+        );
+    }
+
+    // the length is counted in characters rather than bytes.
+    check_password_complexity(&policy, "Abcdéf1!")?;
+
+    Ok(())
+}
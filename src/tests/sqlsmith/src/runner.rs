@@ -118,7 +118,7 @@ impl Runner {
 
             let table_name = create_table_stmt.table.name.clone();
             let mut fields = Vec::new();
-            if let CreateTableSource::Columns(columns) = create_table_stmt.source.unwrap() {
+            if let CreateTableSource::Columns(columns, _) = create_table_stmt.source.unwrap() {
                 for column in columns {
                     let not_null = match column.nullable_constraint {
                         Some(NullableConstraint::NotNull) => true,
@@ -78,6 +78,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 engine: Some(Engine::Fuse),
                 uri_location: None,
                 cluster_by: vec![],
+                partition_by: vec![],
                 table_options: BTreeMap::new(),
                 as_query: None,
                 transient: false,
@@ -180,6 +181,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             expr: None,
             comment: None,
             nullable_constraint,
+            codecs: vec![],
         }
     }
 
@@ -197,9 +199,10 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 expr: None,
                 comment: None,
                 nullable_constraint,
+                codecs: vec![],
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs)
+        CreateTableSource::Columns(column_defs, vec![])
     }
 }
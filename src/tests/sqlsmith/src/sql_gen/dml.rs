@@ -453,6 +453,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                     expr: None,
                     comment: None,
                     nullable_constraint,
+                    codecs: vec![],
                 };
                 (
                     AlterTableAction::ModifyColumn {
@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0005
+echo "create table t12_0005(c int)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(1),(2)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(3)" | $BENDSQL_CLIENT_CONNECT
+
+## Get the snapshot id of the first insertion
+SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default','t12_0005') where row_count=2" | $BENDSQL_CLIENT_CONNECT)
+
+echo "clone the table at the snapshot of the first insertion, which should contain 2 rows"
+echo "create table t12_0005_clone clone t12_0005 at (snapshot => '$SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+echo "select count(*) from t12_0005_clone" | $BENDSQL_CLIENT_CONNECT
+
+echo "the source table is not changed"
+echo "select count(*) from t12_0005" | $BENDSQL_CLIENT_CONNECT
+
+echo "the cloned table has its own history"
+echo "select count(*) from fuse_snapshot('default','t12_0005_clone')" | $BENDSQL_CLIENT_CONNECT
+
+echo "clone the table at snapshot that not exist should report error 2013"
+echo "create table t12_0005_err clone t12_0005 at (snapshot => 'NOTE_EXIST')" | $BENDSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table t12_0005_clone" | $BENDSQL_CLIENT_CONNECT
+echo "drop table t12_0005" | $BENDSQL_CLIENT_CONNECT